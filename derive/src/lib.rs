@@ -0,0 +1,350 @@
+//! Derive macros for the `p-token` program.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Expr, Fields, Ident};
+
+/// Constraints of an account field.
+#[derive(Default)]
+struct AccountConstraints {
+    /// Whether the account must be a signer.
+    signer: bool,
+
+    /// Whether the account must be writable.
+    writable: bool,
+
+    /// Expected owner of the account.
+    owner: Option<Expr>,
+
+    /// Expected address of the account.
+    address: Option<Expr>,
+
+    /// Whether the field receives the remaining accounts.
+    remaining: bool,
+}
+
+/// Derives `TryFrom<&[AccountInfo]>` for a struct of `&AccountInfo` fields.
+///
+/// The accounts are assigned to the fields in order and each field is validated
+/// according to its `#[account(...)]` attribute:
+///
+/// - `signer`: the account must be a signer.
+/// - `writable`: the account must be writable.
+///
+/// The signer, writable and owner checks fail with the `MissingSigner`,
+/// `NotWritable` and `OwnerMismatch` errors of `crate::error::ExtensionError`, which
+/// the deriving crate must define.
+/// - `owner = <expr>`: the account must be owned by the program.
+/// - `address = <expr>`: the account address must match.
+/// - `remaining`: the field receives the remaining accounts; it must be the last
+///   field.
+#[proc_macro_derive(Accounts, attributes(account))]
+pub fn derive_accounts(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_accounts(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_accounts(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+
+    let lifetime = input
+        .generics
+        .lifetimes()
+        .next()
+        .map(|lifetime| lifetime.lifetime.clone())
+        .ok_or_else(|| Error::new(input.generics.span(), "expected a lifetime parameter"))?;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new(input.span(), "expected named fields")),
+        },
+        _ => return Err(Error::new(input.span(), "expected a struct")),
+    };
+
+    let mut accounts = Vec::new();
+    let mut remaining = None;
+    let mut checks = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let ident = field.ident.clone().expect("named field");
+        let constraints = parse_constraints(field)?;
+
+        if constraints.remaining {
+            if index != fields.len() - 1 {
+                return Err(Error::new(
+                    field.span(),
+                    "the remaining accounts must be the last field",
+                ));
+            }
+
+            remaining = Some(ident);
+            continue;
+        }
+
+        checks.push(expand_checks(&ident, &constraints));
+        accounts.push(ident);
+    }
+
+    let remaining_pattern = match &remaining {
+        Some(remaining) => quote!(#remaining @ ..),
+        None => quote!(..),
+    };
+    let field_names = accounts.iter().chain(remaining.iter());
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::TryFrom<&#lifetime [::pinocchio::account_info::AccountInfo]>
+            for #name #ty_generics #where_clause
+        {
+            type Error = ::pinocchio::program_error::ProgramError;
+
+            #[inline(always)]
+            fn try_from(
+                accounts: &#lifetime [::pinocchio::account_info::AccountInfo],
+            ) -> ::core::result::Result<Self, Self::Error> {
+                let [#(#accounts,)* #remaining_pattern] = accounts else {
+                    return Err(::pinocchio::program_error::ProgramError::NotEnoughAccountKeys);
+                };
+
+                #(#checks)*
+
+                Ok(Self { #(#field_names),* })
+            }
+        }
+    })
+}
+
+/// Parses the `#[account(...)]` attributes of a field.
+fn parse_constraints(field: &syn::Field) -> Result<AccountConstraints, Error> {
+    let mut constraints = AccountConstraints::default();
+
+    for attribute in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("account"))
+    {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("signer") {
+                constraints.signer = true;
+            } else if meta.path.is_ident("writable") {
+                constraints.writable = true;
+            } else if meta.path.is_ident("remaining") {
+                constraints.remaining = true;
+            } else if meta.path.is_ident("owner") {
+                constraints.owner = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("address") {
+                constraints.address = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("unsupported account constraint"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    let is_constrained = constraints.signer
+        || constraints.writable
+        || constraints.owner.is_some()
+        || constraints.address.is_some();
+
+    if constraints.remaining && is_constrained {
+        return Err(Error::new(
+            field.span(),
+            "the remaining accounts cannot have constraints",
+        ));
+    }
+
+    Ok(constraints)
+}
+
+/// Expands the checks of the constraints of an account.
+fn expand_checks(ident: &Ident, constraints: &AccountConstraints) -> TokenStream2 {
+    let mut checks = TokenStream2::new();
+
+    if let Some(address) = &constraints.address {
+        checks.extend(quote! {
+            if #ident.key() != &#address {
+                return Err(::pinocchio::program_error::ProgramError::InvalidArgument);
+            }
+        });
+    }
+
+    if let Some(owner) = &constraints.owner {
+        checks.extend(quote! {
+            if #ident.owner() != &#owner {
+                return Err(crate::error::ExtensionError::OwnerMismatch.into());
+            }
+        });
+    }
+
+    if constraints.signer {
+        checks.extend(quote! {
+            if !#ident.is_signer() {
+                return Err(crate::error::ExtensionError::MissingSigner.into());
+            }
+        });
+    }
+
+    if constraints.writable {
+        checks.extend(quote! {
+            if !#ident.is_writable() {
+                return Err(crate::error::ExtensionError::NotWritable.into());
+            }
+        });
+    }
+
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn expands_checks_in_field_order() {
+        let input: DeriveInput = parse_quote! {
+            struct CancelAccounts<'a> {
+                #[account(writable, owner = PROGRAM_ID)]
+                state: &'a AccountInfo,
+
+                #[account(signer, address = AUTHORITY)]
+                authority: &'a AccountInfo,
+
+                #[account(remaining)]
+                remaining: &'a [AccountInfo],
+            }
+        };
+
+        let expected = quote! {
+            impl<'a> ::core::convert::TryFrom<&'a [::pinocchio::account_info::AccountInfo]>
+                for CancelAccounts<'a>
+            {
+                type Error = ::pinocchio::program_error::ProgramError;
+
+                #[inline(always)]
+                fn try_from(
+                    accounts: &'a [::pinocchio::account_info::AccountInfo],
+                ) -> ::core::result::Result<Self, Self::Error> {
+                    let [state, authority, remaining @ ..] = accounts else {
+                        return Err(::pinocchio::program_error::ProgramError::NotEnoughAccountKeys);
+                    };
+
+                    if state.owner() != &PROGRAM_ID {
+                        return Err(crate::error::ExtensionError::OwnerMismatch.into());
+                    }
+                    if !state.is_writable() {
+                        return Err(crate::error::ExtensionError::NotWritable.into());
+                    }
+                    if authority.key() != &AUTHORITY {
+                        return Err(::pinocchio::program_error::ProgramError::InvalidArgument);
+                    }
+                    if !authority.is_signer() {
+                        return Err(crate::error::ExtensionError::MissingSigner.into());
+                    }
+
+                    Ok(Self { state, authority, remaining })
+                }
+            }
+        };
+
+        assert_eq!(
+            expand_accounts(input).unwrap().to_string(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn ignores_extra_accounts_without_remaining_field() {
+        let input: DeriveInput = parse_quote! {
+            struct CloseAccounts<'a> {
+                account: &'a AccountInfo,
+            }
+        };
+
+        let expansion = expand_accounts(input).unwrap().to_string();
+
+        assert!(expansion.contains(&quote!(let [account, ..] = accounts).to_string()));
+        assert!(expansion.contains(&quote!(Ok(Self { account })).to_string()));
+    }
+
+    #[test]
+    fn rejects_missing_lifetime() {
+        let input: DeriveInput = parse_quote! {
+            struct Accounts {
+                account: AccountInfo,
+            }
+        };
+
+        let error = expand_accounts(input).unwrap_err();
+
+        assert_eq!(error.to_string(), "expected a lifetime parameter");
+    }
+
+    #[test]
+    fn rejects_tuple_struct() {
+        let input: DeriveInput = parse_quote! {
+            struct Accounts<'a>(&'a AccountInfo);
+        };
+
+        let error = expand_accounts(input).unwrap_err();
+
+        assert_eq!(error.to_string(), "expected named fields");
+    }
+
+    #[test]
+    fn rejects_unsupported_constraint() {
+        let input: DeriveInput = parse_quote! {
+            struct Accounts<'a> {
+                #[account(mutable)]
+                account: &'a AccountInfo,
+            }
+        };
+
+        let error = expand_accounts(input).unwrap_err();
+
+        assert_eq!(error.to_string(), "unsupported account constraint");
+    }
+
+    #[test]
+    fn rejects_remaining_before_last_field() {
+        let input: DeriveInput = parse_quote! {
+            struct Accounts<'a> {
+                #[account(remaining)]
+                remaining: &'a [AccountInfo],
+
+                account: &'a AccountInfo,
+            }
+        };
+
+        let error = expand_accounts(input).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "the remaining accounts must be the last field"
+        );
+    }
+
+    #[test]
+    fn rejects_constrained_remaining() {
+        let input: DeriveInput = parse_quote! {
+            struct Accounts<'a> {
+                #[account(remaining, signer)]
+                remaining: &'a [AccountInfo],
+            }
+        };
+
+        let error = expand_accounts(input).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "the remaining accounts cannot have constraints"
+        );
+    }
+}
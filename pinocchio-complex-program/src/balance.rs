@@ -0,0 +1,155 @@
+//! Balance snapshots checking the effect of a CPI.
+//!
+//! The amount of a token account, or the lamports of an account, is read before a
+//! CPI and read again afterwards, so a program moving fewer tokens or lamports
+//! than expected fails the instruction instead of completing it silently.
+//!
+//! The token accounts are read with the layout shared by SPL Token and
+//! Token-2022, so they can belong to either program.
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::error::ExtensionError;
+
+/// Offset of the amount in the token account layout shared by SPL Token and
+/// Token-2022.
+const AMOUNT_OFFSET: usize = 64;
+
+/// Offset of the state in the token account layout shared by SPL Token and
+/// Token-2022.
+const STATE_OFFSET: usize = 108;
+
+/// Value of the state of a frozen token account.
+const FROZEN_STATE: u8 = 2;
+
+/// Snapshot of the amount of a token account.
+pub struct TokenBalance<'a> {
+    /// Token account.
+    account_info: &'a AccountInfo,
+
+    /// Amount of the token account when the snapshot was taken.
+    amount: u64,
+}
+
+impl<'a> TokenBalance<'a> {
+    /// Takes a snapshot of the amount of a token account.
+    #[inline(always)]
+    pub fn snapshot(account_info: &'a AccountInfo) -> Result<Self, ProgramError> {
+        Ok(Self {
+            account_info,
+            amount: read_amount(account_info)?,
+        })
+    }
+
+    /// Returns the increase of the amount since the snapshot was taken.
+    ///
+    /// A decrease of the amount fails with `BalanceDecreased`.
+    #[inline(always)]
+    pub fn increase(&self) -> Result<u64, ProgramError> {
+        read_amount(self.account_info)?
+            .checked_sub(self.amount)
+            .ok_or(ExtensionError::BalanceDecreased.into())
+    }
+}
+
+/// Snapshot of the lamports of an account.
+pub struct LamportBalance<'a> {
+    /// Account.
+    account_info: &'a AccountInfo,
+
+    /// Lamports of the account when the snapshot was taken.
+    lamports: u64,
+}
+
+impl<'a> LamportBalance<'a> {
+    /// Takes a snapshot of the lamports of an account.
+    #[inline(always)]
+    pub fn snapshot(account_info: &'a AccountInfo) -> Self {
+        Self {
+            account_info,
+            lamports: account_info.lamports(),
+        }
+    }
+
+    /// Checks that the lamports increased by exactly `amount` since the snapshot was
+    /// taken.
+    #[inline(always)]
+    pub fn assert_increase(&self, amount: u64) -> ProgramResult {
+        check_balance(
+            self.account_info.lamports(),
+            self.lamports.checked_add(amount),
+        )
+    }
+}
+
+/// Checks that the current balance is the expected balance.
+///
+/// An expected balance of `None`, which overflowed, never matches.
+#[inline(always)]
+fn check_balance(balance: u64, expected: Option<u64>) -> ProgramResult {
+    if Some(balance) != expected {
+        return Err(ExtensionError::BalanceMismatch.into());
+    }
+
+    Ok(())
+}
+
+/// Checks whether a token account is frozen.
+///
+/// This is checked before a CPI moving the tokens of the account, so a frozen
+/// account fails with this program's `AccountFrozen` error instead of the error
+/// of the token program owning the account.
+#[inline(always)]
+pub fn is_frozen(account_info: &AccountInfo) -> Result<bool, ProgramError> {
+    let data = account_info.try_borrow_data()?;
+
+    data.get(STATE_OFFSET)
+        .map(|state| *state == FROZEN_STATE)
+        .ok_or(ExtensionError::MalformedAccountData.into())
+}
+
+/// Reads the amount of a token account.
+#[inline(always)]
+fn read_amount(account_info: &AccountInfo) -> Result<u64, ProgramError> {
+    let data = account_info.try_borrow_data()?;
+
+    data.get(AMOUNT_OFFSET..AMOUNT_OFFSET + 8)
+        .and_then(|amount| amount.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or(ExtensionError::MalformedAccountData.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_expected_balance() {
+        assert_eq!(check_balance(150, 100u64.checked_add(50)), Ok(()));
+        assert_eq!(check_balance(50, 100u64.checked_sub(50)), Ok(()));
+    }
+
+    #[test]
+    fn rejects_partial_change() {
+        assert_eq!(
+            check_balance(120, 100u64.checked_add(50)),
+            Err(ExtensionError::BalanceMismatch.into())
+        );
+        assert_eq!(
+            check_balance(100, 100u64.checked_sub(50)),
+            Err(ExtensionError::BalanceMismatch.into())
+        );
+    }
+
+    #[test]
+    fn rejects_overflowed_expectation() {
+        assert_eq!(
+            check_balance(u64::MAX, u64::MAX.checked_add(1)),
+            Err(ExtensionError::BalanceMismatch.into())
+        );
+        assert_eq!(
+            check_balance(0, 0u64.checked_sub(1)),
+            Err(ExtensionError::BalanceMismatch.into())
+        );
+    }
+}
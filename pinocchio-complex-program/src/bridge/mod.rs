@@ -0,0 +1,3 @@
+//! Helpers to post cross-chain messages through bridge programs.
+
+pub mod wormhole;
@@ -0,0 +1,152 @@
+//! Helpers to invoke the Wormhole core bridge program.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::{error::ExtensionError, system};
+
+/// Wormhole core bridge program (`worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth`)
+/// address.
+pub const ID: Pubkey = [
+    14, 10, 88, 154, 65, 165, 95, 189, 102, 197, 42, 71, 95, 45, 146, 166, 211, 220, 155, 71, 71,
+    17, 76, 185, 175, 130, 90, 152, 181, 69, 211, 206,
+];
+
+/// Maximum length of a message payload.
+pub const MAX_PAYLOAD_LEN: usize = 512;
+
+/// Length of the instruction data without the payload.
+const POST_MESSAGE_HEADER_LEN: usize = 10;
+
+/// Accounts of a posted message.
+pub struct PostMessageAccounts<'a> {
+    /// Bridge configuration, holding the message fee.
+    pub config: &'a AccountInfo,
+
+    /// Account receiving the posted message, signing for its creation.
+    pub message: &'a AccountInfo,
+
+    /// Emitter of the message.
+    pub emitter: &'a AccountInfo,
+
+    /// Sequence tracker of the emitter.
+    pub sequence: &'a AccountInfo,
+
+    /// Account paying for the message fee and the created accounts.
+    pub payer: &'a AccountInfo,
+
+    /// Account collecting the message fees.
+    pub fee_collector: &'a AccountInfo,
+
+    /// `Clock` sysvar.
+    pub clock: &'a AccountInfo,
+
+    /// `Rent` sysvar.
+    pub rent: &'a AccountInfo,
+
+    /// System program.
+    pub system_program: &'a AccountInfo,
+}
+
+/// Returns the fee of posting a message, read from the bridge configuration.
+#[inline(always)]
+pub fn message_fee(config: &AccountInfo) -> Result<u64, ProgramError> {
+    require_owner!(config, &ID);
+
+    // Bridge configuration layout:
+    // - [0..4]: guardian set index
+    // - [4..12]: last lamports
+    // - [12..16]: guardian set expiration time
+    // - [16..24]: fee
+    let data = config.try_borrow_data()?;
+
+    let fee = data
+        .get(16..24)
+        .ok_or(ExtensionError::MalformedAccountData)?
+        .try_into()
+        .map_err(|_error| ExtensionError::MalformedAccountData)?;
+
+    Ok(u64::from_le_bytes(fee))
+}
+
+/// Posts a message with `payload`, paying the message fee to the fee collector.
+///
+/// The `signers` are used to sign for derived `emitter`, `message` or `payer`
+/// addresses.
+#[inline(always)]
+pub fn post_message(
+    accounts: &PostMessageAccounts,
+    nonce: u32,
+    payload: &[u8],
+    consistency_level: u8,
+    signers: &[Signer],
+) -> ProgramResult {
+    if payload.len() > MAX_PAYLOAD_LEN {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Pays the message fee, which the bridge expects in the fee collector before
+    // the message is posted.
+
+    let fee = message_fee(accounts.config)?;
+
+    if fee > 0 {
+        system::transfer(accounts.payer, accounts.fee_collector, fee, signers)?;
+    }
+
+    // Posts the message.
+
+    let account_metas = [
+        AccountMeta::writable(accounts.config.key()),
+        AccountMeta::writable_signer(accounts.message.key()),
+        AccountMeta::readonly_signer(accounts.emitter.key()),
+        AccountMeta::writable(accounts.sequence.key()),
+        AccountMeta::writable_signer(accounts.payer.key()),
+        AccountMeta::writable(accounts.fee_collector.key()),
+        AccountMeta::readonly(accounts.clock.key()),
+        AccountMeta::readonly(accounts.rent.key()),
+        AccountMeta::readonly(accounts.system_program.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0]: discriminator (1)
+    // - [1..5]: nonce
+    // - [5..9]: payload length
+    // - [9..9+N]: payload
+    // - [9+N]: consistency level
+    let len = POST_MESSAGE_HEADER_LEN + payload.len();
+    let mut instruction_data = [0u8; POST_MESSAGE_HEADER_LEN + MAX_PAYLOAD_LEN];
+    instruction_data[0] = 1;
+    instruction_data[1..5].copy_from_slice(&nonce.to_le_bytes());
+    instruction_data[5..9].copy_from_slice(&(payload.len() as u32).to_le_bytes());
+    instruction_data[9..len - 1].copy_from_slice(payload);
+    instruction_data[len - 1] = consistency_level;
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data[..len],
+    };
+
+    invoke_signed(
+        &instruction,
+        &[
+            accounts.config,
+            accounts.message,
+            accounts.emitter,
+            accounts.sequence,
+            accounts.payer,
+            accounts.fee_collector,
+            accounts.clock,
+            accounts.rent,
+            accounts.system_program,
+        ],
+        signers,
+    )
+}
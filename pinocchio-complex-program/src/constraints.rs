@@ -0,0 +1,58 @@
+//! Account constraints shared by the processors.
+//!
+//! Each macro checks a constraint on an account and returns early with the error
+//! of the constraint when it is not met. The macros expand in place, so a check
+//! costs the same as the equivalent hand-written `if` statement.
+//!
+//! The errors returned are:
+//!
+//! - `require_signer!`: `ExtensionError::MissingSigner`
+//! - `require_writable!`: `ExtensionError::NotWritable`
+//! - `require_owner!`: `ExtensionError::OwnerMismatch`
+//! - `require_address!`: `InvalidArgument`, unless an error is provided
+
+/// Requires the account to be a signer of the instruction.
+macro_rules! require_signer {
+    ($account_info:expr) => {
+        if !$account_info.is_signer() {
+            return Err($crate::error::ExtensionError::MissingSigner.into());
+        }
+    };
+}
+
+/// Requires the account to be writable.
+macro_rules! require_writable {
+    ($account_info:expr) => {
+        if !$account_info.is_writable() {
+            return Err($crate::error::ExtensionError::NotWritable.into());
+        }
+    };
+}
+
+/// Requires the account to be owned by the `owner` program.
+macro_rules! require_owner {
+    ($account_info:expr, $owner:expr) => {
+        if $account_info.owner() != $owner {
+            return Err($crate::error::ExtensionError::OwnerMismatch.into());
+        }
+    };
+}
+
+/// Requires the account address to be `address`.
+///
+/// The error returned defaults to `InvalidArgument` and can be provided as a third
+/// argument, such as `IncorrectProgramId` when the account is a program.
+macro_rules! require_address {
+    ($account_info:expr, $address:expr) => {
+        require_address!(
+            $account_info,
+            $address,
+            pinocchio::program_error::ProgramError::InvalidArgument
+        )
+    };
+    ($account_info:expr, $address:expr, $error:expr) => {
+        if $account_info.key() != $address {
+            return Err($error.into());
+        }
+    };
+}
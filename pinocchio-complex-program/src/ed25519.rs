@@ -0,0 +1,75 @@
+//! Helpers to inspect Ed25519 program instructions.
+//!
+//! The Ed25519 program is a precompile: the runtime verifies the signatures of its
+//! instructions before the transaction executes, failing the transaction when any
+//! of them is invalid. A program can then rely on a signature by checking, through
+//! the `Instructions` sysvar, that the transaction includes the Ed25519 instruction
+//! verifying it.
+
+use pinocchio::pubkey::{Pubkey, PUBKEY_BYTES};
+
+use crate::sysvar::instructions::IntrospectedInstruction;
+
+/// Ed25519 program (`Ed25519SigVerify111111111111111111111111111`) address.
+pub const ID: Pubkey = [
+    3, 125, 70, 214, 124, 147, 251, 190, 18, 249, 66, 143, 131, 141, 64, 255, 5, 112, 116, 73, 39,
+    244, 138, 100, 252, 202, 112, 68, 128, 0, 0, 0,
+];
+
+/// Length of the header of the instruction data: the number of signatures
+/// followed by a padding byte.
+const HEADER_LEN: usize = 2;
+
+/// Length of the offsets of each signature.
+const OFFSETS_LEN: usize = 14;
+
+/// Instruction index referring to the data of the Ed25519 instruction itself.
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Indicates whether `instruction` is an Ed25519 program instruction verifying a
+/// single signature of `message` by `pubkey`.
+///
+/// The signature, public key and message must all be part of the instruction data,
+/// which is how the instruction is usually built.
+#[inline(always)]
+pub fn is_verification_of(
+    instruction: &IntrospectedInstruction,
+    pubkey: &Pubkey,
+    message: &[u8],
+) -> bool {
+    if instruction.program_id != &ID {
+        return false;
+    }
+
+    let data = instruction.data;
+
+    // Instruction data layout:
+    // -  [0]: number of signatures (u8)
+    // -  [1]: padding (u8)
+    // -  [2..4]: signature offset (u16)
+    // -  [4..6]: signature instruction index (u16)
+    // -  [6..8]: public key offset (u16)
+    // -  [8..10]: public key instruction index (u16)
+    // - [10..12]: message offset (u16)
+    // - [12..14]: message size (u16)
+    // - [14..16]: message instruction index (u16)
+    if data.len() < HEADER_LEN + OFFSETS_LEN || data[0] != 1 {
+        return false;
+    }
+
+    let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+
+    if read_u16(4) != CURRENT_INSTRUCTION
+        || read_u16(8) != CURRENT_INSTRUCTION
+        || read_u16(14) != CURRENT_INSTRUCTION
+    {
+        return false;
+    }
+
+    let pubkey_offset = read_u16(6) as usize;
+    let message_offset = read_u16(10) as usize;
+    let message_size = read_u16(12) as usize;
+
+    data.get(pubkey_offset..pubkey_offset + PUBKEY_BYTES) == Some(pubkey.as_ref())
+        && data.get(message_offset..message_offset + message_size) == Some(message)
+}
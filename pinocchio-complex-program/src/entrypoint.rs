@@ -1,18 +1,103 @@
 use pinocchio::{
-    account_info::AccountInfo, default_panic_handler, no_allocator, program_entrypoint,
-    program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+    account_info::AccountInfo, no_allocator, program_entrypoint, program_error::ProgramError,
+    pubkey::Pubkey, ProgramResult,
 };
 
-use crate::processor::*;
+use crate::{instruction::ProgramInstruction, processor::*};
 
+// The standard entrypoint is used instead of the lazy one: the instruction data
+// follows the accounts in the input, so `InstructionContext` only returns it once
+// every account has been read. The instruction cannot be decoded before its
+// accounts, and reading them all into a buffer has the same cost as this entrypoint.
 program_entrypoint!(process_instruction);
 // Do not allocate memory.
 no_allocator!();
-// Use the default panic handler.
-default_panic_handler!();
+// Use the default panic handler, which logs the panic message.
+#[cfg(not(feature = "minimal-runtime"))]
+pinocchio::default_panic_handler!();
+// Use the `no_std` panic handler, which aborts the program without formatting the
+// panic message, reducing the binary size.
+#[cfg(feature = "minimal-runtime")]
+pinocchio::nostd_panic_handler!();
+
+/// Processes an instruction, logging the error it fails with.
+#[inline(always)]
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let result = process_program_instruction(accounts, instruction_data);
+
+    #[cfg(feature = "logging")]
+    if let Err(error) = &result {
+        crate::logging::log_error(error);
+    }
+
+    result
+}
 
 /// Process an instruction.
 ///
+/// The instruction data is parsed into a `ProgramInstruction` before dispatching the
+/// instruction to its processor.
+///
+/// With the `logging` feature, the parameters and accounts of the instruction are
+/// logged before it is dispatched.
+///
+/// When the program config account is supplied as the last account, state-changing
+/// instructions fail while the program is paused. The config account is removed from
+/// the accounts before dispatching the instruction, except for the fee-charging
+/// instructions, which read the config as their first fee account and check the
+/// pause flag themselves.
+///
+/// With the `hooks` feature, the hooks are invoked before and after the instruction
+/// is processed.
+#[inline(always)]
+fn process_program_instruction(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let instruction = ProgramInstruction::unpack(instruction_data)?;
+
+    #[cfg(feature = "logging")]
+    crate::logging::log_instruction(&instruction, accounts);
+
+    // Read-only instructions and config instructions (so the program can be
+    // unpaused) are not subject to the pause flag. The fee-charging instructions
+    // (AMM `Swap`, OTC `TakeOffer` and splitter `Distribute`) keep the config
+    // account, since it is the first of their fee accounts.
+    let accounts = match instruction {
+        ProgramInstruction::GetAccountDataSize
+        | ProgramInstruction::AmountToUiAmount { .. }
+        | ProgramInstruction::UiAmountToAmount { .. }
+        | ProgramInstruction::GetTokenBalance
+        | ProgramInstruction::Config(_)
+        | ProgramInstruction::Amm([3, ..])
+        | ProgramInstruction::Otc([1, ..])
+        | ProgramInstruction::Splitter([2, ..]) => accounts,
+        _ => config::check_paused(accounts)?,
+    };
+
+    #[cfg(not(feature = "hooks"))]
+    let dispatch = dispatch_instruction;
+    #[cfg(feature = "hooks")]
+    let dispatch = dispatch_with_hooks;
+
+    dispatch(accounts, instruction)
+}
+
+/// Dispatches an instruction to its processor, invoking the hooks before and after
+/// it.
+#[cfg(feature = "hooks")]
+#[inline(always)]
+fn dispatch_with_hooks(accounts: &[AccountInfo], instruction: ProgramInstruction) -> ProgramResult {
+    crate::hooks::before(&instruction, accounts)?;
+
+    let result = dispatch_instruction(accounts, instruction.clone());
+
+    crate::hooks::after(&instruction, accounts, result)
+}
+
+/// Dispatches an instruction to its processor.
+///
 /// The processor of the token program is divided into two parts to reduce the overhead
 /// of having a large `match` statement. The first part of the processor handles the
 /// most common instructions, while the second part handles the remaining instructions.
@@ -28,206 +113,529 @@ default_panic_handler!();
 /// - `18`: `InitializeAccount3`
 /// - `20`: `InitializeMint2`
 #[inline(always)]
-pub fn process_instruction(
-    _program_id: &Pubkey,
+fn dispatch_instruction(
     accounts: &[AccountInfo],
-    instruction_data: &[u8],
+    instruction: ProgramInstruction,
 ) -> ProgramResult {
-    let (discriminator, instruction_data) = instruction_data
-        .split_first()
-        .ok_or(ProgramError::InvalidInstructionData)?;
-
-    match *discriminator {
+    match instruction {
         // 0 - InitializeMint
-        0 => {
+        ProgramInstruction::InitializeMint {
+            decimals,
+            mint_authority,
+            freeze_authority,
+        } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeMint");
 
-            process_initialize_mint(accounts, instruction_data, true)
+            process_initialize_mint(accounts, decimals, mint_authority, freeze_authority, true)
         }
 
         // 3 - Transfer
-        3 => {
+        ProgramInstruction::Transfer { amount } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: Transfer");
 
-            process_transfer(accounts, instruction_data)
+            process_transfer(accounts, amount)
         }
         // 7 - MintTo
-        7 => {
+        ProgramInstruction::MintTo { amount } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: MintTo");
 
-            process_mint_to(accounts, instruction_data)
+            process_mint_to(accounts, amount)
         }
         // 9 - CloseAccount
-        9 => {
+        ProgramInstruction::CloseAccount => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: CloseAccount");
 
             process_close_account(accounts)
         }
         // 18 - InitializeAccount3
-        18 => {
+        ProgramInstruction::InitializeAccount3 { owner } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeAccount3");
 
-            process_initialize_account3(accounts, instruction_data)
+            process_initialize_account3(accounts, owner)
         }
         // 20 - InitializeMint2
-        20 => {
+        ProgramInstruction::InitializeMint2 {
+            decimals,
+            mint_authority,
+            freeze_authority,
+        } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeMint2");
 
-            process_initialize_mint2(accounts, instruction_data)
+            process_initialize_mint2(accounts, decimals, mint_authority, freeze_authority)
         }
-        _ => process_remaining_instruction(accounts, instruction_data, *discriminator),
+        _ => process_remaining_instruction(accounts, instruction),
     }
 }
 
 /// Process the remaining instructions.
 ///
-/// This function is called by the `process_instruction` function if the discriminator
+/// This function is called by the `process_program_instruction` function if the discriminator
 /// does not match any of the common instructions. This function is used to reduce the
-/// overhead of having a large `match` statement in the `process_instruction` function.
+/// overhead of having a large `match` statement in the `process_program_instruction`
+/// function.
+///
+/// Extension instructions use discriminators starting at `100`. Each extension
+/// discriminator is followed by a second byte selecting the instruction within the
+/// extension:
+///
+/// - `100`: AMM
+/// - `101`: Staking
+/// - `102`: Multisig wallet
+/// - `103`: Timelock
+/// - `104`: Config
+/// - `105`: Transfer gate
+/// - `106`: Vault
+/// - `107`: Auction
+/// - `108`: Dutch auction
+/// - `109`: Raffle
+/// - `110`: Campaign
+/// - `111`: Subscription
+/// - `112`: Splitter
+/// - `113`: Royalty
+/// - `114`: Faucet
+/// - `115`: Flash loan
+/// - `116`: Order book
+/// - `117`: OTC swap
+/// - `118`: Lockbox
+/// - `119`: Profile
+/// - `120`: Treasury
+/// - `121`: Referral
+/// - `122`: Bonding curve
+/// - `123`: Soulbound
+/// - `124`: Ticket
+/// - `125`: Voucher
+/// - `126`: Registry
+/// - `127`: Points
+/// - `128`: Savings
+/// - `129`: Dead man's switch
+/// - `130`: Social recovery
+/// - `131`: Grants
+/// - `132`: Rewards
+/// - `133`: Airdrop
+/// - `134`: Sweep
+/// - `135`: Governance
+/// - `136`: Memo
+/// - `137`: Stake
+/// - `138`: Vote
+/// - `139`: Loader
+/// - `140`: Memo transfer
+/// - `141`: Signed escrow
+/// - `142`: EVM wallet
+/// - `143`: Verify preimage
+/// - `144`: Time-gated transfer
+/// - `145`: Check slot hash
+/// - `146`: Get epoch info
+/// - `147`: Transfer if price
+/// - `148`: Legacy swap
+/// - `149`: Post message
+/// - `150`: Guard
+/// - `151`: System proxy
+/// - `152`: Batch
+/// - `153`: Get token balance
+/// - `228`: Emit event (first byte of the Anchor event instruction tag)
 fn process_remaining_instruction(
     accounts: &[AccountInfo],
-    instruction_data: &[u8],
-    discriminator: u8,
+    instruction: ProgramInstruction,
 ) -> ProgramResult {
-    match discriminator {
+    match instruction {
         // 1 - InitializeAccount
-        1 => {
+        ProgramInstruction::InitializeAccount => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeAccount");
 
             process_initialize_account(accounts)
         }
         // 2 - InitializeMultisig
-        2 => {
+        ProgramInstruction::InitializeMultisig { m } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeMultisig");
 
-            process_initialize_multisig(accounts, instruction_data)
+            process_initialize_multisig(accounts, m)
         }
         // 4 - Approve
-        4 => {
+        ProgramInstruction::Approve { amount } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: Approve");
 
-            process_approve(accounts, instruction_data)
+            process_approve(accounts, amount)
         }
         // 5 - Revoke
-        5 => {
+        ProgramInstruction::Revoke => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: Revoke");
 
-            process_revoke(accounts, instruction_data)
+            process_revoke(accounts)
         }
         // 6 - SetAuthority
-        6 => {
+        ProgramInstruction::SetAuthority {
+            authority_type,
+            new_authority,
+        } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: SetAuthority");
 
-            process_set_authority(accounts, instruction_data)
+            process_set_authority(accounts, authority_type, new_authority)
         }
         // 8 - Burn
-        8 => {
+        ProgramInstruction::Burn { amount } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: Burn");
 
-            process_burn(accounts, instruction_data)
+            process_burn(accounts, amount)
         }
         // 10 - FreezeAccount
-        10 => {
+        ProgramInstruction::FreezeAccount => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: FreezeAccount");
 
             process_freeze_account(accounts)
         }
         // 11 - ThawAccount
-        11 => {
+        ProgramInstruction::ThawAccount => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: ThawAccount");
 
             process_thaw_account(accounts)
         }
         // 12 - TransferChecked
-        12 => {
+        ProgramInstruction::TransferChecked { amount, decimals } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: TransferChecked");
 
-            process_transfer_checked(accounts, instruction_data)
+            process_transfer_checked(accounts, amount, decimals)
         }
         // 13 - ApproveChecked
-        13 => {
+        ProgramInstruction::ApproveChecked { amount, decimals } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: ApproveChecked");
 
-            process_approve_checked(accounts, instruction_data)
+            process_approve_checked(accounts, amount, decimals)
         }
         // 14 - MintToChecked
-        14 => {
+        ProgramInstruction::MintToChecked { amount, decimals } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: MintToChecked");
 
-            process_mint_to_checked(accounts, instruction_data)
+            process_mint_to_checked(accounts, amount, decimals)
         }
         // 15 - BurnChecked
-        15 => {
+        ProgramInstruction::BurnChecked { amount, decimals } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: BurnChecked");
 
-            process_burn_checked(accounts, instruction_data)
+            process_burn_checked(accounts, amount, decimals)
         }
         // 16 - InitializeAccount2
-        16 => {
+        ProgramInstruction::InitializeAccount2 { owner } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeAccount2");
 
-            process_initialize_account2(accounts, instruction_data)
+            process_initialize_account2(accounts, owner)
         }
         // 17 - SyncNative
-        17 => {
+        ProgramInstruction::SyncNative => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: SyncNative");
 
             process_sync_native(accounts)
         }
         // 19 - InitializeMultisig2
-        19 => {
+        ProgramInstruction::InitializeMultisig2 { m } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeMultisig2");
 
-            process_initialize_multisig2(accounts, instruction_data)
+            process_initialize_multisig2(accounts, m)
         }
         // 21 - GetAccountDataSize
-        21 => {
+        ProgramInstruction::GetAccountDataSize => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: GetAccountDataSize");
 
             process_get_account_data_size(accounts)
         }
         // 22 - InitializeImmutableOwner
-        22 => {
+        ProgramInstruction::InitializeImmutableOwner => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeImmutableOwner");
 
             process_initialize_immutable_owner(accounts)
         }
         // 23 - AmountToUiAmount
-        23 => {
+        ProgramInstruction::AmountToUiAmount { amount } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: AmountToUiAmount");
 
-            process_amount_to_ui_amount(accounts, instruction_data)
+            process_amount_to_ui_amount(accounts, amount)
         }
         // 24 - UiAmountToAmount
-        24 => {
+        ProgramInstruction::UiAmountToAmount { ui_amount } => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: UiAmountToAmount");
 
-            process_ui_amount_to_amount(accounts, instruction_data)
+            process_ui_amount_to_amount(accounts, ui_amount)
+        }
+        // 100 - AMM
+        ProgramInstruction::Amm(instruction_data) => {
+            process_amm_instruction(accounts, instruction_data)
+        }
+        // 101 - Staking
+        ProgramInstruction::Staking(instruction_data) => {
+            process_staking_instruction(accounts, instruction_data)
+        }
+        // 102 - Multisig wallet
+        ProgramInstruction::MultisigWallet(instruction_data) => {
+            process_multisig_wallet_instruction(accounts, instruction_data)
+        }
+        // 103 - Timelock
+        ProgramInstruction::Timelock(instruction_data) => {
+            process_timelock_instruction(accounts, instruction_data)
+        }
+        // 104 - Config
+        ProgramInstruction::Config(instruction_data) => {
+            process_config_instruction(accounts, instruction_data)
+        }
+        // 105 - Transfer gate
+        ProgramInstruction::TransferGate(instruction_data) => {
+            process_transfer_gate_instruction(accounts, instruction_data)
+        }
+        // 106 - Vault
+        ProgramInstruction::Vault(instruction_data) => {
+            process_vault_instruction(accounts, instruction_data)
+        }
+        // 107 - Auction
+        ProgramInstruction::Auction(instruction_data) => {
+            process_auction_instruction(accounts, instruction_data)
+        }
+        // 108 - Dutch auction
+        ProgramInstruction::DutchAuction(instruction_data) => {
+            process_dutch_auction_instruction(accounts, instruction_data)
+        }
+        // 109 - Raffle
+        ProgramInstruction::Raffle(instruction_data) => {
+            process_raffle_instruction(accounts, instruction_data)
+        }
+        // 110 - Campaign
+        ProgramInstruction::Campaign(instruction_data) => {
+            process_campaign_instruction(accounts, instruction_data)
+        }
+        // 111 - Subscription
+        ProgramInstruction::Subscription(instruction_data) => {
+            process_subscription_instruction(accounts, instruction_data)
+        }
+        // 112 - Splitter
+        ProgramInstruction::Splitter(instruction_data) => {
+            process_splitter_instruction(accounts, instruction_data)
+        }
+        // 113 - Royalty
+        ProgramInstruction::Royalty(instruction_data) => {
+            process_royalty_instruction(accounts, instruction_data)
+        }
+        // 114 - Faucet
+        ProgramInstruction::Faucet(instruction_data) => {
+            process_faucet_instruction(accounts, instruction_data)
+        }
+        // 115 - Flash loan
+        ProgramInstruction::FlashLoan(instruction_data) => {
+            process_flash_loan_instruction(accounts, instruction_data)
+        }
+        // 116 - Order book
+        ProgramInstruction::Orderbook(instruction_data) => {
+            process_orderbook_instruction(accounts, instruction_data)
+        }
+        // 117 - OTC swap
+        ProgramInstruction::Otc(instruction_data) => {
+            process_otc_instruction(accounts, instruction_data)
+        }
+        // 118 - Lockbox
+        ProgramInstruction::Lockbox(instruction_data) => {
+            process_lockbox_instruction(accounts, instruction_data)
+        }
+        // 119 - Profile
+        ProgramInstruction::Profile(instruction_data) => {
+            process_profile_instruction(accounts, instruction_data)
+        }
+        // 120 - Treasury
+        ProgramInstruction::Treasury(instruction_data) => {
+            process_treasury_instruction(accounts, instruction_data)
+        }
+        // 121 - Referral
+        ProgramInstruction::Referral(instruction_data) => {
+            process_referral_instruction(accounts, instruction_data)
+        }
+        // 122 - Bonding curve
+        ProgramInstruction::BondingCurve(instruction_data) => {
+            process_bonding_curve_instruction(accounts, instruction_data)
+        }
+        // 123 - Soulbound
+        ProgramInstruction::Soulbound(instruction_data) => {
+            process_soulbound_instruction(accounts, instruction_data)
+        }
+        // 124 - Ticket
+        ProgramInstruction::Ticket(instruction_data) => {
+            process_ticket_instruction(accounts, instruction_data)
+        }
+        // 125 - Voucher
+        ProgramInstruction::Voucher(instruction_data) => {
+            process_voucher_instruction(accounts, instruction_data)
+        }
+        // 126 - Registry
+        ProgramInstruction::Registry(instruction_data) => {
+            process_registry_instruction(accounts, instruction_data)
+        }
+        // 127 - Points
+        ProgramInstruction::Points(instruction_data) => {
+            process_points_instruction(accounts, instruction_data)
+        }
+        // 128 - Savings
+        ProgramInstruction::Savings(instruction_data) => {
+            process_savings_instruction(accounts, instruction_data)
+        }
+        // 129 - Dead man's switch
+        ProgramInstruction::DeadManSwitch(instruction_data) => {
+            process_dead_man_switch_instruction(accounts, instruction_data)
+        }
+        // 130 - Social recovery
+        ProgramInstruction::SocialRecovery(instruction_data) => {
+            process_social_recovery_instruction(accounts, instruction_data)
+        }
+        // 131 - Grants
+        ProgramInstruction::Grants(instruction_data) => {
+            process_grants_instruction(accounts, instruction_data)
+        }
+        // 132 - Rewards
+        ProgramInstruction::Rewards(instruction_data) => {
+            process_rewards_instruction(accounts, instruction_data)
+        }
+        // 133 - Airdrop
+        ProgramInstruction::Airdrop(instruction_data) => {
+            process_airdrop_instruction(accounts, instruction_data)
+        }
+        // 134 - Sweep
+        ProgramInstruction::Sweep(instruction_data) => {
+            process_sweep_instruction(accounts, instruction_data)
+        }
+        // 135 - Governance
+        ProgramInstruction::Governance(instruction_data) => {
+            process_governance_instruction(accounts, instruction_data)
+        }
+        // 136 - Memo
+        ProgramInstruction::Memo(instruction_data) => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Memo");
+
+            process_memo(accounts, instruction_data)
+        }
+        // 137 - Stake
+        ProgramInstruction::Stake(instruction_data) => {
+            process_stake_instruction(accounts, instruction_data)
+        }
+        // 138 - Vote
+        ProgramInstruction::Vote(instruction_data) => {
+            process_vote_instruction(accounts, instruction_data)
+        }
+        // 139 - Loader
+        ProgramInstruction::Loader(instruction_data) => {
+            process_loader_instruction(accounts, instruction_data)
+        }
+        // 140 - Memo transfer
+        ProgramInstruction::MemoTransfer(instruction_data) => {
+            process_memo_transfer_instruction(accounts, instruction_data)
+        }
+        // 141 - Signed escrow
+        ProgramInstruction::SignedEscrow(instruction_data) => {
+            process_signed_escrow_instruction(accounts, instruction_data)
+        }
+        // 142 - EVM wallet
+        ProgramInstruction::EvmWallet(instruction_data) => {
+            process_evm_wallet_instruction(accounts, instruction_data)
+        }
+        // 143 - Verify preimage
+        ProgramInstruction::VerifyPreimage(instruction_data) => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: VerifyPreimage");
+
+            process_verify_preimage(instruction_data)
+        }
+        // 144 - Time-gated transfer
+        ProgramInstruction::TimeGatedTransfer(instruction_data) => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: TimeGatedTransfer");
+
+            process_time_gated_transfer(accounts, instruction_data)
+        }
+        // 145 - Check slot hash
+        ProgramInstruction::CheckSlotHash(instruction_data) => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CheckSlotHash");
+
+            process_check_slot_hash(accounts, instruction_data)
+        }
+        // 146 - Get epoch info
+        ProgramInstruction::GetEpochInfo => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: GetEpochInfo");
+
+            process_get_epoch_info(accounts)
+        }
+        // 147 - Transfer if price
+        ProgramInstruction::TransferIfPrice(instruction_data) => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: TransferIfPrice");
+
+            process_transfer_if_price(accounts, instruction_data)
+        }
+        // 148 - Legacy swap
+        ProgramInstruction::LegacySwap(instruction_data) => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: LegacySwap");
+
+            process_legacy_swap(accounts, instruction_data)
+        }
+        // 149 - Post message
+        ProgramInstruction::PostMessage(instruction_data) => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: PostMessage");
+
+            process_post_message(accounts, instruction_data)
+        }
+        // 150 - Guard
+        ProgramInstruction::Guard(instruction_data) => {
+            process_guard_instruction(accounts, instruction_data)
+        }
+        // 151 - System proxy
+        ProgramInstruction::SystemProxy(instruction_data) => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SystemProxy");
+
+            process_system_proxy(accounts, instruction_data)
+        }
+        // 152 - Batch
+        ProgramInstruction::Batch(instruction_data) => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Batch");
+
+            process_batch(accounts, instruction_data, dispatch_instruction)
+        }
+        // 153 - Get token balance
+        ProgramInstruction::GetTokenBalance => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: GetTokenBalance");
+
+            process_get_token_balance(accounts)
+        }
+        // 228 - Emit event
+        #[cfg(feature = "events")]
+        ProgramInstruction::EmitEvent(instruction_data) => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: EmitEvent");
+
+            process_emit_event(accounts, instruction_data)
         }
         _ => Err(ProgramError::InvalidInstructionData),
     }
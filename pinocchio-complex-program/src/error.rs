@@ -0,0 +1,171 @@
+//! Errors returned by the extension instructions.
+
+use pinocchio::program_error::ProgramError;
+
+/// Errors that may be returned by the extension instructions.
+///
+/// Error codes start at `100` so they do not overlap with the `TokenError`
+/// codes returned by the token instructions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ExtensionError {
+    /// The account address does not match the expected derived address.
+    DerivedAddressMismatch = 100,
+    /// The account does not match the expected account, such as the account
+    /// stored in the extension state.
+    AccountMismatch,
+    /// The output amount is below the minimum amount requested.
+    SlippageExceeded,
+    /// The pool does not have enough liquidity for the operation.
+    InsufficientLiquidity,
+    /// The transaction has already been executed.
+    AlreadyExecuted,
+    /// The number of approvals is below the required threshold.
+    NotEnoughApprovals,
+    /// The execution time is earlier than the minimum delay allows.
+    InvalidEta,
+    /// The execution time of the action has not been reached.
+    TimelockNotElapsed,
+    /// The instruction does not match the hash of the queued action.
+    InvalidActionHash,
+    /// The account version is not supported.
+    InvalidVersion,
+    /// The program is paused.
+    Paused,
+    /// The transfer is not allowed by the transfer gate.
+    TransferNotAllowed,
+    /// The auction has ended.
+    AuctionEnded,
+    /// The auction has not ended yet.
+    AuctionNotEnded,
+    /// The bid is lower than the minimum or the highest bid.
+    BidTooLow,
+    /// The price is higher than the maximum price requested.
+    PriceExceeded,
+    /// The slot hash required is not available.
+    SlotHashUnavailable,
+    /// The raffle has ended.
+    RaffleEnded,
+    /// The raffle has not ended yet.
+    RaffleNotEnded,
+    /// The raffle winner has already been drawn.
+    AlreadyDrawn,
+    /// The raffle winner has not been drawn yet.
+    NotDrawn,
+    /// The campaign has ended.
+    CampaignEnded,
+    /// The campaign has not ended yet.
+    CampaignNotEnded,
+    /// The campaign did not reach its goal.
+    GoalNotReached,
+    /// The campaign reached its goal.
+    GoalReached,
+    /// The subscription charge is not due yet.
+    ChargeNotDue,
+    /// The faucet cooldown has not elapsed yet.
+    CooldownNotElapsed,
+    /// The rate limit has been exceeded.
+    RateLimitExceeded,
+    /// The flash loan is not repaid later in the transaction.
+    RepayNotFound,
+    /// A flash loan of the pool is already in progress.
+    LoanInProgress,
+    /// The order book side is full.
+    OrderBookFull,
+    /// The order was not found.
+    OrderNotFound,
+    /// The best bid and ask do not cross.
+    OrdersNotCrossing,
+    /// The lockbox is still locked.
+    LockboxLocked,
+    /// The ticket has already been used.
+    TicketUsed,
+    /// The redemption code does not match the voucher hash.
+    InvalidRedemptionCode,
+    /// The withdrawal delay has not elapsed yet.
+    WithdrawalNotReady,
+    /// The owner of the dead man's switch is still active.
+    SwitchActive,
+    /// The grant deadline has passed.
+    GrantExpired,
+    /// The grant deadline has not passed yet.
+    GrantNotExpired,
+    /// The qualifying payment was not found in the transaction.
+    PaymentNotFound,
+    /// The voting period of the proposal has ended.
+    VotingEnded,
+    /// The voting period of the proposal has not ended yet.
+    VotingNotEnded,
+    /// The proposal did not pass.
+    ProposalNotPassed,
+    /// The transaction does not include a memo.
+    MemoNotFound,
+    /// The transaction does not verify the expected Ed25519 signature.
+    SignatureNotVerified,
+    /// The signature was not signed by the expected Ethereum address.
+    EthAddressMismatch,
+    /// The preimage does not match the expected hash.
+    PreimageMismatch,
+    /// The clock has not reached the threshold of the time gate yet.
+    TimeGateNotReached,
+    /// The hash does not match the hash of the slot.
+    SlotHashMismatch,
+    /// The oracle price is older than the maximum age.
+    StalePrice,
+    /// The confidence interval of the oracle price is too wide.
+    PriceConfidenceTooWide,
+    /// The oracle price does not meet the condition.
+    PriceConditionNotMet,
+    /// The condition asserted by a guard does not hold.
+    AssertionFailed,
+    /// The instruction was invoked by a caller that is not allowed.
+    CallerNotAllowed,
+    /// The same account was provided for two accounts that must be distinct.
+    DuplicateAccount,
+    /// The raffle has no tickets sold.
+    NoTicketsSold,
+    /// The version of the instruction data layout is not supported.
+    UnsupportedInstructionVersion,
+    /// The instruction data has trailing bytes after the expected fields.
+    TrailingData,
+    /// The instruction is deprecated.
+    DeprecatedInstruction,
+    /// The seed of an address is not valid UTF-8.
+    InvalidSeedEncoding,
+    /// The amount of a transfer, mint or burn is zero.
+    ZeroAmount,
+    /// An account that must be read-only is writable.
+    UnexpectedWritableAccount,
+    /// More accounts were provided than the instruction expects.
+    UnexpectedAccounts,
+    /// The raffle can still be drawn.
+    DrawWindowOpen,
+    /// An account that must be writable is read-only.
+    NotWritable,
+    /// An account that must sign the instruction is not a signer.
+    MissingSigner,
+    /// The account data is not of the expected account type.
+    AccountTypeMismatch,
+    /// The oracle price has not been fully verified.
+    PriceNotVerified,
+    /// The token balance decreased while it was expected to increase.
+    BalanceDecreased,
+    /// The curve type of the bonding curve is not supported.
+    UnsupportedCurveType,
+    /// The balance of an account did not change by the expected amount.
+    BalanceMismatch,
+    /// The account is not owned by the expected program.
+    OwnerMismatch,
+    /// The mint of a token account is not the expected mint.
+    MintMismatch,
+    /// The account data is too short or malformed for the layout of the account.
+    MalformedAccountData,
+    /// The account does not hold enough lamports to be rent exempt.
+    NeedsRentExemption,
+}
+
+impl From<ExtensionError> for ProgramError {
+    fn from(e: ExtensionError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
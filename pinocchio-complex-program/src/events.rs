@@ -0,0 +1,227 @@
+//! Typed events emitted through a CPI.
+//!
+//! Events are emitted through a CPI, so indexers can read them from the inner
+//! instructions of the transaction instead of parsing logs, which may be
+//! truncated. There are two ways to emit an event, selected by the trailing
+//! accounts of the instruction:
+//!
+//! - Noop program as the last account: the event is serialized as a discriminator
+//!   byte followed by the event fields and emitted through a CPI to the SPL Noop
+//!   program.
+//! - Event authority and token program as the last two accounts: the event is
+//!   emitted through a CPI to the token program itself, signed by the event
+//!   authority, matching the layout of Anchor's `emit_cpi!`. The instruction data
+//!   is `EVENT_IX_TAG`, followed by the 8-byte Anchor discriminator of the event
+//!   and the event fields, so Anchor-compatible indexers can decode the events.
+//!
+//! Emission is opt-in per instruction: since the invoked program must be part of
+//! the transaction for the CPI, this avoids failing existing clients that do not
+//! provide the accounts.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Seed, Signer},
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey, PUBKEY_BYTES},
+    ProgramResult,
+};
+use token_interface::program::ID as TOKEN_PROGRAM_ID;
+
+use crate::noop;
+
+/// Seed of the event authority derived address.
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+/// Tag prefixing the data of self-CPI event instructions, the same as Anchor's
+/// `EVENT_IX_TAG` (`0x1d9acb512ea545e4` little-endian).
+pub const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+/// Maximum length of a serialized event, including its discriminators.
+const MAX_EVENT_DATA_LEN: usize = 128;
+
+/// Event emitted by the program.
+pub trait Event {
+    /// Discriminator prefixing the serialized event emitted through the Noop
+    /// program.
+    const DISCRIMINATOR: u8;
+
+    /// Anchor discriminator of the event: the first 8 bytes of the SHA-256 hash of
+    /// `"event:<name>"`.
+    const ANCHOR_DISCRIMINATOR: [u8; 8];
+
+    /// Length of the serialized event fields.
+    const LEN: usize;
+
+    /// Serializes the event fields into `data`, which is `LEN` bytes long.
+    fn serialize(&self, data: &mut [u8]);
+}
+
+/// Emits `event` through the Noop program or a self-CPI, depending on the last
+/// accounts of the instruction `accounts`.
+#[inline(always)]
+pub fn emit<E: Event>(accounts: &[AccountInfo], event: &E) -> ProgramResult {
+    match accounts {
+        [.., noop_program_info] if noop_program_info.key() == &noop::ID => emit_noop(event),
+        [.., event_authority_info, program_info] if program_info.key() == &TOKEN_PROGRAM_ID => {
+            emit_self_cpi(event_authority_info, event)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the event authority derived address and its bump seed.
+#[inline(always)]
+pub fn event_authority() -> (Pubkey, u8) {
+    find_program_address(&[EVENT_AUTHORITY_SEED], &TOKEN_PROGRAM_ID)
+}
+
+/// Emits `event` through a CPI to the Noop program.
+#[inline(always)]
+fn emit_noop<E: Event>(event: &E) -> ProgramResult {
+    if E::LEN + 1 > MAX_EVENT_DATA_LEN {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut data = [0u8; MAX_EVENT_DATA_LEN];
+    data[0] = E::DISCRIMINATOR;
+    event.serialize(&mut data[1..E::LEN + 1]);
+
+    noop::wrap(&data[..E::LEN + 1])
+}
+
+/// Emits `event` through a CPI to the token program, signed by the event
+/// authority.
+#[inline(always)]
+fn emit_self_cpi<E: Event>(event_authority_info: &AccountInfo, event: &E) -> ProgramResult {
+    let (event_authority, bump) = event_authority();
+
+    if event_authority_info.key() != &event_authority {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Instruction data layout:
+    // - [0..8]: event instruction tag
+    // - [8..16]: Anchor discriminator of the event
+    // - [16..]: event fields
+    let len = EVENT_IX_TAG.len() + E::ANCHOR_DISCRIMINATOR.len() + E::LEN;
+
+    if len > MAX_EVENT_DATA_LEN {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut data = [0u8; MAX_EVENT_DATA_LEN];
+    data[..8].copy_from_slice(&EVENT_IX_TAG);
+    data[8..16].copy_from_slice(&E::ANCHOR_DISCRIMINATOR);
+    event.serialize(&mut data[16..len]);
+
+    let account_metas = [AccountMeta::readonly_signer(event_authority_info.key())];
+
+    let instruction = Instruction {
+        program_id: &TOKEN_PROGRAM_ID,
+        accounts: &account_metas,
+        data: &data[..len],
+    };
+
+    let bump = [bump];
+    let seeds = [Seed::from(EVENT_AUTHORITY_SEED), Seed::from(&bump)];
+
+    invoke_signed(
+        &instruction,
+        &[event_authority_info],
+        &[Signer::from(&seeds)],
+    )
+}
+
+/// Tokens transferred between token accounts.
+pub struct TransferEvent<'a> {
+    /// Source token account.
+    pub source: &'a Pubkey,
+
+    /// Destination token account.
+    pub destination: &'a Pubkey,
+
+    /// Amount of tokens transferred.
+    pub amount: u64,
+}
+
+impl Event for TransferEvent<'_> {
+    const DISCRIMINATOR: u8 = 0;
+
+    const ANCHOR_DISCRIMINATOR: [u8; 8] = [100, 10, 46, 113, 8, 28, 179, 125];
+
+    const LEN: usize = PUBKEY_BYTES * 2 + 8;
+
+    #[inline(always)]
+    fn serialize(&self, data: &mut [u8]) {
+        // Event data layout:
+        // - [0..32]: source
+        // - [32..64]: destination
+        // - [64..72]: amount
+        data[..32].copy_from_slice(self.source);
+        data[32..64].copy_from_slice(self.destination);
+        data[64..72].copy_from_slice(&self.amount.to_le_bytes());
+    }
+}
+
+/// Tokens minted to a token account.
+pub struct MintToEvent<'a> {
+    /// Mint of the tokens.
+    pub mint: &'a Pubkey,
+
+    /// Token account receiving the tokens.
+    pub destination: &'a Pubkey,
+
+    /// Amount of tokens minted.
+    pub amount: u64,
+}
+
+impl Event for MintToEvent<'_> {
+    const DISCRIMINATOR: u8 = 1;
+
+    const ANCHOR_DISCRIMINATOR: [u8; 8] = [100, 191, 47, 110, 51, 179, 182, 242];
+
+    const LEN: usize = PUBKEY_BYTES * 2 + 8;
+
+    #[inline(always)]
+    fn serialize(&self, data: &mut [u8]) {
+        // Event data layout:
+        // - [0..32]: mint
+        // - [32..64]: destination
+        // - [64..72]: amount
+        data[..32].copy_from_slice(self.mint);
+        data[32..64].copy_from_slice(self.destination);
+        data[64..72].copy_from_slice(&self.amount.to_le_bytes());
+    }
+}
+
+/// Escrow funds released to a token account.
+pub struct EscrowReleaseEvent<'a> {
+    /// Escrow account.
+    pub escrow: &'a Pubkey,
+
+    /// Token account receiving the escrow funds.
+    pub destination: &'a Pubkey,
+
+    /// Amount of tokens released.
+    pub amount: u64,
+}
+
+impl Event for EscrowReleaseEvent<'_> {
+    const DISCRIMINATOR: u8 = 2;
+
+    const ANCHOR_DISCRIMINATOR: [u8; 8] = [174, 132, 185, 57, 1, 40, 80, 217];
+
+    const LEN: usize = PUBKEY_BYTES * 2 + 8;
+
+    #[inline(always)]
+    fn serialize(&self, data: &mut [u8]) {
+        // Event data layout:
+        // - [0..32]: escrow
+        // - [32..64]: destination
+        // - [64..72]: amount
+        data[..32].copy_from_slice(self.escrow);
+        data[32..64].copy_from_slice(self.destination);
+        data[64..72].copy_from_slice(&self.amount.to_le_bytes());
+    }
+}
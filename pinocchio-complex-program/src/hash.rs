@@ -0,0 +1,73 @@
+//! Helpers to compute hashes through the hashing syscalls.
+//!
+//! Each helper hashes the concatenation of `values`, which avoids copying the
+//! values to a single buffer when hashing several of them.
+
+/// Length of the hashes computed by the helpers.
+pub const HASH_BYTES: usize = 32;
+
+/// Computes the SHA-256 hash of the concatenation of `values`.
+#[inline(always)]
+pub fn sha256(values: &[&[u8]]) -> [u8; HASH_BYTES] {
+    let mut hash = [0u8; HASH_BYTES];
+
+    #[cfg(target_os = "solana")]
+    // SAFETY: `values` is a slice of byte slices, which matches the layout expected
+    // by the syscall, and `hash` has the length of a SHA-256 hash.
+    unsafe {
+        pinocchio::syscalls::sol_sha256(
+            values.as_ptr() as *const u8,
+            values.len() as u64,
+            hash.as_mut_ptr(),
+        );
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    core::hint::black_box((values, &mut hash));
+
+    hash
+}
+
+/// Computes the Keccak-256 hash of the concatenation of `values`.
+#[inline(always)]
+pub fn keccak256(values: &[&[u8]]) -> [u8; HASH_BYTES] {
+    let mut hash = [0u8; HASH_BYTES];
+
+    #[cfg(target_os = "solana")]
+    // SAFETY: `values` is a slice of byte slices, which matches the layout expected
+    // by the syscall, and `hash` has the length of a Keccak-256 hash.
+    unsafe {
+        pinocchio::syscalls::sol_keccak256(
+            values.as_ptr() as *const u8,
+            values.len() as u64,
+            hash.as_mut_ptr(),
+        );
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    core::hint::black_box((values, &mut hash));
+
+    hash
+}
+
+/// Computes the BLAKE3 hash of the concatenation of `values`.
+#[inline(always)]
+pub fn blake3(values: &[&[u8]]) -> [u8; HASH_BYTES] {
+    let mut hash = [0u8; HASH_BYTES];
+
+    #[cfg(target_os = "solana")]
+    // SAFETY: `values` is a slice of byte slices, which matches the layout expected
+    // by the syscall, and `hash` has the length of a BLAKE3 hash.
+    unsafe {
+        pinocchio::syscalls::sol_blake3(
+            values.as_ptr() as *const u8,
+            values.len() as u64,
+            hash.as_mut_ptr(),
+        );
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    core::hint::black_box((values, &mut hash));
+
+    hash
+}
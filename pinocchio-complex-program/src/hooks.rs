@@ -0,0 +1,33 @@
+//! Hooks invoked before and after each instruction, enabled by the `hooks`
+//! feature.
+//!
+//! The hooks are the extension point for deployers adding metrics, checks or
+//! invariant validation to every instruction without editing the processors. They
+//! receive the parsed instruction, which identifies the discriminator, and the
+//! accounts passed to its processor. The hooks wrap the instruction of the
+//! transaction only: the instructions of a batch are dispatched without them.
+
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+use crate::instruction::ProgramInstruction;
+
+/// Invoked before the instruction is dispatched to its processor.
+///
+/// Returning an error fails the instruction without processing it.
+#[inline(always)]
+pub fn before(_instruction: &ProgramInstruction, _accounts: &[AccountInfo]) -> ProgramResult {
+    Ok(())
+}
+
+/// Invoked after the instruction is processed, with the `result` of its
+/// processor.
+///
+/// The returned result is the result of the instruction.
+#[inline(always)]
+pub fn after(
+    _instruction: &ProgramInstruction,
+    _accounts: &[AccountInfo],
+    result: ProgramResult,
+) -> ProgramResult {
+    result
+}
@@ -0,0 +1,1037 @@
+//! Instructions of the program.
+//!
+//! `ProgramInstruction` is the wire format of the program: `unpack` parses the
+//! instruction data received by the program and, with the `client` feature,
+//! `pack` serializes an instruction for off-chain clients.
+//!
+//! The token instructions use the SPL Token wire format, including the `COption`
+//! encoding of optional authorities, so instruction data built for SPL Token is
+//! processed unchanged. They are parsed into typed fields. Extension instructions
+//! carry the instruction data following their discriminator, which is parsed by
+//! the extension processor.
+//!
+//! Instruction data may start with a version header: the `VERSION_PREFIX` byte,
+//! which is not used as a discriminator, followed by the version of the layout.
+//! Instruction data without the header uses the version `0` layout, so SPL Token
+//! instruction data is parsed unchanged. The layouts are:
+//!
+//! - `0`: the discriminator followed by the instruction data.
+//! - `1`: a flags byte followed by the version `0` layout. No flags are defined
+//!   yet, so the flags must be `0`.
+//!
+//! Discriminators can be deprecated and aliased so the instruction set evolves
+//! without breaking existing clients: an instruction is renumbered by aliasing its
+//! previous discriminator to the new one in `DISCRIMINATOR_ALIASES`, and the
+//! previous discriminator is listed in `DEPRECATED_DISCRIMINATORS` until clients
+//! have migrated.
+
+#[cfg(feature = "client")]
+use alloc::vec::Vec;
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{error::ExtensionError, parse::Cursor};
+
+/// Prefix of instruction data starting with a version header.
+pub const VERSION_PREFIX: u8 = 255;
+
+/// Deprecated discriminators.
+///
+/// Deprecated instructions are still processed, logging a warning with the
+/// `logging` feature, unless the `reject-deprecated` feature is enabled.
+pub const DEPRECATED_DISCRIMINATORS: &[u8] = &[];
+
+/// Discriminator aliases, as `(alias, discriminator)` pairs.
+///
+/// Instruction data using an alias is parsed as the instruction of the
+/// discriminator. Aliases are resolved once, so a discriminator must not be an
+/// alias itself.
+pub const DISCRIMINATOR_ALIASES: &[(u8, u8)] = &[];
+
+/// Instruction of the program.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProgramInstruction<'a> {
+    /// `0` - Initializes a new mint.
+    InitializeMint {
+        /// Number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+
+        /// Authority used to mint new tokens.
+        mint_authority: &'a Pubkey,
+
+        /// Optional authority that can freeze token accounts.
+        freeze_authority: Option<&'a Pubkey>,
+    },
+
+    /// `1` - Initializes a new account to hold tokens.
+    InitializeAccount,
+
+    /// `2` - Initializes a multisignature account with N provided signers.
+    InitializeMultisig {
+        /// Number of signers required to validate a transaction.
+        m: u8,
+    },
+
+    /// `3` - Transfers tokens from one account to another.
+    Transfer {
+        /// Amount of tokens to transfer.
+        amount: u64,
+    },
+
+    /// `4` - Approves a delegate.
+    Approve {
+        /// Amount of tokens the delegate is approved for.
+        amount: u64,
+    },
+
+    /// `5` - Revokes the delegate's authority.
+    Revoke,
+
+    /// `6` - Sets a new authority of a mint or account.
+    SetAuthority {
+        /// Type of the authority to update.
+        authority_type: u8,
+
+        /// New authority.
+        new_authority: Option<&'a Pubkey>,
+    },
+
+    /// `7` - Mints new tokens to an account.
+    MintTo {
+        /// Amount of new tokens to mint.
+        amount: u64,
+    },
+
+    /// `8` - Burns tokens by removing them from an account.
+    Burn {
+        /// Amount of tokens to burn.
+        amount: u64,
+    },
+
+    /// `9` - Closes an account by transferring all its lamports to the destination
+    /// account.
+    CloseAccount,
+
+    /// `10` - Freezes an initialized account.
+    FreezeAccount,
+
+    /// `11` - Thaws a frozen account.
+    ThawAccount,
+
+    /// `12` - Transfers tokens, asserting the token mint and decimals.
+    TransferChecked {
+        /// Amount of tokens to transfer.
+        amount: u64,
+
+        /// Expected number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+    },
+
+    /// `13` - Approves a delegate, asserting the token mint and decimals.
+    ApproveChecked {
+        /// Amount of tokens the delegate is approved for.
+        amount: u64,
+
+        /// Expected number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+    },
+
+    /// `14` - Mints new tokens, asserting the token mint and decimals.
+    MintToChecked {
+        /// Amount of new tokens to mint.
+        amount: u64,
+
+        /// Expected number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+    },
+
+    /// `15` - Burns tokens, asserting the token mint and decimals.
+    BurnChecked {
+        /// Amount of tokens to burn.
+        amount: u64,
+
+        /// Expected number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+    },
+
+    /// `16` - Like `InitializeAccount`, with the owner as instruction data.
+    InitializeAccount2 {
+        /// Owner of the new account.
+        owner: &'a Pubkey,
+    },
+
+    /// `17` - Syncs the amount of a native account with its lamports.
+    SyncNative,
+
+    /// `18` - Like `InitializeAccount2`, without the rent sysvar account.
+    InitializeAccount3 {
+        /// Owner of the new account.
+        owner: &'a Pubkey,
+    },
+
+    /// `19` - Like `InitializeMultisig`, without the rent sysvar account.
+    InitializeMultisig2 {
+        /// Number of signers required to validate a transaction.
+        m: u8,
+    },
+
+    /// `20` - Like `InitializeMint`, without the rent sysvar account.
+    InitializeMint2 {
+        /// Number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+
+        /// Authority used to mint new tokens.
+        mint_authority: &'a Pubkey,
+
+        /// Optional authority that can freeze token accounts.
+        freeze_authority: Option<&'a Pubkey>,
+    },
+
+    /// `21` - Returns the data size of an account for the given mint.
+    GetAccountDataSize,
+
+    /// `22` - Initializes the immutable owner extension of an account.
+    InitializeImmutableOwner,
+
+    /// `23` - Converts an amount of tokens to its UI amount.
+    AmountToUiAmount {
+        /// Amount of tokens to convert.
+        amount: u64,
+    },
+
+    /// `24` - Converts a UI amount of tokens to its raw amount.
+    UiAmountToAmount {
+        /// UI amount of tokens to convert.
+        ui_amount: &'a str,
+    },
+
+    /// `100` - AMM extension instruction.
+    Amm(&'a [u8]),
+
+    /// `101` - Staking extension instruction.
+    Staking(&'a [u8]),
+
+    /// `102` - Multisig wallet extension instruction.
+    MultisigWallet(&'a [u8]),
+
+    /// `103` - Timelock extension instruction.
+    Timelock(&'a [u8]),
+
+    /// `104` - Config extension instruction.
+    Config(&'a [u8]),
+
+    /// `105` - Transfer gate extension instruction.
+    TransferGate(&'a [u8]),
+
+    /// `106` - Vault extension instruction.
+    Vault(&'a [u8]),
+
+    /// `107` - Auction extension instruction.
+    Auction(&'a [u8]),
+
+    /// `108` - Dutch auction extension instruction.
+    DutchAuction(&'a [u8]),
+
+    /// `109` - Raffle extension instruction.
+    Raffle(&'a [u8]),
+
+    /// `110` - Campaign extension instruction.
+    Campaign(&'a [u8]),
+
+    /// `111` - Subscription extension instruction.
+    Subscription(&'a [u8]),
+
+    /// `112` - Splitter extension instruction.
+    Splitter(&'a [u8]),
+
+    /// `113` - Royalty extension instruction.
+    Royalty(&'a [u8]),
+
+    /// `114` - Faucet extension instruction.
+    Faucet(&'a [u8]),
+
+    /// `115` - Flash loan extension instruction.
+    FlashLoan(&'a [u8]),
+
+    /// `116` - Order book extension instruction.
+    Orderbook(&'a [u8]),
+
+    /// `117` - OTC swap extension instruction.
+    Otc(&'a [u8]),
+
+    /// `118` - Lockbox extension instruction.
+    Lockbox(&'a [u8]),
+
+    /// `119` - Profile extension instruction.
+    Profile(&'a [u8]),
+
+    /// `120` - Treasury extension instruction.
+    Treasury(&'a [u8]),
+
+    /// `121` - Referral extension instruction.
+    Referral(&'a [u8]),
+
+    /// `122` - Bonding curve extension instruction.
+    BondingCurve(&'a [u8]),
+
+    /// `123` - Soulbound extension instruction.
+    Soulbound(&'a [u8]),
+
+    /// `124` - Ticket extension instruction.
+    Ticket(&'a [u8]),
+
+    /// `125` - Voucher extension instruction.
+    Voucher(&'a [u8]),
+
+    /// `126` - Registry extension instruction.
+    Registry(&'a [u8]),
+
+    /// `127` - Points extension instruction.
+    Points(&'a [u8]),
+
+    /// `128` - Savings extension instruction.
+    Savings(&'a [u8]),
+
+    /// `129` - Dead man's switch extension instruction.
+    DeadManSwitch(&'a [u8]),
+
+    /// `130` - Social recovery extension instruction.
+    SocialRecovery(&'a [u8]),
+
+    /// `131` - Grants extension instruction.
+    Grants(&'a [u8]),
+
+    /// `132` - Rewards extension instruction.
+    Rewards(&'a [u8]),
+
+    /// `133` - Airdrop extension instruction.
+    Airdrop(&'a [u8]),
+
+    /// `134` - Sweep extension instruction.
+    Sweep(&'a [u8]),
+
+    /// `135` - Governance extension instruction.
+    Governance(&'a [u8]),
+
+    /// `136` - Memo.
+    Memo(&'a [u8]),
+
+    /// `137` - Stake extension instruction.
+    Stake(&'a [u8]),
+
+    /// `138` - Vote extension instruction.
+    Vote(&'a [u8]),
+
+    /// `139` - Loader extension instruction.
+    Loader(&'a [u8]),
+
+    /// `140` - Memo transfer extension instruction.
+    MemoTransfer(&'a [u8]),
+
+    /// `141` - Signed escrow extension instruction.
+    SignedEscrow(&'a [u8]),
+
+    /// `142` - EVM wallet extension instruction.
+    EvmWallet(&'a [u8]),
+
+    /// `143` - Verify preimage.
+    VerifyPreimage(&'a [u8]),
+
+    /// `144` - Time-gated transfer.
+    TimeGatedTransfer(&'a [u8]),
+
+    /// `145` - Check slot hash.
+    CheckSlotHash(&'a [u8]),
+
+    /// `146` - Get epoch info.
+    GetEpochInfo,
+
+    /// `147` - Transfer if price.
+    TransferIfPrice(&'a [u8]),
+
+    /// `148` - Legacy swap.
+    LegacySwap(&'a [u8]),
+
+    /// `149` - Post message.
+    PostMessage(&'a [u8]),
+
+    /// `150` - Guard extension instruction.
+    Guard(&'a [u8]),
+
+    /// `151` - System proxy.
+    SystemProxy(&'a [u8]),
+
+    /// `152` - Batch of instructions.
+    Batch(&'a [u8]),
+
+    /// `153` - Get token balance.
+    GetTokenBalance,
+
+    /// `228` - Emit event.
+    #[cfg(feature = "events")]
+    EmitEvent(&'a [u8]),
+}
+
+impl<'a> ProgramInstruction<'a> {
+    /// Parses an instruction from the instruction `data`, routing it to the parser
+    /// of its layout version.
+    pub fn unpack(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let Some((&VERSION_PREFIX, data)) = data.split_first() else {
+            return Self::unpack_v0(data);
+        };
+
+        let mut cursor = Cursor::new(data);
+
+        match cursor.read_u8()? {
+            0 => Self::unpack_v0(cursor.remaining()),
+            1 => Self::unpack_v1(cursor.remaining()),
+            _ => Err(ExtensionError::UnsupportedInstructionVersion.into()),
+        }
+    }
+
+    /// Parses an instruction with the version `1` layout.
+    fn unpack_v1(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let mut cursor = Cursor::new(data);
+
+        // No flags are defined yet.
+        if cursor.read_u8()? != 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Self::unpack_v0(cursor.remaining())
+    }
+
+    /// Parses an instruction with the version `0` layout.
+    fn unpack_v0(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let (discriminator, data) = data
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        let discriminator = resolve_discriminator(
+            *discriminator,
+            DEPRECATED_DISCRIMINATORS,
+            DISCRIMINATOR_ALIASES,
+        )?;
+
+        let mut cursor = Cursor::new(data);
+
+        Ok(match discriminator {
+            0 => no_trailing_data(
+                Self::InitializeMint {
+                    decimals: cursor.read_u8()?,
+                    mint_authority: cursor.read_address()?,
+                    freeze_authority: cursor.read_optional_address()?,
+                },
+                cursor,
+            )?,
+            1 => no_trailing_data(Self::InitializeAccount, cursor)?,
+            2 => no_trailing_data(
+                Self::InitializeMultisig {
+                    m: cursor.read_u8()?,
+                },
+                cursor,
+            )?,
+            3 => Self::Transfer {
+                amount: unpack_amount(cursor)?,
+            },
+            4 => Self::Approve {
+                amount: unpack_amount(cursor)?,
+            },
+            5 => no_trailing_data(Self::Revoke, cursor)?,
+            6 => no_trailing_data(
+                Self::SetAuthority {
+                    authority_type: cursor.read_u8()?,
+                    new_authority: cursor.read_optional_address()?,
+                },
+                cursor,
+            )?,
+            7 => Self::MintTo {
+                amount: unpack_amount(cursor)?,
+            },
+            8 => Self::Burn {
+                amount: unpack_amount(cursor)?,
+            },
+            9 => no_trailing_data(Self::CloseAccount, cursor)?,
+            10 => no_trailing_data(Self::FreezeAccount, cursor)?,
+            11 => no_trailing_data(Self::ThawAccount, cursor)?,
+            12 => {
+                let (amount, decimals) = unpack_amount_with_decimals(cursor)?;
+                Self::TransferChecked { amount, decimals }
+            }
+            13 => no_trailing_data(
+                Self::ApproveChecked {
+                    amount: cursor.read_u64()?,
+                    decimals: cursor.read_u8()?,
+                },
+                cursor,
+            )?,
+            14 => {
+                let (amount, decimals) = unpack_amount_with_decimals(cursor)?;
+                Self::MintToChecked { amount, decimals }
+            }
+            15 => {
+                let (amount, decimals) = unpack_amount_with_decimals(cursor)?;
+                Self::BurnChecked { amount, decimals }
+            }
+            16 => Self::InitializeAccount2 {
+                owner: unpack_owner(cursor)?,
+            },
+            17 => no_trailing_data(Self::SyncNative, cursor)?,
+            18 => Self::InitializeAccount3 {
+                owner: unpack_owner(cursor)?,
+            },
+            19 => no_trailing_data(
+                Self::InitializeMultisig2 {
+                    m: cursor.read_u8()?,
+                },
+                cursor,
+            )?,
+            20 => no_trailing_data(
+                Self::InitializeMint2 {
+                    decimals: cursor.read_u8()?,
+                    mint_authority: cursor.read_address()?,
+                    freeze_authority: cursor.read_optional_address()?,
+                },
+                cursor,
+            )?,
+            21 => no_trailing_data(Self::GetAccountDataSize, cursor)?,
+            22 => no_trailing_data(Self::InitializeImmutableOwner, cursor)?,
+            23 => Self::AmountToUiAmount {
+                amount: unpack_amount(cursor)?,
+            },
+            24 => Self::UiAmountToAmount {
+                ui_amount: core::str::from_utf8(data)
+                    .map_err(|_error| ProgramError::InvalidInstructionData)?,
+            },
+            100 => Self::Amm(data),
+            101 => Self::Staking(data),
+            102 => Self::MultisigWallet(data),
+            103 => Self::Timelock(data),
+            104 => Self::Config(data),
+            105 => Self::TransferGate(data),
+            106 => Self::Vault(data),
+            107 => Self::Auction(data),
+            108 => Self::DutchAuction(data),
+            109 => Self::Raffle(data),
+            110 => Self::Campaign(data),
+            111 => Self::Subscription(data),
+            112 => Self::Splitter(data),
+            113 => Self::Royalty(data),
+            114 => Self::Faucet(data),
+            115 => Self::FlashLoan(data),
+            116 => Self::Orderbook(data),
+            117 => Self::Otc(data),
+            118 => Self::Lockbox(data),
+            119 => Self::Profile(data),
+            120 => Self::Treasury(data),
+            121 => Self::Referral(data),
+            122 => Self::BondingCurve(data),
+            123 => Self::Soulbound(data),
+            124 => Self::Ticket(data),
+            125 => Self::Voucher(data),
+            126 => Self::Registry(data),
+            127 => Self::Points(data),
+            128 => Self::Savings(data),
+            129 => Self::DeadManSwitch(data),
+            130 => Self::SocialRecovery(data),
+            131 => Self::Grants(data),
+            132 => Self::Rewards(data),
+            133 => Self::Airdrop(data),
+            134 => Self::Sweep(data),
+            135 => Self::Governance(data),
+            136 => Self::Memo(data),
+            137 => Self::Stake(data),
+            138 => Self::Vote(data),
+            139 => Self::Loader(data),
+            140 => Self::MemoTransfer(data),
+            141 => Self::SignedEscrow(data),
+            142 => Self::EvmWallet(data),
+            143 => Self::VerifyPreimage(data),
+            144 => Self::TimeGatedTransfer(data),
+            145 => Self::CheckSlotHash(data),
+            146 => no_trailing_data(Self::GetEpochInfo, cursor)?,
+            147 => Self::TransferIfPrice(data),
+            148 => Self::LegacySwap(data),
+            149 => Self::PostMessage(data),
+            150 => Self::Guard(data),
+            151 => Self::SystemProxy(data),
+            152 => Self::Batch(data),
+            153 => no_trailing_data(Self::GetTokenBalance, cursor)?,
+            #[cfg(feature = "events")]
+            228 => Self::EmitEvent(data),
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+
+    /// Serializes the instruction into instruction data with the version `1`
+    /// layout.
+    #[cfg(feature = "client")]
+    pub fn pack_v1(&self) -> Vec<u8> {
+        let mut buffer = Vec::from([VERSION_PREFIX, 1, 0]);
+        buffer.extend_from_slice(&self.pack());
+        buffer
+    }
+
+    /// Serializes the instruction into instruction data.
+    #[cfg(feature = "client")]
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+
+        match self {
+            Self::InitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => {
+                buffer.extend_from_slice(&[0, *decimals]);
+                buffer.extend_from_slice(*mint_authority);
+                extend_optional_address(&mut buffer, *freeze_authority);
+            }
+            Self::InitializeAccount => buffer.push(1),
+            Self::InitializeMultisig { m } => buffer.extend_from_slice(&[2, *m]),
+            Self::Transfer { amount } => extend(&mut buffer, 3, &amount.to_le_bytes()),
+            Self::Approve { amount } => extend(&mut buffer, 4, &amount.to_le_bytes()),
+            Self::Revoke => buffer.push(5),
+            Self::SetAuthority {
+                authority_type,
+                new_authority,
+            } => {
+                buffer.extend_from_slice(&[6, *authority_type]);
+                extend_optional_address(&mut buffer, *new_authority);
+            }
+            Self::MintTo { amount } => extend(&mut buffer, 7, &amount.to_le_bytes()),
+            Self::Burn { amount } => extend(&mut buffer, 8, &amount.to_le_bytes()),
+            Self::CloseAccount => buffer.push(9),
+            Self::FreezeAccount => buffer.push(10),
+            Self::ThawAccount => buffer.push(11),
+            Self::TransferChecked { amount, decimals } => {
+                extend(&mut buffer, 12, &amount.to_le_bytes());
+                buffer.push(*decimals);
+            }
+            Self::ApproveChecked { amount, decimals } => {
+                extend(&mut buffer, 13, &amount.to_le_bytes());
+                buffer.push(*decimals);
+            }
+            Self::MintToChecked { amount, decimals } => {
+                extend(&mut buffer, 14, &amount.to_le_bytes());
+                buffer.push(*decimals);
+            }
+            Self::BurnChecked { amount, decimals } => {
+                extend(&mut buffer, 15, &amount.to_le_bytes());
+                buffer.push(*decimals);
+            }
+            Self::InitializeAccount2 { owner } => extend(&mut buffer, 16, *owner),
+            Self::SyncNative => buffer.push(17),
+            Self::InitializeAccount3 { owner } => extend(&mut buffer, 18, *owner),
+            Self::InitializeMultisig2 { m } => buffer.extend_from_slice(&[19, *m]),
+            Self::InitializeMint2 {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => {
+                buffer.extend_from_slice(&[20, *decimals]);
+                buffer.extend_from_slice(*mint_authority);
+                extend_optional_address(&mut buffer, *freeze_authority);
+            }
+            Self::GetAccountDataSize => buffer.push(21),
+            Self::InitializeImmutableOwner => buffer.push(22),
+            Self::AmountToUiAmount { amount } => extend(&mut buffer, 23, &amount.to_le_bytes()),
+            Self::UiAmountToAmount { ui_amount } => extend(&mut buffer, 24, ui_amount.as_bytes()),
+            Self::Amm(data) => extend(&mut buffer, 100, data),
+            Self::Staking(data) => extend(&mut buffer, 101, data),
+            Self::MultisigWallet(data) => extend(&mut buffer, 102, data),
+            Self::Timelock(data) => extend(&mut buffer, 103, data),
+            Self::Config(data) => extend(&mut buffer, 104, data),
+            Self::TransferGate(data) => extend(&mut buffer, 105, data),
+            Self::Vault(data) => extend(&mut buffer, 106, data),
+            Self::Auction(data) => extend(&mut buffer, 107, data),
+            Self::DutchAuction(data) => extend(&mut buffer, 108, data),
+            Self::Raffle(data) => extend(&mut buffer, 109, data),
+            Self::Campaign(data) => extend(&mut buffer, 110, data),
+            Self::Subscription(data) => extend(&mut buffer, 111, data),
+            Self::Splitter(data) => extend(&mut buffer, 112, data),
+            Self::Royalty(data) => extend(&mut buffer, 113, data),
+            Self::Faucet(data) => extend(&mut buffer, 114, data),
+            Self::FlashLoan(data) => extend(&mut buffer, 115, data),
+            Self::Orderbook(data) => extend(&mut buffer, 116, data),
+            Self::Otc(data) => extend(&mut buffer, 117, data),
+            Self::Lockbox(data) => extend(&mut buffer, 118, data),
+            Self::Profile(data) => extend(&mut buffer, 119, data),
+            Self::Treasury(data) => extend(&mut buffer, 120, data),
+            Self::Referral(data) => extend(&mut buffer, 121, data),
+            Self::BondingCurve(data) => extend(&mut buffer, 122, data),
+            Self::Soulbound(data) => extend(&mut buffer, 123, data),
+            Self::Ticket(data) => extend(&mut buffer, 124, data),
+            Self::Voucher(data) => extend(&mut buffer, 125, data),
+            Self::Registry(data) => extend(&mut buffer, 126, data),
+            Self::Points(data) => extend(&mut buffer, 127, data),
+            Self::Savings(data) => extend(&mut buffer, 128, data),
+            Self::DeadManSwitch(data) => extend(&mut buffer, 129, data),
+            Self::SocialRecovery(data) => extend(&mut buffer, 130, data),
+            Self::Grants(data) => extend(&mut buffer, 131, data),
+            Self::Rewards(data) => extend(&mut buffer, 132, data),
+            Self::Airdrop(data) => extend(&mut buffer, 133, data),
+            Self::Sweep(data) => extend(&mut buffer, 134, data),
+            Self::Governance(data) => extend(&mut buffer, 135, data),
+            Self::Memo(data) => extend(&mut buffer, 136, data),
+            Self::Stake(data) => extend(&mut buffer, 137, data),
+            Self::Vote(data) => extend(&mut buffer, 138, data),
+            Self::Loader(data) => extend(&mut buffer, 139, data),
+            Self::MemoTransfer(data) => extend(&mut buffer, 140, data),
+            Self::SignedEscrow(data) => extend(&mut buffer, 141, data),
+            Self::EvmWallet(data) => extend(&mut buffer, 142, data),
+            Self::VerifyPreimage(data) => extend(&mut buffer, 143, data),
+            Self::TimeGatedTransfer(data) => extend(&mut buffer, 144, data),
+            Self::CheckSlotHash(data) => extend(&mut buffer, 145, data),
+            Self::GetEpochInfo => buffer.push(146),
+            Self::TransferIfPrice(data) => extend(&mut buffer, 147, data),
+            Self::LegacySwap(data) => extend(&mut buffer, 148, data),
+            Self::PostMessage(data) => extend(&mut buffer, 149, data),
+            Self::Guard(data) => extend(&mut buffer, 150, data),
+            Self::SystemProxy(data) => extend(&mut buffer, 151, data),
+            Self::Batch(data) => extend(&mut buffer, 152, data),
+            Self::GetTokenBalance => buffer.push(153),
+            #[cfg(feature = "events")]
+            Self::EmitEvent(data) => extend(&mut buffer, 228, data),
+        }
+
+        buffer
+    }
+}
+
+/// Parses the amount of an instruction, which must be the only field.
+#[inline(always)]
+fn unpack_amount(mut cursor: Cursor) -> Result<u64, ProgramError> {
+    let amount = cursor.read_u64()?;
+    cursor.finish()?;
+
+    Ok(amount)
+}
+
+/// Parses the amount and decimals of an instruction, which must be the only
+/// fields.
+#[inline(always)]
+fn unpack_amount_with_decimals(mut cursor: Cursor) -> Result<(u64, u8), ProgramError> {
+    let amount = cursor.read_u64()?;
+    let decimals = cursor.read_u8()?;
+    cursor.finish()?;
+
+    Ok((amount, decimals))
+}
+
+/// Parses the owner of an instruction, which must be the only field.
+#[inline(always)]
+fn unpack_owner(mut cursor: Cursor) -> Result<&Pubkey, ProgramError> {
+    let owner = cursor.read_address()?;
+    cursor.finish()?;
+
+    Ok(owner)
+}
+
+/// Checks whether the `discriminator` is deprecated and resolves its alias.
+#[inline(always)]
+fn resolve_discriminator(
+    discriminator: u8,
+    deprecated: &[u8],
+    aliases: &[(u8, u8)],
+) -> Result<u8, ProgramError> {
+    let deprecated = deprecated.contains(&discriminator);
+
+    if deprecated && cfg!(feature = "reject-deprecated") {
+        return Err(ExtensionError::DeprecatedInstruction.into());
+    }
+
+    #[cfg(feature = "logging")]
+    if deprecated {
+        pinocchio_log::log!("Warning: instruction {} is deprecated", discriminator);
+    }
+
+    Ok(aliases
+        .iter()
+        .find(|(alias, _)| *alias == discriminator)
+        .map_or(discriminator, |(_, discriminator)| *discriminator))
+}
+
+/// Returns the parsed `instruction`, checking that all the data was read when the
+/// `strict-data` feature is enabled.
+///
+/// SPL Token ignores trailing data for these instructions, so it is accepted by
+/// default for compatibility.
+#[inline(always)]
+fn no_trailing_data<'a>(
+    instruction: ProgramInstruction<'a>,
+    cursor: Cursor,
+) -> Result<ProgramInstruction<'a>, ProgramError> {
+    if cfg!(feature = "strict-data") && !cursor.remaining().is_empty() {
+        return Err(ExtensionError::TrailingData.into());
+    }
+
+    Ok(instruction)
+}
+
+/// Appends the `discriminator` followed by `data` to `buffer`.
+#[cfg(feature = "client")]
+fn extend(buffer: &mut Vec<u8>, discriminator: u8, data: &[u8]) {
+    buffer.push(discriminator);
+    buffer.extend_from_slice(data);
+}
+
+/// Appends an optional address, as a `u8` tag followed by the address when it is
+/// present, to `buffer`.
+#[cfg(feature = "client")]
+fn extend_optional_address(buffer: &mut Vec<u8>, address: Option<&Pubkey>) {
+    match address {
+        Some(address) => {
+            buffer.push(1);
+            buffer.extend_from_slice(address);
+        }
+        None => buffer.push(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unpacks_token_instructions() {
+        let mut data = [0u8; 9];
+        data[0] = 3;
+        data[1..].copy_from_slice(&42u64.to_le_bytes());
+
+        assert_eq!(
+            ProgramInstruction::unpack(&data),
+            Ok(ProgramInstruction::Transfer { amount: 42 })
+        );
+
+        let mut data = [0u8; 35];
+        data[0] = 0;
+        data[1] = 6;
+        data[2..34].copy_from_slice(&[7; 32]);
+
+        assert_eq!(
+            ProgramInstruction::unpack(&data),
+            Ok(ProgramInstruction::InitializeMint {
+                decimals: 6,
+                mint_authority: &[7; 32],
+                freeze_authority: None,
+            })
+        );
+    }
+
+    #[test]
+    fn unpacks_spl_token_instructions() {
+        use spl_token::{
+            instruction::{AuthorityType, TokenInstruction},
+            solana_program::{program_option::COption, pubkey::Pubkey as SplPubkey},
+        };
+
+        let authority = SplPubkey::new_from_array([7; 32]);
+
+        let data = TokenInstruction::InitializeMint {
+            decimals: 6,
+            mint_authority: authority,
+            freeze_authority: COption::Some(authority),
+        }
+        .pack();
+
+        assert_eq!(
+            ProgramInstruction::unpack(&data),
+            Ok(ProgramInstruction::InitializeMint {
+                decimals: 6,
+                mint_authority: &[7; 32],
+                freeze_authority: Some(&[7; 32]),
+            })
+        );
+
+        let data = TokenInstruction::InitializeMint2 {
+            decimals: 0,
+            mint_authority: authority,
+            freeze_authority: COption::None,
+        }
+        .pack();
+
+        assert_eq!(
+            ProgramInstruction::unpack(&data),
+            Ok(ProgramInstruction::InitializeMint2 {
+                decimals: 0,
+                mint_authority: &[7; 32],
+                freeze_authority: None,
+            })
+        );
+
+        let data = TokenInstruction::SetAuthority {
+            authority_type: AuthorityType::CloseAccount,
+            new_authority: COption::None,
+        }
+        .pack();
+
+        assert_eq!(
+            ProgramInstruction::unpack(&data),
+            Ok(ProgramInstruction::SetAuthority {
+                authority_type: AuthorityType::CloseAccount as u8,
+                new_authority: None,
+            })
+        );
+
+        let data = TokenInstruction::TransferChecked {
+            amount: 42,
+            decimals: 9,
+        }
+        .pack();
+
+        assert_eq!(
+            ProgramInstruction::unpack(&data),
+            Ok(ProgramInstruction::TransferChecked {
+                amount: 42,
+                decimals: 9,
+            })
+        );
+
+        let data = TokenInstruction::UiAmountToAmount { ui_amount: "1.5" }.pack();
+
+        assert_eq!(
+            ProgramInstruction::unpack(&data),
+            Ok(ProgramInstruction::UiAmountToAmount { ui_amount: "1.5" })
+        );
+    }
+
+    #[test]
+    fn unpacks_extension_instructions() {
+        assert_eq!(
+            ProgramInstruction::unpack(&[100, 2, 1, 0]),
+            Ok(ProgramInstruction::Amm(&[2, 1, 0]))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_instruction_data() {
+        // Empty data.
+        assert_eq!(
+            ProgramInstruction::unpack(&[]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        // Truncated amount.
+        assert_eq!(
+            ProgramInstruction::unpack(&[3, 1, 2, 3]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        // Trailing data after the amount.
+        assert_eq!(
+            ProgramInstruction::unpack(&[7, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        // Freeze authority tag without the address.
+        let mut data = [1u8; 35];
+        data[0] = 20;
+        assert_eq!(
+            ProgramInstruction::unpack(&data),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        // Unknown discriminator.
+        assert_eq!(
+            ProgramInstruction::unpack(&[99]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn unpacks_versioned_instructions() {
+        assert_eq!(
+            ProgramInstruction::unpack(&[VERSION_PREFIX, 0, 9]),
+            Ok(ProgramInstruction::CloseAccount)
+        );
+        assert_eq!(
+            ProgramInstruction::unpack(&[VERSION_PREFIX, 1, 0, 9]),
+            Ok(ProgramInstruction::CloseAccount)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_versions() {
+        // Unknown version.
+        assert_eq!(
+            ProgramInstruction::unpack(&[VERSION_PREFIX, 2, 9]),
+            Err(ExtensionError::UnsupportedInstructionVersion.into())
+        );
+        // Missing version.
+        assert_eq!(
+            ProgramInstruction::unpack(&[VERSION_PREFIX]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        // Unknown flags.
+        assert_eq!(
+            ProgramInstruction::unpack(&[VERSION_PREFIX, 1, 1, 9]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn resolves_deprecated_and_aliased_discriminators() {
+        let deprecated = [40];
+        let aliases = [(40, 3), (41, 12)];
+
+        assert_eq!(resolve_discriminator(3, &deprecated, &aliases), Ok(3));
+        assert_eq!(resolve_discriminator(41, &deprecated, &aliases), Ok(12));
+
+        if cfg!(feature = "reject-deprecated") {
+            assert_eq!(
+                resolve_discriminator(40, &deprecated, &aliases),
+                Err(ExtensionError::DeprecatedInstruction.into())
+            );
+        } else {
+            assert_eq!(resolve_discriminator(40, &deprecated, &aliases), Ok(3));
+        }
+    }
+
+    #[test]
+    fn checks_trailing_data() {
+        let result = ProgramInstruction::unpack(&[9, 0]);
+
+        if cfg!(feature = "strict-data") {
+            assert_eq!(result, Err(ExtensionError::TrailingData.into()));
+        } else {
+            assert_eq!(result, Ok(ProgramInstruction::CloseAccount));
+        }
+
+        // Instructions with an amount always reject trailing data.
+        assert_eq!(
+            ProgramInstruction::unpack(&[3, 1, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn packs_and_unpacks_instructions() {
+        let instructions = [
+            ProgramInstruction::InitializeMint2 {
+                decimals: 9,
+                mint_authority: &[1; 32],
+                freeze_authority: Some(&[2; 32]),
+            },
+            ProgramInstruction::SetAuthority {
+                authority_type: 2,
+                new_authority: None,
+            },
+            ProgramInstruction::TransferChecked {
+                amount: 1_000,
+                decimals: 9,
+            },
+            ProgramInstruction::UiAmountToAmount { ui_amount: "1.5" },
+            ProgramInstruction::GetEpochInfo,
+            ProgramInstruction::GetTokenBalance,
+            ProgramInstruction::Guard(&[0, 3, 1, 0, 0, 0, 0, 0, 0, 0]),
+        ];
+
+        for instruction in instructions {
+            assert_eq!(
+                ProgramInstruction::unpack(&instruction.pack()),
+                Ok(instruction.clone())
+            );
+            assert_eq!(
+                ProgramInstruction::unpack(&instruction.pack_v1()),
+                Ok(instruction.clone())
+            );
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Restrictions on how an instruction may be invoked.
+//!
+//! The invocation stack height tells whether an instruction is a top-level
+//! instruction of the transaction or was invoked through a CPI. When invoked
+//! through a CPI, the calling program is read from the `Instructions` sysvar.
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::ExtensionError,
+    sysvar::instructions::{self, Instructions},
+};
+
+/// Stack height of the top-level instructions of a transaction.
+pub const TRANSACTION_LEVEL_STACK_HEIGHT: u64 = 1;
+
+/// Stack height of the instructions invoked by a CPI from a top-level instruction.
+pub const DIRECT_CPI_STACK_HEIGHT: u64 = TRANSACTION_LEVEL_STACK_HEIGHT + 1;
+
+/// Restriction on the invocation of an instruction.
+pub enum CallerRestriction<'a> {
+    /// The instruction must be a top-level instruction of the transaction.
+    TopLevelOnly,
+
+    /// The instruction must be a top-level instruction or be invoked through a CPI
+    /// from one of the programs.
+    ///
+    /// The `Instructions` sysvar only records the top-level instructions, so the
+    /// caller is only known when it is the program of the top-level instruction.
+    /// Nested CPIs are rejected, since the direct caller could be any program
+    /// invoked by an allowlisted one.
+    AllowlistedCallers(&'a [Pubkey]),
+}
+
+/// Returns the current invocation stack height.
+#[cfg(target_os = "solana")]
+#[inline(always)]
+pub fn get_stack_height() -> u64 {
+    // SAFETY: the syscall has no arguments and only reads the invocation context.
+    unsafe { pinocchio::syscalls::sol_get_stack_height() }
+}
+
+/// Returns the current invocation stack height.
+#[cfg(not(target_os = "solana"))]
+#[inline(always)]
+pub fn get_stack_height() -> u64 {
+    TRANSACTION_LEVEL_STACK_HEIGHT
+}
+
+/// Checks that the current instruction is invoked as allowed by `restriction`.
+///
+/// The `Instructions` sysvar is looked up in `accounts` when the instruction is
+/// invoked through a CPI from a program of an allowlist. At that stack height, the
+/// program of the current top-level instruction is the direct caller.
+#[inline(always)]
+pub fn check_caller(
+    restriction: CallerRestriction,
+    accounts: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    let stack_height = get_stack_height();
+
+    if stack_height == TRANSACTION_LEVEL_STACK_HEIGHT {
+        return Ok(());
+    }
+
+    let allowlist = match restriction {
+        CallerRestriction::AllowlistedCallers(allowlist)
+            if stack_height == DIRECT_CPI_STACK_HEIGHT =>
+        {
+            allowlist
+        }
+        _ => return Err(ExtensionError::CallerNotAllowed.into()),
+    };
+
+    let instructions_info = accounts
+        .iter()
+        .find(|account_info| account_info.key() == &instructions::ID)
+        .ok_or(ExtensionError::CallerNotAllowed)?;
+
+    let instructions = Instructions::from_account_info(instructions_info)?;
+    let instruction = instructions.load_instruction_at(instructions.current_index()?)?;
+
+    if !allowlist.contains(instruction.program_id) {
+        return Err(ExtensionError::CallerNotAllowed.into());
+    }
+
+    Ok(())
+}
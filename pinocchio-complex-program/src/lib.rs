@@ -2,5 +2,41 @@
 
 #![no_std]
 
+#[cfg(feature = "client")]
+extern crate alloc;
+
+// Declared first so the constraint macros are available to the other modules.
+#[macro_use]
+mod constraints;
+
+mod balance;
+mod bridge;
+mod ed25519;
 mod entrypoint;
+mod error;
+#[cfg(feature = "events")]
+mod events;
+mod hash;
+#[cfg(feature = "hooks")]
+mod hooks;
+pub mod instruction;
+mod invocation;
+mod loader;
+#[cfg(feature = "logging")]
+mod logging;
+mod math;
+mod memo;
+#[cfg(feature = "events")]
+mod noop;
+mod oracle;
+mod parse;
 mod processor;
+pub mod return_data;
+mod secp256k1;
+mod security;
+mod stake;
+mod state;
+mod system;
+mod sysvar;
+mod token_swap;
+mod vote;
@@ -0,0 +1,114 @@
+//! Helpers to invoke the BPF Upgradeable Loader program.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+use token_interface::program::ID as TOKEN_PROGRAM_ID;
+
+use crate::error::ExtensionError;
+
+/// BPF Upgradeable Loader program (`BPFLoaderUpgradeab1e11111111111111111111111`)
+/// address.
+pub const ID: Pubkey = [
+    2, 168, 246, 145, 78, 136, 161, 176, 226, 16, 21, 62, 247, 99, 174, 43, 0, 194, 185, 61, 22,
+    193, 36, 210, 192, 83, 122, 16, 4, 128, 0, 0,
+];
+
+/// Length of the program data account metadata.
+const PROGRAM_DATA_METADATA_LEN: usize = 45;
+
+/// Returns the upgrade authority of this program, read from its program data
+/// account, or `None` when the program is immutable.
+#[inline(always)]
+pub fn upgrade_authority(program_data: &AccountInfo) -> Result<Option<Pubkey>, ProgramError> {
+    let (address, _) = find_program_address(&[TOKEN_PROGRAM_ID.as_ref()], &ID);
+
+    if program_data.key() != &address {
+        return Err(ExtensionError::DerivedAddressMismatch.into());
+    }
+
+    require_owner!(program_data, &ID);
+
+    let data = program_data.try_borrow_data()?;
+
+    // Program data account layout:
+    // - [0..4]: state discriminator (3)
+    // - [4..12]: slot of the last deployment
+    // - [12]: upgrade authority option
+    // - [13..45]: upgrade authority
+    if data.len() < PROGRAM_DATA_METADATA_LEN || data[0..4] != [3, 0, 0, 0] {
+        return Err(ExtensionError::MalformedAccountData.into());
+    }
+
+    if data[12] == 0 {
+        return Ok(None);
+    }
+
+    let mut authority = Pubkey::default();
+    authority.copy_from_slice(&data[13..PROGRAM_DATA_METADATA_LEN]);
+
+    Ok(Some(authority))
+}
+
+/// Sets the authority of a buffer or program data account to `new_authority`.
+///
+/// The `signers` are used to sign for a derived `authority` address.
+#[inline(always)]
+pub fn set_authority(
+    account: &AccountInfo,
+    authority: &AccountInfo,
+    new_authority: &AccountInfo,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(account.key()),
+        AccountMeta::readonly_signer(authority.key()),
+        AccountMeta::readonly(new_authority.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (4)
+    let instruction_data = [4, 0, 0, 0];
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(&instruction, &[account, authority, new_authority], signers)
+}
+
+/// Closes a buffer account, moving its lamports to the `recipient` account.
+///
+/// The `signers` are used to sign for a derived `authority` address.
+#[inline(always)]
+pub fn close_buffer(
+    buffer: &AccountInfo,
+    recipient: &AccountInfo,
+    authority: &AccountInfo,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(buffer.key()),
+        AccountMeta::writable(recipient.key()),
+        AccountMeta::readonly_signer(authority.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (5)
+    let instruction_data = [5, 0, 0, 0];
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(&instruction, &[buffer, recipient, authority], signers)
+}
@@ -0,0 +1,81 @@
+//! Structured logging of the instructions, enabled by the `logging` feature.
+//!
+//! The parameters and accounts of an instruction are logged when it is dispatched
+//! and the error code is logged when it fails. Addresses are logged in base58 with
+//! the `sol_log_pubkey` syscall, which avoids formatting them in the program.
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey};
+use pinocchio_log::log;
+
+use crate::instruction::ProgramInstruction;
+
+/// Logs the parameters and the account addresses of an instruction.
+pub fn log_instruction(instruction: &ProgramInstruction, accounts: &[AccountInfo]) {
+    match instruction {
+        ProgramInstruction::InitializeMint {
+            decimals,
+            mint_authority,
+            freeze_authority,
+        }
+        | ProgramInstruction::InitializeMint2 {
+            decimals,
+            mint_authority,
+            freeze_authority,
+        } => {
+            log!("decimals: {}", *decimals);
+            log!("mint authority:");
+            pubkey::log(mint_authority);
+
+            if let Some(freeze_authority) = freeze_authority {
+                log!("freeze authority:");
+                pubkey::log(freeze_authority);
+            }
+        }
+        ProgramInstruction::InitializeMultisig { m }
+        | ProgramInstruction::InitializeMultisig2 { m } => log!("m: {}", *m),
+        ProgramInstruction::Transfer { amount }
+        | ProgramInstruction::Approve { amount }
+        | ProgramInstruction::MintTo { amount }
+        | ProgramInstruction::Burn { amount }
+        | ProgramInstruction::AmountToUiAmount { amount } => log!("amount: {}", *amount),
+        ProgramInstruction::TransferChecked { amount, decimals }
+        | ProgramInstruction::ApproveChecked { amount, decimals }
+        | ProgramInstruction::MintToChecked { amount, decimals }
+        | ProgramInstruction::BurnChecked { amount, decimals } => {
+            log!("amount: {}", *amount);
+            log!("decimals: {}", *decimals);
+        }
+        ProgramInstruction::SetAuthority {
+            authority_type,
+            new_authority,
+        } => {
+            log!("authority type: {}", *authority_type);
+
+            if let Some(new_authority) = new_authority {
+                log!("new authority:");
+                pubkey::log(new_authority);
+            }
+        }
+        ProgramInstruction::InitializeAccount2 { owner }
+        | ProgramInstruction::InitializeAccount3 { owner } => {
+            log!("owner:");
+            pubkey::log(owner);
+        }
+        ProgramInstruction::UiAmountToAmount { ui_amount } => log!("ui amount: {}", *ui_amount),
+        // Extension instructions log their own name and parameters.
+        _ => (),
+    }
+
+    for (index, account_info) in accounts.iter().enumerate() {
+        log!("account {}:", index);
+        pubkey::log(account_info.key());
+    }
+}
+
+/// Logs the error code of a failed instruction.
+pub fn log_error(error: &ProgramError) {
+    log!(
+        "Instruction failed: error code {}",
+        u64::from(error.clone())
+    );
+}
@@ -0,0 +1,79 @@
+//! Checked arithmetic on token amounts.
+//!
+//! Products are computed in `u128`, so they cannot overflow, and results that do
+//! not fit in a `u64` or divisions by zero fail with `Overflow` instead of
+//! aborting the program. The rounding of every division is explicit.
+
+use pinocchio::program_error::ProgramError;
+use token_interface::error::TokenError;
+
+/// Number of basis points in 100%.
+pub const MAX_BPS: u16 = 10_000;
+
+/// Rounding of a division.
+#[derive(Clone, Copy)]
+pub enum Rounding {
+    /// Rounds towards zero.
+    Down,
+    /// Rounds away from zero.
+    Up,
+}
+
+/// Computes `value * numerator / denominator`.
+#[inline(always)]
+pub fn mul_div(
+    value: u64,
+    numerator: u64,
+    denominator: u64,
+    rounding: Rounding,
+) -> Result<u64, ProgramError> {
+    if denominator == 0 {
+        return Err(TokenError::Overflow.into());
+    }
+
+    let product = value as u128 * numerator as u128;
+    let quotient = product / denominator as u128;
+
+    let quotient = match rounding {
+        Rounding::Up if product % denominator as u128 != 0 => quotient + 1,
+        _ => quotient,
+    };
+
+    u64::try_from(quotient).map_err(|_error| TokenError::Overflow.into())
+}
+
+/// Computes `bps` basis points of `amount`.
+#[inline(always)]
+pub fn bps(amount: u64, bps: u16, rounding: Rounding) -> Result<u64, ProgramError> {
+    mul_div(amount, bps as u64, MAX_BPS as u64, rounding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_divisions() {
+        assert_eq!(mul_div(10, 1, 3, Rounding::Down), Ok(3));
+        assert_eq!(mul_div(10, 1, 3, Rounding::Up), Ok(4));
+        assert_eq!(mul_div(9, 1, 3, Rounding::Up), Ok(3));
+        assert_eq!(bps(99, 100, Rounding::Down), Ok(0));
+        assert_eq!(bps(99, 100, Rounding::Up), Ok(1));
+    }
+
+    #[test]
+    fn rejects_overflows() {
+        assert_eq!(
+            mul_div(u64::MAX, u64::MAX, 1, Rounding::Down),
+            Err(TokenError::Overflow.into())
+        );
+        assert_eq!(
+            mul_div(1, 1, 0, Rounding::Down),
+            Err(TokenError::Overflow.into())
+        );
+        assert_eq!(
+            mul_div(u64::MAX, u64::MAX, u64::MAX, Rounding::Up),
+            Ok(u64::MAX)
+        );
+    }
+}
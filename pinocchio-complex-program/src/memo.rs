@@ -0,0 +1,67 @@
+//! Helpers to invoke the SPL Memo program.
+
+use core::{mem::MaybeUninit, slice::from_raw_parts};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Account, AccountMeta, Instruction, Signer},
+    program::invoke_signed_unchecked,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// SPL Memo program (`MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr`) address.
+pub const ID: Pubkey = [
+    5, 74, 83, 90, 153, 41, 33, 6, 77, 36, 232, 113, 96, 218, 56, 124, 124, 53, 181, 221, 188, 146,
+    187, 129, 228, 31, 168, 64, 65, 5, 68, 141,
+];
+
+/// Maximum number of signer accounts of a memo.
+pub const MAX_MEMO_SIGNERS: usize = 11;
+
+/// An uninitialized account meta.
+const UNINIT_ACCOUNT_META: MaybeUninit<AccountMeta> = MaybeUninit::uninit();
+
+/// An uninitialized CPI account.
+const UNINIT_ACCOUNT: MaybeUninit<Account> = MaybeUninit::uninit();
+
+/// Records a UTF-8 `text` memo signed by the `signer_accounts`.
+///
+/// The Memo program fails when any of the `signer_accounts` is not a signer of the
+/// instruction. The `signers` are used to sign for derived signer addresses.
+#[inline(always)]
+pub fn memo(text: &[u8], signer_accounts: &[AccountInfo], signers: &[Signer]) -> ProgramResult {
+    if signer_accounts.len() > MAX_MEMO_SIGNERS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut account_metas = [UNINIT_ACCOUNT_META; MAX_MEMO_SIGNERS];
+    let mut accounts = [UNINIT_ACCOUNT; MAX_MEMO_SIGNERS];
+
+    for (i, account_info) in signer_accounts.iter().enumerate() {
+        account_metas[i].write(AccountMeta::readonly_signer(account_info.key()));
+        accounts[i].write(Account::from(account_info));
+    }
+
+    // SAFETY: the first `signer_accounts.len()` elements of `account_metas` and
+    // `accounts` are initialized above.
+    let (account_metas, accounts) = unsafe {
+        (
+            from_raw_parts(
+                account_metas.as_ptr() as *const AccountMeta,
+                signer_accounts.len(),
+            ),
+            from_raw_parts(accounts.as_ptr() as *const Account, signer_accounts.len()),
+        )
+    };
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: account_metas,
+        data: text,
+    };
+
+    // SAFETY: the Memo program only reads the keys of the signer accounts, which
+    // are not mutably borrowed by the caller.
+    unsafe { invoke_signed_unchecked(&instruction, accounts, signers) }
+}
@@ -0,0 +1,29 @@
+//! Helpers to invoke the SPL Noop program.
+
+use pinocchio::{
+    instruction::Instruction, program::invoke, program_error::ProgramError, pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// SPL Noop program (`noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV`) address.
+pub const ID: Pubkey = [
+    11, 188, 15, 192, 187, 71, 202, 47, 116, 196, 17, 46, 148, 171, 19, 207, 163, 198, 52, 229,
+    220, 23, 234, 203, 3, 205, 26, 35, 205, 126, 120, 124,
+];
+
+/// Invokes the Noop program with `data`, recording it in the inner instructions of
+/// the transaction.
+#[inline(always)]
+pub fn wrap(data: &[u8]) -> ProgramResult {
+    if data.is_empty() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &[],
+        data,
+    };
+
+    invoke(&instruction, &[])
+}
@@ -0,0 +1,96 @@
+//! Readers of oracle price accounts.
+//!
+//! Each oracle module validates and parses the price accounts of its vendor into a
+//! common `Price`, so price-gated logic does not depend on a particular oracle.
+//! The vendor of a price account is selected from the account owner.
+//!
+//! Switchboard feeds are supported when the `switchboard` feature is enabled.
+
+pub mod pyth;
+#[cfg(feature = "switchboard")]
+pub mod switchboard;
+
+use core::cmp::Ordering;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::error::ExtensionError;
+
+/// Maximum confidence interval, in basis points of the price.
+const MAX_BPS: u128 = 10_000;
+
+/// Price reported by an oracle, in units of `10^exponent`.
+pub struct Price {
+    /// Price value.
+    pub price: i128,
+
+    /// Confidence interval around the price.
+    pub conf: u128,
+
+    /// Exponent of the price and confidence values.
+    pub exponent: i32,
+
+    /// Unix timestamp at which the price was published.
+    pub publish_time: i64,
+}
+
+impl Price {
+    /// Checks that the price was published at most `max_age` seconds before `now`.
+    #[inline(always)]
+    pub fn check_staleness(&self, now: i64, max_age: u64) -> ProgramResult {
+        if now.saturating_sub(self.publish_time) as i128 > max_age as i128 {
+            return Err(ExtensionError::StalePrice.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the confidence interval is at most `max_conf_bps` basis points of
+    /// the price.
+    #[inline(always)]
+    pub fn check_confidence(&self, max_conf_bps: u16) -> ProgramResult {
+        let conf = self.conf.checked_mul(MAX_BPS);
+        let max_conf = self.price.unsigned_abs().checked_mul(max_conf_bps as u128);
+
+        match (conf, max_conf) {
+            (Some(conf), Some(max_conf)) if conf <= max_conf => Ok(()),
+            _ => Err(ExtensionError::PriceConfidenceTooWide.into()),
+        }
+    }
+
+    /// Compares the price with `value * 10^exponent`.
+    ///
+    /// Returns `None` when scaling the values to the same exponent overflows.
+    #[inline(always)]
+    pub fn compare(&self, value: i64, exponent: i32) -> Option<Ordering> {
+        let (price, value) = match self.exponent.cmp(&exponent) {
+            Ordering::Less => (
+                self.price,
+                (value as i128)
+                    .checked_mul(10i128.checked_pow(exponent.abs_diff(self.exponent))?)?,
+            ),
+            Ordering::Greater => (
+                self.price
+                    .checked_mul(10i128.checked_pow(self.exponent.abs_diff(exponent))?)?,
+                value as i128,
+            ),
+            Ordering::Equal => (self.price, value as i128),
+        };
+
+        Some(price.cmp(&value))
+    }
+}
+
+/// Loads the price of `feed_id` from an oracle price account.
+///
+/// The `feed_id` identifies the price feed in the format of the account vendor.
+#[inline(always)]
+pub fn load_price(account_info: &AccountInfo, feed_id: &[u8; 32]) -> Result<Price, ProgramError> {
+    match account_info.owner() {
+        owner if owner == &pyth::RECEIVER_ID => pyth::load_price(account_info, feed_id),
+        #[cfg(feature = "switchboard")]
+        owner if owner == &switchboard::ON_DEMAND_ID => {
+            switchboard::load_price(account_info, feed_id)
+        }
+        _ => Err(ProgramError::IncorrectProgramId),
+    }
+}
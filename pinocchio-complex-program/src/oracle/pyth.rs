@@ -0,0 +1,74 @@
+//! Reader of Pyth pull oracle price accounts.
+//!
+//! Prices are posted by the Pyth Receiver program in `PriceUpdateV2` accounts. Only
+//! fully verified updates are accepted, i.e. updates whose Wormhole signatures were
+//! all verified by the receiver.
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use super::Price;
+use crate::error::ExtensionError;
+
+/// Pyth Receiver program (`rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ`) address.
+pub const RECEIVER_ID: Pubkey = [
+    12, 183, 250, 187, 82, 247, 166, 72, 187, 91, 49, 125, 154, 1, 139, 144, 87, 203, 2, 71, 116,
+    250, 254, 1, 230, 196, 223, 152, 204, 56, 88, 129,
+];
+
+/// Anchor discriminator of `PriceUpdateV2` accounts.
+const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// `Full` verification level.
+const VERIFICATION_LEVEL_FULL: u8 = 1;
+
+/// Length of a fully verified `PriceUpdateV2` account data.
+const PRICE_UPDATE_V2_LEN: usize = 133;
+
+/// Loads the price of `feed_id` from a `PriceUpdateV2` account.
+///
+/// The account data layout is:
+/// - [0..8]: discriminator
+/// - [8..40]: write authority
+/// - [40]: verification level (`1` for `Full`)
+/// - [41..73]: feed id
+/// - [73..81]: price (i64)
+/// - [81..89]: confidence (u64)
+/// - [89..93]: exponent (i32)
+/// - [93..101]: publish time (i64)
+/// - [101..133]: previous publish time, EMA price and confidence, posted slot
+#[inline(always)]
+pub fn load_price(account_info: &AccountInfo, feed_id: &[u8; 32]) -> Result<Price, ProgramError> {
+    require_owner!(account_info, &RECEIVER_ID);
+
+    // SAFETY: scoped immutable borrow to `account_info` account data, which is
+    // owned by the Pyth Receiver program.
+    let data = unsafe { account_info.borrow_data_unchecked() };
+
+    if data.len() < PRICE_UPDATE_V2_LEN || data[..8] != PRICE_UPDATE_V2_DISCRIMINATOR {
+        return Err(ExtensionError::AccountTypeMismatch.into());
+    }
+
+    if data[40] != VERIFICATION_LEVEL_FULL {
+        return Err(ExtensionError::PriceNotVerified.into());
+    }
+
+    if &data[41..73] != feed_id {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    let read_i64 = |offset: usize| {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&data[offset..offset + 8]);
+        i64::from_le_bytes(bytes)
+    };
+
+    let mut exponent = [0u8; 4];
+    exponent.copy_from_slice(&data[89..93]);
+
+    Ok(Price {
+        price: read_i64(73) as i128,
+        conf: read_i64(81) as u64 as u128,
+        exponent: i32::from_le_bytes(exponent),
+        publish_time: read_i64(93),
+    })
+}
@@ -0,0 +1,79 @@
+//! Reader of Switchboard on-demand pull feed accounts.
+//!
+//! Feeds are identified by the address of their `PullFeedAccountData` account, and
+//! their current result is the median of the latest oracle submissions, scaled by
+//! `10^18`.
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use super::Price;
+use crate::error::ExtensionError;
+
+/// Switchboard on-demand program (`SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv`)
+/// address.
+pub const ON_DEMAND_ID: Pubkey = [
+    6, 115, 189, 70, 242, 228, 126, 4, 241, 43, 217, 47, 183, 49, 150, 142, 205, 157, 151, 87, 194,
+    116, 218, 135, 71, 111, 70, 92, 4, 12, 101, 115,
+];
+
+/// Anchor discriminator of `PullFeedAccountData` accounts.
+const PULL_FEED_DISCRIMINATOR: [u8; 8] = [196, 27, 108, 196, 10, 215, 219, 40];
+
+/// Exponent of the feed values.
+const EXPONENT: i32 = -18;
+
+/// Offset of the last update timestamp.
+const LAST_UPDATE_TIMESTAMP_OFFSET: usize = 2216;
+
+/// Offset of the current result.
+const RESULT_OFFSET: usize = 2264;
+
+/// Minimum length of a `PullFeedAccountData` account data, up to the end of the
+/// current result.
+const PULL_FEED_MIN_LEN: usize = RESULT_OFFSET + 128;
+
+/// Loads the current result of a `PullFeedAccountData` account, whose address must
+/// be `feed_id`.
+///
+/// The account data layout is:
+/// - [0..8]: discriminator
+/// - [8..2216]: oracle submissions, authority, queue and feed configuration
+/// - [2216..2224]: last update timestamp (i64)
+/// - [2224..2264]: lookup table slot and reserved bytes
+/// - [2264..2280]: result value (i128)
+/// - [2280..2296]: result standard deviation (i128)
+/// - [2296..2392]: result mean, range, minimum and maximum values, number of
+///   samples and slots
+#[inline(always)]
+pub fn load_price(account_info: &AccountInfo, feed_id: &[u8; 32]) -> Result<Price, ProgramError> {
+    require_owner!(account_info, &ON_DEMAND_ID);
+
+    if account_info.key() != feed_id {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // SAFETY: scoped immutable borrow to `account_info` account data, which is
+    // owned by the Switchboard on-demand program.
+    let data = unsafe { account_info.borrow_data_unchecked() };
+
+    if data.len() < PULL_FEED_MIN_LEN || data[..8] != PULL_FEED_DISCRIMINATOR {
+        return Err(ExtensionError::AccountTypeMismatch.into());
+    }
+
+    let read_i128 = |offset: usize| {
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&data[offset..offset + 16]);
+        i128::from_le_bytes(bytes)
+    };
+
+    let mut last_update_timestamp = [0u8; 8];
+    last_update_timestamp
+        .copy_from_slice(&data[LAST_UPDATE_TIMESTAMP_OFFSET..LAST_UPDATE_TIMESTAMP_OFFSET + 8]);
+
+    Ok(Price {
+        price: read_i128(RESULT_OFFSET),
+        conf: read_i128(RESULT_OFFSET + 16).unsigned_abs(),
+        exponent: EXPONENT,
+        publish_time: i64::from_le_bytes(last_update_timestamp),
+    })
+}
@@ -0,0 +1,221 @@
+//! Safe parsing of instruction data.
+//!
+//! Instruction data is read through a cursor that checks the length of the
+//! remaining data on every read, so truncated instruction data results in an
+//! `InvalidInstructionData` error instead of an out-of-bounds read.
+
+use pinocchio::{
+    program_error::ProgramError,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+
+/// Cursor over instruction data.
+pub struct Cursor<'a> {
+    /// Data not read yet.
+    data: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    /// Creates a cursor at the start of `data`.
+    #[inline(always)]
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Returns the data not read yet.
+    #[inline(always)]
+    pub fn remaining(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Checks that all the data was read.
+    #[inline(always)]
+    pub fn finish(self) -> Result<(), ProgramError> {
+        if self.data.is_empty() {
+            Ok(())
+        } else {
+            Err(ProgramError::InvalidInstructionData)
+        }
+    }
+
+    /// Reads `len` bytes.
+    #[inline(always)]
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], ProgramError> {
+        if self.data.len() < len {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let (bytes, data) = self.data.split_at(len);
+        self.data = data;
+
+        Ok(bytes)
+    }
+
+    /// Reads a `u8`.
+    #[inline(always)]
+    pub fn read_u8(&mut self) -> Result<u8, ProgramError> {
+        self.read_array::<1>().map(|bytes| bytes[0])
+    }
+
+    /// Reads a little-endian `u16`.
+    #[inline(always)]
+    pub fn read_u16(&mut self) -> Result<u16, ProgramError> {
+        self.read_array::<2>()
+            .map(|bytes| u16::from_le_bytes(*bytes))
+    }
+
+    /// Reads a little-endian `u32`.
+    #[inline(always)]
+    pub fn read_u32(&mut self) -> Result<u32, ProgramError> {
+        self.read_array::<4>()
+            .map(|bytes| u32::from_le_bytes(*bytes))
+    }
+
+    /// Reads a little-endian `i32`.
+    #[inline(always)]
+    pub fn read_i32(&mut self) -> Result<i32, ProgramError> {
+        self.read_array::<4>()
+            .map(|bytes| i32::from_le_bytes(*bytes))
+    }
+
+    /// Reads a little-endian `u64`.
+    #[inline(always)]
+    pub fn read_u64(&mut self) -> Result<u64, ProgramError> {
+        self.read_array::<8>()
+            .map(|bytes| u64::from_le_bytes(*bytes))
+    }
+
+    /// Reads a little-endian `i64`.
+    #[inline(always)]
+    pub fn read_i64(&mut self) -> Result<i64, ProgramError> {
+        self.read_array::<8>()
+            .map(|bytes| i64::from_le_bytes(*bytes))
+    }
+
+    /// Reads an address.
+    #[inline(always)]
+    pub fn read_address(&mut self) -> Result<&'a Pubkey, ProgramError> {
+        self.read_array::<PUBKEY_BYTES>()
+    }
+
+    /// Reads an optional address, encoded as in SPL Token instructions: a `u8` tag
+    /// (`0` for `None`, `1` for `Some`) followed by the address when present.
+    #[inline(always)]
+    pub fn read_optional_address(&mut self) -> Result<Option<&'a Pubkey>, ProgramError> {
+        match self.read_u8()? {
+            0 => Ok(None),
+            1 => self.read_address().map(Some),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    /// Reads a fixed-size array of `N` bytes.
+    #[inline(always)]
+    pub fn read_array<const N: usize>(&mut self) -> Result<&'a [u8; N], ProgramError> {
+        self.read_bytes(N)?
+            .try_into()
+            .map_err(|_error| ProgramError::InvalidInstructionData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_values_in_order() {
+        let mut data = [0u8; 50];
+        data[0] = 7;
+        data[1..9].copy_from_slice(&42u64.to_le_bytes());
+        data[9..17].copy_from_slice(&(-42i64).to_le_bytes());
+        data[17] = 1;
+        data[18..50].copy_from_slice(&[9; 32]);
+
+        let mut cursor = Cursor::new(&data);
+
+        assert_eq!(cursor.read_u8(), Ok(7));
+        assert_eq!(cursor.read_u64(), Ok(42));
+        assert_eq!(cursor.read_i64(), Ok(-42));
+        assert_eq!(cursor.read_optional_address(), Ok(Some(&[9; 32])));
+        assert!(cursor.remaining().is_empty());
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        assert_eq!(
+            Cursor::new(&[]).read_u8(),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            Cursor::new(&[0; 1]).read_u16(),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            Cursor::new(&[0; 3]).read_u32(),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            Cursor::new(&[0; 3]).read_i32(),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            Cursor::new(&[0; 7]).read_u64(),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            Cursor::new(&[0; 7]).read_i64(),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            Cursor::new(&[0; 31]).read_address(),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            Cursor::new(&[1; 32]).read_optional_address(),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            Cursor::new(&[0; 3]).read_bytes(4),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn does_not_advance_on_truncated_data() {
+        let data = [1, 2, 3];
+        let mut cursor = Cursor::new(&data);
+
+        assert!(cursor.read_u64().is_err());
+        assert_eq!(cursor.remaining(), &data);
+        assert_eq!(cursor.read_u8(), Ok(1));
+        assert_eq!(cursor.remaining(), &data[1..]);
+    }
+
+    #[test]
+    fn rejects_unread_data() {
+        let mut cursor = Cursor::new(&[1, 2]);
+
+        assert_eq!(cursor.read_u8(), Ok(1));
+        assert_eq!(cursor.finish(), Err(ProgramError::InvalidInstructionData));
+        assert_eq!(Cursor::new(&[]).finish(), Ok(()));
+    }
+
+    #[test]
+    fn reads_absent_optional_address() {
+        let mut cursor = Cursor::new(&[0]);
+
+        assert_eq!(cursor.read_optional_address(), Ok(None));
+        assert!(cursor.remaining().is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_optional_address_tag() {
+        let mut data = [0u8; 33];
+        data[0] = 2;
+
+        assert_eq!(
+            Cursor::new(&data).read_optional_address(),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load, load_mut_unchecked, mint::Mint, RawType},
+};
+
+use crate::{
+    processor::{check_derived_address, create_derived_account},
+    state::airdrop::{Airdrop, AIRDROP_SEED},
+};
+
+/// Initializes a new airdrop.
+///
+/// The mint authority of the mint must already be set to the airdrop derived
+/// address.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The airdrop authority, paying for the airdrop account.
+/// 1. `[writable]` The airdrop account.
+/// 2. `[]` The mint of the airdropped tokens.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_initialize_airdrop(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected u8 (1)
+    let [bump] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [authority_info, airdrop_info, mint_info, _system_program_info, _remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: scoped immutable borrow to `mint_info` account data and
+    // `load` validates that the mint is initialized.
+    let mint = unsafe { load::<Mint>(mint_info.borrow_data_unchecked())? };
+
+    if mint.mint_authority() != Some(airdrop_info.key()) {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    // Creates the airdrop account.
+
+    let bump_seed = [*bump];
+    check_derived_address(
+        airdrop_info,
+        &[AIRDROP_SEED, mint_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(AIRDROP_SEED),
+        Seed::from(mint_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(authority_info, airdrop_info, Airdrop::LEN, &seeds)?;
+
+    // Initializes the airdrop.
+
+    // SAFETY: single mutable borrow to `airdrop_info` account data; the account was
+    // created above with the expected length.
+    let airdrop =
+        unsafe { load_mut_unchecked::<Airdrop>(airdrop_info.borrow_mut_data_unchecked())? };
+
+    airdrop.set_initialized();
+    airdrop.bump = *bump;
+    airdrop.authority = *authority_info.key();
+    airdrop.mint = *mint_info.key();
+
+    Ok(())
+}
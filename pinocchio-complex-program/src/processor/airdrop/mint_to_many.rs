@@ -0,0 +1,81 @@
+use core::mem::size_of;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_log::log;
+use token_interface::{
+    error::TokenError,
+    state::{load, mint::Mint},
+};
+
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_account_owner, check_decimals, shared::custody},
+    state::airdrop::{Airdrop, MAX_RECIPIENTS},
+};
+
+/// Mints tokens to many recipients, checking the decimals of the mint.
+///
+/// The instruction data holds the expected decimals followed by one amount for
+/// each recipient token account, in the same order as the accounts. When minting
+/// to a recipient fails, its index is logged before returning the error.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The airdrop account.
+/// 1. `[writable]` The mint of the airdropped tokens.
+/// 2. `[signer]` The airdrop authority.
+/// 3. ..3+N `[writable]` The N recipient token accounts, at most `MAX_RECIPIENTS`.
+#[inline(always)]
+pub fn process_mint_to_many(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let [airdrop_info, mint_info, authority_info, recipients @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // expected u8 (1) + u64 (8) * N
+    let mut cursor = Cursor::new(instruction_data);
+    let decimals = cursor.read_u8()?;
+
+    if recipients.is_empty()
+        || recipients.len() > MAX_RECIPIENTS
+        || cursor.remaining().len() != recipients.len() * size_of::<u64>()
+    {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    check_account_owner(airdrop_info)?;
+    // SAFETY: scoped immutable borrow to `airdrop_info` account data and
+    // `load` validates that the airdrop is initialized.
+    let airdrop = unsafe { load::<Airdrop>(airdrop_info.borrow_data_unchecked())? };
+
+    if mint_info.key() != &airdrop.mint {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if &airdrop.authority != authority_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(authority_info);
+
+    {
+        // SAFETY: scoped immutable borrow to `mint_info` account data and
+        // `load` validates that the mint is initialized.
+        let mint = unsafe { load::<Mint>(mint_info.borrow_data_unchecked())? };
+
+        check_decimals(decimals, mint)?;
+    }
+
+    // Mints the tokens to each recipient.
+
+    for (index, recipient_info) in recipients.iter().enumerate() {
+        let amount = cursor.read_u64()?;
+
+        if let Err(error) = custody::mint_to(mint_info, recipient_info, airdrop_info.key(), amount)
+        {
+            log!("MintToMany: recipient {} failed", index);
+            return Err(error);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//! Batched airdrops.
+//!
+//! The airdrop account is the mint authority of its mint, and its authority can
+//! mint to many recipients in a single instruction.
+//!
+//! Instructions:
+//!
+//! - `0`: `InitializeAirdrop`
+//! - `1`: `MintToMany`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod initialize_airdrop;
+pub mod mint_to_many;
+
+pub use initialize_airdrop::process_initialize_airdrop;
+pub use mint_to_many::process_mint_to_many;
+
+/// Process an airdrop instruction.
+#[inline(always)]
+pub fn process_airdrop_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - InitializeAirdrop
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializeAirdrop");
+
+            process_initialize_airdrop(accounts, instruction_data)
+        }
+        // 1 - MintToMany
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: MintToMany");
+
+            process_mint_to_many(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
@@ -0,0 +1,142 @@
+use core::cmp::min;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load, mint::Mint},
+};
+
+use super::{locked_supply, sqrt, MINIMUM_LIQUIDITY};
+use crate::{
+    error::ExtensionError,
+    math::{self, Rounding},
+    parse::Cursor,
+    processor::{check_account_owner, shared},
+    state::pool::Pool,
+};
+
+/// Deposits tokens into the pool in exchange for LP tokens.
+///
+/// The amount of LP tokens minted is proportional to the smallest share of the
+/// reserves deposited, and only the amounts matching that share are transferred:
+/// `maximum_amount_a` and `maximum_amount_b` are upper bounds. The first deposit
+/// mints the geometric mean of the amounts minus [`MINIMUM_LIQUIDITY`], which
+/// stays locked in the pool.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The pool account.
+/// 1. `[writable]` The LP mint.
+/// 2. `[writable]` The destination LP token account.
+/// 3. `[writable]` The token A vault.
+/// 4. `[writable]` The token B vault.
+/// 5. `[writable]` The source token A account.
+/// 6. `[writable]` The source token B account.
+/// 7. `[signer]` The source accounts owner or delegate.
+#[inline(always)]
+pub fn process_add_liquidity(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let maximum_amount_a = cursor.read_u64()?;
+    let maximum_amount_b = cursor.read_u64()?;
+    let minimum_lp_amount = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [pool_info, lp_mint_info, destination_lp_info, vault_a_info, vault_b_info, source_a_info, source_b_info, authority_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validates the pool accounts.
+
+    check_account_owner(pool_info)?;
+    // SAFETY: single immutable borrow to `pool_info` account data and
+    // `load` validates that the pool is initialized.
+    let pool = unsafe { load::<Pool>(pool_info.borrow_data_unchecked())? };
+
+    if lp_mint_info.key() != &pool.lp_mint
+        || vault_a_info.key() != &pool.vault_a
+        || vault_b_info.key() != &pool.vault_b
+    {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // Calculates the LP amount.
+
+    // SAFETY: scoped immutable borrows to the vaults and LP mint account data; the
+    // accounts were initialized by the pool.
+    let (reserve_a, reserve_b, lp_supply) = unsafe {
+        (
+            load::<Account>(vault_a_info.borrow_data_unchecked())?.amount(),
+            load::<Account>(vault_b_info.borrow_data_unchecked())?.amount(),
+            load::<Mint>(lp_mint_info.borrow_data_unchecked())?.supply(),
+        )
+    };
+
+    let (lp_amount, amount_a, amount_b) = if lp_supply == 0 {
+        let liquidity = sqrt(maximum_amount_a as u128 * maximum_amount_b as u128);
+        let liquidity = u64::try_from(liquidity).map_err(|_error| TokenError::Overflow)?;
+
+        (
+            liquidity.saturating_sub(MINIMUM_LIQUIDITY),
+            maximum_amount_a,
+            maximum_amount_b,
+        )
+    } else {
+        if reserve_a == 0 || reserve_b == 0 {
+            return Err(ExtensionError::InsufficientLiquidity.into());
+        }
+
+        let total_supply = locked_supply(lp_supply)?;
+
+        let lp_amount = min(
+            math::mul_div(maximum_amount_a, total_supply, reserve_a, Rounding::Down)?,
+            math::mul_div(maximum_amount_b, total_supply, reserve_b, Rounding::Down)?,
+        );
+
+        // Rounding up, the amounts are at most the maximum amounts since
+        // `lp_amount` is rounded down.
+        (
+            lp_amount,
+            math::mul_div(lp_amount, reserve_a, total_supply, Rounding::Up)?,
+            math::mul_div(lp_amount, reserve_b, total_supply, Rounding::Up)?,
+        )
+    };
+
+    if lp_amount == 0 {
+        return Err(ExtensionError::InsufficientLiquidity.into());
+    }
+
+    if lp_amount < minimum_lp_amount {
+        return Err(ExtensionError::SlippageExceeded.into());
+    }
+
+    // Moves the tokens into the vaults.
+
+    shared::transfer::process_transfer(
+        &[
+            source_a_info.clone(),
+            vault_a_info.clone(),
+            authority_info.clone(),
+        ],
+        amount_a,
+        None,
+    )?;
+    shared::transfer::process_transfer(
+        &[
+            source_b_info.clone(),
+            vault_b_info.clone(),
+            authority_info.clone(),
+        ],
+        amount_b,
+        None,
+    )?;
+
+    // Mints the LP tokens.
+
+    shared::custody::mint_to(
+        lp_mint_info,
+        destination_lp_info,
+        pool_info.key(),
+        lp_amount,
+    )
+}
@@ -0,0 +1,113 @@
+use core::slice::from_ref;
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use super::MAX_FEE_BPS;
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, process_initialize_mint, shared},
+    state::pool::{Pool, POOL_SEED},
+};
+
+/// Number of decimals of the LP mint.
+const LP_MINT_DECIMALS: u8 = 9;
+
+/// Initializes a new pool.
+///
+/// The vaults and the LP mint must be uninitialized accounts owned by the token
+/// program; they are initialized with the pool derived address as their owner and
+/// mint authority, respectively.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the pool account.
+/// 1. `[writable]` The pool account.
+/// 2. `[]` The mint of the token A.
+/// 3. `[]` The mint of the token B.
+/// 4. `[writable]` The token A vault.
+/// 5. `[writable]` The token B vault.
+/// 6. `[writable]` The LP mint.
+/// 7. `[]` The system program.
+#[inline(always)]
+pub fn process_initialize_pool(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let fee_bps = cursor.read_u16()?;
+    cursor.finish()?;
+
+    if fee_bps > MAX_FEE_BPS {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [payer_info, pool_info, mint_a_info, mint_b_info, vault_a_info, vault_b_info, lp_mint_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if mint_a_info.key() == mint_b_info.key() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Creates the pool account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        pool_info,
+        &[
+            POOL_SEED,
+            mint_a_info.key().as_ref(),
+            mint_b_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(POOL_SEED),
+        Seed::from(mint_a_info.key()),
+        Seed::from(mint_b_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(payer_info, pool_info, Pool::LEN, &seeds)?;
+
+    // Initializes the vaults and LP mint under the pool authority.
+
+    shared::initialize_account::process_initialize_account(
+        &[vault_a_info.clone(), mint_a_info.clone()],
+        Some(pool_info.key()),
+        false,
+    )?;
+    shared::initialize_account::process_initialize_account(
+        &[vault_b_info.clone(), mint_b_info.clone()],
+        Some(pool_info.key()),
+        false,
+    )?;
+
+    process_initialize_mint(
+        from_ref(lp_mint_info),
+        LP_MINT_DECIMALS,
+        pool_info.key(),
+        None,
+        false,
+    )?;
+
+    // Initializes the pool.
+
+    // SAFETY: single mutable borrow to `pool_info` account data; the account was
+    // created above with the expected length.
+    let pool = unsafe { load_mut_unchecked::<Pool>(pool_info.borrow_mut_data_unchecked())? };
+
+    pool.set_initialized();
+    pool.bump = bump;
+    pool.set_fee_bps(fee_bps);
+    pool.mint_a = *mint_a_info.key();
+    pool.mint_b = *mint_b_info.key();
+    pool.vault_a = *vault_a_info.key();
+    pool.vault_b = *vault_b_info.key();
+    pool.lp_mint = *lp_mint_info.key();
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! Constant-product AMM.
+//!
+//! A pool holds the reserves of two tokens in vaults owned by the pool derived
+//! address, which is also the mint authority of the pool LP mint. Swaps keep the
+//! product of the reserves constant (minus the swap fee, which accrues to the
+//! liquidity providers).
+//!
+//! The first deposit locks [`MINIMUM_LIQUIDITY`] LP tokens: they are never minted
+//! but count in the LP supply of every computation, so the share price cannot be
+//! inflated by the first liquidity provider to round later deposits down to zero.
+//!
+//! Instructions:
+//!
+//! - `0`: `InitializePool`
+//! - `1`: `AddLiquidity`
+//! - `2`: `RemoveLiquidity`
+//! - `3`: `Swap`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::error::TokenError;
+
+pub mod add_liquidity;
+pub mod initialize_pool;
+pub mod remove_liquidity;
+pub mod swap;
+
+pub use add_liquidity::process_add_liquidity;
+pub use initialize_pool::process_initialize_pool;
+pub use remove_liquidity::process_remove_liquidity;
+pub use swap::process_swap;
+
+/// Maximum swap fee in basis points.
+const MAX_FEE_BPS: u16 = 10_000;
+
+/// Amount of LP tokens locked by the first deposit.
+pub const MINIMUM_LIQUIDITY: u64 = 1_000;
+
+/// Process an AMM instruction.
+#[inline(always)]
+pub fn process_amm_instruction(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - InitializePool
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializePool");
+
+            process_initialize_pool(accounts, instruction_data)
+        }
+        // 1 - AddLiquidity
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: AddLiquidity");
+
+            process_add_liquidity(accounts, instruction_data)
+        }
+        // 2 - RemoveLiquidity
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: RemoveLiquidity");
+
+            process_remove_liquidity(accounts, instruction_data)
+        }
+        // 3 - Swap
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Swap");
+
+            process_swap(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Integer square root (rounded down).
+#[inline(always)]
+fn sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = (x + 1) / 2;
+
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+
+    x
+}
+
+/// Returns the LP supply including the locked [`MINIMUM_LIQUIDITY`].
+#[inline(always)]
+fn locked_supply(lp_supply: u64) -> Result<u64, ProgramError> {
+    lp_supply
+        .checked_add(MINIMUM_LIQUIDITY)
+        .ok_or(TokenError::Overflow.into())
+}
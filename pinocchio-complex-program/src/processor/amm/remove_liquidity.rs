@@ -0,0 +1,96 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::{account::Account, load, mint::Mint};
+
+use super::locked_supply;
+use crate::{
+    error::ExtensionError,
+    math::{self, Rounding},
+    parse::Cursor,
+    processor::{check_account_owner, shared},
+    state::pool::Pool,
+};
+
+/// Burns LP tokens in exchange for the proportional share of the reserves.
+///
+/// The share is computed over the LP supply including the locked
+/// [`super::MINIMUM_LIQUIDITY`].
+///
+/// Accounts expected:
+///
+/// 0. `[]` The pool account.
+/// 1. `[writable]` The source LP token account.
+/// 2. `[writable]` The LP mint.
+/// 3. `[signer]` The source LP account owner or delegate.
+/// 4. `[writable]` The token A vault.
+/// 5. `[writable]` The token B vault.
+/// 6. `[writable]` The destination token A account.
+/// 7. `[writable]` The destination token B account.
+#[inline(always)]
+pub fn process_remove_liquidity(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let lp_amount = cursor.read_u64()?;
+    let minimum_amount_a = cursor.read_u64()?;
+    let minimum_amount_b = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [pool_info, _source_lp_info, lp_mint_info, _authority_info, vault_a_info, vault_b_info, destination_a_info, destination_b_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validates the pool accounts.
+
+    check_account_owner(pool_info)?;
+    // SAFETY: single immutable borrow to `pool_info` account data and
+    // `load` validates that the pool is initialized.
+    let pool = unsafe { load::<Pool>(pool_info.borrow_data_unchecked())? };
+
+    if lp_mint_info.key() != &pool.lp_mint
+        || vault_a_info.key() != &pool.vault_a
+        || vault_b_info.key() != &pool.vault_b
+    {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // Calculates the amounts to withdraw.
+
+    // SAFETY: scoped immutable borrows to the vaults and LP mint account data; the
+    // accounts were initialized by the pool.
+    let (reserve_a, reserve_b, lp_supply) = unsafe {
+        (
+            load::<Account>(vault_a_info.borrow_data_unchecked())?.amount(),
+            load::<Account>(vault_b_info.borrow_data_unchecked())?.amount(),
+            load::<Mint>(lp_mint_info.borrow_data_unchecked())?.supply(),
+        )
+    };
+
+    if lp_supply == 0 {
+        return Err(ExtensionError::InsufficientLiquidity.into());
+    }
+
+    // The locked LP tokens are part of the supply, so their share of the reserves
+    // stays in the pool.
+    let total_supply = locked_supply(lp_supply)?;
+
+    // The amounts are bounded by the reserves since `lp_amount <= lp_supply` is
+    // enforced by the burn.
+    let amount_a = math::mul_div(lp_amount, reserve_a, total_supply, Rounding::Down)?;
+    let amount_b = math::mul_div(lp_amount, reserve_b, total_supply, Rounding::Down)?;
+
+    if amount_a < minimum_amount_a || amount_b < minimum_amount_b {
+        return Err(ExtensionError::SlippageExceeded.into());
+    }
+
+    // Burns the LP tokens.
+
+    shared::burn::process_burn(&accounts[1..4], lp_amount, None)?;
+
+    // Moves the tokens out of the vaults.
+
+    shared::custody::transfer(vault_a_info, destination_a_info, pool_info.key(), amount_a)?;
+    shared::custody::transfer(vault_b_info, destination_b_info, pool_info.key(), amount_b)
+}
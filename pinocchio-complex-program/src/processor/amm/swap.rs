@@ -0,0 +1,125 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load},
+};
+
+use crate::{
+    error::ExtensionError,
+    math::{self, Rounding},
+    parse::Cursor,
+    processor::{check_account_owner, shared, treasury::protocol_fee},
+    state::pool::Pool,
+};
+
+/// Swaps tokens using the pool reserves.
+///
+/// The direction of the swap is given by the vault accounts: tokens are deposited
+/// into the "input" vault and withdrawn from the "output" vault.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The pool account.
+/// 1. `[writable]` The source token account.
+/// 2. `[writable]` The input vault.
+/// 3. `[signer]` The source account owner or delegate.
+/// 4. `[writable]` The output vault.
+/// 5. `[writable]` The destination token account.
+/// 6. ..11 The fee accounts, to take the protocol fee out of the input amount (see
+///    [`protocol_fee`]).
+#[inline(always)]
+pub fn process_swap(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount_in = cursor.read_u64()?;
+    let minimum_amount_out = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [pool_info, source_info, vault_in_info, authority_info, vault_out_info, destination_info, fee_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validates the pool accounts.
+
+    check_account_owner(pool_info)?;
+    // SAFETY: single immutable borrow to `pool_info` account data and
+    // `load` validates that the pool is initialized.
+    let pool = unsafe { load::<Pool>(pool_info.borrow_data_unchecked())? };
+
+    let a_to_b = vault_in_info.key() == &pool.vault_a && vault_out_info.key() == &pool.vault_b;
+    let b_to_a = vault_in_info.key() == &pool.vault_b && vault_out_info.key() == &pool.vault_a;
+
+    if !(a_to_b || b_to_a) {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // Takes the protocol fee out of the input amount.
+
+    let treasury_fee = protocol_fee(fee_accounts, authority_info.key(), amount_in)?;
+
+    let amount_in = match &treasury_fee {
+        Some(treasury_fee) => amount_in
+            .checked_sub(treasury_fee.amount()?)
+            .ok_or(TokenError::Overflow)?,
+        None => amount_in,
+    };
+
+    // Calculates the output amount.
+
+    // SAFETY: scoped immutable borrows to the vaults account data; the accounts were
+    // initialized by the pool.
+    let (reserve_in, reserve_out) = unsafe {
+        (
+            load::<Account>(vault_in_info.borrow_data_unchecked())?.amount(),
+            load::<Account>(vault_out_info.borrow_data_unchecked())?.amount(),
+        )
+    };
+
+    // `fee_bps <= MAX_FEE_BPS`, so the fee is at most `amount_in`.
+    let fee = math::bps(amount_in, pool.fee_bps(), Rounding::Down)?;
+    let amount_in_after_fee = amount_in.checked_sub(fee).ok_or(TokenError::Overflow)?;
+
+    let amount_out = math::mul_div(
+        reserve_out,
+        amount_in_after_fee,
+        reserve_in
+            .checked_add(amount_in_after_fee)
+            .ok_or(TokenError::Overflow)?,
+        Rounding::Down,
+    )
+    .map_err(|_error| ExtensionError::InsufficientLiquidity)?;
+
+    if amount_out == 0 {
+        return Err(ExtensionError::InsufficientLiquidity.into());
+    }
+
+    if amount_out < minimum_amount_out {
+        return Err(ExtensionError::SlippageExceeded.into());
+    }
+
+    // Moves the tokens.
+
+    if let Some(treasury_fee) = treasury_fee {
+        for (fee_account_info, amount) in treasury_fee.shares() {
+            shared::transfer::process_transfer(
+                &[
+                    source_info.clone(),
+                    fee_account_info.clone(),
+                    authority_info.clone(),
+                ],
+                amount,
+                None,
+            )?;
+        }
+    }
+
+    shared::transfer::process_transfer(&accounts[1..4], amount_in, None)?;
+
+    shared::custody::transfer(
+        vault_out_info,
+        destination_info,
+        pool_info.key(),
+        amount_out,
+    )
+}
@@ -11,16 +11,7 @@ use token_interface::{
 use super::{check_account_owner, MAX_FORMATTED_DIGITS};
 
 #[inline(always)]
-pub fn process_amount_to_ui_amount(
-    accounts: &[AccountInfo],
-    instruction_data: &[u8],
-) -> ProgramResult {
-    let amount = u64::from_le_bytes(
-        instruction_data
-            .try_into()
-            .map_err(|_error| ProgramError::InvalidInstructionData)?,
-    );
-
+pub fn process_amount_to_ui_amount(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
     let mint_info = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
     check_account_owner(mint_info)?;
     // SAFETY: single immutable borrow to `mint_info` account data and
@@ -0,0 +1,79 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{error::TokenError, state::load_mut};
+
+use crate::{
+    error::ExtensionError, parse::Cursor, processor::check_account_owner, state::auction::Auction,
+    system,
+};
+
+/// Places a bid, escrowing the lamports in the auction account and refunding the
+/// previous highest bidder.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The auction account.
+/// 1. `[writable, signer]` The bidder.
+/// 2. `[writable]` The previous highest bidder (ignored when there are no bids).
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_bid(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [auction_info, bidder_info, previous_bidder_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(auction_info)?;
+    // SAFETY: single mutable borrow to `auction_info` account data and
+    // `load_mut` validates that the auction is initialized.
+    let auction = unsafe { load_mut::<Auction>(auction_info.borrow_mut_data_unchecked())? };
+
+    if Clock::get()?.slot >= auction.end_slot() {
+        return Err(ExtensionError::AuctionEnded.into());
+    }
+
+    if amount < auction.min_bid() || amount <= auction.highest_bid() {
+        return Err(ExtensionError::BidTooLow.into());
+    }
+
+    // Escrows the bid.
+
+    system::transfer(bidder_info, auction_info, amount, &[])?;
+
+    // Refunds the previous highest bidder.
+
+    if auction.has_bid() {
+        if previous_bidder_info.key() != &auction.highest_bidder {
+            return Err(ExtensionError::AccountMismatch.into());
+        }
+
+        let refund = auction.highest_bid();
+        let previous_bidder_starting_lamports = previous_bidder_info.lamports();
+        // SAFETY: single mutable borrow to `auction_info` and `previous_bidder_info`
+        // lamports; the auction account is owned by the token program.
+        unsafe {
+            *auction_info.borrow_mut_lamports_unchecked() = auction_info
+                .lamports()
+                .checked_sub(refund)
+                .ok_or(TokenError::InsufficientFunds)?;
+            *previous_bidder_info.borrow_mut_lamports_unchecked() =
+                previous_bidder_starting_lamports
+                    .checked_add(refund)
+                    .ok_or(TokenError::Overflow)?;
+        }
+    }
+
+    auction.highest_bidder = *bidder_info.key();
+    auction.set_highest_bid(amount);
+
+    Ok(())
+}
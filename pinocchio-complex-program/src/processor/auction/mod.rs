@@ -0,0 +1,58 @@
+//! English auction.
+//!
+//! The seller escrows the item tokens in a vault owned by the auction derived
+//! address. Bids escrow lamports in the auction account, refunding the previous
+//! highest bidder, and once the end slot is reached the auction is settled: the item
+//! goes to the highest bidder and the proceeds to the seller.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateAuction`
+//! - `1`: `Bid`
+//! - `2`: `SettleAuction`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod bid;
+pub mod create_auction;
+pub mod settle_auction;
+
+pub use bid::process_bid;
+pub use create_auction::process_create_auction;
+pub use settle_auction::process_settle_auction;
+
+/// Process an auction instruction.
+#[inline(always)]
+pub fn process_auction_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateAuction
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateAuction");
+
+            process_create_auction(accounts, instruction_data)
+        }
+        // 1 - Bid
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Bid");
+
+            process_bid(accounts, instruction_data)
+        }
+        // 2 - SettleAuction
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SettleAuction");
+
+            process_settle_auction(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
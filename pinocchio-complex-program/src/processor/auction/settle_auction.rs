@@ -0,0 +1,77 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load},
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, close_extension_account, shared::custody},
+    state::auction::Auction,
+};
+
+/// Settles an auction after its end slot, closing the auction account.
+///
+/// The item goes to the highest bidder, or back to the seller when there are no
+/// bids, and the auction lamports (including the highest bid) go to the seller.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The auction account.
+/// 1. `[writable]` The item vault.
+/// 2. `[writable]` The item token account of the winner (or the seller).
+/// 3. `[writable]` The seller.
+#[inline(always)]
+pub fn process_settle_auction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [auction_info, item_vault_info, destination_account_info, seller_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(auction_info)?;
+    // SAFETY: scoped immutable borrow to `auction_info` account data and
+    // `load` validates that the auction is initialized.
+    let auction = unsafe { load::<Auction>(auction_info.borrow_data_unchecked())? };
+
+    if Clock::get()?.slot < auction.end_slot() {
+        return Err(ExtensionError::AuctionNotEnded.into());
+    }
+
+    if item_vault_info.key() != &auction.item_vault || seller_info.key() != &auction.seller {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    let recipient = if auction.has_bid() {
+        &auction.highest_bidder
+    } else {
+        &auction.seller
+    };
+
+    // SAFETY: scoped immutable borrow to `destination_account_info` account data and
+    // `load` validates that the account is initialized.
+    let destination_owner =
+        unsafe { load::<Account>(destination_account_info.borrow_data_unchecked())?.owner };
+
+    if &destination_owner != recipient {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    // Moves the item to the recipient.
+
+    custody::transfer(
+        item_vault_info,
+        destination_account_info,
+        auction_info.key(),
+        auction.item_amount(),
+    )?;
+
+    // Moves the proceeds to the seller.
+
+    close_extension_account(auction_info, seller_info)
+}
@@ -0,0 +1,75 @@
+use core::mem::MaybeUninit;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{instruction::ProgramInstruction, parse::Cursor};
+
+/// Maximum number of accounts of an instruction of a batch.
+pub const MAX_BATCH_INSTRUCTION_ACCOUNTS: usize = 32;
+
+/// An uninitialized account info.
+const UNINIT_ACCOUNT: MaybeUninit<AccountInfo> = MaybeUninit::uninit();
+
+/// Executes a batch of instructions of the program in order.
+///
+/// Each instruction is executed against the accounts selected by its account
+/// indexes, which refer to the accounts of the batch instruction. The batch fails
+/// when any of its instructions fails, reverting all of them. Batches cannot be
+/// nested.
+///
+/// The instruction data layout is:
+/// - [0]: number of instructions
+/// - for each instruction:
+///   - data length (`u16`)
+///   - instruction data, starting with the discriminator
+///   - number of accounts (`u8`)
+///   - account indexes (`u8` each)
+///
+/// Accounts expected:
+///
+/// 0. ..N The accounts referenced by the instructions of the batch.
+#[inline(always)]
+pub fn process_batch(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+    dispatch: fn(&[AccountInfo], ProgramInstruction) -> ProgramResult,
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+
+    for _ in 0..cursor.read_u8()? {
+        let data_len = cursor.read_u16()?;
+        let instruction = ProgramInstruction::unpack(cursor.read_bytes(data_len as usize)?)?;
+
+        if let ProgramInstruction::Batch(_) = instruction {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let indexes = cursor.read_bytes(cursor.read_u8()? as usize)?;
+
+        if indexes.len() > MAX_BATCH_INSTRUCTION_ACCOUNTS {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut instruction_accounts = [UNINIT_ACCOUNT; MAX_BATCH_INSTRUCTION_ACCOUNTS];
+
+        for (instruction_account, index) in instruction_accounts.iter_mut().zip(indexes) {
+            let account_info = accounts
+                .get(*index as usize)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            instruction_account.write(account_info.clone());
+        }
+
+        // SAFETY: the first `indexes.len()` accounts are initialized above and
+        // `MaybeUninit` has the same memory layout as `AccountInfo`.
+        let instruction_accounts = unsafe {
+            core::slice::from_raw_parts(
+                instruction_accounts.as_ptr() as *const AccountInfo,
+                indexes.len(),
+            )
+        };
+
+        dispatch(instruction_accounts, instruction)?;
+    }
+
+    cursor.finish()
+}
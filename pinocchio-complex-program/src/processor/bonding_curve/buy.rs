@@ -0,0 +1,65 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use super::{parse_trade, reserve_for_supply};
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, shared::custody},
+    state::bonding_curve::BondingCurve,
+    system,
+};
+
+/// Mints curve tokens, paying their price in lamports into the curve reserve.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The bonding curve account.
+/// 1. `[writable]` The mint of the curve tokens.
+/// 2. `[writable]` The destination token account.
+/// 3. `[writable, signer]` The buyer, paying the lamports.
+/// 4. `[]` The system program.
+#[inline(always)]
+pub fn process_buy(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let (amount, maximum_cost) = parse_trade(instruction_data)?;
+
+    let [curve_info, mint_info, destination_account_info, buyer_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(curve_info)?;
+    // SAFETY: single mutable borrow to `curve_info` account data and
+    // `load_mut` validates that the bonding curve is initialized.
+    let curve = unsafe { load_mut::<BondingCurve>(curve_info.borrow_mut_data_unchecked())? };
+
+    if mint_info.key() != &curve.mint {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // Calculates the cost of the tokens.
+
+    let supply = curve.supply();
+    let new_supply = supply.checked_add(amount).ok_or(TokenError::Overflow)?;
+
+    let cost = reserve_for_supply(curve, new_supply)?
+        .checked_sub(reserve_for_supply(curve, supply)?)
+        .ok_or(TokenError::Overflow)?;
+
+    if cost > maximum_cost {
+        return Err(ExtensionError::PriceExceeded.into());
+    }
+
+    curve.set_supply(new_supply);
+
+    // Moves the lamports into the reserve and mints the tokens.
+
+    system::transfer(buyer_info, curve_info, cost, &[])?;
+
+    custody::mint_to(
+        mint_info,
+        destination_account_info,
+        curve_info.key(),
+        amount,
+    )
+}
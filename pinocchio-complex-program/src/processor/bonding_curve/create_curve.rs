@@ -0,0 +1,86 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load, load_mut_unchecked, mint::Mint, RawType},
+};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account},
+    state::bonding_curve::{BondingCurve, BONDING_CURVE_SEED, EXPONENTIAL, LINEAR},
+};
+
+/// Creates a new bonding curve for a mint.
+///
+/// The mint authority must already be set to the bonding curve derived address.
+/// The factor is the fixed-point slope of a linear curve, in lamports per token,
+/// or the fixed-point growth rate of an exponential curve, which must not be zero.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the bonding curve account.
+/// 1. `[writable]` The bonding curve account.
+/// 2. `[]` The mint of the curve tokens.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_create_curve(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let curve_type = cursor.read_u8()?;
+    let base_price = cursor.read_u64()?;
+    let factor = cursor.read_u64()?;
+    cursor.finish()?;
+
+    match curve_type {
+        LINEAR => (),
+        EXPONENTIAL if factor > 0 => (),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    let [payer_info, curve_info, mint_info, _system_program_info, _remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: scoped immutable borrow to `mint_info` account data and
+    // `load` validates that the mint is initialized.
+    let mint = unsafe { load::<Mint>(mint_info.borrow_data_unchecked())? };
+
+    if mint.mint_authority() != Some(curve_info.key()) {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    // Creates the bonding curve account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        curve_info,
+        &[BONDING_CURVE_SEED, mint_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(BONDING_CURVE_SEED),
+        Seed::from(mint_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(payer_info, curve_info, BondingCurve::LEN, &seeds)?;
+
+    // Initializes the bonding curve.
+
+    // SAFETY: single mutable borrow to `curve_info` account data; the account was
+    // created above with the expected length.
+    let curve =
+        unsafe { load_mut_unchecked::<BondingCurve>(curve_info.borrow_mut_data_unchecked())? };
+
+    curve.set_initialized();
+    curve.bump = bump;
+    curve.curve_type = curve_type;
+    curve.mint = *mint_info.key();
+    curve.set_base_price(base_price);
+    curve.set_factor(factor);
+
+    Ok(())
+}
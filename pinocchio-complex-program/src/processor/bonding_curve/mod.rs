@@ -0,0 +1,136 @@
+//! Bonding curve.
+//!
+//! Buyers mint curve tokens paying lamports into the curve reserve, at a price
+//! that increases with the supply along a linear or exponential curve. Sellers
+//! burn curve tokens in exchange for the reserve lamports they are worth.
+//!
+//! The reserve required for a supply is the area under the curve, so buying and
+//! selling the same amount of tokens at the same supply is always balanced.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateCurve`
+//! - `1`: `Buy`
+//! - `2`: `Sell`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::error::TokenError;
+
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    state::bonding_curve::{BondingCurve, EXPONENTIAL, LINEAR},
+};
+
+pub mod buy;
+pub mod create_curve;
+pub mod sell;
+
+pub use buy::process_buy;
+pub use create_curve::process_create_curve;
+pub use sell::process_sell;
+
+/// Fixed-point precision of the curve factor.
+const PRECISION: u128 = 1_000_000_000;
+
+/// Process a bonding curve instruction.
+#[inline(always)]
+pub fn process_bonding_curve_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateCurve
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateCurve");
+
+            process_create_curve(accounts, instruction_data)
+        }
+        // 1 - Buy
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Buy");
+
+            process_buy(accounts, instruction_data)
+        }
+        // 2 - Sell
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Sell");
+
+            process_sell(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Parses the trade parameters from the instruction data:
+/// - amount (8 bytes)
+/// - lamports limit (8 bytes)
+#[inline(always)]
+fn parse_trade(instruction_data: &[u8]) -> Result<(u64, u64), ProgramError> {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    let limit = cursor.read_u64()?;
+    cursor.finish()?;
+
+    if amount == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok((amount, limit))
+}
+
+/// Returns the reserve lamports backing `supply` tokens of the curve.
+///
+/// For a linear curve with price `base + slope * s`, the reserve is
+/// `base * supply + slope * supply^2 / 2`. For an exponential curve with price
+/// `base * (1 + rate)^s`, the reserve is `base * ((1 + rate)^supply - 1) / rate`.
+#[inline(always)]
+fn reserve_for_supply(curve: &BondingCurve, supply: u64) -> Result<u64, ProgramError> {
+    let base_price = curve.base_price() as u128;
+    let factor = curve.factor() as u128;
+    let supply = supply as u128;
+
+    let reserve = match curve.curve_type {
+        LINEAR => supply
+            .checked_mul(supply)
+            .and_then(|supply_squared| supply_squared.checked_mul(factor))
+            .map(|area| area / (2 * PRECISION))
+            .and_then(|area| area.checked_add(base_price.checked_mul(supply)?)),
+        EXPONENTIAL => pow_fixed(PRECISION + factor, supply)
+            .and_then(|growth| (growth - PRECISION).checked_mul(base_price))
+            .map(|area| area / factor),
+        _ => return Err(ExtensionError::UnsupportedCurveType.into()),
+    };
+
+    Ok(reserve
+        .and_then(|reserve| u64::try_from(reserve).ok())
+        .ok_or(TokenError::Overflow)?)
+}
+
+/// Raises the fixed-point `base` to the power of `exponent`, with checked
+/// arithmetic.
+#[inline(always)]
+fn pow_fixed(mut base: u128, mut exponent: u128) -> Option<u128> {
+    let mut result = PRECISION;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result.checked_mul(base)? / PRECISION;
+        }
+
+        exponent >>= 1;
+
+        if exponent > 0 {
+            base = base.checked_mul(base)? / PRECISION;
+        }
+    }
+
+    Some(result)
+}
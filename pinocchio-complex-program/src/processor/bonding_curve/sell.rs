@@ -0,0 +1,79 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use super::{parse_trade, reserve_for_supply};
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, shared},
+    state::bonding_curve::BondingCurve,
+};
+
+/// Burns curve tokens in exchange for their value in reserve lamports.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The bonding curve account.
+/// 1. `[writable]` The mint of the curve tokens.
+/// 2. `[writable]` The source token account.
+/// 3. `[writable, signer]` The source account owner, receiving the lamports.
+#[inline(always)]
+pub fn process_sell(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let (amount, minimum_proceeds) = parse_trade(instruction_data)?;
+
+    let [curve_info, mint_info, source_account_info, owner_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(curve_info)?;
+    // SAFETY: single mutable borrow to `curve_info` account data and
+    // `load_mut` validates that the bonding curve is initialized.
+    let curve = unsafe { load_mut::<BondingCurve>(curve_info.borrow_mut_data_unchecked())? };
+
+    if mint_info.key() != &curve.mint {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // Calculates the proceeds of the tokens.
+
+    let supply = curve.supply();
+    let new_supply = supply
+        .checked_sub(amount)
+        .ok_or(TokenError::InsufficientFunds)?;
+
+    let proceeds = reserve_for_supply(curve, supply)?
+        .checked_sub(reserve_for_supply(curve, new_supply)?)
+        .ok_or(TokenError::Overflow)?;
+
+    if proceeds < minimum_proceeds {
+        return Err(ExtensionError::SlippageExceeded.into());
+    }
+
+    curve.set_supply(new_supply);
+
+    // Burns the tokens and moves the lamports out of the reserve.
+
+    shared::burn::process_burn(
+        &[
+            source_account_info.clone(),
+            mint_info.clone(),
+            owner_info.clone(),
+        ],
+        amount,
+        None,
+    )?;
+
+    let owner_starting_lamports = owner_info.lamports();
+    // SAFETY: single mutable borrow to `curve_info` and `owner_info` lamports; the
+    // bonding curve account is owned by the token program.
+    unsafe {
+        *curve_info.borrow_mut_lamports_unchecked() = curve_info
+            .lamports()
+            .checked_sub(proceeds)
+            .ok_or(TokenError::InsufficientFunds)?;
+        *owner_info.borrow_mut_lamports_unchecked() = owner_starting_lamports
+            .checked_add(proceeds)
+            .ok_or(TokenError::Overflow)?;
+    }
+
+    Ok(())
+}
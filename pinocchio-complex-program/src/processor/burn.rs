@@ -1,14 +1,8 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio::{account_info::AccountInfo, ProgramResult};
 
 use super::shared;
 
 #[inline(always)]
-pub fn process_burn(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
-    let amount = u64::from_le_bytes(
-        instruction_data
-            .try_into()
-            .map_err(|_error| ProgramError::InvalidInstructionData)?,
-    );
-
+pub fn process_burn(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
     shared::burn::process_burn(accounts, amount, None)
 }
@@ -0,0 +1,71 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{error::TokenError, state::load};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, close_extension_account, shared::custody},
+    state::campaign::Campaign,
+};
+
+/// Claims the funds of a campaign that reached its goal, closing the campaign
+/// account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The campaign account.
+/// 1. `[writable, signer]` The creator of the campaign, receiving the SOL.
+/// 2. `[writable]` (Token campaign) The token vault.
+/// 3. `[writable]` (Token campaign) The destination token account.
+#[inline(always)]
+pub fn process_claim(accounts: &[AccountInfo]) -> ProgramResult {
+    let [campaign_info, creator_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(campaign_info)?;
+    // SAFETY: scoped immutable borrow to `campaign_info` account data and
+    // `load` validates that the campaign is initialized.
+    let campaign = unsafe { load::<Campaign>(campaign_info.borrow_data_unchecked())? };
+
+    if &campaign.creator != creator_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(creator_info);
+
+    if Clock::get()?.unix_timestamp < campaign.deadline() {
+        return Err(ExtensionError::CampaignNotEnded.into());
+    }
+
+    if campaign.raised() < campaign.goal() {
+        return Err(ExtensionError::GoalNotReached.into());
+    }
+
+    // Moves the tokens out of the token vault.
+
+    if campaign.has_token_vault() {
+        let [token_vault_info, destination_account_info, ..] = remaining else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if token_vault_info.key() != &campaign.token_vault {
+            return Err(ExtensionError::AccountMismatch.into());
+        }
+
+        custody::transfer(
+            token_vault_info,
+            destination_account_info,
+            campaign_info.key(),
+            campaign.raised(),
+        )?;
+    }
+
+    // Closing the campaign moves the SOL raised to the creator.
+
+    close_extension_account(campaign_info, creator_info)
+}
@@ -0,0 +1,99 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{error::TokenError, state::load_mut};
+
+use super::load_or_create_contribution;
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_account_owner, shared},
+    state::campaign::Campaign,
+    system,
+};
+
+/// Contributes to a campaign before its deadline.
+///
+/// The contribution account of the contributor is created on the first
+/// contribution. SOL campaigns receive lamports from the contributor; token
+/// campaigns receive tokens from the source token account into the token vault.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The campaign account.
+/// 1. `[writable]` The contribution account.
+/// 2. `[writable, signer]` The contributor, paying for the contribution account.
+/// 3. `[]` The system program.
+/// 4. `[writable]` (Token campaign) The source token account.
+/// 5. `[writable]` (Token campaign) The token vault.
+#[inline(always)]
+pub fn process_contribute(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    let bump = cursor.read_u8()?;
+    cursor.finish()?;
+
+    let [campaign_info, contribution_info, contributor_info, _system_program_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if amount == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    check_account_owner(campaign_info)?;
+    // SAFETY: single mutable borrow to `campaign_info` account data and
+    // `load_mut` validates that the campaign is initialized.
+    let campaign = unsafe { load_mut::<Campaign>(campaign_info.borrow_mut_data_unchecked())? };
+
+    if Clock::get()?.unix_timestamp >= campaign.deadline() {
+        return Err(ExtensionError::CampaignEnded.into());
+    }
+
+    let contribution =
+        load_or_create_contribution(campaign_info, contribution_info, contributor_info, bump)?;
+
+    // Moves the contribution into the campaign.
+
+    if campaign.has_token_vault() {
+        let [source_account_info, token_vault_info, ..] = remaining else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if token_vault_info.key() != &campaign.token_vault {
+            return Err(ExtensionError::AccountMismatch.into());
+        }
+
+        shared::transfer::process_transfer(
+            &[
+                source_account_info.clone(),
+                token_vault_info.clone(),
+                contributor_info.clone(),
+            ],
+            amount,
+            None,
+        )?;
+    } else {
+        system::transfer(contributor_info, campaign_info, amount, &[])?;
+    }
+
+    contribution.set_amount(
+        contribution
+            .amount()
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?,
+    );
+    campaign.set_raised(
+        campaign
+            .raised()
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?,
+    );
+
+    Ok(())
+}
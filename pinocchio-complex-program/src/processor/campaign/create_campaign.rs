@@ -0,0 +1,105 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, shared},
+    state::campaign::{Campaign, CAMPAIGN_SEED},
+};
+
+/// Creates a new crowdfunding campaign.
+///
+/// When the token vault and mint accounts are provided, the campaign raises tokens
+/// and the token vault must be an uninitialized account owned by the token program;
+/// it is initialized with the campaign derived address as its owner. Otherwise the
+/// campaign raises SOL.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The creator of the campaign, paying for the campaign
+///    account.
+/// 1. `[writable]` The campaign account.
+/// 2. `[]` The key used to derive the campaign address.
+/// 3. `[]` The system program.
+/// 4. `[writable]` (Optional) The token vault.
+/// 5. `[]` (Optional) The mint of the contributed tokens.
+#[inline(always)]
+pub fn process_create_campaign(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let goal = cursor.read_u64()?;
+    let deadline = cursor.read_i64()?;
+    cursor.finish()?;
+
+    let [creator_info, campaign_info, create_key_info, _system_program_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if goal == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if deadline <= Clock::get()?.unix_timestamp {
+        return Err(ExtensionError::CampaignEnded.into());
+    }
+
+    // Creates the campaign account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        campaign_info,
+        &[CAMPAIGN_SEED, create_key_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(CAMPAIGN_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(creator_info, campaign_info, Campaign::LEN, &seeds)?;
+
+    // Initializes the token vault under the campaign authority.
+
+    let token_vault = if let [token_vault_info, mint_info, ..] = remaining {
+        shared::initialize_account::process_initialize_account(
+            &[token_vault_info.clone(), mint_info.clone()],
+            Some(campaign_info.key()),
+            false,
+        )?;
+
+        Some((*token_vault_info.key(), *mint_info.key()))
+    } else {
+        None
+    };
+
+    // Initializes the campaign.
+
+    // SAFETY: single mutable borrow to `campaign_info` account data; the account was
+    // created above with the expected length.
+    let campaign =
+        unsafe { load_mut_unchecked::<Campaign>(campaign_info.borrow_mut_data_unchecked())? };
+
+    campaign.set_initialized();
+    campaign.bump = bump;
+    campaign.create_key = *create_key_info.key();
+    campaign.creator = *creator_info.key();
+    campaign.set_goal(goal);
+    campaign.set_deadline(deadline);
+
+    if let Some((token_vault, mint)) = token_vault {
+        campaign.token_vault = token_vault;
+        campaign.mint = mint;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+//! Crowdfunding campaign.
+//!
+//! Contributors fund a campaign with SOL or tokens until its deadline. Once the
+//! deadline has passed, the creator claims the funds if the goal was reached;
+//! otherwise each contributor is refunded their contribution.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateCampaign`
+//! - `1`: `Contribute`
+//! - `2`: `Claim`
+//! - `3`: `Refund`
+
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut, load_mut_unchecked, RawType};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, check_derived_address, create_derived_account},
+    state::campaign::{Contribution, CONTRIBUTION_SEED},
+};
+
+pub mod claim;
+pub mod contribute;
+pub mod create_campaign;
+pub mod refund;
+
+pub use claim::process_claim;
+pub use contribute::process_contribute;
+pub use create_campaign::process_create_campaign;
+pub use refund::process_refund;
+
+/// Process a campaign instruction.
+#[inline(always)]
+pub fn process_campaign_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateCampaign
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateCampaign");
+
+            process_create_campaign(accounts, instruction_data)
+        }
+        // 1 - Contribute
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Contribute");
+
+            process_contribute(accounts, instruction_data)
+        }
+        // 2 - Claim
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Claim");
+
+            process_claim(accounts)
+        }
+        // 3 - Refund
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Refund");
+
+            process_refund(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Loads the contribution account of `contributor_info`, creating it on the first
+/// contribution.
+///
+/// The contributor pays for the contribution account.
+#[inline(always)]
+fn load_or_create_contribution<'a>(
+    campaign_info: &AccountInfo,
+    contribution_info: &'a AccountInfo,
+    contributor_info: &AccountInfo,
+    bump: u8,
+) -> Result<&'a mut Contribution, ProgramError> {
+    if contribution_info.data_len() != 0 {
+        return load_contribution(campaign_info, contribution_info, contributor_info);
+    }
+
+    let bump_seed = [bump];
+    check_derived_address(
+        contribution_info,
+        &[
+            CONTRIBUTION_SEED,
+            campaign_info.key().as_ref(),
+            contributor_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(CONTRIBUTION_SEED),
+        Seed::from(campaign_info.key()),
+        Seed::from(contributor_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(
+        contributor_info,
+        contribution_info,
+        Contribution::LEN,
+        &seeds,
+    )?;
+
+    // SAFETY: single mutable borrow to `contribution_info` account data; the account
+    // was created above with the expected length.
+    let contribution = unsafe {
+        load_mut_unchecked::<Contribution>(contribution_info.borrow_mut_data_unchecked())?
+    };
+
+    contribution.set_initialized();
+    contribution.bump = bump;
+    contribution.campaign = *campaign_info.key();
+    contribution.contributor = *contributor_info.key();
+
+    Ok(contribution)
+}
+
+/// Loads the contribution account of `contributor_info` to `campaign_info`.
+#[inline(always)]
+fn load_contribution<'a>(
+    campaign_info: &AccountInfo,
+    contribution_info: &'a AccountInfo,
+    contributor_info: &AccountInfo,
+) -> Result<&'a mut Contribution, ProgramError> {
+    check_account_owner(contribution_info)?;
+    // SAFETY: single mutable borrow to `contribution_info` account data and
+    // `load_mut` validates that the contribution is initialized.
+    let contribution =
+        unsafe { load_mut::<Contribution>(contribution_info.borrow_mut_data_unchecked())? };
+
+    if &contribution.campaign != campaign_info.key()
+        || &contribution.contributor != contributor_info.key()
+    {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    Ok(contribution)
+}
@@ -0,0 +1,104 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load, load_mut},
+};
+
+use super::load_contribution;
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, close_extension_account, shared::custody},
+    state::campaign::Campaign,
+};
+
+/// Refunds a contribution to a campaign that missed its goal, closing the
+/// contribution account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The campaign account.
+/// 1. `[writable]` The contribution account.
+/// 2. `[writable]` The contributor, receiving the SOL.
+/// 3. `[writable]` (Token campaign) The token vault.
+/// 4. `[writable]` (Token campaign) The destination token account of the
+///    contributor.
+#[inline(always)]
+pub fn process_refund(accounts: &[AccountInfo]) -> ProgramResult {
+    let [campaign_info, contribution_info, contributor_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(campaign_info)?;
+    // SAFETY: single mutable borrow to `campaign_info` account data and
+    // `load_mut` validates that the campaign is initialized.
+    let campaign = unsafe { load_mut::<Campaign>(campaign_info.borrow_mut_data_unchecked())? };
+
+    if Clock::get()?.unix_timestamp < campaign.deadline() {
+        return Err(ExtensionError::CampaignNotEnded.into());
+    }
+
+    if campaign.raised() >= campaign.goal() {
+        return Err(ExtensionError::GoalReached.into());
+    }
+
+    let amount = load_contribution(campaign_info, contribution_info, contributor_info)?.amount();
+
+    campaign.set_raised(
+        campaign
+            .raised()
+            .checked_sub(amount)
+            .ok_or(TokenError::Overflow)?,
+    );
+
+    // Moves the contribution back to the contributor.
+
+    if campaign.has_token_vault() {
+        let [token_vault_info, destination_account_info, ..] = remaining else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if token_vault_info.key() != &campaign.token_vault {
+            return Err(ExtensionError::AccountMismatch.into());
+        }
+
+        // Refunds are permissionless, so the tokens may only go to an account of
+        // the contributor.
+
+        // SAFETY: scoped immutable borrow to `destination_account_info` account data
+        // and `load` validates that the account is initialized.
+        let destination =
+            unsafe { load::<Account>(destination_account_info.borrow_data_unchecked())? };
+
+        if &destination.owner != contributor_info.key() {
+            return Err(TokenError::OwnerMismatch.into());
+        }
+
+        custody::transfer(
+            token_vault_info,
+            destination_account_info,
+            campaign_info.key(),
+            amount,
+        )?;
+    } else {
+        let campaign_remaining_lamports = campaign_info
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(TokenError::Overflow)?;
+        let contributor_starting_lamports = contributor_info.lamports();
+        // SAFETY: single mutable borrow to `campaign_info` and `contributor_info`
+        // lamports; the campaign account is owned by the token program.
+        unsafe {
+            *campaign_info.borrow_mut_lamports_unchecked() = campaign_remaining_lamports;
+            *contributor_info.borrow_mut_lamports_unchecked() = contributor_starting_lamports
+                .checked_add(amount)
+                .ok_or(TokenError::Overflow)?;
+        }
+    }
+
+    close_extension_account(contribution_info, contributor_info)
+}
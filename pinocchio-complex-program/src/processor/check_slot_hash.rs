@@ -0,0 +1,37 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{error::ExtensionError, parse::Cursor, sysvar::slot_hashes::SlotHashes};
+
+/// Checks that a `(slot, hash)` pair is one of the recent entries of the
+/// `SlotHashes` sysvar.
+///
+/// The instruction fails when the slot is not one of the recent entries or its
+/// hash does not match, proving that the caller observed a recent slot of the
+/// cluster (e.g. to commit to a slot hash used as randomness).
+///
+/// Accounts expected:
+///
+/// 0. `[]` The `SlotHashes` sysvar.
+#[inline(always)]
+pub fn process_check_slot_hash(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let slot = cursor.read_u64()?;
+    let hash = cursor.read_array::<32>()?;
+    cursor.finish()?;
+
+    let [slot_hashes_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let slot_hashes = SlotHashes::from_account_info(slot_hashes_info)?;
+
+    let slot_hash = slot_hashes
+        .get(slot)
+        .ok_or(ExtensionError::SlotHashUnavailable)?;
+
+    if slot_hash != hash {
+        return Err(ExtensionError::SlotHashMismatch.into());
+    }
+
+    Ok(())
+}
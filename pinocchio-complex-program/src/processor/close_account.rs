@@ -7,6 +7,7 @@ use token_interface::{
 };
 
 use super::validate_owner;
+use crate::error::ExtensionError;
 
 /// Incinerator (`1nc1nerator11111111111111111111111111111111`) address.
 const INCINERATOR_ID: Pubkey = [
@@ -25,7 +26,7 @@ pub fn process_close_account(accounts: &[AccountInfo]) -> ProgramResult {
     // not - this is a faster comparison since it just checks the internal
     // raw pointer.
     if source_account_info == destination_account_info {
-        return Err(ProgramError::InvalidAccountData);
+        return Err(ExtensionError::DuplicateAccount.into());
     } else {
         // SAFETY: scoped immutable borrow to `source_account_info` account data and
         // `load` validates that the account is initialized.
@@ -43,7 +44,7 @@ pub fn process_close_account(accounts: &[AccountInfo]) -> ProgramResult {
         if !source_account.is_owned_by_system_program_or_incinerator() {
             validate_owner(authority, authority_info, remaining)?;
         } else if destination_account_info.key() != &INCINERATOR_ID {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(ExtensionError::AccountMismatch.into());
         }
     }
 
@@ -0,0 +1,38 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use super::load_config;
+use crate::{security, state::config::Config};
+
+/// Accepts the config admin role proposed to the signer.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The config account.
+/// 1. `[signer]` The pending config admin.
+#[inline(always)]
+pub fn process_accept_admin(accounts: &[AccountInfo]) -> ProgramResult {
+    security::assert_expected_account_count(accounts, 2)?;
+
+    let [config_info, pending_admin_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: scoped immutable borrow to `config_info` account data.
+    let pending_admin = unsafe { load_config(config_info)?.pending_admin };
+
+    if &pending_admin != pending_admin_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(pending_admin_info);
+
+    // SAFETY: single mutable borrow to `config_info` account data and
+    // `load_mut` validates that the config is initialized.
+    let config = unsafe { load_mut::<Config>(config_info.borrow_mut_data_unchecked())? };
+
+    config.admin = pending_admin;
+    config.pending_admin = [0; 32];
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use super::load_config;
+use crate::{return_data, security};
+
+/// Length of the return data payload.
+const PAYLOAD_LEN: usize = 75;
+
+/// Returns the program config, so programs can read it through a CPI without
+/// depending on the layout of the account.
+///
+/// The layout of the return data payload is:
+/// - [0..32]: config admin
+/// - [32..64]: fee recipient
+/// - [64..66]: program fee, in basis points
+/// - [66..74]: feature toggles
+/// - [74]: whether the program is paused (`0` or `1`)
+///
+/// Accounts expected:
+///
+/// 0. `[]` The config account.
+#[inline(always)]
+pub fn process_get_config(accounts: &[AccountInfo]) -> ProgramResult {
+    security::assert_expected_account_count(accounts, 1)?;
+
+    let [config_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: scoped immutable borrow to `config_info` account data.
+    let config = unsafe { load_config(config_info)? };
+
+    let mut payload = [0u8; PAYLOAD_LEN];
+
+    payload[0..32].copy_from_slice(&config.admin);
+    payload[32..64].copy_from_slice(&config.fee_recipient);
+    payload[64..66].copy_from_slice(&config.fee_bps().to_le_bytes());
+    payload[66..74].copy_from_slice(&config.features().to_le_bytes());
+    payload[74] = config.is_paused() as u8;
+
+    return_data::set(&payload)
+}
@@ -0,0 +1,80 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load_mut_unchecked, RawType},
+};
+
+use super::parse_config_parameters;
+use crate::{
+    loader,
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account},
+    security,
+    state::config::{Config, CONFIG_SEED, CONFIG_VERSION},
+};
+
+/// Initializes the program config.
+///
+/// The signer of the instruction becomes the config admin and must be the upgrade
+/// authority of the program, so the config cannot be created by anyone else once
+/// the program is deployed.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The admin of the config, which pays for the account.
+/// 1. `[writable]` The config account.
+/// 2. `[]` The program data account of the program.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_initialize_config(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+
+    let (fee_recipient, fee_bps, features) = parse_config_parameters(cursor.remaining())?;
+
+    security::assert_expected_account_count(accounts, 4)?;
+
+    let [admin_info, config_info, program_data_info, system_program_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    security::assert_no_unexpected_writable([program_data_info, system_program_info])?;
+
+    // Validates the admin.
+
+    require_signer!(admin_info);
+
+    if loader::upgrade_authority(program_data_info)?.as_ref() != Some(admin_info.key()) {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    // Creates the config account.
+
+    let bump_seed = [bump];
+    check_derived_address(config_info, &[CONFIG_SEED, &bump_seed])?;
+
+    let seeds = [Seed::from(CONFIG_SEED), Seed::from(&bump_seed)];
+
+    create_derived_account(admin_info, config_info, Config::LEN, &seeds)?;
+
+    // Initializes the config.
+
+    // SAFETY: single mutable borrow to `config_info` account data; the account was
+    // created above with the expected length.
+    let config = unsafe { load_mut_unchecked::<Config>(config_info.borrow_mut_data_unchecked())? };
+
+    config.set_initialized();
+    config.version = CONFIG_VERSION;
+    config.bump = bump;
+    config.admin = *admin_info.key();
+    config.fee_recipient = *fee_recipient;
+    config.set_fee_bps(fee_bps);
+    config.set_features(features);
+
+    Ok(())
+}
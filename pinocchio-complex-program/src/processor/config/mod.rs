@@ -0,0 +1,246 @@
+//! Program config.
+//!
+//! The config is a single derived account storing the program admin, the fee
+//! parameters and the feature toggles, which other processors read through
+//! [`load_config`].
+//!
+//! The config instructions reject accounts they do not expect, with the checks of
+//! [`crate::security`].
+//!
+//! Instructions:
+//!
+//! - `0`: `InitializeConfig`
+//! - `1`: `UpdateConfig`
+//! - `2`: `ProposeAdmin`
+//! - `3`: `AcceptAdmin`
+//! - `4`: `Pause`
+//! - `5`: `Unpause`
+//! - `6`: `GetConfig`
+
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+use token_interface::{error::TokenError, program::ID as TOKEN_PROGRAM_ID, state::load};
+
+use crate::{
+    error::ExtensionError,
+    invocation::{check_caller, CallerRestriction},
+    parse::Cursor,
+    processor::{check_account_owner, check_derived_address},
+    state::{
+        config::{Config, CONFIG_SEED, CONFIG_VERSION},
+        AccountDiscriminator,
+    },
+    sysvar::instructions,
+};
+
+pub mod accept_admin;
+pub mod get_config;
+pub mod initialize_config;
+pub mod propose_admin;
+pub mod set_paused;
+pub mod update_config;
+
+pub use accept_admin::process_accept_admin;
+pub use get_config::process_get_config;
+pub use initialize_config::process_initialize_config;
+pub use propose_admin::process_propose_admin;
+pub use set_paused::process_set_paused;
+pub use update_config::process_update_config;
+
+/// Maximum program fee in basis points.
+const MAX_FEE_BPS: u16 = 10_000;
+
+/// Programs allowed to invoke the admin instructions through a CPI, so the admin
+/// can be a multisig or a DAO.
+///
+/// This program is included so the admin can be one of its own signer PDAs, such
+/// as a timelock, a multisig wallet or a governance, which invoke the admin
+/// instructions from a top-level instruction of this program.
+///
+/// Only direct CPIs from a top-level instruction of these programs are allowed:
+/// the caller is read from the `Instructions` sysvar, which does not record the
+/// programs of nested CPIs.
+const ADMIN_CALLERS: [Pubkey; 3] = [
+    TOKEN_PROGRAM_ID,
+    // Squads v4 multisig program (`SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf`).
+    [
+        6, 129, 196, 206, 71, 226, 35, 104, 184, 177, 85, 94, 200, 135, 175, 9, 46, 252, 126, 251,
+        182, 108, 163, 245, 47, 191, 104, 212, 172, 156, 183, 168,
+    ],
+    // SPL Governance program (`GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZw`).
+    [
+        234, 228, 53, 189, 238, 117, 183, 52, 205, 89, 62, 207, 154, 48, 75, 128, 36, 186, 40, 152,
+        103, 183, 105, 177, 249, 60, 167, 187, 184, 142, 70, 254,
+    ],
+];
+
+/// Process a config instruction.
+#[inline(always)]
+pub fn process_config_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    // The config is initialized by a top-level instruction and can be read by any
+    // program, while the admin instructions may also be invoked by the allowlisted
+    // programs.
+
+    match *discriminator {
+        0 => check_caller(CallerRestriction::TopLevelOnly, accounts)?,
+        6 => (),
+        _ => check_caller(
+            CallerRestriction::AllowlistedCallers(&ADMIN_CALLERS),
+            accounts,
+        )?,
+    }
+
+    // The `Instructions` sysvar is only read to check the caller, so it is removed
+    // before the processors check the number of accounts.
+    let accounts = match accounts.split_last() {
+        Some((instructions_info, accounts)) if instructions_info.key() == &instructions::ID => {
+            accounts
+        }
+        _ => accounts,
+    };
+
+    match *discriminator {
+        // 0 - InitializeConfig
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializeConfig");
+
+            process_initialize_config(accounts, instruction_data)
+        }
+        // 1 - UpdateConfig
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: UpdateConfig");
+
+            process_update_config(accounts, instruction_data)
+        }
+        // 2 - ProposeAdmin
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ProposeAdmin");
+
+            process_propose_admin(accounts, instruction_data)
+        }
+        // 3 - AcceptAdmin
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: AcceptAdmin");
+
+            process_accept_admin(accounts)
+        }
+        // 4 - Pause
+        4 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Pause");
+
+            process_set_paused(accounts, true)
+        }
+        // 5 - Unpause
+        5 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Unpause");
+
+            process_set_paused(accounts, false)
+        }
+        // 6 - GetConfig
+        6 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: GetConfig");
+
+            process_get_config(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Loads the program config, validating that `config_info` is the config derived
+/// address.
+///
+/// # Safety
+///
+/// The caller must ensure that there are no mutable borrows of `config_info`
+/// account data while the returned reference is alive.
+#[inline(always)]
+pub unsafe fn load_config(config_info: &AccountInfo) -> Result<&Config, ProgramError> {
+    check_account_owner(config_info)?;
+
+    let config = load::<Config>(config_info.borrow_data_unchecked())?;
+
+    if config.version != CONFIG_VERSION {
+        return Err(ExtensionError::InvalidVersion.into());
+    }
+
+    check_derived_address(config_info, &[CONFIG_SEED, &[config.bump]])?;
+
+    Ok(config)
+}
+
+/// Checks that `admin_info` is the config admin and signed the instruction.
+#[inline(always)]
+fn validate_admin(config_info: &AccountInfo, admin_info: &AccountInfo) -> ProgramResult {
+    // SAFETY: scoped immutable borrow to `config_info` account data.
+    let admin = unsafe { load_config(config_info)?.admin };
+
+    if &admin != admin_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(admin_info);
+
+    Ok(())
+}
+
+/// Checks the pause flag of the config account, when it is supplied as the last
+/// account of the instruction.
+///
+/// Returns the accounts of the instruction without the config account, so the
+/// processors are not affected by its presence.
+#[inline(always)]
+pub fn check_paused(accounts: &[AccountInfo]) -> Result<&[AccountInfo], ProgramError> {
+    let Some((config_info, remaining)) = accounts.split_last() else {
+        return Ok(accounts);
+    };
+
+    if config_info.data_len() != Config::LEN || config_info.owner() != &TOKEN_PROGRAM_ID {
+        return Ok(accounts);
+    }
+
+    // SAFETY: `config_info` account data has the length of a config account.
+    if unsafe { config_info.borrow_data_unchecked()[0] } != AccountDiscriminator::Config as u8 {
+        return Ok(accounts);
+    }
+
+    // SAFETY: scoped immutable borrow to `config_info` account data.
+    if unsafe { load_config(config_info)?.is_paused() } {
+        return Err(ExtensionError::Paused.into());
+    }
+
+    Ok(remaining)
+}
+
+/// Parses the config parameters from the instruction data:
+/// - fee_recipient (32 bytes)
+/// - fee_bps (2 bytes)
+/// - features (8 bytes)
+#[inline(always)]
+fn parse_config_parameters(instruction_data: &[u8]) -> Result<(&Pubkey, u16, u64), ProgramError> {
+    let mut cursor = Cursor::new(instruction_data);
+    let fee_recipient = cursor.read_address()?;
+    let fee_bps = cursor.read_u16()?;
+    let features = cursor.read_u64()?;
+    cursor.finish()?;
+
+    if fee_bps > MAX_FEE_BPS {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok((fee_recipient, fee_bps, features))
+}
@@ -0,0 +1,38 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::load_mut;
+
+use super::validate_admin;
+use crate::{security, state::config::Config};
+
+/// Proposes a new config admin.
+///
+/// The admin is only replaced once the proposed admin accepts the role; proposing
+/// another admin replaces any pending proposal.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The config account.
+/// 1. `[signer]` The config admin.
+#[inline(always)]
+pub fn process_propose_admin(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected [u8; 32] (32)
+    if instruction_data.len() != 32 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    security::assert_expected_account_count(accounts, 2)?;
+
+    let [config_info, admin_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    validate_admin(config_info, admin_info)?;
+
+    // SAFETY: single mutable borrow to `config_info` account data and
+    // `load_mut` validates that the config is initialized.
+    let config = unsafe { load_mut::<Config>(config_info.borrow_mut_data_unchecked())? };
+
+    config.pending_admin.copy_from_slice(instruction_data);
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::load_mut;
+
+use super::validate_admin;
+use crate::{security, state::config::Config};
+
+/// Sets the pause flag of the program config.
+///
+/// While paused, state-changing instructions that supply the config account fail
+/// with [`crate::error::ExtensionError::Paused`].
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The config account.
+/// 1. `[signer]` The config admin.
+#[inline(always)]
+pub fn process_set_paused(accounts: &[AccountInfo], paused: bool) -> ProgramResult {
+    security::assert_expected_account_count(accounts, 2)?;
+
+    let [config_info, admin_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    validate_admin(config_info, admin_info)?;
+
+    // SAFETY: single mutable borrow to `config_info` account data and
+    // `load_mut` validates that the config is initialized.
+    let config = unsafe { load_mut::<Config>(config_info.borrow_mut_data_unchecked())? };
+
+    config.set_paused(paused);
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::load_mut;
+
+use super::{parse_config_parameters, validate_admin};
+use crate::{security, state::config::Config};
+
+/// Updates the fee parameters and feature toggles of the program config.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The config account.
+/// 1. `[signer]` The config admin.
+#[inline(always)]
+pub fn process_update_config(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let (fee_recipient, fee_bps, features) = parse_config_parameters(instruction_data)?;
+
+    security::assert_expected_account_count(accounts, 2)?;
+
+    let [config_info, admin_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    validate_admin(config_info, admin_info)?;
+
+    // SAFETY: single mutable borrow to `config_info` account data and
+    // `load_mut` validates that the config is initialized.
+    let config = unsafe { load_mut::<Config>(config_info.borrow_mut_data_unchecked())? };
+
+    config.fee_recipient = *fee_recipient;
+    config.set_fee_bps(fee_bps);
+    config.set_features(features);
+
+    Ok(())
+}
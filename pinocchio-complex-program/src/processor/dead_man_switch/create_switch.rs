@@ -0,0 +1,92 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, shared},
+    state::dead_man_switch::{DeadManSwitch, DEAD_MAN_SWITCH_SEED},
+};
+
+/// Creates a new dead man's switch.
+///
+/// The token vault must be an uninitialized account owned by the token program;
+/// it is initialized with the switch derived address as its owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The owner of the switch, paying for the switch account.
+/// 1. `[writable]` The switch account.
+/// 2. `[]` The key used to derive the switch address.
+/// 3. `[]` The beneficiary of the switch.
+/// 4. `[writable]` The token vault.
+/// 5. `[]` The mint of the token vault.
+/// 6. `[]` The system program.
+#[inline(always)]
+pub fn process_create_switch(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let inactivity_window = cursor.read_i64()?;
+    cursor.finish()?;
+
+    if inactivity_window <= 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [owner_info, switch_info, create_key_info, beneficiary_info, token_vault_info, mint_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the switch account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        switch_info,
+        &[
+            DEAD_MAN_SWITCH_SEED,
+            create_key_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(DEAD_MAN_SWITCH_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(owner_info, switch_info, DeadManSwitch::LEN, &seeds)?;
+
+    // Initializes the token vault under the switch authority.
+
+    shared::initialize_account::process_initialize_account(
+        &[token_vault_info.clone(), mint_info.clone()],
+        Some(switch_info.key()),
+        false,
+    )?;
+
+    // Initializes the switch.
+
+    // SAFETY: single mutable borrow to `switch_info` account data; the account was
+    // created above with the expected length.
+    let switch =
+        unsafe { load_mut_unchecked::<DeadManSwitch>(switch_info.borrow_mut_data_unchecked())? };
+
+    switch.set_initialized();
+    switch.bump = bump;
+    switch.owner = *owner_info.key();
+    switch.beneficiary = *beneficiary_info.key();
+    switch.create_key = *create_key_info.key();
+    switch.token_vault = *token_vault_info.key();
+    switch.set_inactivity_window(inactivity_window);
+    switch.set_last_ping(Clock::get()?.unix_timestamp);
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//! Dead man's switch.
+//!
+//! The owner of a switch must ping it at least once per inactivity window. While
+//! the owner is active, only the owner can withdraw from the token vault of the
+//! switch; once the owner has been inactive for longer than the window, the
+//! beneficiary can sweep the token vault, closing the switch.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateSwitch`
+//! - `1`: `Deposit`
+//! - `2`: `Ping`
+//! - `3`: `Withdraw`
+//! - `4`: `Sweep`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use crate::{parse::Cursor, processor::check_account_owner, state::dead_man_switch::DeadManSwitch};
+
+pub mod create_switch;
+pub mod deposit;
+pub mod ping;
+pub mod sweep;
+pub mod withdraw;
+
+pub use create_switch::process_create_switch;
+pub use deposit::process_deposit;
+pub use ping::process_ping;
+pub use sweep::process_sweep;
+pub use withdraw::process_withdraw;
+
+/// Process a dead man's switch instruction.
+#[inline(always)]
+pub fn process_dead_man_switch_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateSwitch
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateSwitch");
+
+            process_create_switch(accounts, instruction_data)
+        }
+        // 1 - Deposit
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Deposit");
+
+            process_deposit(accounts, instruction_data)
+        }
+        // 2 - Ping
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Ping");
+
+            process_ping(accounts)
+        }
+        // 3 - Withdraw
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Withdraw");
+
+            process_withdraw(accounts, instruction_data)
+        }
+        // 4 - Sweep
+        4 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Sweep");
+
+            process_sweep(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Parses the amount from the instruction data.
+#[inline(always)]
+fn parse_amount(instruction_data: &[u8]) -> Result<u64, ProgramError> {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    cursor.finish()?;
+
+    if amount == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok(amount)
+}
+
+/// Loads a switch, validating that `owner_info` is the switch owner and signed the
+/// instruction.
+///
+/// # Safety
+///
+/// The caller must ensure that there are no other borrows of `switch_info`
+/// account data while the returned reference is alive.
+#[inline(always)]
+unsafe fn load_switch_mut<'a>(
+    switch_info: &'a AccountInfo,
+    owner_info: &AccountInfo,
+) -> Result<&'a mut DeadManSwitch, ProgramError> {
+    check_account_owner(switch_info)?;
+    let switch = load_mut::<DeadManSwitch>(switch_info.borrow_mut_data_unchecked())?;
+
+    if &switch.owner != owner_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(owner_info);
+
+    Ok(switch)
+}
@@ -0,0 +1,31 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use super::load_switch_mut;
+
+/// Pings a switch, proving that the owner is still active.
+///
+/// Pinging a switch whose inactivity window has already elapsed is allowed, as
+/// long as the beneficiary has not swept it yet.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The switch account.
+/// 1. `[signer]` The owner of the switch.
+#[inline(always)]
+pub fn process_ping(accounts: &[AccountInfo]) -> ProgramResult {
+    let [switch_info, owner_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `switch_info` account data.
+    let switch = unsafe { load_switch_mut(switch_info, owner_info)? };
+
+    switch.set_last_ping(Clock::get()?.unix_timestamp);
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load},
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, close_extension_account, shared::custody},
+    state::dead_man_switch::DeadManSwitch,
+};
+
+/// Sweeps the token vault of a switch whose owner is inactive, closing the switch
+/// account and its token vault.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The switch account.
+/// 1. `[writable, signer]` The beneficiary of the switch.
+/// 2. `[writable]` The token vault.
+/// 3. `[writable]` The destination token account.
+#[inline(always)]
+pub fn process_sweep(accounts: &[AccountInfo]) -> ProgramResult {
+    let [switch_info, beneficiary_info, token_vault_info, destination_account_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(switch_info)?;
+    // SAFETY: scoped immutable borrow to `switch_info` account data and
+    // `load` validates that the switch is initialized.
+    let switch = unsafe { load::<DeadManSwitch>(switch_info.borrow_data_unchecked())? };
+
+    if &switch.beneficiary != beneficiary_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(beneficiary_info);
+
+    if token_vault_info.key() != &switch.token_vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if !switch.is_expired(Clock::get()?.unix_timestamp) {
+        return Err(ExtensionError::SwitchActive.into());
+    }
+
+    // Moves the tokens out of the token vault and closes it.
+
+    // SAFETY: scoped immutable borrow to `token_vault_info` account data and
+    // `load` validates that the token vault is initialized.
+    let amount = unsafe { load::<Account>(token_vault_info.borrow_data_unchecked())?.amount() };
+
+    if amount > 0 {
+        custody::transfer(
+            token_vault_info,
+            destination_account_info,
+            switch_info.key(),
+            amount,
+        )?;
+    }
+
+    custody::close_account(token_vault_info, beneficiary_info, switch_info.key())?;
+
+    close_extension_account(switch_info, beneficiary_info)
+}
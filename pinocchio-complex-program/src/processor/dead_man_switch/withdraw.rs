@@ -0,0 +1,47 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use super::{load_switch_mut, parse_amount};
+use crate::{error::ExtensionError, processor::shared::custody};
+
+/// Withdraws tokens from the token vault of a switch, which also counts as a
+/// ping.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The switch account.
+/// 1. `[signer]` The owner of the switch.
+/// 2. `[writable]` The token vault.
+/// 3. `[writable]` The destination token account.
+#[inline(always)]
+pub fn process_withdraw(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let amount = parse_amount(instruction_data)?;
+
+    let [switch_info, owner_info, token_vault_info, destination_account_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `switch_info` account data.
+    let switch = unsafe { load_switch_mut(switch_info, owner_info)? };
+
+    if token_vault_info.key() != &switch.token_vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    switch.set_last_ping(Clock::get()?.unix_timestamp);
+
+    // Moves the tokens out of the token vault.
+
+    custody::transfer(
+        token_vault_info,
+        destination_account_info,
+        switch_info.key(),
+        amount,
+    )
+}
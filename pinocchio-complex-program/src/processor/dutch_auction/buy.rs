@@ -0,0 +1,67 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::state::load;
+
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_account_owner, close_extension_account, shared::custody},
+    state::dutch_auction::DutchAuction,
+    system,
+};
+
+/// Buys the item of a Dutch auction at the current price, closing the auction
+/// account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The auction account.
+/// 1. `[writable]` The item vault.
+/// 2. `[writable]` The buyer item token account.
+/// 3. `[writable, signer]` The buyer.
+/// 4. `[writable]` The seller.
+/// 5. `[]` The system program.
+#[inline(always)]
+pub fn process_buy(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let max_price = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [auction_info, item_vault_info, buyer_item_info, buyer_info, seller_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(auction_info)?;
+    // SAFETY: scoped immutable borrow to `auction_info` account data and
+    // `load` validates that the auction is initialized.
+    let auction = unsafe { load::<DutchAuction>(auction_info.borrow_data_unchecked())? };
+
+    if item_vault_info.key() != &auction.item_vault || seller_info.key() != &auction.seller {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    let price = auction.price_at(Clock::get()?.unix_timestamp)?;
+
+    if price > max_price {
+        return Err(ExtensionError::PriceExceeded.into());
+    }
+
+    // Pays the seller and moves the item to the buyer.
+
+    system::transfer(buyer_info, seller_info, price, &[])?;
+
+    custody::transfer(
+        item_vault_info,
+        buyer_item_info,
+        auction_info.key(),
+        auction.item_amount(),
+    )?;
+
+    close_extension_account(auction_info, seller_info)
+}
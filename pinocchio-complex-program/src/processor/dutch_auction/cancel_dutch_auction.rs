@@ -0,0 +1,49 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, close_extension_account, shared::custody},
+    state::dutch_auction::DutchAuction,
+};
+
+/// Cancels an unsold Dutch auction, returning the item to the seller and closing the
+/// auction account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The auction account.
+/// 1. `[writable]` The item vault.
+/// 2. `[writable]` The seller item token account.
+/// 3. `[writable, signer]` The seller.
+#[inline(always)]
+pub fn process_cancel_dutch_auction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [auction_info, item_vault_info, seller_item_info, seller_info, _remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(auction_info)?;
+    // SAFETY: scoped immutable borrow to `auction_info` account data and
+    // `load` validates that the auction is initialized.
+    let auction = unsafe { load::<DutchAuction>(auction_info.borrow_data_unchecked())? };
+
+    if item_vault_info.key() != &auction.item_vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if seller_info.key() != &auction.seller {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(seller_info);
+
+    custody::transfer(
+        item_vault_info,
+        seller_item_info,
+        auction_info.key(),
+        auction.item_amount(),
+    )?;
+
+    close_extension_account(auction_info, seller_info)
+}
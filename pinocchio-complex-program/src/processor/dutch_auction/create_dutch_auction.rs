@@ -0,0 +1,108 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, shared},
+    state::dutch_auction::{DutchAuction, DUTCH_AUCTION_SEED},
+};
+
+/// Creates a new Dutch auction, escrowing the item tokens.
+///
+/// The item vault must be an uninitialized account owned by the token program; it is
+/// initialized with the auction derived address as its owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The seller, paying for the auction account.
+/// 1. `[writable]` The auction account.
+/// 2. `[]` The key used to derive the auction address.
+/// 3. `[]` The mint of the item.
+/// 4. `[writable]` The seller item token account.
+/// 5. `[writable]` The item vault.
+/// 6. `[]` The system program.
+#[inline(always)]
+pub fn process_create_dutch_auction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let item_amount = cursor.read_u64()?;
+    let start_price = cursor.read_u64()?;
+    let end_price = cursor.read_u64()?;
+    let start_time = cursor.read_i64()?;
+    let end_time = cursor.read_i64()?;
+    let step_duration = cursor.read_u64()?;
+    cursor.finish()?;
+
+    if start_price < end_price || end_time <= start_time {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [seller_info, auction_info, create_key_info, mint_info, seller_item_info, item_vault_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the auction account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        auction_info,
+        &[
+            DUTCH_AUCTION_SEED,
+            create_key_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(DUTCH_AUCTION_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(seller_info, auction_info, DutchAuction::LEN, &seeds)?;
+
+    // Escrows the item into the item vault.
+
+    shared::initialize_account::process_initialize_account(
+        &[item_vault_info.clone(), mint_info.clone()],
+        Some(auction_info.key()),
+        false,
+    )?;
+
+    shared::transfer::process_transfer(
+        &[
+            seller_item_info.clone(),
+            item_vault_info.clone(),
+            seller_info.clone(),
+        ],
+        item_amount,
+        None,
+    )?;
+
+    // Initializes the auction.
+
+    // SAFETY: single mutable borrow to `auction_info` account data; the account was
+    // created above with the expected length.
+    let auction =
+        unsafe { load_mut_unchecked::<DutchAuction>(auction_info.borrow_mut_data_unchecked())? };
+
+    auction.set_initialized();
+    auction.bump = bump;
+    auction.seller = *seller_info.key();
+    auction.item_vault = *item_vault_info.key();
+    auction.set_item_amount(item_amount);
+    auction.set_start_price(start_price);
+    auction.set_end_price(end_price);
+    auction.set_start_time(start_time);
+    auction.set_end_time(end_time);
+    auction.set_step_duration(step_duration);
+
+    Ok(())
+}
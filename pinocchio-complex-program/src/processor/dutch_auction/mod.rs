@@ -0,0 +1,57 @@
+//! Dutch auction.
+//!
+//! The seller escrows the item tokens in a vault owned by the auction derived
+//! address, and the price declines over time following the configured decay. The
+//! first buyer settles the sale atomically at the current price.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateDutchAuction`
+//! - `1`: `Buy`
+//! - `2`: `CancelDutchAuction`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod buy;
+pub mod cancel_dutch_auction;
+pub mod create_dutch_auction;
+
+pub use buy::process_buy;
+pub use cancel_dutch_auction::process_cancel_dutch_auction;
+pub use create_dutch_auction::process_create_dutch_auction;
+
+/// Process a Dutch auction instruction.
+#[inline(always)]
+pub fn process_dutch_auction_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateDutchAuction
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateDutchAuction");
+
+            process_create_dutch_auction(accounts, instruction_data)
+        }
+        // 1 - Buy
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Buy");
+
+            process_buy(accounts, instruction_data)
+        }
+        // 2 - CancelDutchAuction
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CancelDutchAuction");
+
+            process_cancel_dutch_auction(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
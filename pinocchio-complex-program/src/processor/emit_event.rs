@@ -0,0 +1,35 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::events::{event_authority, EVENT_IX_TAG};
+
+/// Handles an event emitted through a CPI to the token program itself.
+///
+/// The instruction has no effect: indexers read the event from its data. It only
+/// checks that it was invoked by the token program, the only program able to sign
+/// for the event authority, so events cannot be forged by other callers.
+///
+/// The first byte of `EVENT_IX_TAG` is the instruction discriminator, so the
+/// instruction data is the rest of the tag, followed by the Anchor discriminator of
+/// the event and the event fields.
+///
+/// Accounts expected:
+///
+/// 0. `[signer]` The event authority.
+#[inline(always)]
+pub fn process_emit_event(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    if !instruction_data.starts_with(&EVENT_IX_TAG[1..]) {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [event_authority_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_signer!(event_authority_info);
+
+    if event_authority_info.key() != &event_authority().0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(())
+}
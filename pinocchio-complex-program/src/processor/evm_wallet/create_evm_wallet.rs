@@ -0,0 +1,67 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account},
+    secp256k1::ETH_ADDRESS_LEN,
+    state::evm_wallet::{EvmWallet, EVM_WALLET_SEED},
+};
+
+/// Creates a new wallet controlled by an Ethereum address.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the wallet account.
+/// 1. `[writable]` The wallet account.
+/// 2. `[]` The key used to derive the wallet address.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_create_evm_wallet(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let eth_address = cursor.read_array::<ETH_ADDRESS_LEN>()?;
+    cursor.finish()?;
+
+    let [payer_info, wallet_info, create_key_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the wallet account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        wallet_info,
+        &[EVM_WALLET_SEED, create_key_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(EVM_WALLET_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(payer_info, wallet_info, EvmWallet::LEN, &seeds)?;
+
+    // Initializes the wallet.
+
+    // SAFETY: single mutable borrow to `wallet_info` account data; the account was
+    // created above with the expected length.
+    let wallet =
+        unsafe { load_mut_unchecked::<EvmWallet>(wallet_info.borrow_mut_data_unchecked())? };
+
+    wallet.set_initialized();
+    wallet.bump = bump;
+    wallet.create_key = *create_key_info.key();
+    wallet.eth_address = *eth_address;
+    wallet.set_nonce(0);
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! Wallets controlled by an Ethereum address.
+//!
+//! A wallet is a derived address owning token accounts, whose actions are
+//! authorized by secp256k1 signatures of an Ethereum address, such as those
+//! produced by the `personal_sign` method of EVM wallets. The signer is recovered
+//! from the signature, so anyone can submit a signed action on behalf of the
+//! Ethereum address. Each action signs the current nonce of the wallet, which is
+//! incremented to prevent replays.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateEvmWallet`
+//! - `1`: `SignedTransfer`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod create_evm_wallet;
+pub mod signed_transfer;
+
+pub use create_evm_wallet::process_create_evm_wallet;
+pub use signed_transfer::process_signed_transfer;
+
+/// Process an EVM wallet instruction.
+#[inline(always)]
+pub fn process_evm_wallet_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateEvmWallet
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateEvmWallet");
+
+            process_create_evm_wallet(accounts, instruction_data)
+        }
+        // 1 - SignedTransfer
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SignedTransfer");
+
+            process_signed_transfer(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
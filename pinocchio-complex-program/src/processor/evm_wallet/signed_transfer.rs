@@ -0,0 +1,76 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::PUBKEY_BYTES, ProgramResult,
+};
+use token_interface::state::load_mut;
+
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_account_owner, shared::custody},
+    secp256k1::{recover_eth_address, SIGNATURE_LEN},
+    state::evm_wallet::EvmWallet,
+};
+
+/// Length of the signed message: the wallet, source and destination addresses
+/// followed by the amount and the nonce.
+const MESSAGE_LEN: usize = PUBKEY_BYTES * 3 + 8 + 8;
+
+/// Transfers tokens from a token account owned by the wallet, authorized by a
+/// signature of the wallet Ethereum address.
+///
+/// The signed message is the wallet address, the source and destination account
+/// addresses, the amount (u64 little-endian) and the current wallet nonce (u64
+/// little-endian).
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The wallet account.
+/// 1. `[writable]` The source token account, owned by the wallet.
+/// 2. `[writable]` The destination token account.
+#[inline(always)]
+pub fn process_signed_transfer(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    let signature = cursor.read_array::<SIGNATURE_LEN>()?;
+    cursor.finish()?;
+
+    let [wallet_info, source_account_info, destination_account_info, _remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(wallet_info)?;
+    // SAFETY: single mutable borrow to `wallet_info` account data and
+    // `load_mut` validates that the wallet is initialized.
+    let wallet = unsafe { load_mut::<EvmWallet>(wallet_info.borrow_mut_data_unchecked())? };
+
+    // Checks that the Ethereum address signed the transfer.
+
+    let nonce = wallet.nonce();
+
+    let mut message = [0u8; MESSAGE_LEN];
+    message[..32].copy_from_slice(wallet_info.key());
+    message[32..64].copy_from_slice(source_account_info.key());
+    message[64..96].copy_from_slice(destination_account_info.key());
+    message[96..104].copy_from_slice(&amount.to_le_bytes());
+    message[104..].copy_from_slice(&nonce.to_le_bytes());
+
+    if recover_eth_address(&message, signature)? != wallet.eth_address {
+        return Err(ExtensionError::EthAddressMismatch.into());
+    }
+
+    wallet.set_nonce(
+        nonce
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+    );
+
+    // Transfers the tokens under the wallet authority.
+
+    custody::transfer(
+        source_account_info,
+        destination_account_info,
+        wallet_info.key(),
+        amount,
+    )
+}
@@ -0,0 +1,139 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load, load_mut, load_mut_unchecked, RawType},
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::{
+        check_account_owner, check_derived_address, create_derived_account,
+        shared::{custody, rate_limit},
+    },
+    state::faucet::{Faucet, FaucetClaim, FAUCET_CLAIM_SEED},
+};
+
+/// Claims the drip amount of a faucet.
+///
+/// The claim account of the user is created on the first claim.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The faucet account.
+/// 1. `[writable]` The claim account.
+/// 2. `[writable]` The mint of the dripped tokens.
+/// 3. `[writable]` The destination token account.
+/// 4. `[writable, signer]` The user, paying for the claim account.
+/// 5. `[]` The system program.
+/// 6. `[writable]` (Rate limited faucet) The faucet rate limit account.
+#[inline(always)]
+pub fn process_claim(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1)
+    let [bump] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [faucet_info, claim_info, mint_info, destination_account_info, user_info, _system_program_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(faucet_info)?;
+    // SAFETY: scoped immutable borrow to `faucet_info` account data and
+    // `load` validates that the faucet is initialized.
+    let faucet = unsafe { load::<Faucet>(faucet_info.borrow_data_unchecked())? };
+
+    if faucet.is_paused() {
+        return Err(ExtensionError::Paused.into());
+    }
+
+    if mint_info.key() != &faucet.mint {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    require_signer!(user_info);
+
+    let now = Clock::get()?.unix_timestamp;
+
+    // Creates the claim account on the first claim, otherwise enforces the cooldown.
+
+    let claim = if claim_info.data_len() == 0 {
+        let bump_seed = [*bump];
+        check_derived_address(
+            claim_info,
+            &[
+                FAUCET_CLAIM_SEED,
+                faucet_info.key().as_ref(),
+                user_info.key().as_ref(),
+                &bump_seed,
+            ],
+        )?;
+
+        let seeds = [
+            Seed::from(FAUCET_CLAIM_SEED),
+            Seed::from(faucet_info.key()),
+            Seed::from(user_info.key()),
+            Seed::from(&bump_seed),
+        ];
+
+        create_derived_account(user_info, claim_info, FaucetClaim::LEN, &seeds)?;
+
+        // SAFETY: single mutable borrow to `claim_info` account data; the account was
+        // created above with the expected length.
+        let claim =
+            unsafe { load_mut_unchecked::<FaucetClaim>(claim_info.borrow_mut_data_unchecked())? };
+
+        claim.set_initialized();
+        claim.bump = *bump;
+        claim.faucet = *faucet_info.key();
+        claim.user = *user_info.key();
+
+        claim
+    } else {
+        check_account_owner(claim_info)?;
+        // SAFETY: single mutable borrow to `claim_info` account data and
+        // `load_mut` validates that the claim is initialized.
+        let claim = unsafe { load_mut::<FaucetClaim>(claim_info.borrow_mut_data_unchecked())? };
+
+        if &claim.faucet != faucet_info.key() || &claim.user != user_info.key() {
+            return Err(ExtensionError::AccountMismatch.into());
+        }
+
+        let next_claim = claim
+            .last_claim()
+            .checked_add(faucet.cooldown())
+            .ok_or(TokenError::Overflow)?;
+
+        if now < next_claim {
+            return Err(ExtensionError::CooldownNotElapsed.into());
+        }
+
+        claim
+    };
+
+    claim.set_last_claim(now);
+
+    if faucet.is_rate_limited() {
+        let [rate_limit_info, ..] = remaining else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        rate_limit::consume(rate_limit_info, faucet_info, faucet.drip_amount())?;
+    }
+
+    // Mints the drip amount.
+
+    custody::mint_to(
+        mint_info,
+        destination_account_info,
+        faucet_info.key(),
+        faucet.drip_amount(),
+    )
+}
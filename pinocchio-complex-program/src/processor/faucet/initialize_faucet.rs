@@ -0,0 +1,79 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load, load_mut_unchecked, mint::Mint, RawType},
+};
+
+use super::parse_faucet_parameters;
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account},
+    state::faucet::{Faucet, FAUCET_SEED},
+};
+
+/// Initializes a new faucet for a mint.
+///
+/// The mint authority must already be set to the faucet derived address.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The faucet admin, paying for the faucet account.
+/// 1. `[writable]` The faucet account.
+/// 2. `[]` The mint of the dripped tokens.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_initialize_faucet(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected u8 (1) + u64 (8) + i64 (8)
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let (drip_amount, cooldown) = parse_faucet_parameters(cursor.remaining())?;
+
+    let [admin_info, faucet_info, mint_info, _system_program_info, _remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: scoped immutable borrow to `mint_info` account data and
+    // `load` validates that the mint is initialized.
+    let mint = unsafe { load::<Mint>(mint_info.borrow_data_unchecked())? };
+
+    if mint.mint_authority() != Some(faucet_info.key()) {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    // Creates the faucet account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        faucet_info,
+        &[FAUCET_SEED, mint_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(FAUCET_SEED),
+        Seed::from(mint_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(admin_info, faucet_info, Faucet::LEN, &seeds)?;
+
+    // Initializes the faucet.
+
+    // SAFETY: single mutable borrow to `faucet_info` account data; the account was
+    // created above with the expected length.
+    let faucet = unsafe { load_mut_unchecked::<Faucet>(faucet_info.borrow_mut_data_unchecked())? };
+
+    faucet.set_initialized();
+    faucet.bump = bump;
+    faucet.admin = *admin_info.key();
+    faucet.mint = *mint_info.key();
+    faucet.set_drip_amount(drip_amount);
+    faucet.set_cooldown(cooldown);
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+//! Token faucet with a per-user cooldown.
+//!
+//! The faucet is the mint authority of its mint and mints the drip amount to any
+//! user claiming from it, at most once per cooldown. The last claim of each user is
+//! tracked in its own derived account. The admin can also limit the total amount
+//! dripped with a token bucket rate limit.
+//!
+//! Instructions:
+//!
+//! - `0`: `InitializeFaucet`
+//! - `1`: `Claim`
+//! - `2`: `UpdateFaucet`
+//! - `3`: `Pause`
+//! - `4`: `Unpause`
+//! - `5`: `SetRateLimit`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load};
+
+use crate::{parse::Cursor, processor::check_account_owner, state::faucet::Faucet};
+
+pub mod claim;
+pub mod initialize_faucet;
+pub mod set_paused;
+pub mod set_rate_limit;
+pub mod update_faucet;
+
+pub use claim::process_claim;
+pub use initialize_faucet::process_initialize_faucet;
+pub use set_paused::process_set_paused;
+pub use set_rate_limit::process_set_rate_limit;
+pub use update_faucet::process_update_faucet;
+
+/// Process a faucet instruction.
+#[inline(always)]
+pub fn process_faucet_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - InitializeFaucet
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializeFaucet");
+
+            process_initialize_faucet(accounts, instruction_data)
+        }
+        // 1 - Claim
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Claim");
+
+            process_claim(accounts, instruction_data)
+        }
+        // 2 - UpdateFaucet
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: UpdateFaucet");
+
+            process_update_faucet(accounts, instruction_data)
+        }
+        // 3 - Pause
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Pause");
+
+            process_set_paused(accounts, true)
+        }
+        // 4 - Unpause
+        4 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Unpause");
+
+            process_set_paused(accounts, false)
+        }
+        // 5 - SetRateLimit
+        5 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SetRateLimit");
+
+            process_set_rate_limit(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Checks that `admin_info` is the faucet admin and signed the instruction.
+#[inline(always)]
+fn validate_admin(faucet_info: &AccountInfo, admin_info: &AccountInfo) -> ProgramResult {
+    check_account_owner(faucet_info)?;
+    // SAFETY: scoped immutable borrow to `faucet_info` account data and
+    // `load` validates that the faucet is initialized.
+    let faucet = unsafe { load::<Faucet>(faucet_info.borrow_data_unchecked())? };
+
+    if &faucet.admin != admin_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(admin_info);
+
+    Ok(())
+}
+
+/// Parses the faucet parameters from the instruction data:
+/// - drip_amount (8 bytes)
+/// - cooldown (8 bytes)
+#[inline(always)]
+fn parse_faucet_parameters(instruction_data: &[u8]) -> Result<(u64, i64), ProgramError> {
+    let mut cursor = Cursor::new(instruction_data);
+    let drip_amount = cursor.read_u64()?;
+    let cooldown = cursor.read_i64()?;
+    cursor.finish()?;
+
+    if drip_amount == 0 || cooldown < 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok((drip_amount, cooldown))
+}
@@ -0,0 +1,30 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::load_mut;
+
+use super::validate_admin;
+use crate::state::faucet::Faucet;
+
+/// Sets the pause flag of a faucet.
+///
+/// While paused, claims fail with [`crate::error::ExtensionError::Paused`].
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The faucet account.
+/// 1. `[signer]` The faucet admin.
+#[inline(always)]
+pub fn process_set_paused(accounts: &[AccountInfo], paused: bool) -> ProgramResult {
+    let [faucet_info, admin_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    validate_admin(faucet_info, admin_info)?;
+
+    // SAFETY: single mutable borrow to `faucet_info` account data and
+    // `load_mut` validates that the faucet is initialized.
+    let faucet = unsafe { load_mut::<Faucet>(faucet_info.borrow_mut_data_unchecked())? };
+
+    faucet.set_paused(paused);
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::load_mut;
+
+use super::validate_admin;
+use crate::{parse::Cursor, processor::shared::rate_limit, state::faucet::Faucet};
+
+/// Limits the total amount dripped by a faucet with a token bucket, refilled every
+/// slot.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The faucet account.
+/// 1. `[writable, signer]` The faucet admin, paying for the rate limit account.
+/// 2. `[writable]` The faucet rate limit account.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_set_rate_limit(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let capacity = cursor.read_u64()?;
+    let refill_rate = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [faucet_info, admin_info, rate_limit_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    validate_admin(faucet_info, admin_info)?;
+
+    rate_limit::initialize(
+        admin_info,
+        rate_limit_info,
+        faucet_info,
+        capacity,
+        refill_rate,
+        bump,
+    )?;
+
+    // SAFETY: single mutable borrow to `faucet_info` account data and
+    // `load_mut` validates that the faucet is initialized.
+    let faucet = unsafe { load_mut::<Faucet>(faucet_info.borrow_mut_data_unchecked())? };
+
+    faucet.set_rate_limited();
+
+    Ok(())
+}
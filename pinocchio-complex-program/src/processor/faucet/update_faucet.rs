@@ -0,0 +1,31 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::load_mut;
+
+use super::{parse_faucet_parameters, validate_admin};
+use crate::state::faucet::Faucet;
+
+/// Updates the drip amount and cooldown of a faucet.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The faucet account.
+/// 1. `[signer]` The faucet admin.
+#[inline(always)]
+pub fn process_update_faucet(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let (drip_amount, cooldown) = parse_faucet_parameters(instruction_data)?;
+
+    let [faucet_info, admin_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    validate_admin(faucet_info, admin_info)?;
+
+    // SAFETY: single mutable borrow to `faucet_info` account data and
+    // `load_mut` validates that the faucet is initialized.
+    let faucet = unsafe { load_mut::<Faucet>(faucet_info.borrow_mut_data_unchecked())? };
+
+    faucet.set_drip_amount(drip_amount);
+    faucet.set_cooldown(cooldown);
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use super::check_repay_follows;
+use crate::{
+    error::ExtensionError,
+    math::{self, Rounding},
+    parse::Cursor,
+    processor::{check_account_owner, shared::custody},
+    state::flash_loan::FlashLoanPool,
+};
+
+/// Borrows tokens from a flash loan pool.
+///
+/// A `Repay` of the pool must follow in the same transaction.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The pool account.
+/// 1. `[writable]` The token vault.
+/// 2. `[writable]` The destination token account.
+/// 3. `[]` The `Instructions` sysvar.
+#[inline(always)]
+pub fn process_borrow(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [pool_info, token_vault_info, destination_account_info, instructions_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(pool_info)?;
+    // SAFETY: single mutable borrow to `pool_info` account data and
+    // `load_mut` validates that the pool is initialized.
+    let pool = unsafe { load_mut::<FlashLoanPool>(pool_info.borrow_mut_data_unchecked())? };
+
+    if token_vault_info.key() != &pool.token_vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // A single `Repay` settles a single loan.
+    if pool.repay_amount() != 0 {
+        return Err(ExtensionError::LoanInProgress.into());
+    }
+
+    check_repay_follows(instructions_info, pool_info.key())?;
+
+    // The fee is rounded up, so small loans cannot avoid it.
+    let fee = math::bps(amount, pool.fee_bps(), Rounding::Up)?;
+
+    pool.set_repay_amount(amount.checked_add(fee).ok_or(TokenError::Overflow)?);
+
+    // Moves the tokens out of the token vault.
+
+    custody::transfer(
+        token_vault_info,
+        destination_account_info,
+        pool_info.key(),
+        amount,
+    )
+}
@@ -0,0 +1,87 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use super::MAX_FEE_BPS;
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, shared},
+    state::flash_loan::{FlashLoanPool, FLASH_LOAN_POOL_SEED},
+};
+
+/// Initializes a new flash loan pool.
+///
+/// The token vault must be an uninitialized account owned by the token program; it
+/// is initialized with the pool derived address as its owner. Liquidity is provided
+/// by transferring tokens to the token vault.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the pool account.
+/// 1. `[writable]` The pool account.
+/// 2. `[]` The key used to derive the pool address.
+/// 3. `[]` The system program.
+/// 4. `[writable]` The token vault.
+/// 5. `[]` The mint of the lent tokens.
+#[inline(always)]
+pub fn process_initialize_pool(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let fee_bps = cursor.read_u16()?;
+    cursor.finish()?;
+
+    if fee_bps > MAX_FEE_BPS {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [payer_info, pool_info, create_key_info, _system_program_info, token_vault_info, mint_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the pool account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        pool_info,
+        &[
+            FLASH_LOAN_POOL_SEED,
+            create_key_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(FLASH_LOAN_POOL_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(payer_info, pool_info, FlashLoanPool::LEN, &seeds)?;
+
+    // Initializes the token vault under the pool authority.
+
+    shared::initialize_account::process_initialize_account(
+        &[token_vault_info.clone(), mint_info.clone()],
+        Some(pool_info.key()),
+        false,
+    )?;
+
+    // Initializes the pool.
+
+    // SAFETY: single mutable borrow to `pool_info` account data; the account was
+    // created above with the expected length.
+    let pool =
+        unsafe { load_mut_unchecked::<FlashLoanPool>(pool_info.borrow_mut_data_unchecked())? };
+
+    pool.set_initialized();
+    pool.bump = bump;
+    pool.set_fee_bps(fee_bps);
+    pool.create_key = *create_key_info.key();
+    pool.token_vault = *token_vault_info.key();
+    pool.mint = *mint_info.key();
+
+    Ok(())
+}
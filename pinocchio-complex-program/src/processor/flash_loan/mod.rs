@@ -0,0 +1,92 @@
+//! Flash loans verified with instruction introspection.
+//!
+//! `Borrow` lends tokens from the pool token vault only when the `Instructions`
+//! sysvar shows a `Repay` of the same pool later in the transaction. `Repay` pulls
+//! the borrowed amount plus the loan fee back into the token vault, so the loan
+//! either is repaid or the whole transaction fails.
+//!
+//! Instructions:
+//!
+//! - `0`: `InitializePool`
+//! - `1`: `Borrow`
+//! - `2`: `Repay`
+
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+use token_interface::program::ID as TOKEN_PROGRAM_ID;
+
+use crate::{error::ExtensionError, sysvar::instructions::Instructions};
+
+pub mod borrow;
+pub mod initialize_pool;
+pub mod repay;
+
+pub use borrow::process_borrow;
+pub use initialize_pool::process_initialize_pool;
+pub use repay::process_repay;
+
+/// Instruction data of a `Repay` instruction, including the extension discriminator.
+const REPAY_INSTRUCTION_DATA: [u8; 2] = [115, 2];
+
+/// Maximum loan fee, in basis points, which is 100% of the borrowed amount.
+const MAX_FEE_BPS: u16 = 10_000;
+
+/// Process a flash loan instruction.
+#[inline(always)]
+pub fn process_flash_loan_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - InitializePool
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializePool");
+
+            process_initialize_pool(accounts, instruction_data)
+        }
+        // 1 - Borrow
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Borrow");
+
+            process_borrow(accounts, instruction_data)
+        }
+        // 2 - Repay
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Repay");
+
+            process_repay(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Checks that a `Repay` of `pool` follows the current instruction in the
+/// `Instructions` sysvar.
+#[inline(always)]
+fn check_repay_follows(instructions_info: &AccountInfo, pool: &Pubkey) -> ProgramResult {
+    let instructions = Instructions::from_account_info(instructions_info)?;
+
+    for index in instructions.current_index()? + 1..instructions.num_instructions()? {
+        let instruction = instructions.load_instruction_at(index)?;
+
+        if instruction.program_id != &TOKEN_PROGRAM_ID || instruction.data != REPAY_INSTRUCTION_DATA
+        {
+            continue;
+        }
+
+        // The pool is the first account of the instruction.
+        if instruction.account_key(0) == Some(pool) {
+            return Ok(());
+        }
+    }
+
+    Err(ExtensionError::RepayNotFound.into())
+}
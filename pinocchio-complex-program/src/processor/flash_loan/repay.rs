@@ -0,0 +1,46 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::load_mut;
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, shared},
+    state::flash_loan::FlashLoanPool,
+};
+
+/// Repays the loan in progress of a flash loan pool, including the loan fee.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The pool account.
+/// 1. `[writable]` The source token account.
+/// 2. `[writable]` The token vault.
+/// 3. `[signer]` The source account owner or delegate.
+#[inline(always)]
+pub fn process_repay(accounts: &[AccountInfo]) -> ProgramResult {
+    let [pool_info, _source_account_info, token_vault_info, _authority_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(pool_info)?;
+    // SAFETY: single mutable borrow to `pool_info` account data and
+    // `load_mut` validates that the pool is initialized.
+    let pool = unsafe { load_mut::<FlashLoanPool>(pool_info.borrow_mut_data_unchecked())? };
+
+    if token_vault_info.key() != &pool.token_vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    let repay_amount = pool.repay_amount();
+
+    if repay_amount == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    pool.set_repay_amount(0);
+
+    // Moves the tokens back into the token vault.
+
+    shared::transfer::process_transfer(&accounts[1..4], repay_amount, None)
+}
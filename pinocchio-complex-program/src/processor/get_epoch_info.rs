@@ -0,0 +1,76 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    return_data,
+    sysvar::{
+        epoch_rewards::{self, EpochRewards},
+        epoch_schedule::EpochSchedule,
+    },
+};
+
+/// Length of the return data payload.
+const PAYLOAD_LEN: usize = 57;
+
+/// Returns the boundaries of the current epoch and the status of the rewards
+/// distribution.
+///
+/// The layout of the return data payload is:
+/// - [0..8]: current epoch
+/// - [8..16]: first slot of the epoch
+/// - [16..24]: last slot of the epoch
+/// - [24..32]: current slot
+/// - [32]: whether the rewards are being distributed (`0` or `1`)
+/// - [33..41]: block height at which the distribution starts
+/// - [41..49]: total rewards, in lamports
+/// - [49..57]: rewards distributed so far, in lamports
+///
+/// The rewards fields are zero when the `EpochRewards` sysvar account has not been
+/// created yet.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The `EpochRewards` sysvar.
+#[inline(always)]
+pub fn process_get_epoch_info(accounts: &[AccountInfo]) -> ProgramResult {
+    let [epoch_rewards_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_address!(epoch_rewards_info, &epoch_rewards::ID);
+
+    let clock = Clock::get()?;
+    let epoch_schedule = EpochSchedule::get()?;
+
+    let mut payload = [0u8; PAYLOAD_LEN];
+
+    payload[0..8].copy_from_slice(&clock.epoch.to_le_bytes());
+    payload[8..16].copy_from_slice(
+        &epoch_schedule
+            .first_slot_in_epoch(clock.epoch)
+            .to_le_bytes(),
+    );
+    payload[16..24].copy_from_slice(&epoch_schedule.last_slot_in_epoch(clock.epoch).to_le_bytes());
+    payload[24..32].copy_from_slice(&clock.slot.to_le_bytes());
+
+    if epoch_rewards_info.data_len() > 0 {
+        // SAFETY: scoped immutable borrow to `epoch_rewards_info` account data;
+        // sysvar accounts cannot be modified by the program.
+        let epoch_rewards = unsafe { EpochRewards::from_account_info(epoch_rewards_info)? };
+
+        payload[32] = epoch_rewards.is_active() as u8;
+        payload[33..41].copy_from_slice(
+            &epoch_rewards
+                .distribution_starting_block_height()
+                .to_le_bytes(),
+        );
+        payload[41..49].copy_from_slice(&epoch_rewards.total_rewards().to_le_bytes());
+        payload[49..57].copy_from_slice(&epoch_rewards.distributed_rewards().to_le_bytes());
+    }
+
+    return_data::set(&payload)
+}
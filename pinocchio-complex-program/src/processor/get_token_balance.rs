@@ -0,0 +1,40 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::{account::Account, load};
+
+use crate::{processor::check_account_owner, return_data};
+
+/// Length of the return data payload.
+const PAYLOAD_LEN: usize = 73;
+
+/// Returns the balance of a token account, so programs can read it through a CPI
+/// without depending on the layout of the account.
+///
+/// The layout of the return data payload is:
+/// - [0..32]: mint of the account
+/// - [32..64]: owner of the account
+/// - [64..72]: amount of tokens
+/// - [72]: whether the account is frozen (`0` or `1`)
+///
+/// Accounts expected:
+///
+/// 0. `[]` The token account.
+#[inline(always)]
+pub fn process_get_token_balance(accounts: &[AccountInfo]) -> ProgramResult {
+    let [token_account_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(token_account_info)?;
+    // SAFETY: scoped immutable borrow to `token_account_info` account data and
+    // `load` validates that the account is initialized.
+    let token_account = unsafe { load::<Account>(token_account_info.borrow_data_unchecked())? };
+
+    let mut payload = [0u8; PAYLOAD_LEN];
+
+    payload[0..32].copy_from_slice(&token_account.mint);
+    payload[32..64].copy_from_slice(&token_account.owner);
+    payload[64..72].copy_from_slice(&token_account.amount().to_le_bytes());
+    payload[72] = token_account.is_frozen() as u8;
+
+    return_data::set(&payload)
+}
@@ -0,0 +1,103 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load, load_mut_unchecked, mint::Mint, RawType};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_account_owner, check_derived_address, create_derived_account, shared},
+    state::governance::{Governance, GOVERNANCE_AUTHORITY_SEED, GOVERNANCE_SEED},
+};
+
+/// Creates the governance of a voting token.
+///
+/// The vote escrow must be an uninitialized account owned by the token program; it
+/// is initialized with the governance derived address as its owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the governance account.
+/// 1. `[writable]` The governance account.
+/// 2. `[]` The governance authority.
+/// 3. `[]` The mint of the voting token.
+/// 4. `[]` The system program.
+/// 5. `[writable]` The vote escrow.
+#[inline(always)]
+pub fn process_create_governance(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let authority_bump = cursor.read_u8()?;
+    let voting_period = cursor.read_i64()?;
+    let quorum = cursor.read_u64()?;
+    cursor.finish()?;
+
+    if voting_period <= 0 || quorum == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [payer_info, governance_info, authority_info, mint_info, _system_program_info, escrow_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(mint_info)?;
+    // SAFETY: scoped immutable borrow to `mint_info` account data and
+    // `load` validates that the mint is initialized.
+    unsafe { load::<Mint>(mint_info.borrow_data_unchecked())? };
+
+    // Validates the authority address.
+
+    check_derived_address(
+        authority_info,
+        &[
+            GOVERNANCE_AUTHORITY_SEED,
+            governance_info.key().as_ref(),
+            &[authority_bump],
+        ],
+    )?;
+
+    // Creates the governance account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        governance_info,
+        &[GOVERNANCE_SEED, mint_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(GOVERNANCE_SEED),
+        Seed::from(mint_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(payer_info, governance_info, Governance::LEN, &seeds)?;
+
+    // Initializes the vote escrow under the governance.
+
+    shared::initialize_account::process_initialize_account(
+        &[escrow_info.clone(), mint_info.clone()],
+        Some(governance_info.key()),
+        false,
+    )?;
+
+    // Initializes the governance.
+
+    // SAFETY: single mutable borrow to `governance_info` account data; the account
+    // was created above with the expected length.
+    let governance =
+        unsafe { load_mut_unchecked::<Governance>(governance_info.borrow_mut_data_unchecked())? };
+
+    governance.set_initialized();
+    governance.bump = bump;
+    governance.authority_bump = authority_bump;
+    governance.mint = *mint_info.key();
+    governance.escrow = *escrow_info.key();
+    governance.set_voting_period(voting_period);
+    governance.set_quorum(quorum);
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    pubkey::PUBKEY_BYTES,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load_mut, load_mut_unchecked, RawType},
+};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_account_owner, check_derived_address, create_derived_account},
+    state::governance::{
+        Governance, Proposal, MAX_PROPOSAL_ACCOUNTS, MAX_PROPOSAL_DATA, PROPOSAL_SEED,
+    },
+};
+
+/// Length of each account entry in the instruction data.
+const ACCOUNT_ENTRY_LEN: usize = PUBKEY_BYTES + 2;
+
+/// Creates a new proposal, opening its voting period.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The governance account.
+/// 1. `[writable]` The proposal account.
+/// 2. `[writable, signer]` The proposer, which pays for the account.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_create_proposal(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // Validates the instruction data:
+    // - bump (1 byte)
+    // - program_id (32 bytes)
+    // - accounts_len (1 byte)
+    // - accounts (accounts_len * (32 bytes + 1 byte + 1 byte))
+    // - data (remaining bytes)
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let program_id = cursor.read_address()?;
+    let accounts_len = cursor.read_u8()? as usize;
+
+    if accounts_len > MAX_PROPOSAL_ACCOUNTS {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let account_entries = cursor.read_bytes(accounts_len * ACCOUNT_ENTRY_LEN)?;
+    let data = cursor.remaining();
+
+    if data.len() > MAX_PROPOSAL_DATA {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [governance_info, proposal_info, proposer_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(governance_info)?;
+    // SAFETY: single mutable borrow to `governance_info` account data and
+    // `load_mut` validates that the governance is initialized.
+    let governance =
+        unsafe { load_mut::<Governance>(governance_info.borrow_mut_data_unchecked())? };
+
+    let voting_ends_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(governance.voting_period())
+        .ok_or(TokenError::Overflow)?;
+
+    // Creates the proposal account.
+
+    let index = governance.proposal_index();
+    let index_seed = index.to_le_bytes();
+    let bump_seed = [bump];
+
+    check_derived_address(
+        proposal_info,
+        &[
+            PROPOSAL_SEED,
+            governance_info.key().as_ref(),
+            &index_seed,
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(PROPOSAL_SEED),
+        Seed::from(governance_info.key()),
+        Seed::from(&index_seed),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(proposer_info, proposal_info, Proposal::LEN, &seeds)?;
+
+    governance.set_proposal_index(index.checked_add(1).ok_or(TokenError::Overflow)?);
+
+    // Initializes the proposal.
+
+    // SAFETY: single mutable borrow to `proposal_info` account data; the account
+    // was created above with the expected length.
+    let proposal =
+        unsafe { load_mut_unchecked::<Proposal>(proposal_info.borrow_mut_data_unchecked())? };
+
+    proposal.set_initialized();
+    proposal.bump = bump;
+    proposal.governance = *governance_info.key();
+    proposal.set_index(index);
+    proposal.set_voting_ends_at(voting_ends_at);
+    proposal.program_id = *program_id;
+    proposal.accounts_len = accounts_len as u8;
+
+    for (account, entry) in proposal
+        .accounts
+        .iter_mut()
+        .zip(account_entries.chunks_exact(ACCOUNT_ENTRY_LEN))
+    {
+        account.pubkey.copy_from_slice(&entry[..PUBKEY_BYTES]);
+        account.is_signer = entry[PUBKEY_BYTES];
+        account.is_writable = entry[PUBKEY_BYTES + 1];
+    }
+
+    proposal.set_data(data);
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use core::{mem::MaybeUninit, slice::from_raw_parts};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Account, AccountMeta, Instruction, Seed, Signer},
+    program::invoke_signed_unchecked,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::state::{load, load_mut};
+
+use crate::{
+    error::ExtensionError,
+    processor::check_account_owner,
+    state::governance::{Governance, Proposal, GOVERNANCE_AUTHORITY_SEED, MAX_PROPOSAL_ACCOUNTS},
+};
+
+/// An uninitialized account meta.
+const UNINIT_ACCOUNT_META: MaybeUninit<AccountMeta> = MaybeUninit::uninit();
+
+/// An uninitialized CPI account.
+const UNINIT_ACCOUNT: MaybeUninit<Account> = MaybeUninit::uninit();
+
+/// Executes a passed proposal after its voting period ends.
+///
+/// The instruction is invoked with the governance authority as a signer.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The governance account.
+/// 1. `[writable]` The proposal account.
+/// 2. `[]` The program to invoke.
+/// 3. ..3+N `[]` The N accounts of the instruction, in the order they were proposed.
+#[inline(always)]
+pub fn process_execute_proposal(accounts: &[AccountInfo]) -> ProgramResult {
+    let [governance_info, proposal_info, program_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(governance_info)?;
+    // SAFETY: single immutable borrow to `governance_info` account data and
+    // `load` validates that the governance is initialized.
+    let governance = unsafe { load::<Governance>(governance_info.borrow_data_unchecked())? };
+
+    check_account_owner(proposal_info)?;
+    // SAFETY: single mutable borrow to `proposal_info` account data and
+    // `load_mut` validates that the proposal is initialized.
+    let proposal = unsafe { load_mut::<Proposal>(proposal_info.borrow_mut_data_unchecked())? };
+
+    if &proposal.governance != governance_info.key() || &proposal.program_id != program_info.key() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if proposal.is_executed() {
+        return Err(ExtensionError::AlreadyExecuted.into());
+    }
+
+    if Clock::get()?.unix_timestamp < proposal.voting_ends_at() {
+        return Err(ExtensionError::VotingNotEnded.into());
+    }
+
+    if !proposal.is_passed(governance.quorum()) {
+        return Err(ExtensionError::ProposalNotPassed.into());
+    }
+
+    // Marks the proposal as executed before invoking the instruction to prevent
+    // it from being executed again through reentrancy.
+    proposal.set_executed();
+
+    // Builds the instruction.
+
+    let accounts_len = proposal.accounts_len as usize;
+
+    if remaining.len() < accounts_len {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let mut account_metas = [UNINIT_ACCOUNT_META; MAX_PROPOSAL_ACCOUNTS];
+    let mut cpi_accounts = [UNINIT_ACCOUNT; MAX_PROPOSAL_ACCOUNTS];
+
+    for (i, (account, account_info)) in proposal.accounts[..accounts_len]
+        .iter()
+        .zip(remaining.iter())
+        .enumerate()
+    {
+        if &account.pubkey != account_info.key() {
+            return Err(ExtensionError::AccountMismatch.into());
+        }
+
+        account_metas[i].write(AccountMeta::new(
+            account_info.key(),
+            account.is_writable != 0,
+            account.is_signer != 0,
+        ));
+        cpi_accounts[i].write(Account::from(account_info));
+    }
+
+    // SAFETY: the first `accounts_len` elements of `account_metas` and `cpi_accounts`
+    // are initialized above.
+    let (account_metas, cpi_accounts) = unsafe {
+        (
+            from_raw_parts(account_metas.as_ptr() as *const AccountMeta, accounts_len),
+            from_raw_parts(cpi_accounts.as_ptr() as *const Account, accounts_len),
+        )
+    };
+
+    let instruction = Instruction {
+        program_id: program_info.key(),
+        accounts: account_metas,
+        data: proposal.data(),
+    };
+
+    // Invokes the instruction signed by the governance authority.
+
+    let authority_bump = [governance.authority_bump];
+    let seeds = [
+        Seed::from(GOVERNANCE_AUTHORITY_SEED),
+        Seed::from(governance_info.key()),
+        Seed::from(&authority_bump),
+    ];
+
+    // SAFETY: the accounts of the instruction are not borrowed by this processor;
+    // the proposal data is only read while the instruction is serialized.
+    unsafe { invoke_signed_unchecked(&instruction, cpi_accounts, &[Signer::from(&seeds)]) }
+}
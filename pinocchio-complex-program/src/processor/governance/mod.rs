@@ -0,0 +1,83 @@
+//! Token-weighted governance.
+//!
+//! Holders of the governance token vote on proposals, each storing a single
+//! instruction. Once the voting period ends, a proposal whose votes in favour
+//! reach the quorum and outweigh the votes against is executed through a CPI
+//! signed by the governance authority.
+//!
+//! The weight of a vote is the voter token balance, which is escrowed by the
+//! governance when voting, so the same tokens cannot vote again from another
+//! wallet. Voters withdraw their tokens with `WithdrawVote` once the voting period
+//! of the proposal ends.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateGovernance`
+//! - `1`: `CreateProposal`
+//! - `2`: `Vote`
+//! - `3`: `ExecuteProposal`
+//! - `4`: `WithdrawVote`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod create_governance;
+pub mod create_proposal;
+pub mod execute_proposal;
+pub mod vote;
+pub mod withdraw_vote;
+
+pub use create_governance::process_create_governance;
+pub use create_proposal::process_create_proposal;
+pub use execute_proposal::process_execute_proposal;
+pub use vote::process_vote;
+pub use withdraw_vote::process_withdraw_vote;
+
+/// Process a governance instruction.
+#[inline(always)]
+pub fn process_governance_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateGovernance
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateGovernance");
+
+            process_create_governance(accounts, instruction_data)
+        }
+        // 1 - CreateProposal
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateProposal");
+
+            process_create_proposal(accounts, instruction_data)
+        }
+        // 2 - Vote
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Vote");
+
+            process_vote(accounts, instruction_data)
+        }
+        // 3 - ExecuteProposal
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ExecuteProposal");
+
+            process_execute_proposal(accounts)
+        }
+        // 4 - WithdrawVote
+        4 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: WithdrawVote");
+
+            process_withdraw_vote(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
@@ -0,0 +1,159 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load, load_mut, load_mut_unchecked, RawType},
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, check_derived_address, create_derived_account, shared},
+    state::governance::{Governance, Proposal, VoteRecord, VOTE_RECORD_SEED},
+};
+
+/// Votes on a proposal with the voter token balance as the weight.
+///
+/// The balance is moved to the vote escrow until the voting period ends, so the
+/// same tokens cannot vote again from another account.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The governance account.
+/// 1. `[writable]` The proposal account.
+/// 2. `[writable]` The vote record account.
+/// 3. `[writable, signer]` The voter, which pays for the vote record account.
+/// 4. `[writable]` The voter token account of the voting token.
+/// 5. `[]` The system program.
+/// 6. `[writable]` The vote escrow.
+#[inline(always)]
+pub fn process_vote(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1) + u8 (1)
+    let [bump, approve] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let approve = match approve {
+        0 => false,
+        1 => true,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    let [governance_info, proposal_info, vote_record_info, voter_info, voter_account_info, _system_program_info, escrow_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(governance_info)?;
+    // SAFETY: scoped immutable borrow to `governance_info` account data and
+    // `load` validates that the governance is initialized.
+    let governance = unsafe { load::<Governance>(governance_info.borrow_data_unchecked())? };
+
+    check_account_owner(proposal_info)?;
+    // SAFETY: single mutable borrow to `proposal_info` account data and
+    // `load_mut` validates that the proposal is initialized.
+    let proposal = unsafe { load_mut::<Proposal>(proposal_info.borrow_mut_data_unchecked())? };
+
+    if &proposal.governance != governance_info.key() || escrow_info.key() != &governance.escrow {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if Clock::get()?.unix_timestamp >= proposal.voting_ends_at() {
+        return Err(ExtensionError::VotingEnded.into());
+    }
+
+    // Reads the voter token balance.
+
+    check_account_owner(voter_account_info)?;
+
+    let weight = {
+        // SAFETY: scoped immutable borrow to `voter_account_info` account data and
+        // `load` validates that the account is initialized.
+        let voter_account = unsafe { load::<Account>(voter_account_info.borrow_data_unchecked())? };
+
+        if voter_account.mint != governance.mint {
+            return Err(ExtensionError::MintMismatch.into());
+        }
+
+        if &voter_account.owner != voter_info.key() {
+            return Err(TokenError::OwnerMismatch.into());
+        }
+
+        voter_account.amount()
+    };
+
+    if weight == 0 {
+        return Err(TokenError::InsufficientFunds.into());
+    }
+
+    // Creates the vote record account, which fails if the voter already voted.
+
+    let bump_seed = [*bump];
+    check_derived_address(
+        vote_record_info,
+        &[
+            VOTE_RECORD_SEED,
+            proposal_info.key().as_ref(),
+            voter_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(VOTE_RECORD_SEED),
+        Seed::from(proposal_info.key()),
+        Seed::from(voter_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(voter_info, vote_record_info, VoteRecord::LEN, &seeds)?;
+
+    // SAFETY: single mutable borrow to `vote_record_info` account data; the account
+    // was created above with the expected length.
+    let vote_record =
+        unsafe { load_mut_unchecked::<VoteRecord>(vote_record_info.borrow_mut_data_unchecked())? };
+
+    vote_record.set_initialized();
+    vote_record.bump = *bump;
+    vote_record.proposal = *proposal_info.key();
+    vote_record.voter = *voter_info.key();
+    vote_record.set_approve(approve);
+    vote_record.set_weight(weight);
+
+    // Escrows the voter tokens.
+
+    shared::transfer::process_transfer(
+        &[
+            voter_account_info.clone(),
+            escrow_info.clone(),
+            voter_info.clone(),
+        ],
+        weight,
+        None,
+    )?;
+
+    // Tallies the vote.
+
+    if approve {
+        proposal.set_yes_votes(
+            proposal
+                .yes_votes()
+                .checked_add(weight)
+                .ok_or(TokenError::Overflow)?,
+        );
+    } else {
+        proposal.set_no_votes(
+            proposal
+                .no_votes()
+                .checked_add(weight)
+                .ok_or(TokenError::Overflow)?,
+        );
+    }
+
+    Ok(())
+}
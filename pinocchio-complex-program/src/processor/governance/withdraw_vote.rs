@@ -0,0 +1,86 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load},
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, close_extension_account, shared::custody},
+    state::governance::{Governance, Proposal, VoteRecord},
+};
+
+/// Returns the tokens escrowed by a vote once the voting period of the proposal
+/// ends, closing the vote record account.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The governance account.
+/// 1. `[]` The proposal account.
+/// 2. `[writable]` The vote record account.
+/// 3. `[writable]` The voter, receiving the vote record rent.
+/// 4. `[writable]` The vote escrow.
+/// 5. `[writable]` The destination token account of the voter.
+#[inline(always)]
+pub fn process_withdraw_vote(accounts: &[AccountInfo]) -> ProgramResult {
+    let [governance_info, proposal_info, vote_record_info, voter_info, escrow_info, destination_account_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(governance_info)?;
+    // SAFETY: scoped immutable borrow to `governance_info` account data and
+    // `load` validates that the governance is initialized.
+    let governance = unsafe { load::<Governance>(governance_info.borrow_data_unchecked())? };
+
+    check_account_owner(proposal_info)?;
+    // SAFETY: scoped immutable borrow to `proposal_info` account data and
+    // `load` validates that the proposal is initialized.
+    let proposal = unsafe { load::<Proposal>(proposal_info.borrow_data_unchecked())? };
+
+    if &proposal.governance != governance_info.key() || escrow_info.key() != &governance.escrow {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // Votes are counted until the voting period ends, so the tokens stay in the
+    // escrow until then.
+
+    if Clock::get()?.unix_timestamp < proposal.voting_ends_at() {
+        return Err(ExtensionError::VotingNotEnded.into());
+    }
+
+    check_account_owner(vote_record_info)?;
+    // SAFETY: scoped immutable borrow to `vote_record_info` account data and
+    // `load` validates that the vote record is initialized.
+    let vote_record = unsafe { load::<VoteRecord>(vote_record_info.borrow_data_unchecked())? };
+
+    if &vote_record.proposal != proposal_info.key() || &vote_record.voter != voter_info.key() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // Withdrawals are permissionless, so the tokens may only go to an account of
+    // the voter.
+
+    // SAFETY: scoped immutable borrow to `destination_account_info` account data
+    // and `load` validates that the account is initialized.
+    let destination = unsafe { load::<Account>(destination_account_info.borrow_data_unchecked())? };
+
+    if &destination.owner != voter_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    custody::transfer(
+        escrow_info,
+        destination_account_info,
+        governance_info.key(),
+        vote_record.weight(),
+    )?;
+
+    close_extension_account(vote_record_info, voter_info)
+}
@@ -0,0 +1,125 @@
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load_mut_unchecked, RawType},
+};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, shared},
+    state::grants::{Grant, GRANT_SEED, MAX_MILESTONES},
+};
+
+/// Creates a new grant, escrowing the total amount of its milestones.
+///
+/// The token vault must be an uninitialized account owned by the token program;
+/// it is initialized with the grant derived address as its owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The funder of the grant, paying for the grant account.
+/// 1. `[writable]` The grant account.
+/// 2. `[]` The key used to derive the grant address.
+/// 3. `[]` The approver of the milestones.
+/// 4. `[]` The grantee token account.
+/// 5. `[writable]` The token vault.
+/// 6. `[]` The mint of the grant funds.
+/// 7. `[writable]` The funder token account.
+/// 8. `[]` The system program.
+#[inline(always)]
+pub fn process_create_grant(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1) + i64 (8) + u64 (8) * N
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let deadline = cursor.read_i64()?;
+
+    if cursor.remaining().is_empty() || cursor.remaining().len() % size_of::<u64>() != 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let milestones_len = cursor.remaining().len() / size_of::<u64>();
+
+    if milestones_len > MAX_MILESTONES {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [funder_info, grant_info, create_key_info, approver_info, grantee_account_info, token_vault_info, mint_info, source_account_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if deadline <= Clock::get()?.unix_timestamp {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Creates the grant account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        grant_info,
+        &[GRANT_SEED, create_key_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(GRANT_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(funder_info, grant_info, Grant::LEN, &seeds)?;
+
+    // Initializes the grant.
+
+    // SAFETY: single mutable borrow to `grant_info` account data; the account was
+    // created above with the expected length.
+    let grant = unsafe { load_mut_unchecked::<Grant>(grant_info.borrow_mut_data_unchecked())? };
+
+    grant.set_initialized();
+    grant.bump = bump;
+    grant.milestones_len = milestones_len as u8;
+    grant.create_key = *create_key_info.key();
+    grant.funder = *funder_info.key();
+    grant.approver = *approver_info.key();
+    grant.grantee_account = *grantee_account_info.key();
+    grant.token_vault = *token_vault_info.key();
+    grant.set_deadline(deadline);
+
+    let mut total: u64 = 0;
+
+    for index in 0..milestones_len {
+        let amount = cursor.read_u64()?;
+
+        if amount == 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        grant.set_milestone(index, amount);
+        total = total.checked_add(amount).ok_or(TokenError::Overflow)?;
+    }
+
+    // Initializes the token vault under the grant authority and escrows the funds.
+
+    shared::initialize_account::process_initialize_account(
+        &[token_vault_info.clone(), mint_info.clone()],
+        Some(grant_info.key()),
+        false,
+    )?;
+
+    shared::transfer::process_transfer(
+        &[
+            source_account_info.clone(),
+            token_vault_info.clone(),
+            funder_info.clone(),
+        ],
+        total,
+        None,
+    )
+}
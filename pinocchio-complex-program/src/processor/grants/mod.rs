@@ -0,0 +1,58 @@
+//! Milestone-based grants.
+//!
+//! The funder escrows the total amount of the grant milestones in a token vault.
+//! Until the deadline, the approver releases the milestones in order to the
+//! grantee; after the deadline, the funder can reclaim the unreleased funds,
+//! closing the grant.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateGrant`
+//! - `1`: `ReleaseMilestone`
+//! - `2`: `Refund`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod create_grant;
+pub mod refund;
+pub mod release_milestone;
+
+pub use create_grant::process_create_grant;
+pub use refund::process_refund;
+pub use release_milestone::process_release_milestone;
+
+/// Process a grants instruction.
+#[inline(always)]
+pub fn process_grants_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateGrant
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateGrant");
+
+            process_create_grant(accounts, instruction_data)
+        }
+        // 1 - ReleaseMilestone
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ReleaseMilestone");
+
+            process_release_milestone(accounts)
+        }
+        // 2 - Refund
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Refund");
+
+            process_refund(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
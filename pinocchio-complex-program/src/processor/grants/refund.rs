@@ -0,0 +1,72 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load},
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, close_extension_account, shared::custody},
+    state::grants::Grant,
+};
+
+/// Refunds the unreleased funds of a grant to the funder after the deadline,
+/// closing the grant account and its token vault.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The grant account.
+/// 1. `[writable, signer]` The funder of the grant.
+/// 2. `[writable]` The token vault.
+/// 3. `[writable]` The destination token account.
+#[inline(always)]
+pub fn process_refund(accounts: &[AccountInfo]) -> ProgramResult {
+    let [grant_info, funder_info, token_vault_info, destination_account_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(grant_info)?;
+    // SAFETY: scoped immutable borrow to `grant_info` account data and
+    // `load` validates that the grant is initialized.
+    let grant = unsafe { load::<Grant>(grant_info.borrow_data_unchecked())? };
+
+    if &grant.funder != funder_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(funder_info);
+
+    if token_vault_info.key() != &grant.token_vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if Clock::get()?.unix_timestamp < grant.deadline() {
+        return Err(ExtensionError::GrantNotExpired.into());
+    }
+
+    // Moves the unreleased funds out of the token vault and closes it.
+
+    // SAFETY: scoped immutable borrow to `token_vault_info` account data and
+    // `load` validates that the token vault is initialized.
+    let amount = unsafe { load::<Account>(token_vault_info.borrow_data_unchecked())?.amount() };
+
+    if amount > 0 {
+        custody::transfer(
+            token_vault_info,
+            destination_account_info,
+            grant_info.key(),
+            amount,
+        )?;
+    }
+
+    custody::close_account(token_vault_info, funder_info, grant_info.key())?;
+
+    close_extension_account(grant_info, funder_info)
+}
@@ -0,0 +1,63 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{error::TokenError, state::load_mut};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, shared::custody},
+    state::grants::Grant,
+};
+
+/// Releases the next milestone of a grant to the grantee.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The grant account.
+/// 1. `[signer]` The approver of the milestones.
+/// 2. `[writable]` The token vault.
+/// 3. `[writable]` The grantee token account.
+#[inline(always)]
+pub fn process_release_milestone(accounts: &[AccountInfo]) -> ProgramResult {
+    let [grant_info, approver_info, token_vault_info, grantee_account_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(grant_info)?;
+    // SAFETY: single mutable borrow to `grant_info` account data and
+    // `load_mut` validates that the grant is initialized.
+    let grant = unsafe { load_mut::<Grant>(grant_info.borrow_mut_data_unchecked())? };
+
+    if &grant.approver != approver_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(approver_info);
+
+    if token_vault_info.key() != &grant.token_vault
+        || grantee_account_info.key() != &grant.grantee_account
+    {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if Clock::get()?.unix_timestamp >= grant.deadline() {
+        return Err(ExtensionError::GrantExpired.into());
+    }
+
+    let amount = grant.next_milestone().ok_or(TokenError::InvalidState)?;
+    grant.released += 1;
+
+    // Moves the milestone amount to the grantee.
+
+    custody::transfer(
+        token_vault_info,
+        grantee_account_info,
+        grant_info.key(),
+        amount,
+    )
+}
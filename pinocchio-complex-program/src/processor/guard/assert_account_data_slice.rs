@@ -0,0 +1,39 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{error::ExtensionError, parse::Cursor};
+
+/// Asserts that the data of an account contains the expected bytes at an offset.
+///
+/// The assertion fails when the account data is too short to contain the
+/// expected bytes at the offset.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The account to check.
+#[inline(always)]
+pub fn process_assert_account_data_slice(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected u32 (4) + [u8] (N)
+    let mut cursor = Cursor::new(instruction_data);
+    let offset = cursor.read_u32()? as usize;
+    let expected = cursor.remaining();
+
+    let [account_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let data = account_info.try_borrow_data()?;
+
+    let matches = data
+        .get(offset..)
+        .and_then(|data| data.get(..expected.len()))
+        .is_some_and(|slice| slice == expected);
+
+    if !matches {
+        return Err(ExtensionError::AssertionFailed.into());
+    }
+
+    Ok(())
+}
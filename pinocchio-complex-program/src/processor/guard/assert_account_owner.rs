@@ -0,0 +1,31 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::PUBKEY_BYTES, ProgramResult,
+};
+
+use crate::error::ExtensionError;
+
+/// Asserts that an account is owned by the expected program.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The account to check.
+#[inline(always)]
+pub fn process_assert_account_owner(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected Pubkey (32)
+    if instruction_data.len() != PUBKEY_BYTES {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [account_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if account_info.owner().as_ref() != instruction_data {
+        return Err(ExtensionError::AssertionFailed.into());
+    }
+
+    Ok(())
+}
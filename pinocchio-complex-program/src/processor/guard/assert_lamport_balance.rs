@@ -0,0 +1,23 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use super::{check_comparison, unpack_comparison};
+
+/// Asserts that the lamport balance of an account compares with an expected
+/// balance.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The account to check.
+#[inline(always)]
+pub fn process_assert_lamport_balance(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (operator, expected) = unpack_comparison(instruction_data)?;
+
+    let [account_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_comparison(operator, account_info.lamports(), expected)
+}
@@ -0,0 +1,30 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::{account::Account, load};
+
+use super::{check_comparison, unpack_comparison};
+use crate::processor::check_account_owner;
+
+/// Asserts that the token balance of a token account compares with an expected
+/// amount.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The token account to check.
+#[inline(always)]
+pub fn process_assert_token_balance(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (operator, expected) = unpack_comparison(instruction_data)?;
+
+    let [token_account_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(token_account_info)?;
+    // SAFETY: scoped immutable borrow to `token_account_info` account data and
+    // `load` validates that the account is initialized.
+    let token_account = unsafe { load::<Account>(token_account_info.borrow_data_unchecked())? };
+
+    check_comparison(operator, token_account.amount(), expected)
+}
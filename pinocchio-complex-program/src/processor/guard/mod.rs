@@ -0,0 +1,110 @@
+//! Read-only guards asserting post-conditions of a transaction.
+//!
+//! Each guard checks a condition on an account and fails the transaction when it
+//! does not hold, so clients can append guard instructions to a transaction to
+//! protect it against unexpected state changes. Guards never modify accounts.
+//!
+//! Numeric guards compare the account value with the expected value using one of
+//! the operators:
+//!
+//! - `0`: equal
+//! - `1`: not equal
+//! - `2`: greater than
+//! - `3`: greater than or equal
+//! - `4`: less than
+//! - `5`: less than or equal
+//!
+//! Instructions:
+//!
+//! - `0`: `AssertLamportBalance`
+//! - `1`: `AssertTokenBalance`
+//! - `2`: `AssertAccountOwner`
+//! - `3`: `AssertAccountDataSlice`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{error::ExtensionError, parse::Cursor};
+
+pub mod assert_account_data_slice;
+pub mod assert_account_owner;
+pub mod assert_lamport_balance;
+pub mod assert_token_balance;
+
+pub use assert_account_data_slice::process_assert_account_data_slice;
+pub use assert_account_owner::process_assert_account_owner;
+pub use assert_lamport_balance::process_assert_lamport_balance;
+pub use assert_token_balance::process_assert_token_balance;
+
+/// Process a guard instruction.
+#[inline(always)]
+pub fn process_guard_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - AssertLamportBalance
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: AssertLamportBalance");
+
+            process_assert_lamport_balance(accounts, instruction_data)
+        }
+        // 1 - AssertTokenBalance
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: AssertTokenBalance");
+
+            process_assert_token_balance(accounts, instruction_data)
+        }
+        // 2 - AssertAccountOwner
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: AssertAccountOwner");
+
+            process_assert_account_owner(accounts, instruction_data)
+        }
+        // 3 - AssertAccountDataSlice
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: AssertAccountDataSlice");
+
+            process_assert_account_data_slice(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Parses the operator and expected value of a numeric guard.
+#[inline(always)]
+fn unpack_comparison(instruction_data: &[u8]) -> Result<(u8, u64), ProgramError> {
+    let mut cursor = Cursor::new(instruction_data);
+    let operator = cursor.read_u8()?;
+    let expected = cursor.read_u64()?;
+    cursor.finish()?;
+
+    Ok((operator, expected))
+}
+
+/// Checks that `value` compares with `expected` as required by `operator`.
+#[inline(always)]
+fn check_comparison(operator: u8, value: u64, expected: u64) -> ProgramResult {
+    let holds = match operator {
+        0 => value == expected,
+        1 => value != expected,
+        2 => value > expected,
+        3 => value >= expected,
+        4 => value < expected,
+        5 => value <= expected,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if holds {
+        Ok(())
+    } else {
+        Err(ExtensionError::AssertionFailed.into())
+    }
+}
@@ -1,4 +1,4 @@
-use core::{marker::PhantomData, mem::size_of};
+use core::mem::size_of;
 use pinocchio::{
     account_info::AccountInfo,
     program_error::ProgramError,
@@ -14,13 +14,11 @@ use token_interface::{
 #[inline(always)]
 pub fn process_initialize_mint(
     accounts: &[AccountInfo],
-    instruction_data: &[u8],
+    decimals: u8,
+    mint_authority: &Pubkey,
+    freeze_authority: Option<&Pubkey>,
     rent_sysvar_account: bool,
 ) -> ProgramResult {
-    // Validates the instruction data.
-
-    let args = InitializeMint::try_from_bytes(instruction_data)?;
-
     // Validates the accounts.
 
     let (mint_info, rent_sysvar_info) = if rent_sysvar_account {
@@ -60,61 +58,12 @@ pub fn process_initialize_mint(
     // Initialize the mint.
 
     mint.set_initialized(true);
-    mint.set_mint_authority(args.mint_authority());
-    mint.decimals = args.decimals();
+    mint.set_mint_authority(mint_authority);
+    mint.decimals = decimals;
 
-    if let Some(freeze_authority) = args.freeze_authority() {
+    if let Some(freeze_authority) = freeze_authority {
         mint.set_freeze_authority(freeze_authority);
     }
 
     Ok(())
 }
-
-/// Instruction data for the `InitializeMint` instruction.
-pub struct InitializeMint<'a> {
-    raw: *const u8,
-
-    _data: PhantomData<&'a [u8]>,
-}
-
-impl InitializeMint<'_> {
-    #[inline]
-    pub fn try_from_bytes(bytes: &[u8]) -> Result<InitializeMint, ProgramError> {
-        // The minimum expected size of the instruction data.
-        // - decimals (1 byte)
-        // - mint_authority (32 bytes)
-        // - option + freeze_authority (1 byte + 32 bytes)
-        if bytes.len() < 34 || (bytes[33] == 1 && bytes.len() < 66) {
-            return Err(ProgramError::InvalidInstructionData);
-        }
-
-        Ok(InitializeMint {
-            raw: bytes.as_ptr(),
-            _data: PhantomData,
-        })
-    }
-
-    #[inline]
-    pub fn decimals(&self) -> u8 {
-        // SAFETY: the `bytes` length was validated in `try_from_bytes`.
-        unsafe { *self.raw }
-    }
-
-    #[inline]
-    pub fn mint_authority(&self) -> &Pubkey {
-        // SAFETY: the `bytes` length was validated in `try_from_bytes`.
-        unsafe { &*(self.raw.add(1) as *const Pubkey) }
-    }
-
-    #[inline]
-    pub fn freeze_authority(&self) -> Option<&Pubkey> {
-        // SAFETY: the `bytes` length was validated in `try_from_bytes`.
-        unsafe {
-            if *self.raw.add(33) == 0 {
-                Option::None
-            } else {
-                Option::Some(&*(self.raw.add(34) as *const Pubkey))
-            }
-        }
-    }
-}
@@ -1,11 +1,13 @@
-use pinocchio::{account_info::AccountInfo, ProgramResult};
+use pinocchio::{account_info::AccountInfo, pubkey::Pubkey, ProgramResult};
 
 use super::initialize_mint::process_initialize_mint;
 
 #[inline(always)]
 pub fn process_initialize_mint2(
     accounts: &[AccountInfo],
-    instruction_data: &[u8],
+    decimals: u8,
+    mint_authority: &Pubkey,
+    freeze_authority: Option<&Pubkey>,
 ) -> ProgramResult {
-    process_initialize_mint(accounts, instruction_data, false)
+    process_initialize_mint(accounts, decimals, mint_authority, freeze_authority, false)
 }
@@ -0,0 +1,130 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::error::TokenError;
+
+use crate::{
+    balance::{self, TokenBalance},
+    error::ExtensionError,
+    math::{self, Rounding},
+    parse::Cursor,
+    token_swap::{self, SwapAccounts},
+};
+
+/// Maximum slippage tolerance in basis points.
+const MAX_SLIPPAGE_BPS: u16 = 10_000;
+
+/// Swaps tokens through a legacy SPL Token Swap pool.
+///
+/// The minimum amount out passed to the pool is the expected amount out reduced by
+/// the slippage tolerance, so the swap fails when the pool price moved more than
+/// `slippage_bps` basis points since the expected amount was quoted. The amount
+/// received by the destination account is checked against it after the swap.
+///
+/// The user source and destination accounts must be owned by the token program of
+/// the pool and must not be frozen.
+///
+/// The Token Swap program transfers the tokens through a CPI to the token program
+/// of the pool, so the pool and user token accounts must belong to another token
+/// program (e.g., Token-2022): invoking this program again would be a reentrant
+/// CPI.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The token swap pool state.
+/// 1. `[]` The swap authority, derived from the pool state.
+/// 2. `[signer]` The user transfer authority of the source account.
+/// 3. `[writable]` The user source account.
+/// 4. `[writable]` The pool account receiving the source tokens.
+/// 5. `[writable]` The pool account sending the destination tokens.
+/// 6. `[writable]` The user destination account.
+/// 7. `[writable]` The pool mint.
+/// 8. `[writable]` The pool fee account.
+/// 9. `[]` The token program of the pool, SPL Token or Token-2022.
+/// 10. `[]` The SPL Token Swap program.
+/// 11. `[writable]` (optional) The host fee account.
+#[inline(always)]
+pub fn process_legacy_swap(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount_in = cursor.read_u64()?;
+    let expected_amount_out = cursor.read_u64()?;
+    let slippage_bps = cursor.read_u16()?;
+    cursor.finish()?;
+
+    // The minimum amount out is `MAX_SLIPPAGE_BPS - slippage_bps` basis points of
+    // the expected amount out.
+    let minimum_bps = MAX_SLIPPAGE_BPS
+        .checked_sub(slippage_bps)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    let [swap_info, swap_authority_info, user_transfer_authority_info, source_account_info, swap_source_info, swap_destination_info, destination_account_info, pool_mint_info, pool_fee_info, token_program_info, token_swap_program_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_address!(
+        token_swap_program_info,
+        &token_swap::ID,
+        ProgramError::IncorrectProgramId
+    );
+
+    // The token program is passed to the pool, which transfers the tokens through
+    // it.
+    if (token_program_info.key() != &token_swap::SPL_TOKEN_ID
+        && token_program_info.key() != &token_swap::TOKEN_2022_ID)
+        || !token_program_info.executable()
+    {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    require_signer!(user_transfer_authority_info);
+
+    // The user accounts belong to another token program, so they are checked to be
+    // owned by it before they are read, and a frozen account is rejected here with
+    // this program's error.
+    require_owner!(source_account_info, token_program_info.key());
+
+    if balance::is_frozen(source_account_info)? {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    require_owner!(destination_account_info, token_program_info.key());
+
+    if balance::is_frozen(destination_account_info)? {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    // Computes the minimum amount out from the slippage tolerance.
+
+    // `minimum_bps <= MAX_SLIPPAGE_BPS`, so the minimum amount out is at most
+    // `expected_amount_out`.
+    let minimum_amount_out = math::bps(expected_amount_out, minimum_bps, Rounding::Down)?;
+
+    let destination_balance = TokenBalance::snapshot(destination_account_info)?;
+
+    token_swap::swap(
+        &SwapAccounts {
+            swap: swap_info,
+            swap_authority: swap_authority_info,
+            user_transfer_authority: user_transfer_authority_info,
+            source: source_account_info,
+            swap_source: swap_source_info,
+            swap_destination: swap_destination_info,
+            destination: destination_account_info,
+            pool_mint: pool_mint_info,
+            pool_fee: pool_fee_info,
+            token_program: token_program_info,
+            host_fee: remaining.first(),
+        },
+        amount_in,
+        minimum_amount_out,
+        &[],
+    )?;
+
+    // The pool is another program, so the amount received is checked on the
+    // destination account.
+    if destination_balance.increase()? < minimum_amount_out {
+        return Err(ExtensionError::SlippageExceeded.into());
+    }
+
+    Ok(())
+}
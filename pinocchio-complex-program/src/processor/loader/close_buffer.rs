@@ -0,0 +1,42 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Signer, program_error::ProgramError, ProgramResult,
+};
+
+use super::authority_seeds;
+use crate::loader;
+
+/// Closes a buffer account to reclaim its rent.
+///
+/// The instruction data is either empty, when the buffer authority signs, or the
+/// bump of the loader authority of the owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The buffer account.
+/// 1. `[writable]` The recipient of the buffer lamports.
+/// 2. `[]` The buffer authority, a signer or the loader authority.
+/// 3. `[]` The BPF Upgradeable Loader program.
+/// 4. `[signer]` (optional) The owner of the loader authority.
+#[inline(always)]
+pub fn process_close_buffer(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected [] or u8 (1)
+    if instruction_data.len() > 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [buffer_info, recipient_info, authority_info, _loader_program_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    match authority_seeds(authority_info, remaining, instruction_data)? {
+        Some(seeds) => loader::close_buffer(
+            buffer_info,
+            recipient_info,
+            authority_info,
+            &[Signer::from(&seeds)],
+        ),
+        None => loader::close_buffer(buffer_info, recipient_info, authority_info, &[]),
+    }
+}
@@ -0,0 +1,90 @@
+//! BPF Upgradeable Loader instructions invoked through CPIs.
+//!
+//! The authority of a buffer or program data account is either a signer of the
+//! instruction or the loader authority, a PDA derived from
+//! `[LOADER_AUTHORITY_SEED, owner]` that signs on behalf of its signing owner.
+//! The instruction data holds the bump of the loader authority when it is used.
+//!
+//! Instructions:
+//!
+//! - `0`: `SetAuthority`
+//! - `1`: `CloseBuffer`
+
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+
+use super::check_derived_address;
+
+pub mod close_buffer;
+pub mod set_authority;
+
+pub use close_buffer::process_close_buffer;
+pub use set_authority::process_set_authority;
+
+/// Seed prefix of the loader authority derived address.
+pub const LOADER_AUTHORITY_SEED: &[u8] = b"loader_authority";
+
+/// Process a loader instruction.
+#[inline(always)]
+pub fn process_loader_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - SetAuthority
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SetAuthority");
+
+            process_set_authority(accounts, instruction_data)
+        }
+        // 1 - CloseBuffer
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CloseBuffer");
+
+            process_close_buffer(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Validates the authority of a loader instruction.
+///
+/// Returns the seeds of the loader authority when `bump` is present, in which case
+/// the first of the `remaining` accounts must be the signing owner. Otherwise, the
+/// authority must be a signer.
+#[inline(always)]
+fn authority_seeds<'a>(
+    authority_info: &AccountInfo,
+    remaining: &'a [AccountInfo],
+    bump: &'a [u8],
+) -> Result<Option<[Seed<'a>; 3]>, ProgramError> {
+    if bump.is_empty() {
+        require_signer!(authority_info);
+
+        return Ok(None);
+    }
+
+    let [owner_info, ..] = remaining else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_signer!(owner_info);
+
+    check_derived_address(
+        authority_info,
+        &[LOADER_AUTHORITY_SEED, owner_info.key().as_ref(), bump],
+    )?;
+
+    Ok(Some([
+        Seed::from(LOADER_AUTHORITY_SEED),
+        Seed::from(owner_info.key()),
+        Seed::from(bump),
+    ]))
+}
@@ -0,0 +1,43 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Signer, program_error::ProgramError, ProgramResult,
+};
+
+use super::authority_seeds;
+use crate::loader;
+
+/// Hands the authority of a buffer or program data account to a new authority,
+/// such as a governance PDA.
+///
+/// The instruction data is either empty, when the current authority signs, or the
+/// bump of the loader authority of the owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The buffer or program data account.
+/// 1. `[]` The current authority, a signer or the loader authority.
+/// 2. `[]` The new authority.
+/// 3. `[]` The BPF Upgradeable Loader program.
+/// 4. `[signer]` (optional) The owner of the loader authority.
+#[inline(always)]
+pub fn process_set_authority(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected [] or u8 (1)
+    if instruction_data.len() > 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [account_info, authority_info, new_authority_info, _loader_program_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    match authority_seeds(authority_info, remaining, instruction_data)? {
+        Some(seeds) => loader::set_authority(
+            account_info,
+            authority_info,
+            new_authority_info,
+            &[Signer::from(&seeds)],
+        ),
+        None => loader::set_authority(account_info, authority_info, new_authority_info, &[]),
+    }
+}
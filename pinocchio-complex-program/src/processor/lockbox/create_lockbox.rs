@@ -0,0 +1,94 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, shared},
+    state::lockbox::{Lockbox, LOCKBOX_SEED},
+    sysvar::clock,
+};
+
+/// Creates a new lockbox.
+///
+/// When the token vault and mint accounts are provided, the lockbox also locks
+/// tokens and the token vault must be an uninitialized account owned by the token
+/// program; it is initialized with the lockbox derived address as its owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The owner of the lockbox, paying for the lockbox
+///    account.
+/// 1. `[writable]` The lockbox account.
+/// 2. `[]` The key used to derive the lockbox address.
+/// 3. `[]` The system program.
+/// 4. `[writable]` (Optional) The token vault.
+/// 5. `[]` (Optional) The mint of the locked tokens.
+#[inline(always)]
+pub fn process_create_lockbox(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let unlock_timestamp = cursor.read_i64()?;
+    cursor.finish()?;
+
+    let [owner_info, lockbox_info, create_key_info, _system_program_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if unlock_timestamp <= clock::unix_timestamp()? {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Creates the lockbox account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        lockbox_info,
+        &[LOCKBOX_SEED, create_key_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(LOCKBOX_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(owner_info, lockbox_info, Lockbox::LEN, &seeds)?;
+
+    // Initializes the token vault under the lockbox authority.
+
+    let token_vault = if let [token_vault_info, mint_info, ..] = remaining {
+        shared::initialize_account::process_initialize_account(
+            &[token_vault_info.clone(), mint_info.clone()],
+            Some(lockbox_info.key()),
+            false,
+        )?;
+
+        Some((*token_vault_info.key(), *mint_info.key()))
+    } else {
+        None
+    };
+
+    // Initializes the lockbox.
+
+    // SAFETY: single mutable borrow to `lockbox_info` account data; the account was
+    // created above with the expected length.
+    let lockbox =
+        unsafe { load_mut_unchecked::<Lockbox>(lockbox_info.borrow_mut_data_unchecked())? };
+
+    lockbox.set_initialized();
+    lockbox.bump = bump;
+    lockbox.owner = *owner_info.key();
+    lockbox.create_key = *create_key_info.key();
+    lockbox.set_unlock_timestamp(unlock_timestamp);
+
+    if let Some((token_vault, mint)) = token_vault {
+        lockbox.token_vault = token_vault;
+        lockbox.mint = mint;
+    }
+
+    Ok(())
+}
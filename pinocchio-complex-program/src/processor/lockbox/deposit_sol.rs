@@ -0,0 +1,30 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::load;
+
+use super::parse_amount;
+use crate::{processor::check_account_owner, state::lockbox::Lockbox, system};
+
+/// Deposits SOL into a lockbox.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The lockbox account.
+/// 1. `[writable, signer]` The depositor.
+/// 2. `[]` The system program.
+#[inline(always)]
+pub fn process_deposit_sol(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let amount = parse_amount(instruction_data)?;
+
+    let [lockbox_info, depositor_info, _system_program_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(lockbox_info)?;
+    // SAFETY: scoped immutable borrow to `lockbox_info` account data and
+    // `load` validates that the lockbox is initialized.
+    unsafe { load::<Lockbox>(lockbox_info.borrow_data_unchecked())? };
+
+    // Moves the lamports into the lockbox.
+
+    system::transfer(depositor_info, lockbox_info, amount, &[])
+}
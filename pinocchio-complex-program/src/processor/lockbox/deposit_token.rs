@@ -0,0 +1,53 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load};
+
+use super::parse_amount;
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, shared},
+    state::lockbox::Lockbox,
+};
+
+/// Deposits tokens into the token vault of a lockbox.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The lockbox account.
+/// 1. `[writable]` The token vault.
+/// 2. `[writable]` The source token account.
+/// 3. `[signer]` The source token account owner.
+#[inline(always)]
+pub fn process_deposit_token(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let amount = parse_amount(instruction_data)?;
+
+    let [lockbox_info, token_vault_info, source_account_info, authority_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(lockbox_info)?;
+    // SAFETY: scoped immutable borrow to `lockbox_info` account data and
+    // `load` validates that the lockbox is initialized.
+    let lockbox = unsafe { load::<Lockbox>(lockbox_info.borrow_data_unchecked())? };
+
+    if !lockbox.has_token_vault() {
+        return Err(TokenError::NativeNotSupported.into());
+    }
+
+    if token_vault_info.key() != &lockbox.token_vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // Moves the tokens into the token vault.
+
+    shared::transfer::process_transfer(
+        &[
+            source_account_info.clone(),
+            token_vault_info.clone(),
+            authority_info.clone(),
+        ],
+        amount,
+        None,
+    )
+}
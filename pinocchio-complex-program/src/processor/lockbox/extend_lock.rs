@@ -0,0 +1,41 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use crate::{parse::Cursor, processor::check_account_owner, state::lockbox::Lockbox};
+
+/// Extends the lock of a lockbox to a later unlock timestamp.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The lockbox account.
+/// 1. `[signer]` The owner of the lockbox.
+#[inline(always)]
+pub fn process_extend_lock(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let unlock_timestamp = cursor.read_i64()?;
+    cursor.finish()?;
+
+    let [lockbox_info, owner_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(lockbox_info)?;
+    // SAFETY: single mutable borrow to `lockbox_info` account data and
+    // `load_mut` validates that the lockbox is initialized.
+    let lockbox = unsafe { load_mut::<Lockbox>(lockbox_info.borrow_mut_data_unchecked())? };
+
+    if &lockbox.owner != owner_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(owner_info);
+
+    // The lock can only be extended.
+    if unlock_timestamp <= lockbox.unlock_timestamp() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    lockbox.set_unlock_timestamp(unlock_timestamp);
+
+    Ok(())
+}
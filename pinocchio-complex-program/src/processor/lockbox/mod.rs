@@ -0,0 +1,93 @@
+//! Time-locked lockbox.
+//!
+//! A lockbox holds SOL and, optionally, tokens of a single mint until its unlock
+//! timestamp. Once the clock passes the unlock timestamp, the owner withdraws the
+//! lockbox funds, closing the lockbox. The owner can extend the lock at any time.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateLockbox`
+//! - `1`: `DepositSol`
+//! - `2`: `DepositToken`
+//! - `3`: `Withdraw`
+//! - `4`: `ExtendLock`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::parse::Cursor;
+
+pub mod create_lockbox;
+pub mod deposit_sol;
+pub mod deposit_token;
+pub mod extend_lock;
+pub mod withdraw;
+
+pub use create_lockbox::process_create_lockbox;
+pub use deposit_sol::process_deposit_sol;
+pub use deposit_token::process_deposit_token;
+pub use extend_lock::process_extend_lock;
+pub use withdraw::process_withdraw;
+
+/// Process a lockbox instruction.
+#[inline(always)]
+pub fn process_lockbox_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateLockbox
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateLockbox");
+
+            process_create_lockbox(accounts, instruction_data)
+        }
+        // 1 - DepositSol
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: DepositSol");
+
+            process_deposit_sol(accounts, instruction_data)
+        }
+        // 2 - DepositToken
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: DepositToken");
+
+            process_deposit_token(accounts, instruction_data)
+        }
+        // 3 - Withdraw
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Withdraw");
+
+            process_withdraw(accounts)
+        }
+        // 4 - ExtendLock
+        4 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ExtendLock");
+
+            process_extend_lock(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Parses the deposit amount from the instruction data.
+#[inline(always)]
+fn parse_amount(instruction_data: &[u8]) -> Result<u64, ProgramError> {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    cursor.finish()?;
+
+    if amount == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok(amount)
+}
@@ -0,0 +1,74 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load},
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, close_extension_account, shared::custody},
+    state::lockbox::Lockbox,
+    sysvar::clock,
+};
+
+/// Withdraws the funds of an unlocked lockbox, closing the lockbox account and
+/// its token vault.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The lockbox account.
+/// 1. `[writable, signer]` The owner of the lockbox, receiving the SOL.
+/// 2. `[writable]` (Token lockbox) The token vault.
+/// 3. `[writable]` (Token lockbox) The destination token account.
+#[inline(always)]
+pub fn process_withdraw(accounts: &[AccountInfo]) -> ProgramResult {
+    let [lockbox_info, owner_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(lockbox_info)?;
+    // SAFETY: scoped immutable borrow to `lockbox_info` account data and
+    // `load` validates that the lockbox is initialized.
+    let lockbox = unsafe { load::<Lockbox>(lockbox_info.borrow_data_unchecked())? };
+
+    if &lockbox.owner != owner_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(owner_info);
+
+    if clock::unix_timestamp()? < lockbox.unlock_timestamp() {
+        return Err(ExtensionError::LockboxLocked.into());
+    }
+
+    // Moves the tokens out of the token vault and closes it.
+
+    if lockbox.has_token_vault() {
+        let [token_vault_info, destination_account_info, ..] = remaining else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if token_vault_info.key() != &lockbox.token_vault {
+            return Err(ExtensionError::AccountMismatch.into());
+        }
+
+        // SAFETY: scoped immutable borrow to `token_vault_info` account data and
+        // `load` validates that the token vault is initialized.
+        let amount = unsafe { load::<Account>(token_vault_info.borrow_data_unchecked())?.amount() };
+
+        if amount > 0 {
+            custody::transfer(
+                token_vault_info,
+                destination_account_info,
+                lockbox_info.key(),
+                amount,
+            )?;
+        }
+
+        custody::close_account(token_vault_info, owner_info, lockbox_info.key())?;
+    }
+
+    // Closing the lockbox moves the locked SOL to the owner.
+
+    close_extension_account(lockbox_info, owner_info)
+}
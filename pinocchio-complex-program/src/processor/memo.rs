@@ -0,0 +1,45 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_program_derive::Accounts;
+
+use crate::{
+    error::ExtensionError,
+    memo::{self, MAX_MEMO_SIGNERS},
+};
+
+/// Accounts of the `Memo` instruction.
+#[derive(Accounts)]
+struct MemoAccounts<'a> {
+    /// The SPL Memo program, which is only validated.
+    #[account(address = memo::ID)]
+    _memo_program: &'a AccountInfo,
+
+    /// The signers of the memo.
+    #[account(remaining)]
+    signers: &'a [AccountInfo],
+}
+
+/// Records a memo through the SPL Memo program.
+///
+/// The instruction data is the UTF-8 text of the memo, which is validated before
+/// invoking the Memo program.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The SPL Memo program.
+/// 1. ..1+N `[signer]` The N signers of the memo, at most `MAX_MEMO_SIGNERS`.
+#[inline(always)]
+pub fn process_memo(accounts: &[AccountInfo], text: &[u8]) -> ProgramResult {
+    let MemoAccounts { signers, .. } = MemoAccounts::try_from(accounts)?;
+
+    if signers.len() > MAX_MEMO_SIGNERS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if signers.iter().any(|signer_info| !signer_info.is_signer()) {
+        return Err(ExtensionError::MissingSigner.into());
+    }
+
+    core::str::from_utf8(text).map_err(|_error| ProgramError::InvalidInstructionData)?;
+
+    memo::memo(text, signers, &[])
+}
@@ -0,0 +1,37 @@
+//! Transfers that require a memo.
+//!
+//! `TransferWithMemo` only moves tokens when the `Instructions` sysvar shows an
+//! SPL Memo instruction in the same transaction, so every payment carries a
+//! reference for the recipient.
+//!
+//! Instructions:
+//!
+//! - `0`: `TransferWithMemo`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod transfer_with_memo;
+
+pub use transfer_with_memo::process_transfer_with_memo;
+
+/// Process a memo transfer instruction.
+#[inline(always)]
+pub fn process_memo_transfer_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - TransferWithMemo
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: TransferWithMemo");
+
+            process_transfer_with_memo(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
@@ -0,0 +1,57 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{
+    error::ExtensionError, memo, parse::Cursor, processor::shared,
+    sysvar::instructions::Instructions,
+};
+
+/// Transfers tokens when the transaction includes a memo.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The `Instructions` sysvar.
+/// 1. `[writable]` The source account.
+/// 2. `[writable]` The destination account.
+///
+/// Single owner/delegate:
+///
+/// 3. `[signer]` The source account's owner/delegate.
+///
+/// Multisignature owner/delegate:
+///
+/// 3. `[]` The source account's multisignature owner/delegate.
+/// 4. ..4+M `[signer]` M signer accounts.
+#[inline(always)]
+pub fn process_transfer_with_memo(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [instructions_info, transfer_accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Looks for a memo in the transaction.
+
+    let instructions = Instructions::from_account_info(instructions_info)?;
+    let mut has_memo = false;
+
+    for index in 0..instructions.num_instructions()? {
+        if instructions
+            .load_instruction_at(index)?
+            .is_invocation_of(&memo::ID, &[])
+        {
+            has_memo = true;
+            break;
+        }
+    }
+
+    if !has_memo {
+        return Err(ExtensionError::MemoNotFound.into());
+    }
+
+    shared::transfer::process_transfer(transfer_accounts, amount, None)
+}
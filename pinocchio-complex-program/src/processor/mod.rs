@@ -5,7 +5,12 @@ use core::{
     str::from_utf8_unchecked,
 };
 use pinocchio::{
-    account_info::AccountInfo, memory::sol_memcpy, program_error::ProgramError, pubkey::Pubkey,
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    memory::sol_memcpy,
+    program_error::ProgramError,
+    pubkey::{create_program_address, find_program_address, Pubkey, MAX_SEEDS},
+    sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
 use token_interface::{
@@ -13,11 +18,14 @@ use token_interface::{
     program::ID as TOKEN_PROGRAM_ID,
     state::{
         load,
+        mint::Mint,
         multisig::{Multisig, MAX_SIGNERS},
         RawType,
     },
 };
 
+use crate::{error::ExtensionError, system};
+
 pub mod amount_to_ui_amount;
 pub mod approve;
 pub mod approve_checked;
@@ -45,6 +53,63 @@ pub mod transfer_checked;
 pub mod ui_amount_to_amount;
 // Shared processors.
 pub mod shared;
+// Extension processors.
+pub mod airdrop;
+pub mod amm;
+pub mod auction;
+pub mod batch;
+pub mod bonding_curve;
+pub mod campaign;
+pub mod check_slot_hash;
+pub mod config;
+pub mod dead_man_switch;
+pub mod dutch_auction;
+#[cfg(feature = "events")]
+pub mod emit_event;
+pub mod evm_wallet;
+pub mod faucet;
+pub mod flash_loan;
+pub mod get_epoch_info;
+pub mod get_token_balance;
+pub mod governance;
+pub mod grants;
+pub mod guard;
+pub mod legacy_swap;
+pub mod loader;
+pub mod lockbox;
+pub mod memo;
+pub mod memo_transfer;
+pub mod multisig_wallet;
+pub mod orderbook;
+pub mod otc;
+pub mod points;
+pub mod post_message;
+pub mod profile;
+pub mod raffle;
+pub mod referral;
+pub mod registry;
+pub mod rewards;
+pub mod royalty;
+pub mod savings;
+pub mod signed_escrow;
+pub mod social_recovery;
+pub mod soulbound;
+pub mod splitter;
+pub mod stake;
+pub mod staking;
+pub mod subscription;
+pub mod sweep;
+pub mod system_proxy;
+pub mod ticket;
+pub mod time_gated_transfer;
+pub mod timelock;
+pub mod transfer_gate;
+pub mod transfer_if_price;
+pub mod treasury;
+pub mod vault;
+pub mod verify_preimage;
+pub mod vote;
+pub mod voucher;
 
 pub use amount_to_ui_amount::process_amount_to_ui_amount;
 pub use approve::process_approve;
@@ -72,6 +137,63 @@ pub use transfer::process_transfer;
 pub use transfer_checked::process_transfer_checked;
 pub use ui_amount_to_amount::process_ui_amount_to_amount;
 
+pub use airdrop::process_airdrop_instruction;
+pub use amm::process_amm_instruction;
+pub use auction::process_auction_instruction;
+pub use batch::process_batch;
+pub use bonding_curve::process_bonding_curve_instruction;
+pub use campaign::process_campaign_instruction;
+pub use check_slot_hash::process_check_slot_hash;
+pub use config::process_config_instruction;
+pub use dead_man_switch::process_dead_man_switch_instruction;
+pub use dutch_auction::process_dutch_auction_instruction;
+#[cfg(feature = "events")]
+pub use emit_event::process_emit_event;
+pub use evm_wallet::process_evm_wallet_instruction;
+pub use faucet::process_faucet_instruction;
+pub use flash_loan::process_flash_loan_instruction;
+pub use get_epoch_info::process_get_epoch_info;
+pub use get_token_balance::process_get_token_balance;
+pub use governance::process_governance_instruction;
+pub use grants::process_grants_instruction;
+pub use guard::process_guard_instruction;
+pub use legacy_swap::process_legacy_swap;
+pub use loader::process_loader_instruction;
+pub use lockbox::process_lockbox_instruction;
+pub use memo::process_memo;
+pub use memo_transfer::process_memo_transfer_instruction;
+pub use multisig_wallet::process_multisig_wallet_instruction;
+pub use orderbook::process_orderbook_instruction;
+pub use otc::process_otc_instruction;
+pub use points::process_points_instruction;
+pub use post_message::process_post_message;
+pub use profile::process_profile_instruction;
+pub use raffle::process_raffle_instruction;
+pub use referral::process_referral_instruction;
+pub use registry::process_registry_instruction;
+pub use rewards::process_rewards_instruction;
+pub use royalty::process_royalty_instruction;
+pub use savings::process_savings_instruction;
+pub use signed_escrow::process_signed_escrow_instruction;
+pub use social_recovery::process_social_recovery_instruction;
+pub use soulbound::process_soulbound_instruction;
+pub use splitter::process_splitter_instruction;
+pub use stake::process_stake_instruction;
+pub use staking::process_staking_instruction;
+pub use subscription::process_subscription_instruction;
+pub use sweep::process_sweep_instruction;
+pub use system_proxy::process_system_proxy;
+pub use ticket::process_ticket_instruction;
+pub use time_gated_transfer::process_time_gated_transfer;
+pub use timelock::process_timelock_instruction;
+pub use transfer_gate::process_transfer_gate_instruction;
+pub use transfer_if_price::process_transfer_if_price;
+pub use treasury::process_treasury_instruction;
+pub use vault::process_vault_instruction;
+pub use verify_preimage::process_verify_preimage;
+pub use vote::process_vote_instruction;
+pub use voucher::process_voucher_instruction;
+
 /// An uninitialized byte.
 const UNINIT_BYTE: MaybeUninit<u8> = MaybeUninit::uninit();
 
@@ -83,6 +205,9 @@ const UNINIT_BYTE: MaybeUninit<u8> = MaybeUninit::uninit();
 const MAX_FORMATTED_DIGITS: usize = u8::MAX as usize + 2;
 
 /// Checks that the account is owned by the expected program.
+///
+/// This fails with `IncorrectProgramId`, the error of SPL Token, since it is used by
+/// the token instructions; `require_owner!` returns the extension error instead.
 #[inline(always)]
 fn check_account_owner(account_info: &AccountInfo) -> ProgramResult {
     if &TOKEN_PROGRAM_ID != account_info.owner() {
@@ -92,6 +217,152 @@ fn check_account_owner(account_info: &AccountInfo) -> ProgramResult {
     }
 }
 
+/// Checks that the account address is the program derived address of the
+/// given `seeds`.
+///
+/// The `seeds` must include the bump seed of the derived address.
+#[inline(always)]
+fn check_derived_address(account_info: &AccountInfo, seeds: &[&[u8]]) -> ProgramResult {
+    if account_info.key() != &create_program_address(seeds, &TOKEN_PROGRAM_ID)? {
+        Err(ExtensionError::DerivedAddressMismatch.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that the account address is the canonical program derived address of the
+/// given `seeds`, derived with the highest valid bump seed, and that `bump` is the
+/// canonical bump seed.
+///
+/// Every bump seed deriving an off-curve address is valid, so the same `seeds` can
+/// derive several addresses. Requiring the canonical bump seed keeps a single
+/// address for the `seeds`.
+#[inline(always)]
+fn check_canonical_derived_address(
+    account_info: &AccountInfo,
+    seeds: &[&[u8]],
+    bump: u8,
+) -> ProgramResult {
+    let (address, canonical_bump) = find_program_address(seeds, &TOKEN_PROGRAM_ID);
+
+    if bump != canonical_bump || account_info.key() != &address {
+        Err(ExtensionError::DerivedAddressMismatch.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Creates a rent-exempt account owned by the token program at the derived address
+/// of the given `seeds`.
+///
+/// The last seed is the bump seed, which must be the canonical bump seed so that a
+/// single account can be created for the other seeds.
+#[inline(always)]
+fn create_derived_account(
+    payer_info: &AccountInfo,
+    account_info: &AccountInfo,
+    space: usize,
+    seeds: &[Seed],
+) -> ProgramResult {
+    let [seeds_without_bump @ .., bump_seed] = seeds else {
+        return Err(ProgramError::InvalidSeeds);
+    };
+
+    let [bump] = **bump_seed else {
+        return Err(ProgramError::InvalidSeeds);
+    };
+
+    if seeds_without_bump.len() >= MAX_SEEDS {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut derivation_seeds: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+
+    for (derivation_seed, seed) in derivation_seeds.iter_mut().zip(seeds_without_bump) {
+        *derivation_seed = &**seed;
+    }
+
+    check_canonical_derived_address(
+        account_info,
+        &derivation_seeds[..seeds_without_bump.len()],
+        bump,
+    )?;
+
+    system::create_account(
+        payer_info,
+        account_info,
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &TOKEN_PROGRAM_ID,
+        &[Signer::from(seeds)],
+    )
+}
+
+/// Closes an extension account, moving its lamports to the destination account.
+///
+/// Both accounts are required to be writable and distinct, so a read-only account
+/// fails with `NotWritable` and a closed account used as its own destination fails
+/// with `DuplicateAccount` instead of a runtime error after the instruction.
+#[inline(always)]
+fn close_extension_account(
+    account_info: &AccountInfo,
+    destination_info: &AccountInfo,
+) -> ProgramResult {
+    require_writable!(account_info);
+    require_writable!(destination_info);
+
+    if account_info == destination_info {
+        return Err(ExtensionError::DuplicateAccount.into());
+    }
+
+    let destination_starting_lamports = destination_info.lamports();
+    // SAFETY: single mutable borrow to `destination_info` lamports and there are
+    // no "active" borrows of `account_info` account data.
+    unsafe {
+        // Moves the lamports to the destination account.
+        *destination_info.borrow_mut_lamports_unchecked() = destination_starting_lamports
+            .checked_add(account_info.lamports())
+            .ok_or(TokenError::Overflow)?;
+        // Closes the account.
+        account_info.close_unchecked();
+    }
+
+    Ok(())
+}
+
+/// Checks that the `decimals` of an instruction match the decimals of the mint.
+///
+/// With the `logging` feature, both values are logged when they do not match.
+#[inline(always)]
+fn check_decimals(decimals: u8, mint: &Mint) -> ProgramResult {
+    if decimals != mint.decimals {
+        #[cfg(feature = "logging")]
+        pinocchio_log::log!(
+            "Decimals mismatch: instruction {}, mint {}",
+            decimals,
+            mint.decimals
+        );
+
+        return Err(TokenError::MintDecimalsMismatch.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that the `amount` of a transfer, mint or burn is not zero when the
+/// `reject-zero-amount` feature is enabled.
+///
+/// SPL Token accepts zero amounts, so they are accepted by default for
+/// compatibility.
+#[inline(always)]
+fn check_amount(amount: u64) -> ProgramResult {
+    if cfg!(feature = "reject-zero-amount") && amount == 0 {
+        return Err(ExtensionError::ZeroAmount.into());
+    }
+
+    Ok(())
+}
+
 /// Validates owner(s) are present.
 ///
 /// Note that `owner_account_info` will be immutable borrowed when it represents
@@ -0,0 +1,54 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{
+    error::TokenError,
+    state::{load, load_mut},
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::check_account_owner,
+    state::multisig_wallet::{MultisigTransaction, MultisigWallet},
+};
+
+/// Approves a transaction.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The wallet account.
+/// 1. `[writable]` The transaction account.
+/// 2. `[signer]` One of the wallet owners.
+#[inline(always)]
+pub fn process_approve_transaction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [wallet_info, transaction_info, owner_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(wallet_info)?;
+    // SAFETY: single immutable borrow to `wallet_info` account data and
+    // `load` validates that the wallet is initialized.
+    let wallet = unsafe { load::<MultisigWallet>(wallet_info.borrow_data_unchecked())? };
+
+    check_account_owner(transaction_info)?;
+    // SAFETY: single mutable borrow to `transaction_info` account data and
+    // `load_mut` validates that the transaction is initialized.
+    let transaction =
+        unsafe { load_mut::<MultisigTransaction>(transaction_info.borrow_mut_data_unchecked())? };
+
+    if &transaction.wallet != wallet_info.key() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if transaction.is_executed() {
+        return Err(ExtensionError::AlreadyExecuted.into());
+    }
+
+    let position = wallet
+        .owner_position(owner_info.key())
+        .ok_or(TokenError::OwnerMismatch)?;
+
+    require_signer!(owner_info);
+
+    transaction.approve(position);
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load_mut_unchecked, RawType},
+};
+
+use crate::{
+    processor::{check_derived_address, create_derived_account},
+    state::multisig_wallet::{
+        MultisigWallet, MAX_OWNERS, MULTISIG_VAULT_SEED, MULTISIG_WALLET_SEED,
+    },
+};
+
+/// Creates a new multisig wallet.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the wallet account.
+/// 1. `[writable]` The wallet account.
+/// 2. `[]` The wallet vault.
+/// 3. `[]` The key used to derive the wallet address.
+/// 4. `[]` The system program.
+/// 5. ..5+N `[]` The N owner accounts.
+#[inline(always)]
+pub fn process_create_wallet(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1) + u8 (1) + u8 (1)
+    let [bump, vault_bump, m] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [payer_info, wallet_info, vault_info, create_key_info, _system_program_info, owners @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if owners.is_empty() || owners.len() > MAX_OWNERS {
+        return Err(TokenError::InvalidNumberOfProvidedSigners.into());
+    }
+    if *m == 0 || *m as usize > owners.len() {
+        return Err(TokenError::InvalidNumberOfRequiredSigners.into());
+    }
+
+    // Validates the vault address.
+
+    check_derived_address(
+        vault_info,
+        &[
+            MULTISIG_VAULT_SEED,
+            wallet_info.key().as_ref(),
+            &[*vault_bump],
+        ],
+    )?;
+
+    // Creates the wallet account.
+
+    let bump_seed = [*bump];
+    check_derived_address(
+        wallet_info,
+        &[
+            MULTISIG_WALLET_SEED,
+            create_key_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(MULTISIG_WALLET_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(payer_info, wallet_info, MultisigWallet::LEN, &seeds)?;
+
+    // Initializes the wallet.
+
+    // SAFETY: single mutable borrow to `wallet_info` account data; the account was
+    // created above with the expected length.
+    let wallet =
+        unsafe { load_mut_unchecked::<MultisigWallet>(wallet_info.borrow_mut_data_unchecked())? };
+
+    wallet.set_initialized();
+    wallet.bump = *bump;
+    wallet.vault_bump = *vault_bump;
+    wallet.m = *m;
+    wallet.n = owners.len() as u8;
+    wallet.create_key = *create_key_info.key();
+
+    for (i, owner_info) in owners.iter().enumerate() {
+        wallet.owners[i] = *owner_info.key();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use core::{mem::MaybeUninit, slice::from_raw_parts};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Account, AccountMeta, Instruction, Seed, Signer},
+    program::invoke_signed_unchecked,
+    program_error::ProgramError,
+    ProgramResult,
+};
+use token_interface::state::{load, load_mut};
+
+use crate::{
+    error::ExtensionError,
+    processor::check_account_owner,
+    state::multisig_wallet::{
+        MultisigTransaction, MultisigWallet, MAX_TRANSACTION_ACCOUNTS, MULTISIG_VAULT_SEED,
+    },
+};
+
+/// An uninitialized account meta.
+const UNINIT_ACCOUNT_META: MaybeUninit<AccountMeta> = MaybeUninit::uninit();
+
+/// An uninitialized CPI account.
+const UNINIT_ACCOUNT: MaybeUninit<Account> = MaybeUninit::uninit();
+
+/// Executes an approved transaction.
+///
+/// The instruction is invoked with the wallet vault as a signer.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The wallet account.
+/// 1. `[writable]` The transaction account.
+/// 2. `[]` The program to invoke.
+/// 3. ..3+N `[]` The N accounts of the instruction, in the order they were proposed.
+#[inline(always)]
+pub fn process_execute_transaction(accounts: &[AccountInfo]) -> ProgramResult {
+    let [wallet_info, transaction_info, program_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(wallet_info)?;
+    // SAFETY: single immutable borrow to `wallet_info` account data and
+    // `load` validates that the wallet is initialized.
+    let wallet = unsafe { load::<MultisigWallet>(wallet_info.borrow_data_unchecked())? };
+
+    check_account_owner(transaction_info)?;
+    // SAFETY: single mutable borrow to `transaction_info` account data and
+    // `load_mut` validates that the transaction is initialized.
+    let transaction =
+        unsafe { load_mut::<MultisigTransaction>(transaction_info.borrow_mut_data_unchecked())? };
+
+    if &transaction.wallet != wallet_info.key() || &transaction.program_id != program_info.key() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if transaction.is_executed() {
+        return Err(ExtensionError::AlreadyExecuted.into());
+    }
+
+    if transaction.approvals() < wallet.m {
+        return Err(ExtensionError::NotEnoughApprovals.into());
+    }
+
+    // Marks the transaction as executed before invoking the instruction to prevent
+    // it from being executed again through reentrancy.
+    transaction.set_executed();
+
+    // Builds the instruction.
+
+    let accounts_len = transaction.accounts_len as usize;
+
+    if remaining.len() < accounts_len {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let mut account_metas = [UNINIT_ACCOUNT_META; MAX_TRANSACTION_ACCOUNTS];
+    let mut cpi_accounts = [UNINIT_ACCOUNT; MAX_TRANSACTION_ACCOUNTS];
+
+    for (i, (account, account_info)) in transaction.accounts[..accounts_len]
+        .iter()
+        .zip(remaining.iter())
+        .enumerate()
+    {
+        if &account.pubkey != account_info.key() {
+            return Err(ExtensionError::AccountMismatch.into());
+        }
+
+        account_metas[i].write(AccountMeta::new(
+            account_info.key(),
+            account.is_writable != 0,
+            account.is_signer != 0,
+        ));
+        cpi_accounts[i].write(Account::from(account_info));
+    }
+
+    // SAFETY: the first `accounts_len` elements of `account_metas` and `cpi_accounts`
+    // are initialized above.
+    let (account_metas, cpi_accounts) = unsafe {
+        (
+            from_raw_parts(account_metas.as_ptr() as *const AccountMeta, accounts_len),
+            from_raw_parts(cpi_accounts.as_ptr() as *const Account, accounts_len),
+        )
+    };
+
+    let instruction = Instruction {
+        program_id: program_info.key(),
+        accounts: account_metas,
+        data: transaction.data(),
+    };
+
+    // Invokes the instruction signed by the wallet vault.
+
+    let vault_bump = [wallet.vault_bump];
+    let seeds = [
+        Seed::from(MULTISIG_VAULT_SEED),
+        Seed::from(wallet_info.key()),
+        Seed::from(&vault_bump),
+    ];
+
+    // SAFETY: the accounts of the instruction are not borrowed by this processor;
+    // the transaction data is only read while the instruction is serialized.
+    unsafe { invoke_signed_unchecked(&instruction, cpi_accounts, &[Signer::from(&seeds)]) }
+}
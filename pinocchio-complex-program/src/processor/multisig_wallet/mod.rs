@@ -0,0 +1,67 @@
+//! Program-level M-of-N multisig wallet.
+//!
+//! Owners propose arbitrary instructions, stored in transaction accounts, which are
+//! executed through a CPI signed by the wallet vault once the number of approvals
+//! reaches the wallet threshold.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateWallet`
+//! - `1`: `ProposeTransaction`
+//! - `2`: `ApproveTransaction`
+//! - `3`: `ExecuteTransaction`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod approve_transaction;
+pub mod create_wallet;
+pub mod execute_transaction;
+pub mod propose_transaction;
+
+pub use approve_transaction::process_approve_transaction;
+pub use create_wallet::process_create_wallet;
+pub use execute_transaction::process_execute_transaction;
+pub use propose_transaction::process_propose_transaction;
+
+/// Process a multisig wallet instruction.
+#[inline(always)]
+pub fn process_multisig_wallet_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateWallet
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateWallet");
+
+            process_create_wallet(accounts, instruction_data)
+        }
+        // 1 - ProposeTransaction
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ProposeTransaction");
+
+            process_propose_transaction(accounts, instruction_data)
+        }
+        // 2 - ApproveTransaction
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ApproveTransaction");
+
+            process_approve_transaction(accounts)
+        }
+        // 3 - ExecuteTransaction
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ExecuteTransaction");
+
+            process_execute_transaction(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
@@ -0,0 +1,137 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError,
+    pubkey::PUBKEY_BYTES, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load_mut, load_mut_unchecked, RawType},
+};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_account_owner, check_derived_address, create_derived_account},
+    state::multisig_wallet::{
+        MultisigTransaction, MultisigWallet, MAX_TRANSACTION_ACCOUNTS, MAX_TRANSACTION_DATA,
+        MULTISIG_TRANSACTION_SEED,
+    },
+};
+
+/// Length of each account entry in the instruction data.
+const ACCOUNT_ENTRY_LEN: usize = PUBKEY_BYTES + 2;
+
+/// Proposes a new transaction, which is approved by the proposer.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The wallet account.
+/// 1. `[writable]` The transaction account.
+/// 2. `[writable, signer]` The proposer, one of the wallet owners.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_propose_transaction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // Validates the instruction data:
+    // - bump (1 byte)
+    // - program_id (32 bytes)
+    // - accounts_len (1 byte)
+    // - accounts (accounts_len * (32 bytes + 1 byte + 1 byte))
+    // - data (remaining bytes)
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let program_id = cursor.read_address()?;
+    let accounts_len = cursor.read_u8()? as usize;
+
+    if accounts_len > MAX_TRANSACTION_ACCOUNTS {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let account_entries = cursor.read_bytes(accounts_len * ACCOUNT_ENTRY_LEN)?;
+    let data = cursor.remaining();
+
+    if data.len() > MAX_TRANSACTION_DATA {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [wallet_info, transaction_info, proposer_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validates the proposer.
+
+    check_account_owner(wallet_info)?;
+    // SAFETY: single mutable borrow to `wallet_info` account data and
+    // `load_mut` validates that the wallet is initialized.
+    let wallet = unsafe { load_mut::<MultisigWallet>(wallet_info.borrow_mut_data_unchecked())? };
+
+    let position = wallet
+        .owner_position(proposer_info.key())
+        .ok_or(TokenError::OwnerMismatch)?;
+
+    require_signer!(proposer_info);
+
+    // Creates the transaction account.
+
+    let index = wallet.transaction_index();
+    let index_seed = index.to_le_bytes();
+    let bump_seed = [bump];
+
+    check_derived_address(
+        transaction_info,
+        &[
+            MULTISIG_TRANSACTION_SEED,
+            wallet_info.key().as_ref(),
+            &index_seed,
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(MULTISIG_TRANSACTION_SEED),
+        Seed::from(wallet_info.key()),
+        Seed::from(&index_seed),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(
+        proposer_info,
+        transaction_info,
+        MultisigTransaction::LEN,
+        &seeds,
+    )?;
+
+    wallet.set_transaction_index(index.checked_add(1).ok_or(TokenError::Overflow)?);
+
+    // Initializes the transaction.
+
+    // SAFETY: single mutable borrow to `transaction_info` account data; the account
+    // was created above with the expected length.
+    let transaction = unsafe {
+        load_mut_unchecked::<MultisigTransaction>(transaction_info.borrow_mut_data_unchecked())?
+    };
+
+    transaction.set_initialized();
+    transaction.bump = bump;
+    transaction.wallet = *wallet_info.key();
+    transaction.set_index(index);
+    transaction.program_id = *program_id;
+    transaction.accounts_len = accounts_len as u8;
+
+    for (account, entry) in transaction
+        .accounts
+        .iter_mut()
+        .zip(account_entries.chunks_exact(ACCOUNT_ENTRY_LEN))
+    {
+        account.pubkey.copy_from_slice(&entry[..PUBKEY_BYTES]);
+        account.is_signer = entry[PUBKEY_BYTES];
+        account.is_writable = entry[PUBKEY_BYTES + 1];
+    }
+
+    transaction.set_data(data);
+    transaction.approve(position);
+
+    Ok(())
+}
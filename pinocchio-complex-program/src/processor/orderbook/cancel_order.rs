@@ -0,0 +1,86 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use super::quote_amount;
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_account_owner, shared::custody},
+    state::orderbook::{Market, BID},
+};
+
+/// Cancels a resting order, refunding its remaining escrowed funds.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The market account.
+/// 1. `[writable]` The vault escrowing the order funds (quote vault for bids, base
+///    vault for asks).
+/// 2. `[writable]` The refund token account of the order (quote account for bids,
+///    base account for asks).
+/// 3. `[signer]` The owner of the order.
+#[inline(always)]
+pub fn process_cancel_order(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let side = cursor.read_u8()?;
+    let id = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [market_info, vault_info, refund_account_info, owner_info, _remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(market_info)?;
+    // SAFETY: single mutable borrow to `market_info` account data and
+    // `load_mut` validates that the market is initialized.
+    let market = unsafe { load_mut::<Market>(market_info.borrow_mut_data_unchecked())? };
+
+    let is_bid = side == BID;
+    let vault = if is_bid {
+        market.quote_vault
+    } else {
+        market.base_vault
+    };
+
+    if vault_info.key() != &vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    let order = market
+        .orders_mut(side)
+        .ok_or(ProgramError::InvalidInstructionData)?
+        .iter_mut()
+        .find(|order| order.is_resting() && order.id() == id)
+        .ok_or(ExtensionError::OrderNotFound)?;
+
+    if &order.owner != owner_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(owner_info);
+
+    let (refund_account, refund_amount) = if is_bid {
+        (
+            order.quote_account,
+            quote_amount(order.price(), order.quantity())?,
+        )
+    } else {
+        (order.base_account, order.quantity())
+    };
+
+    if refund_account_info.key() != &refund_account {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    order.set_quantity(0);
+
+    // Moves the remaining funds out of the vault.
+
+    custody::transfer(
+        vault_info,
+        refund_account_info,
+        market_info.key(),
+        refund_amount,
+    )
+}
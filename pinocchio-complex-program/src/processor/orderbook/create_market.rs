@@ -0,0 +1,88 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    processor::{check_derived_address, create_derived_account, shared},
+    state::orderbook::{Market, MARKET_SEED},
+};
+
+/// Creates a new order book market.
+///
+/// The base and quote vaults must be uninitialized accounts owned by the token
+/// program; they are initialized with the market derived address as their owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the market account.
+/// 1. `[writable]` The market account.
+/// 2. `[]` The key used to derive the market address.
+/// 3. `[]` The system program.
+/// 4. `[writable]` The base vault.
+/// 5. `[]` The base mint.
+/// 6. `[writable]` The quote vault.
+/// 7. `[]` The quote mint.
+#[inline(always)]
+pub fn process_create_market(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1)
+    let [bump] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [payer_info, market_info, create_key_info, _system_program_info, base_vault_info, base_mint_info, quote_vault_info, quote_mint_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if base_mint_info.key() == quote_mint_info.key() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Creates the market account.
+
+    let bump_seed = [*bump];
+    check_derived_address(
+        market_info,
+        &[MARKET_SEED, create_key_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(MARKET_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(payer_info, market_info, Market::LEN, &seeds)?;
+
+    // Initializes the vaults under the market authority.
+
+    shared::initialize_account::process_initialize_account(
+        &[base_vault_info.clone(), base_mint_info.clone()],
+        Some(market_info.key()),
+        false,
+    )?;
+
+    shared::initialize_account::process_initialize_account(
+        &[quote_vault_info.clone(), quote_mint_info.clone()],
+        Some(market_info.key()),
+        false,
+    )?;
+
+    // Initializes the market.
+
+    // SAFETY: single mutable borrow to `market_info` account data; the account was
+    // created above with the expected length.
+    let market = unsafe { load_mut_unchecked::<Market>(market_info.borrow_mut_data_unchecked())? };
+
+    market.set_initialized();
+    market.bump = *bump;
+    market.create_key = *create_key_info.key();
+    market.base_vault = *base_vault_info.key();
+    market.quote_vault = *quote_vault_info.key();
+    market.base_mint = *base_mint_info.key();
+    market.quote_mint = *quote_mint_info.key();
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use super::{best_order, quote_amount};
+use crate::{
+    error::ExtensionError,
+    processor::check_account_owner,
+    state::orderbook::{Market, Order, ASK, BID},
+};
+
+/// Fills the best bid against the best ask when they cross.
+///
+/// The fill is executed at the price of the older order. Anyone can crank this
+/// instruction, since it only credits the proceeds to the orders; their owners
+/// withdraw them with `SettleOrder`.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The market account.
+#[inline(always)]
+pub fn process_match_orders(accounts: &[AccountInfo]) -> ProgramResult {
+    let [market_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(market_info)?;
+    // SAFETY: single mutable borrow to `market_info` account data and
+    // `load_mut` validates that the market is initialized.
+    let market = unsafe { load_mut::<Market>(market_info.borrow_mut_data_unchecked())? };
+
+    let (Some(bid_index), Some(ask_index)) =
+        (best_order(&market.bids, BID), best_order(&market.asks, ASK))
+    else {
+        return Err(ExtensionError::OrdersNotCrossing.into());
+    };
+
+    let bid = &mut market.bids[bid_index];
+    let ask = &mut market.asks[ask_index];
+
+    if bid.price() < ask.price() {
+        return Err(ExtensionError::OrdersNotCrossing.into());
+    }
+
+    let quantity = bid.quantity().min(ask.quantity());
+    let price = if bid.id() < ask.id() {
+        bid.price()
+    } else {
+        ask.price()
+    };
+
+    // `ask.price() <= price <= bid.price()`, so the refund does not underflow.
+    let refund_amount = quote_amount(
+        bid.price().checked_sub(price).ok_or(TokenError::Overflow)?,
+        quantity,
+    )?;
+    let payment = quote_amount(price, quantity)?;
+
+    // Credits the base tokens to the bid and the quote tokens to the ask.
+
+    fill(bid, quantity, quantity, refund_amount)?;
+    fill(ask, quantity, 0, payment)
+}
+
+/// Fills `quantity` of `order`, crediting it `base_amount` base tokens and
+/// `quote_amount` quote tokens.
+#[inline(always)]
+fn fill(order: &mut Order, quantity: u64, base_amount: u64, quote_amount: u64) -> ProgramResult {
+    order.set_quantity(
+        order
+            .quantity()
+            .checked_sub(quantity)
+            .ok_or(TokenError::Overflow)?,
+    );
+    order.set_base_owed(
+        order
+            .base_owed()
+            .checked_add(base_amount)
+            .ok_or(TokenError::Overflow)?,
+    );
+    order.set_quote_owed(
+        order
+            .quote_owed()
+            .checked_add(quote_amount)
+            .ok_or(TokenError::Overflow)?,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+//! Order book with price-time priority.
+//!
+//! Resting orders escrow their funds in the market vaults: bids escrow the quote
+//! tokens at their price and asks escrow the base tokens. `MatchOrders` is a crank
+//! filling the best bid against the best ask while they cross, at the price of the
+//! older order; a bid filled below its price is refunded the difference.
+//!
+//! Fills only credit the orders, so the crank does not depend on any token
+//! account of the owners: a closed or frozen payout account cannot block the
+//! book. Owners withdraw the proceeds of their orders with `SettleOrder`.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateMarket`
+//! - `1`: `PlaceOrder`
+//! - `2`: `CancelOrder`
+//! - `3`: `MatchOrders`
+//! - `4`: `SettleOrder`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::error::TokenError;
+
+use crate::state::orderbook::{Order, BID};
+
+pub mod cancel_order;
+pub mod create_market;
+pub mod match_orders;
+pub mod place_order;
+pub mod settle_order;
+
+pub use cancel_order::process_cancel_order;
+pub use create_market::process_create_market;
+pub use match_orders::process_match_orders;
+pub use place_order::process_place_order;
+pub use settle_order::process_settle_order;
+
+/// Process an order book instruction.
+#[inline(always)]
+pub fn process_orderbook_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateMarket
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateMarket");
+
+            process_create_market(accounts, instruction_data)
+        }
+        // 1 - PlaceOrder
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: PlaceOrder");
+
+            process_place_order(accounts, instruction_data)
+        }
+        // 2 - CancelOrder
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CancelOrder");
+
+            process_cancel_order(accounts, instruction_data)
+        }
+        // 3 - MatchOrders
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: MatchOrders");
+
+            process_match_orders(accounts)
+        }
+        // 4 - SettleOrder
+        4 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SettleOrder");
+
+            process_settle_order(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Returns the amount of quote tokens escrowed by a bid of `quantity` at `price`.
+#[inline(always)]
+fn quote_amount(price: u64, quantity: u64) -> Result<u64, ProgramError> {
+    price
+        .checked_mul(quantity)
+        .ok_or(TokenError::Overflow.into())
+}
+
+/// Returns the index of the order with the best price of `side`, the oldest one
+/// among orders with the same price.
+#[inline(always)]
+fn best_order(orders: &[Order], side: u8) -> Option<usize> {
+    orders
+        .iter()
+        .enumerate()
+        .filter(|(_, order)| order.is_resting())
+        .min_by_key(|(_, order)| {
+            // Bids are ranked by the highest price and asks by the lowest price.
+            let price = if side == BID {
+                u64::MAX - order.price()
+            } else {
+                order.price()
+            };
+
+            (price, order.id())
+        })
+        .map(|(index, _)| index)
+}
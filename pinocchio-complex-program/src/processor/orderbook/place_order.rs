@@ -0,0 +1,117 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load, load_mut},
+};
+
+use super::quote_amount;
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_account_owner, shared},
+    state::orderbook::{Market, BID},
+};
+
+/// Places a resting order, escrowing its funds in the market vaults.
+///
+/// Bids escrow `price * quantity` quote tokens from the quote account and asks
+/// escrow `quantity` base tokens from the base account. Cancelled orders are
+/// refunded to the same accounts.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The market account.
+/// 1. `[writable]` The base vault.
+/// 2. `[writable]` The quote vault.
+/// 3. `[writable]` The base token account of the owner.
+/// 4. `[writable]` The quote token account of the owner.
+/// 5. `[signer]` The owner of the order.
+#[inline(always)]
+pub fn process_place_order(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let side = cursor.read_u8()?;
+    let price = cursor.read_u64()?;
+    let quantity = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [market_info, base_vault_info, quote_vault_info, base_account_info, quote_account_info, owner_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if price == 0 || quantity == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    check_account_owner(market_info)?;
+    // SAFETY: single mutable borrow to `market_info` account data and
+    // `load_mut` validates that the market is initialized.
+    let market = unsafe { load_mut::<Market>(market_info.borrow_mut_data_unchecked())? };
+
+    if base_vault_info.key() != &market.base_vault || quote_vault_info.key() != &market.quote_vault
+    {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // Validates the refund accounts of the order.
+
+    // SAFETY: scoped immutable borrow to `base_account_info` account data and
+    // `load` validates that the account is initialized.
+    let base_account = unsafe { load::<Account>(base_account_info.borrow_data_unchecked())? };
+
+    if base_account.mint != market.base_mint {
+        return Err(ExtensionError::MintMismatch.into());
+    }
+
+    // SAFETY: scoped immutable borrow to `quote_account_info` account data and
+    // `load` validates that the account is initialized.
+    let quote_account = unsafe { load::<Account>(quote_account_info.borrow_data_unchecked())? };
+
+    if quote_account.mint != market.quote_mint {
+        return Err(ExtensionError::MintMismatch.into());
+    }
+
+    // Appends the order.
+
+    let id = market.next_order_id();
+    market.set_next_order_id(id.checked_add(1).ok_or(TokenError::Overflow)?);
+
+    let order = market
+        .orders_mut(side)
+        .ok_or(ProgramError::InvalidInstructionData)?
+        .iter_mut()
+        .find(|order| order.is_empty())
+        .ok_or(ExtensionError::OrderBookFull)?;
+
+    order.set_id(id);
+    order.owner = *owner_info.key();
+    order.base_account = *base_account_info.key();
+    order.quote_account = *quote_account_info.key();
+    order.set_price(price);
+    order.set_quantity(quantity);
+
+    // Escrows the funds of the order.
+
+    if side == BID {
+        shared::transfer::process_transfer(
+            &[
+                quote_account_info.clone(),
+                quote_vault_info.clone(),
+                owner_info.clone(),
+            ],
+            quote_amount(price, quantity)?,
+            None,
+        )
+    } else {
+        shared::transfer::process_transfer(
+            &[
+                base_account_info.clone(),
+                base_vault_info.clone(),
+                owner_info.clone(),
+            ],
+            quantity,
+            None,
+        )
+    }
+}
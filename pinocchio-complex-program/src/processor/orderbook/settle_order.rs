@@ -0,0 +1,87 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_account_owner, shared::custody},
+    state::orderbook::Market,
+};
+
+/// Withdraws the proceeds credited to an order by its fills.
+///
+/// The owner chooses the destination accounts, so the proceeds are not lost when
+/// the payout accounts recorded at placement were closed or frozen.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The market account.
+/// 1. `[writable]` The base vault.
+/// 2. `[writable]` The quote vault.
+/// 3. `[writable]` The destination base token account.
+/// 4. `[writable]` The destination quote token account.
+/// 5. `[signer]` The owner of the order.
+#[inline(always)]
+pub fn process_settle_order(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1) + u64 (8)
+    let mut cursor = Cursor::new(instruction_data);
+    let side = cursor.read_u8()?;
+    let id = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [market_info, base_vault_info, quote_vault_info, base_account_info, quote_account_info, owner_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(market_info)?;
+    // SAFETY: single mutable borrow to `market_info` account data and
+    // `load_mut` validates that the market is initialized.
+    let market = unsafe { load_mut::<Market>(market_info.borrow_mut_data_unchecked())? };
+
+    if base_vault_info.key() != &market.base_vault || quote_vault_info.key() != &market.quote_vault
+    {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    let order = market
+        .orders_mut(side)
+        .ok_or(ProgramError::InvalidInstructionData)?
+        .iter_mut()
+        .find(|order| !order.is_empty() && order.id() == id)
+        .ok_or(ExtensionError::OrderNotFound)?;
+
+    if &order.owner != owner_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(owner_info);
+
+    let (base_amount, quote_amount) = (order.base_owed(), order.quote_owed());
+
+    order.set_base_owed(0);
+    order.set_quote_owed(0);
+
+    // Moves the proceeds out of the vaults.
+
+    if base_amount > 0 {
+        custody::transfer(
+            base_vault_info,
+            base_account_info,
+            market_info.key(),
+            base_amount,
+        )?;
+    }
+
+    if quote_amount > 0 {
+        custody::transfer(
+            quote_vault_info,
+            quote_account_info,
+            market_info.key(),
+            quote_amount,
+        )?;
+    }
+
+    Ok(())
+}
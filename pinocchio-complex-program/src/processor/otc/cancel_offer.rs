@@ -0,0 +1,72 @@
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+use token_interface::{error::TokenError, program::ID as TOKEN_PROGRAM_ID, state::load};
+use token_program_derive::Accounts;
+
+use crate::{
+    error::ExtensionError,
+    processor::{close_extension_account, shared::custody},
+    state::otc::OtcOffer,
+};
+
+/// Accounts of the `CancelOffer` instruction.
+#[derive(Accounts)]
+struct CancelOfferAccounts<'a> {
+    /// The offer account.
+    #[account(writable, owner = TOKEN_PROGRAM_ID)]
+    offer: &'a AccountInfo,
+
+    /// The escrow.
+    #[account(writable)]
+    escrow: &'a AccountInfo,
+
+    /// The maker token account receiving the escrowed tokens.
+    #[account(writable)]
+    destination_account: &'a AccountInfo,
+
+    /// The maker.
+    #[account(writable, signer)]
+    maker: &'a AccountInfo,
+}
+
+/// Cancels an OTC offer, refunding the escrowed tokens and closing the offer and
+/// escrow accounts to the maker.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The offer account.
+/// 1. `[writable]` The escrow.
+/// 2. `[writable]` The maker token account receiving the escrowed tokens.
+/// 3. `[writable, signer]` The maker.
+#[inline(always)]
+pub fn process_cancel_offer(accounts: &[AccountInfo]) -> ProgramResult {
+    let CancelOfferAccounts {
+        offer: offer_info,
+        escrow: escrow_info,
+        destination_account: destination_account_info,
+        maker: maker_info,
+    } = CancelOfferAccounts::try_from(accounts)?;
+
+    // SAFETY: scoped immutable borrow to `offer_info` account data and
+    // `load` validates that the offer is initialized.
+    let offer = unsafe { load::<OtcOffer>(offer_info.borrow_data_unchecked())? };
+
+    if escrow_info.key() != &offer.escrow {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if &offer.maker != maker_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    // Refunds the escrowed tokens.
+
+    custody::transfer(
+        escrow_info,
+        destination_account_info,
+        offer_info.key(),
+        offer.offered_amount(),
+    )?;
+
+    custody::close_account(escrow_info, maker_info, offer_info.key())?;
+    close_extension_account(offer_info, maker_info)
+}
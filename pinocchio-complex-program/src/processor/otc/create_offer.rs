@@ -0,0 +1,110 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{account::Account, load, load_mut_unchecked, RawType};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, shared},
+    state::otc::{OtcOffer, OTC_OFFER_SEED},
+};
+
+/// Creates a new OTC offer, escrowing the offered tokens.
+///
+/// The escrow must be an uninitialized account owned by the token program; it is
+/// initialized with the offer derived address as its owner. The offer can be
+/// restricted to a single taker by appending its address to the instruction data.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The maker, paying for the offer account.
+/// 1. `[writable]` The offer account.
+/// 2. `[]` The key used to derive the offer address.
+/// 3. `[]` The system program.
+/// 4. `[writable]` The escrow.
+/// 5. `[]` The mint of the offered tokens.
+/// 6. `[writable]` The maker token account of the offered tokens.
+/// 7. `[]` The maker token account receiving the requested tokens.
+#[inline(always)]
+pub fn process_create_offer(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let offered_amount = cursor.read_u64()?;
+    let requested_amount = cursor.read_u64()?;
+    let taker = if cursor.remaining().is_empty() {
+        None
+    } else {
+        Some(cursor.read_address()?)
+    };
+    cursor.finish()?;
+
+    let [maker_info, offer_info, create_key_info, _system_program_info, escrow_info, mint_info, maker_source_info, maker_receive_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if offered_amount == 0 || requested_amount == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // SAFETY: scoped immutable borrow to `maker_receive_info` account data and
+    // `load` validates that the account is initialized.
+    unsafe { load::<Account>(maker_receive_info.borrow_data_unchecked())? };
+
+    // Creates the offer account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        offer_info,
+        &[OTC_OFFER_SEED, create_key_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(OTC_OFFER_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(maker_info, offer_info, OtcOffer::LEN, &seeds)?;
+
+    // Initializes the escrow under the offer authority and escrows the offered
+    // tokens.
+
+    shared::initialize_account::process_initialize_account(
+        &[escrow_info.clone(), mint_info.clone()],
+        Some(offer_info.key()),
+        false,
+    )?;
+
+    shared::transfer::process_transfer(
+        &[
+            maker_source_info.clone(),
+            escrow_info.clone(),
+            maker_info.clone(),
+        ],
+        offered_amount,
+        None,
+    )?;
+
+    // Initializes the offer.
+
+    // SAFETY: single mutable borrow to `offer_info` account data; the account was
+    // created above with the expected length.
+    let offer = unsafe { load_mut_unchecked::<OtcOffer>(offer_info.borrow_mut_data_unchecked())? };
+
+    offer.set_initialized();
+    offer.bump = bump;
+    offer.maker = *maker_info.key();
+    offer.create_key = *create_key_info.key();
+    offer.escrow = *escrow_info.key();
+    offer.maker_receive_account = *maker_receive_info.key();
+    offer.set_offered_amount(offered_amount);
+    offer.set_requested_amount(requested_amount);
+
+    if let Some(taker) = taker {
+        offer.taker = *taker;
+    }
+
+    Ok(())
+}
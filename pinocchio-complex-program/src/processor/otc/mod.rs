@@ -0,0 +1,54 @@
+//! Atomic two-party OTC swap.
+//!
+//! The maker escrows the offered tokens and specifies the amount of requested
+//! tokens. A taker fills the offer atomically, sending the requested tokens to the
+//! maker and receiving the escrowed tokens, or the maker cancels it.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateOffer`
+//! - `1`: `TakeOffer`
+//! - `2`: `CancelOffer`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod cancel_offer;
+pub mod create_offer;
+pub mod take_offer;
+
+pub use cancel_offer::process_cancel_offer;
+pub use create_offer::process_create_offer;
+pub use take_offer::process_take_offer;
+
+/// Process an OTC swap instruction.
+#[inline(always)]
+pub fn process_otc_instruction(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateOffer
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateOffer");
+
+            process_create_offer(accounts, instruction_data)
+        }
+        // 1 - TakeOffer
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: TakeOffer");
+
+            process_take_offer(accounts)
+        }
+        // 2 - CancelOffer
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CancelOffer");
+
+            process_cancel_offer(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
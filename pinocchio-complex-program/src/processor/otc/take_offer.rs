@@ -0,0 +1,84 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load};
+
+use crate::{
+    error::ExtensionError,
+    processor::{
+        check_account_owner, close_extension_account, shared, shared::custody,
+        treasury::protocol_fee,
+    },
+    state::otc::OtcOffer,
+};
+
+/// Fills an OTC offer, closing the offer and escrow accounts to the maker.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The offer account.
+/// 1. `[writable]` The escrow.
+/// 2. `[writable]` The taker token account receiving the offered tokens.
+/// 3. `[writable]` The taker token account of the requested tokens.
+/// 4. `[writable]` The maker token account receiving the requested tokens.
+/// 5. `[signer]` The taker.
+/// 6. `[writable]` The maker.
+/// 7. ..12 The fee accounts, to take the protocol fee out of the offered tokens
+///    (see [`protocol_fee`]).
+#[inline(always)]
+pub fn process_take_offer(accounts: &[AccountInfo]) -> ProgramResult {
+    let [offer_info, escrow_info, taker_receive_info, taker_source_info, maker_receive_info, taker_info, maker_info, fee_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(offer_info)?;
+    // SAFETY: scoped immutable borrow to `offer_info` account data and
+    // `load` validates that the offer is initialized.
+    let offer = unsafe { load::<OtcOffer>(offer_info.borrow_data_unchecked())? };
+
+    if escrow_info.key() != &offer.escrow
+        || maker_receive_info.key() != &offer.maker_receive_account
+        || maker_info.key() != &offer.maker
+    {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if !offer.is_fillable_by(taker_info.key()) {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    // Sends the requested tokens to the maker.
+
+    shared::transfer::process_transfer(
+        &[
+            taker_source_info.clone(),
+            maker_receive_info.clone(),
+            taker_info.clone(),
+        ],
+        offer.requested_amount(),
+        None,
+    )?;
+
+    // Sends the escrowed tokens to the taker, net of the protocol fee.
+
+    let mut offered_amount = offer.offered_amount();
+
+    if let Some(fee) = protocol_fee(fee_accounts, taker_info.key(), offered_amount)? {
+        for (fee_account_info, amount) in fee.shares() {
+            custody::transfer(escrow_info, fee_account_info, offer_info.key(), amount)?;
+        }
+        offered_amount = offered_amount
+            .checked_sub(fee.amount()?)
+            .ok_or(TokenError::Overflow)?;
+    }
+
+    custody::transfer(
+        escrow_info,
+        taker_receive_info,
+        offer_info.key(),
+        offered_amount,
+    )?;
+
+    custody::close_account(escrow_info, maker_info, offer_info.key())?;
+    close_extension_account(offer_info, maker_info)
+}
@@ -0,0 +1,53 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use super::{award, validate_issuer};
+use crate::{
+    error::ExtensionError, parse::Cursor, processor::check_account_owner,
+    state::points::PointsLedger,
+};
+
+/// Awards points to or deducts points from a user ledger.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The points program account.
+/// 1. `[signer]` The issuer of the points program.
+/// 2. `[writable]` The ledger account.
+#[inline(always)]
+pub fn process_adjust(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+    is_award: bool,
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [points_program_info, issuer_info, ledger_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    validate_issuer(points_program_info, issuer_info)?;
+
+    if is_award {
+        return award(points_program_info, ledger_info, amount);
+    }
+
+    check_account_owner(ledger_info)?;
+    // SAFETY: single mutable borrow to `ledger_info` account data and
+    // `load_mut` validates that the ledger is initialized.
+    let ledger = unsafe { load_mut::<PointsLedger>(ledger_info.borrow_mut_data_unchecked())? };
+
+    if &ledger.points_program != points_program_info.key() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    let balance = ledger
+        .balance()
+        .checked_sub(amount)
+        .ok_or(TokenError::InsufficientFunds)?;
+    ledger.set_balance(balance);
+
+    Ok(())
+}
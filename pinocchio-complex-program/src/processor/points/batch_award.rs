@@ -0,0 +1,40 @@
+use core::mem::size_of;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use super::{award, validate_issuer};
+
+use crate::parse::Cursor;
+
+/// Awards points to several user ledgers.
+///
+/// The instruction data holds one amount for each ledger account, in the same
+/// order as the accounts.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The points program account.
+/// 1. `[signer]` The issuer of the points program.
+/// 2. ..2+N `[writable]` The N ledger accounts, in the instruction data order.
+#[inline(always)]
+pub fn process_batch_award(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let [points_program_info, issuer_info, ledger_infos @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // expected u64 (8) * N
+    if ledger_infos.is_empty() || instruction_data.len() != ledger_infos.len() * size_of::<u64>() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    validate_issuer(points_program_info, issuer_info)?;
+
+    let mut cursor = Cursor::new(instruction_data);
+
+    for ledger_info in ledger_infos {
+        let amount = cursor.read_u64()?;
+
+        award(points_program_info, ledger_info, amount)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load, load_mut_unchecked, RawType};
+
+use crate::{
+    processor::{check_account_owner, check_derived_address, create_derived_account},
+    state::points::{PointsLedger, PointsProgram, POINTS_LEDGER_SEED},
+};
+
+/// Creates the points ledger of a user.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the ledger account.
+/// 1. `[writable]` The ledger account.
+/// 2. `[]` The points program account.
+/// 3. `[]` The user holding the points.
+/// 4. `[]` The system program.
+#[inline(always)]
+pub fn process_create_ledger(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1)
+    let [bump] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [payer_info, ledger_info, points_program_info, user_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(points_program_info)?;
+    // SAFETY: scoped immutable borrow to `points_program_info` account data and
+    // `load` validates that the points program is initialized.
+    unsafe { load::<PointsProgram>(points_program_info.borrow_data_unchecked())? };
+
+    // Creates the ledger account.
+
+    let bump_seed = [*bump];
+    check_derived_address(
+        ledger_info,
+        &[
+            POINTS_LEDGER_SEED,
+            points_program_info.key().as_ref(),
+            user_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(POINTS_LEDGER_SEED),
+        Seed::from(points_program_info.key()),
+        Seed::from(user_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(payer_info, ledger_info, PointsLedger::LEN, &seeds)?;
+
+    // Initializes the ledger.
+
+    // SAFETY: single mutable borrow to `ledger_info` account data; the account was
+    // created above with the expected length.
+    let ledger =
+        unsafe { load_mut_unchecked::<PointsLedger>(ledger_info.borrow_mut_data_unchecked())? };
+
+    ledger.set_initialized();
+    ledger.bump = *bump;
+    ledger.points_program = *points_program_info.key();
+    ledger.user = *user_info.key();
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account},
+    state::points::{PointsProgram, POINTS_PROGRAM_SEED},
+};
+
+/// Initializes a new points program.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The admin of the points program, paying for the points
+///    program account.
+/// 1. `[writable]` The points program account.
+/// 2. `[]` The key used to derive the points program address.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_initialize_points_program(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let issuer = cursor.read_address()?;
+    cursor.finish()?;
+
+    let [admin_info, points_program_info, create_key_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the points program account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        points_program_info,
+        &[
+            POINTS_PROGRAM_SEED,
+            create_key_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(POINTS_PROGRAM_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(admin_info, points_program_info, PointsProgram::LEN, &seeds)?;
+
+    // Initializes the points program.
+
+    // SAFETY: single mutable borrow to `points_program_info` account data; the account
+    // was created above with the expected length.
+    let points_program = unsafe {
+        load_mut_unchecked::<PointsProgram>(points_program_info.borrow_mut_data_unchecked())?
+    };
+
+    points_program.set_initialized();
+    points_program.bump = bump;
+    points_program.admin = *admin_info.key();
+    points_program.issuer = *issuer;
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+//! Points ledger.
+//!
+//! A points program tracks a points balance for each user in its own derived
+//! ledger account. Only the issuer of the points program can award and deduct
+//! points, while anyone can create a ledger and query its balance.
+//!
+//! Instructions:
+//!
+//! - `0`: `InitializePointsProgram`
+//! - `1`: `SetIssuer`
+//! - `2`: `CreateLedger`
+//! - `3`: `Award`
+//! - `4`: `Deduct`
+//! - `5`: `Query`
+//! - `6`: `BatchAward`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{
+    error::TokenError,
+    state::{load, load_mut},
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::check_account_owner,
+    state::points::{PointsLedger, PointsProgram},
+};
+
+pub mod adjust;
+pub mod batch_award;
+pub mod create_ledger;
+pub mod initialize_points_program;
+pub mod query;
+pub mod set_issuer;
+
+pub use adjust::process_adjust;
+pub use batch_award::process_batch_award;
+pub use create_ledger::process_create_ledger;
+pub use initialize_points_program::process_initialize_points_program;
+pub use query::process_query;
+pub use set_issuer::process_set_issuer;
+
+/// Process a points instruction.
+#[inline(always)]
+pub fn process_points_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - InitializePointsProgram
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializePointsProgram");
+
+            process_initialize_points_program(accounts, instruction_data)
+        }
+        // 1 - SetIssuer
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SetIssuer");
+
+            process_set_issuer(accounts, instruction_data)
+        }
+        // 2 - CreateLedger
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateLedger");
+
+            process_create_ledger(accounts, instruction_data)
+        }
+        // 3 - Award
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Award");
+
+            process_adjust(accounts, instruction_data, true)
+        }
+        // 4 - Deduct
+        4 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Deduct");
+
+            process_adjust(accounts, instruction_data, false)
+        }
+        // 5 - Query
+        5 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Query");
+
+            process_query(accounts)
+        }
+        // 6 - BatchAward
+        6 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: BatchAward");
+
+            process_batch_award(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Checks that `issuer_info` is the issuer of the points program and signed the
+/// instruction.
+#[inline(always)]
+fn validate_issuer(points_program_info: &AccountInfo, issuer_info: &AccountInfo) -> ProgramResult {
+    check_account_owner(points_program_info)?;
+    // SAFETY: scoped immutable borrow to `points_program_info` account data and
+    // `load` validates that the points program is initialized.
+    let points_program =
+        unsafe { load::<PointsProgram>(points_program_info.borrow_data_unchecked())? };
+
+    if &points_program.issuer != issuer_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(issuer_info);
+
+    Ok(())
+}
+
+/// Adds `amount` points to the balance of a ledger of the points program.
+#[inline(always)]
+fn award(
+    points_program_info: &AccountInfo,
+    ledger_info: &AccountInfo,
+    amount: u64,
+) -> ProgramResult {
+    check_account_owner(ledger_info)?;
+    // SAFETY: single mutable borrow to `ledger_info` account data and
+    // `load_mut` validates that the ledger is initialized.
+    let ledger = unsafe { load_mut::<PointsLedger>(ledger_info.borrow_mut_data_unchecked())? };
+
+    if &ledger.points_program != points_program_info.key() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    let balance = ledger
+        .balance()
+        .checked_add(amount)
+        .ok_or(TokenError::Overflow)?;
+    ledger.set_balance(balance);
+
+    Ok(())
+}
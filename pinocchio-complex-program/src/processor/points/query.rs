@@ -0,0 +1,24 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::load;
+
+use crate::{processor::check_account_owner, return_data, state::points::PointsLedger};
+
+/// Returns the points balance (`u64`) of a user ledger as the payload of the
+/// return data envelope of the instruction.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The ledger account.
+#[inline(always)]
+pub fn process_query(accounts: &[AccountInfo]) -> ProgramResult {
+    let [ledger_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(ledger_info)?;
+    // SAFETY: scoped immutable borrow to `ledger_info` account data and
+    // `load` validates that the ledger is initialized.
+    let ledger = unsafe { load::<PointsLedger>(ledger_info.borrow_data_unchecked())? };
+
+    return_data::set(&ledger.balance().to_le_bytes())
+}
@@ -0,0 +1,40 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+use token_interface::{error::TokenError, state::load_mut};
+
+use crate::{processor::check_account_owner, state::points::PointsProgram};
+
+/// Sets the issuer of a points program.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The points program account.
+/// 1. `[signer]` The admin of the points program.
+#[inline(always)]
+pub fn process_set_issuer(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected Pubkey (32)
+    let issuer: Pubkey = instruction_data
+        .try_into()
+        .map_err(|_error| ProgramError::InvalidInstructionData)?;
+
+    let [points_program_info, admin_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(points_program_info)?;
+    // SAFETY: single mutable borrow to `points_program_info` account data and
+    // `load_mut` validates that the points program is initialized.
+    let points_program =
+        unsafe { load_mut::<PointsProgram>(points_program_info.borrow_mut_data_unchecked())? };
+
+    if &points_program.admin != admin_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(admin_info);
+
+    points_program.issuer = issuer;
+
+    Ok(())
+}
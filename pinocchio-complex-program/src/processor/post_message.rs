@@ -0,0 +1,92 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    ProgramResult,
+};
+
+use super::check_derived_address;
+use crate::bridge::wormhole::{self, PostMessageAccounts};
+
+use crate::parse::Cursor;
+
+/// Seed prefix of the emitter derived address.
+pub const EMITTER_SEED: &[u8] = b"emitter";
+
+/// Posts a cross-chain message with an arbitrary payload through the Wormhole core
+/// bridge.
+///
+/// The message is emitted by a derived address of `[EMITTER_SEED, authority]`, so
+/// receivers on other chains can attribute the message to the authority. The
+/// message fee set by the bridge is paid by the payer.
+///
+/// Accounts expected:
+///
+/// 0. `[signer]` The authority of the emitter.
+/// 1. `[]` The emitter, derived from the authority.
+/// 2. `[writable]` The bridge configuration.
+/// 3. `[writable, signer]` The account receiving the posted message.
+/// 4. `[writable]` The sequence tracker of the emitter.
+/// 5. `[writable, signer]` The payer of the message fee and accounts.
+/// 6. `[writable]` The bridge fee collector.
+/// 7. `[]` The `Clock` sysvar.
+/// 8. `[]` The `Rent` sysvar.
+/// 9. `[]` The system program.
+/// 10. `[]` The Wormhole core bridge program.
+#[inline(always)]
+pub fn process_post_message(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1) + u32 (4) + u8 (1) + [u8] (N)
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let nonce = cursor.read_u32()?;
+    let consistency_level = cursor.read_u8()?;
+    let payload = cursor.remaining();
+
+    if payload.len() > wormhole::MAX_PAYLOAD_LEN {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [authority_info, emitter_info, config_info, message_info, sequence_info, payer_info, fee_collector_info, clock_info, rent_info, system_program_info, wormhole_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_address!(
+        wormhole_program_info,
+        &wormhole::ID,
+        ProgramError::IncorrectProgramId
+    );
+
+    require_signer!(authority_info);
+
+    let bump_seed = [bump];
+    check_derived_address(
+        emitter_info,
+        &[EMITTER_SEED, authority_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(EMITTER_SEED),
+        Seed::from(authority_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    wormhole::post_message(
+        &PostMessageAccounts {
+            config: config_info,
+            message: message_info,
+            emitter: emitter_info,
+            sequence: sequence_info,
+            payer: payer_info,
+            fee_collector: fee_collector_info,
+            clock: clock_info,
+            rent: rent_info,
+            system_program: system_program_info,
+        },
+        nonce,
+        payload,
+        consistency_level,
+        &[Signer::from(&seeds)],
+    )
+}
@@ -0,0 +1,58 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    processor::{check_derived_address, create_derived_account},
+    state::profile::{Profile, PROFILE_SEED},
+};
+
+/// Creates a new profile with the current layout version.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The authority of the profile, paying for the profile
+///    account.
+/// 1. `[writable]` The profile account.
+/// 2. `[]` The system program.
+#[inline(always)]
+pub fn process_create_profile(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1)
+    let [bump] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [authority_info, profile_info, _system_program_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the profile account.
+
+    let bump_seed = [*bump];
+    check_derived_address(
+        profile_info,
+        &[PROFILE_SEED, authority_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(PROFILE_SEED),
+        Seed::from(authority_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(authority_info, profile_info, Profile::LEN, &seeds)?;
+
+    // Initializes the profile.
+
+    // SAFETY: single mutable borrow to `profile_info` account data; the account was
+    // created above with the expected length.
+    let profile =
+        unsafe { load_mut_unchecked::<Profile>(profile_info.borrow_mut_data_unchecked())? };
+
+    profile.set_initialized();
+    profile.bump = *bump;
+    profile.authority = *authority_info.key();
+
+    Ok(())
+}
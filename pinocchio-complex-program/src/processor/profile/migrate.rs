@@ -0,0 +1,76 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    error::ExtensionError,
+    processor::shared::migration::migrate_account,
+    state::{
+        migration::Migratable,
+        profile::{Profile, ProfileV1, PROFILE_VERSION},
+        AccountDiscriminator,
+    },
+};
+
+impl Migratable for Profile {
+    const DISCRIMINATOR: AccountDiscriminator = AccountDiscriminator::Profile;
+
+    const VERSION: u8 = PROFILE_VERSION;
+
+    #[inline(always)]
+    fn len(version: u8) -> Result<usize, ProgramError> {
+        match version {
+            1 => Ok(ProfileV1::LEN),
+            PROFILE_VERSION => Ok(Profile::LEN),
+            _ => Err(ExtensionError::InvalidVersion.into()),
+        }
+    }
+
+    #[inline(always)]
+    fn migrate(version: u8, data: &mut [u8]) -> ProgramResult {
+        match version {
+            // Widens the score to a `u64` and sets the slot of the last update to
+            // the current slot.
+            1 => {
+                // SAFETY: the data was reallocated to the length of the current
+                // layout and it starts with the version `1` layout.
+                let score = unsafe {
+                    load_mut_unchecked::<ProfileV1>(&mut data[..ProfileV1::LEN])?.score()
+                };
+                // SAFETY: as above.
+                let profile = unsafe { load_mut_unchecked::<Profile>(data)? };
+
+                profile.set_score(u64::from(score));
+                profile.set_last_update_slot(Clock::get()?.slot);
+
+                Ok(())
+            }
+            _ => Err(ExtensionError::InvalidVersion.into()),
+        }
+    }
+}
+
+/// Migrates a profile with an older layout to the current layout.
+///
+/// The account is reallocated to the current length, with the payer covering
+/// the additional rent, and its fields are transformed: the score is widened to a
+/// `u64` and the slot of the last update is set to the current slot. Migrations
+/// do not change the profile data otherwise, so any account can pay for them.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The profile account.
+/// 1. `[writable, signer]` The payer of the additional rent.
+/// 2. `[]` The system program.
+#[inline(always)]
+pub fn process_migrate(accounts: &[AccountInfo]) -> ProgramResult {
+    let [profile_info, payer_info, _system_program_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    migrate_account::<Profile>(profile_info, payer_info)
+}
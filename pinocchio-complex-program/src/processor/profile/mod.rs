@@ -0,0 +1,97 @@
+//! Versioned profile.
+//!
+//! A profile account records the version of its layout in its state header.
+//! Processors only accept accounts with the current layout version; accounts with
+//! an older layout must first be upgraded with the [`Migrate`] instruction, which
+//! reallocates the account and transforms its fields.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateProfile`
+//! - `1`: `UpdateScore`
+//! - `2`: `Migrate`
+//!
+//! [`Migrate`]: migrate::process_migrate
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use crate::{
+    processor::{check_account_owner, shared::migration::check_version},
+    state::profile::Profile,
+};
+
+pub mod create_profile;
+pub mod migrate;
+pub mod update_score;
+
+pub use create_profile::process_create_profile;
+pub use migrate::process_migrate;
+pub use update_score::process_update_score;
+
+/// Process a profile instruction.
+#[inline(always)]
+pub fn process_profile_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateProfile
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateProfile");
+
+            process_create_profile(accounts, instruction_data)
+        }
+        // 1 - UpdateScore
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: UpdateScore");
+
+            process_update_score(accounts, instruction_data)
+        }
+        // 2 - Migrate
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Migrate");
+
+            process_migrate(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Loads a profile with the current layout version, validating that
+/// `authority_info` is the profile authority and signed the instruction.
+///
+/// # Safety
+///
+/// The caller must ensure that there are no other borrows of `profile_info`
+/// account data while the returned reference is alive.
+#[inline(always)]
+unsafe fn load_profile_mut<'a>(
+    profile_info: &'a AccountInfo,
+    authority_info: &AccountInfo,
+) -> Result<&'a mut Profile, ProgramError> {
+    check_account_owner(profile_info)?;
+
+    let data = profile_info.borrow_mut_data_unchecked();
+
+    // The version is checked before loading the account, since the length of
+    // older layouts does not match the current one.
+    check_version::<Profile>(data)?;
+
+    let profile = load_mut::<Profile>(data)?;
+
+    if &profile.authority != authority_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(authority_info);
+
+    Ok(profile)
+}
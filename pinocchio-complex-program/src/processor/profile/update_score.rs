@@ -0,0 +1,41 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::error::TokenError;
+
+use super::load_profile_mut;
+
+use crate::parse::Cursor;
+
+/// Adds to the score of a profile.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The profile account.
+/// 1. `[signer]` The authority of the profile.
+#[inline(always)]
+pub fn process_update_score(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let points = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [profile_info, authority_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `profile_info` account data.
+    let profile = unsafe { load_profile_mut(profile_info, authority_info)? };
+
+    profile.set_score(
+        profile
+            .score()
+            .checked_add(points)
+            .ok_or(TokenError::Overflow)?,
+    );
+    profile.set_last_update_slot(Clock::get()?.slot);
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load_mut, load_mut_unchecked, RawType},
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, check_derived_address, create_derived_account},
+    state::raffle::{Raffle, RaffleTicket, RAFFLE_TICKET_SEED},
+    system,
+};
+
+/// Buys the next ticket of a raffle.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The raffle account.
+/// 1. `[writable]` The ticket account for the next ticket index.
+/// 2. `[writable, signer]` The buyer, paying for the ticket and its account.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_buy_ticket(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1)
+    let [bump] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [raffle_info, ticket_info, buyer_info, _system_program_info, _remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(raffle_info)?;
+    // SAFETY: single mutable borrow to `raffle_info` account data and
+    // `load_mut` validates that the raffle is initialized.
+    let raffle = unsafe { load_mut::<Raffle>(raffle_info.borrow_mut_data_unchecked())? };
+
+    if Clock::get()?.slot >= raffle.end_slot() {
+        return Err(ExtensionError::RaffleEnded.into());
+    }
+
+    let index = raffle.tickets_sold();
+
+    // Creates the ticket account.
+
+    let index_seed = index.to_le_bytes();
+    let bump_seed = [*bump];
+    check_derived_address(
+        ticket_info,
+        &[
+            RAFFLE_TICKET_SEED,
+            raffle_info.key().as_ref(),
+            &index_seed,
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(RAFFLE_TICKET_SEED),
+        Seed::from(raffle_info.key()),
+        Seed::from(&index_seed),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(buyer_info, ticket_info, RaffleTicket::LEN, &seeds)?;
+
+    // SAFETY: single mutable borrow to `ticket_info` account data; the account was
+    // created above with the expected length.
+    let ticket =
+        unsafe { load_mut_unchecked::<RaffleTicket>(ticket_info.borrow_mut_data_unchecked())? };
+
+    ticket.set_initialized();
+    ticket.bump = *bump;
+    ticket.raffle = *raffle_info.key();
+    ticket.owner = *buyer_info.key();
+    ticket.set_index(index);
+
+    // Pays for the ticket.
+
+    system::transfer(buyer_info, raffle_info, raffle.ticket_price(), &[])?;
+
+    raffle.set_tickets_sold(index.checked_add(1).ok_or(TokenError::Overflow)?);
+
+    Ok(())
+}
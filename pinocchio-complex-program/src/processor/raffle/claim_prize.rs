@@ -0,0 +1,48 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, close_extension_account},
+    state::raffle::{Raffle, RaffleTicket},
+};
+
+/// Claims the prize of a raffle with the winning ticket, closing the raffle and
+/// ticket accounts.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The raffle account.
+/// 1. `[writable]` The winning ticket account.
+/// 2. `[writable]` The ticket owner.
+#[inline(always)]
+pub fn process_claim_prize(accounts: &[AccountInfo]) -> ProgramResult {
+    let [raffle_info, ticket_info, owner_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(raffle_info)?;
+    // SAFETY: scoped immutable borrow to `raffle_info` account data and
+    // `load` validates that the raffle is initialized.
+    let raffle = unsafe { load::<Raffle>(raffle_info.borrow_data_unchecked())? };
+
+    if !raffle.is_drawn() {
+        return Err(ExtensionError::NotDrawn.into());
+    }
+
+    check_account_owner(ticket_info)?;
+    // SAFETY: scoped immutable borrow to `ticket_info` account data and
+    // `load` validates that the ticket is initialized.
+    let ticket = unsafe { load::<RaffleTicket>(ticket_info.borrow_data_unchecked())? };
+
+    if &ticket.raffle != raffle_info.key() || ticket.index() != raffle.winning_ticket() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if &ticket.owner != owner_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    close_extension_account(ticket_info, owner_info)?;
+    close_extension_account(raffle_info, owner_info)
+}
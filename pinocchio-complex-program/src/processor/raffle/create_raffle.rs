@@ -0,0 +1,76 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account},
+    state::raffle::{Raffle, RAFFLE_SEED},
+};
+
+/// Creates a new raffle.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the raffle account.
+/// 1. `[writable]` The raffle account.
+/// 2. `[]` The key used to derive the raffle address.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_create_raffle(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let ticket_price = cursor.read_u64()?;
+    let end_slot = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [payer_info, raffle_info, create_key_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if ticket_price == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if end_slot <= Clock::get()?.slot {
+        return Err(ExtensionError::RaffleEnded.into());
+    }
+
+    // Creates the raffle account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        raffle_info,
+        &[RAFFLE_SEED, create_key_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(RAFFLE_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(payer_info, raffle_info, Raffle::LEN, &seeds)?;
+
+    // Initializes the raffle.
+
+    // SAFETY: single mutable borrow to `raffle_info` account data; the account was
+    // created above with the expected length.
+    let raffle = unsafe { load_mut_unchecked::<Raffle>(raffle_info.borrow_mut_data_unchecked())? };
+
+    raffle.set_initialized();
+    raffle.bump = bump;
+    raffle.create_key = *create_key_info.key();
+    raffle.set_ticket_price(ticket_price);
+    raffle.set_end_slot(end_slot);
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::state::load_mut;
+
+use super::slot_hash_at_or_after;
+use crate::{error::ExtensionError, processor::check_account_owner, state::raffle::Raffle};
+
+/// Draws the winning ticket of a raffle from the hash of its end slot.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The raffle account.
+/// 1. `[]` The `SlotHashes` sysvar.
+#[inline(always)]
+pub fn process_draw_winner(accounts: &[AccountInfo]) -> ProgramResult {
+    let [raffle_info, slot_hashes_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(raffle_info)?;
+    // SAFETY: single mutable borrow to `raffle_info` account data and
+    // `load_mut` validates that the raffle is initialized.
+    let raffle = unsafe { load_mut::<Raffle>(raffle_info.borrow_mut_data_unchecked())? };
+
+    if raffle.is_drawn() {
+        return Err(ExtensionError::AlreadyDrawn.into());
+    }
+
+    // The end slot hash is only available once the end slot has passed.
+    if Clock::get()?.slot <= raffle.end_slot() {
+        return Err(ExtensionError::RaffleNotEnded.into());
+    }
+
+    if raffle.tickets_sold() == 0 {
+        return Err(ExtensionError::NoTicketsSold.into());
+    }
+
+    let hash = slot_hash_at_or_after(slot_hashes_info, raffle.end_slot())?;
+
+    // Uses the first 16 bytes of the hash to reduce the modulo bias.
+    let mut value = [0u8; 16];
+    value.copy_from_slice(&hash[..16]);
+    let value = u128::from_le_bytes(value);
+
+    raffle.set_winning_ticket((value % raffle.tickets_sold() as u128) as u64);
+    raffle.set_drawn();
+
+    Ok(())
+}
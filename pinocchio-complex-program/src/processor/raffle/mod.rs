@@ -0,0 +1,121 @@
+//! Raffle with randomness from the `SlotHashes` sysvar.
+//!
+//! Tickets are sold until the end slot of the raffle, each recorded in its own
+//! derived account. The winner is drawn from the hash of the first slot at or after
+//! the end slot, which is unknown while tickets are sold (commit) and is read from
+//! the `SlotHashes` sysvar once available (reveal).
+//!
+//! The sysvar only keeps the most recent slots. Once the end slot is older than its
+//! entries, the winner can no longer be drawn, and the ticket owners are refunded
+//! instead: drawing from a later slot would let the drawer pick the winner by
+//! choosing when to draw.
+//!
+//! Note that slot hashes can be influenced by the leader producing the slot, so this
+//! is not suitable for raffles where the prize is worth more than a skipped slot.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateRaffle`
+//! - `1`: `BuyTicket`
+//! - `2`: `DrawWinner`
+//! - `3`: `ClaimPrize`
+//! - `4`: `RefundTicket`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{error::ExtensionError, sysvar::slot_hashes::SlotHashes};
+
+pub mod buy_ticket;
+pub mod claim_prize;
+pub mod create_raffle;
+pub mod draw_winner;
+pub mod refund_ticket;
+
+pub use buy_ticket::process_buy_ticket;
+pub use claim_prize::process_claim_prize;
+pub use create_raffle::process_create_raffle;
+pub use draw_winner::process_draw_winner;
+pub use refund_ticket::process_refund_ticket;
+
+/// Process a raffle instruction.
+#[inline(always)]
+pub fn process_raffle_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateRaffle
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateRaffle");
+
+            process_create_raffle(accounts, instruction_data)
+        }
+        // 1 - BuyTicket
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: BuyTicket");
+
+            process_buy_ticket(accounts, instruction_data)
+        }
+        // 2 - DrawWinner
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: DrawWinner");
+
+            process_draw_winner(accounts)
+        }
+        // 3 - ClaimPrize
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ClaimPrize");
+
+            process_claim_prize(accounts)
+        }
+        // 4 - RefundTicket
+        4 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: RefundTicket");
+
+            process_refund_ticket(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Returns the hash of the first slot at or after `slot` in the `SlotHashes` sysvar.
+///
+/// Once `slot` is older than the entries kept by the sysvar, the first slot at or
+/// after it may no longer be one of the entries, so this fails with
+/// `SlotHashUnavailable` instead of using a later slot.
+#[inline(always)]
+fn slot_hash_at_or_after(
+    slot_hashes_info: &AccountInfo,
+    slot: u64,
+) -> Result<[u8; 32], ProgramError> {
+    if is_slot_hash_expired(slot_hashes_info, slot)? {
+        return Err(ExtensionError::SlotHashUnavailable.into());
+    }
+
+    // Entries are sorted by descending slot, so the last entry at or after `slot` is
+    // the oldest one.
+    SlotHashes::from_account_info(slot_hashes_info)?
+        .iter()
+        .take_while(|(entry_slot, _)| *entry_slot >= slot)
+        .last()
+        .map(|(_, hash)| *hash)
+        .ok_or(ExtensionError::SlotHashUnavailable.into())
+}
+
+/// Checks whether `slot` is older than the entries of the `SlotHashes` sysvar.
+#[inline(always)]
+fn is_slot_hash_expired(slot_hashes_info: &AccountInfo, slot: u64) -> Result<bool, ProgramError> {
+    Ok(SlotHashes::from_account_info(slot_hashes_info)?
+        .iter()
+        .last()
+        .is_some_and(|(oldest_slot, _)| oldest_slot > slot))
+}
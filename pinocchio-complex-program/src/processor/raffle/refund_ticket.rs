@@ -0,0 +1,82 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{error::TokenError, state::load};
+
+use super::is_slot_hash_expired;
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, close_extension_account},
+    state::raffle::{Raffle, RaffleTicket},
+};
+
+/// Refunds a ticket of a raffle that can no longer be drawn, closing the ticket
+/// account.
+///
+/// A raffle can no longer be drawn once its end slot is older than the entries of
+/// the `SlotHashes` sysvar.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The raffle account.
+/// 1. `[writable]` The ticket account.
+/// 2. `[writable]` The ticket owner.
+/// 3. `[]` The `SlotHashes` sysvar.
+#[inline(always)]
+pub fn process_refund_ticket(accounts: &[AccountInfo]) -> ProgramResult {
+    let [raffle_info, ticket_info, owner_info, slot_hashes_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(raffle_info)?;
+    // SAFETY: scoped immutable borrow to `raffle_info` account data and
+    // `load` validates that the raffle is initialized.
+    let raffle = unsafe { load::<Raffle>(raffle_info.borrow_data_unchecked())? };
+
+    if raffle.is_drawn() {
+        return Err(ExtensionError::AlreadyDrawn.into());
+    }
+
+    if Clock::get()?.slot <= raffle.end_slot() {
+        return Err(ExtensionError::RaffleNotEnded.into());
+    }
+
+    if !is_slot_hash_expired(slot_hashes_info, raffle.end_slot())? {
+        return Err(ExtensionError::DrawWindowOpen.into());
+    }
+
+    let ticket_price = raffle.ticket_price();
+
+    check_account_owner(ticket_info)?;
+    // SAFETY: scoped immutable borrow to `ticket_info` account data and
+    // `load` validates that the ticket is initialized.
+    let ticket = unsafe { load::<RaffleTicket>(ticket_info.borrow_data_unchecked())? };
+
+    if &ticket.raffle != raffle_info.key() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if &ticket.owner != owner_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    // Moves the ticket price back to the owner.
+
+    let owner_starting_lamports = owner_info.lamports();
+    // SAFETY: single mutable borrow to `raffle_info` and `owner_info` lamports; the
+    // raffle account is owned by the token program.
+    unsafe {
+        *raffle_info.borrow_mut_lamports_unchecked() = raffle_info
+            .lamports()
+            .checked_sub(ticket_price)
+            .ok_or(TokenError::InsufficientFunds)?;
+        *owner_info.borrow_mut_lamports_unchecked() = owner_starting_lamports
+            .checked_add(ticket_price)
+            .ok_or(TokenError::Overflow)?;
+    }
+
+    close_extension_account(ticket_info, owner_info)
+}
@@ -0,0 +1,43 @@
+//! Referral tracking.
+//!
+//! A referee registers its referrer once. Fee-charging instructions paying the
+//! protocol fee of a referee split the configured treasury share to the referrer
+//! token account when the referral accounts are supplied with the fee accounts.
+//!
+//! Referrers are not required to have any history with the program, so a fee
+//! payer can refer itself through a second key it controls. This is accepted: the
+//! referral share then acts as a fee discount of at most the treasury referral
+//! share, which the fee authority sets with this in mind. Any account history
+//! could be created by the same fee payer, so requiring one would not prevent it.
+//!
+//! Instructions:
+//!
+//! - `0`: `RegisterReferral`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod register_referral;
+
+pub use register_referral::process_register_referral;
+
+/// Process a referral instruction.
+#[inline(always)]
+pub fn process_referral_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - RegisterReferral
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: RegisterReferral");
+
+            process_register_referral(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
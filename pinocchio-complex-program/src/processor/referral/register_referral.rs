@@ -0,0 +1,71 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    processor::{check_derived_address, create_derived_account},
+    state::referral::{Referral, REFERRAL_SEED},
+};
+
+/// Binds a referee to its referrer.
+///
+/// The referrer only needs to be a different key than the referee; self-referral
+/// through another key is accepted (see the module documentation).
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The referee, paying for the referral account.
+/// 1. `[writable]` The referral account.
+/// 2. `[]` The referrer.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_register_referral(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected u8 (1)
+    let [bump] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [referee_info, referral_info, referrer_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if referee_info.key() == referrer_info.key() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Creates the referral account.
+
+    let bump_seed = [*bump];
+    check_derived_address(
+        referral_info,
+        &[REFERRAL_SEED, referee_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(REFERRAL_SEED),
+        Seed::from(referee_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(referee_info, referral_info, Referral::LEN, &seeds)?;
+
+    // Initializes the referral.
+
+    // SAFETY: single mutable borrow to `referral_info` account data; the account was
+    // created above with the expected length.
+    let referral =
+        unsafe { load_mut_unchecked::<Referral>(referral_info.borrow_mut_data_unchecked())? };
+
+    referral.set_initialized();
+    referral.bump = *bump;
+    referral.referee = *referee_info.key();
+    referral.referrer = *referrer_info.key();
+
+    Ok(())
+}
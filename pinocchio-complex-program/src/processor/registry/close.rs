@@ -0,0 +1,23 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use super::load_record_mut;
+use crate::processor::close_extension_account;
+
+/// Closes a name record, releasing the name and returning the rent of the record.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The name record account.
+/// 1. `[signer]` The owner of the name.
+/// 2. `[writable]` The destination account for the rent of the record.
+#[inline(always)]
+pub fn process_close(accounts: &[AccountInfo]) -> ProgramResult {
+    let [record_info, owner_info, destination_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: scoped mutable borrow to `record_info` account data.
+    unsafe { load_record_mut(record_info, owner_info)? };
+
+    close_extension_account(record_info, destination_info)
+}
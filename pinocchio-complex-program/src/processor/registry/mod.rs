@@ -0,0 +1,94 @@
+//! Name registry.
+//!
+//! Each name record maps a UTF-8 name to an address. The record address is derived
+//! from the SHA-256 hash of the name, so names of any supported length fit in a
+//! single seed and can be resolved off-chain by hashing the name.
+//!
+//! Instructions:
+//!
+//! - `0`: `Register`
+//! - `1`: `Update`
+//! - `2`: `TransferOwnership`
+//! - `3`: `Close`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use crate::{processor::check_account_owner, state::registry::NameRecord};
+
+pub mod close;
+pub mod register;
+pub mod transfer_ownership;
+pub mod update;
+
+pub use close::process_close;
+pub use register::process_register;
+pub use transfer_ownership::process_transfer_ownership;
+pub use update::process_update;
+
+/// Process a registry instruction.
+#[inline(always)]
+pub fn process_registry_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - Register
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Register");
+
+            process_register(accounts, instruction_data)
+        }
+        // 1 - Update
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Update");
+
+            process_update(accounts, instruction_data)
+        }
+        // 2 - TransferOwnership
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: TransferOwnership");
+
+            process_transfer_ownership(accounts)
+        }
+        // 3 - Close
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Close");
+
+            process_close(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Loads a name record, validating that `owner_info` is the record owner and
+/// signed the instruction.
+///
+/// # Safety
+///
+/// The caller must ensure that there are no other borrows of `record_info`
+/// account data while the returned reference is alive.
+#[inline(always)]
+unsafe fn load_record_mut<'a>(
+    record_info: &'a AccountInfo,
+    owner_info: &AccountInfo,
+) -> Result<&'a mut NameRecord, ProgramError> {
+    check_account_owner(record_info)?;
+    let record = load_mut::<NameRecord>(record_info.borrow_mut_data_unchecked())?;
+
+    if &record.owner != owner_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(owner_info);
+
+    Ok(record)
+}
@@ -0,0 +1,66 @@
+use core::str::from_utf8;
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    hash::sha256,
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account},
+    state::registry::{NameRecord, MAX_NAME_LEN, NAME_RECORD_SEED},
+};
+
+/// Registers a new name, creating its name record.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The owner of the name, paying for the name record
+///    account.
+/// 1. `[writable]` The name record account.
+/// 2. `[]` The system program.
+#[inline(always)]
+pub fn process_register(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1) + Pubkey (32) + name (1..=MAX_NAME_LEN)
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let target = cursor.read_address()?;
+    let name = cursor.remaining();
+
+    if name.is_empty() || name.len() > MAX_NAME_LEN || from_utf8(name).is_err() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [owner_info, record_info, _system_program_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the name record account.
+
+    let hash = sha256(&[name]);
+    let bump_seed = [bump];
+    check_derived_address(record_info, &[NAME_RECORD_SEED, &hash, &bump_seed])?;
+
+    let seeds = [
+        Seed::from(NAME_RECORD_SEED),
+        Seed::from(&hash),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(owner_info, record_info, NameRecord::LEN, &seeds)?;
+
+    // Initializes the name record.
+
+    // SAFETY: single mutable borrow to `record_info` account data; the account was
+    // created above with the expected length.
+    let record =
+        unsafe { load_mut_unchecked::<NameRecord>(record_info.borrow_mut_data_unchecked())? };
+
+    record.set_initialized();
+    record.bump = bump;
+    record.owner = *owner_info.key();
+    record.target = *target;
+    record.set_name(name);
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use super::load_record_mut;
+
+/// Transfers the ownership of a name to a new owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The name record account.
+/// 1. `[signer]` The owner of the name.
+/// 2. `[]` The new owner of the name.
+#[inline(always)]
+pub fn process_transfer_ownership(accounts: &[AccountInfo]) -> ProgramResult {
+    let [record_info, owner_info, new_owner_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `record_info` account data.
+    let record = unsafe { load_record_mut(record_info, owner_info)? };
+
+    record.owner = *new_owner_info.key();
+
+    Ok(())
+}
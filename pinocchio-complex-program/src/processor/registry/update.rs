@@ -0,0 +1,28 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use super::load_record_mut;
+
+/// Updates the address a name resolves to.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The name record account.
+/// 1. `[signer]` The owner of the name.
+#[inline(always)]
+pub fn process_update(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected Pubkey (32)
+    let target = instruction_data
+        .try_into()
+        .map_err(|_error| ProgramError::InvalidInstructionData)?;
+
+    let [record_info, owner_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `record_info` account data.
+    let record = unsafe { load_record_mut(record_info, owner_info)? };
+
+    record.target = target;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::load;
+
+use super::preceding_payment;
+use crate::{
+    error::ExtensionError,
+    math::{self, Rounding},
+    processor::{check_account_owner, shared::custody},
+    state::rewards::Rewards,
+};
+
+/// Claims the loyalty tokens rewarding the payment made by the preceding
+/// instruction.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The rewards account.
+/// 1. `[writable]` The mint of the loyalty tokens.
+/// 2. `[writable]` The destination token account.
+/// 3. `[]` The `Instructions` sysvar.
+#[inline(always)]
+pub fn process_claim_reward(accounts: &[AccountInfo]) -> ProgramResult {
+    let [rewards_info, mint_info, destination_account_info, instructions_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(rewards_info)?;
+    // SAFETY: scoped immutable borrow to `rewards_info` account data and
+    // `load` validates that the rewards account is initialized.
+    let rewards = unsafe { load::<Rewards>(rewards_info.borrow_data_unchecked())? };
+
+    if mint_info.key() != &rewards.mint {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    let payment = preceding_payment(instructions_info, &rewards.payment_account)?;
+
+    // `reward_bps <= MAX_REWARD_BPS`, so the reward is at most `payment`.
+    let reward = math::bps(payment, rewards.reward_bps(), Rounding::Down)?;
+
+    // Mints the loyalty tokens.
+
+    custody::mint_to(
+        mint_info,
+        destination_account_info,
+        rewards_info.key(),
+        reward,
+    )
+}
@@ -0,0 +1,83 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load, load_mut_unchecked, mint::Mint, RawType},
+};
+
+use super::MAX_REWARD_BPS;
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account},
+    state::rewards::{Rewards, REWARDS_SEED},
+};
+
+/// Creates the purchase rewards of a loyalty token mint.
+///
+/// The mint authority of the mint must already be set to the rewards derived
+/// address.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The merchant, paying for the rewards account.
+/// 1. `[writable]` The rewards account.
+/// 2. `[]` The mint of the loyalty tokens.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_create_rewards(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let payment_account = cursor.read_address()?;
+    let reward_bps = cursor.read_u16()?;
+    cursor.finish()?;
+
+    if reward_bps > MAX_REWARD_BPS {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [merchant_info, rewards_info, mint_info, _system_program_info, _remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: scoped immutable borrow to `mint_info` account data and
+    // `load` validates that the mint is initialized.
+    let mint = unsafe { load::<Mint>(mint_info.borrow_data_unchecked())? };
+
+    if mint.mint_authority() != Some(rewards_info.key()) {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    // Creates the rewards account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        rewards_info,
+        &[REWARDS_SEED, mint_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(REWARDS_SEED),
+        Seed::from(mint_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(merchant_info, rewards_info, Rewards::LEN, &seeds)?;
+
+    // Initializes the rewards.
+
+    // SAFETY: single mutable borrow to `rewards_info` account data; the account was
+    // created above with the expected length.
+    let rewards =
+        unsafe { load_mut_unchecked::<Rewards>(rewards_info.borrow_mut_data_unchecked())? };
+
+    rewards.set_initialized();
+    rewards.bump = bump;
+    rewards.set_reward_bps(reward_bps);
+    rewards.merchant = *merchant_info.key();
+    rewards.payment_account = *payment_account;
+    rewards.mint = *mint_info.key();
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! Loyalty rewards for purchases verified with instruction introspection.
+//!
+//! `ClaimReward` mints loyalty tokens only when the `Instructions` sysvar shows a
+//! qualifying payment to the merchant as the instruction immediately before it:
+//! either a system transfer to the merchant payment account or a token `Transfer`
+//! or `TransferChecked` to it. Requiring the payment right before the claim ensures
+//! each payment is rewarded once.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateRewards`
+//! - `1`: `ClaimReward`
+
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+use token_interface::program::ID as TOKEN_PROGRAM_ID;
+
+use crate::{error::ExtensionError, system, sysvar::instructions::Instructions};
+
+pub mod claim_reward;
+pub mod create_rewards;
+
+pub use claim_reward::process_claim_reward;
+pub use create_rewards::process_create_rewards;
+
+/// Maximum reward, in basis points, which is 100% of the payment amount.
+const MAX_REWARD_BPS: u16 = 10_000;
+
+/// Process a rewards instruction.
+#[inline(always)]
+pub fn process_rewards_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateRewards
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateRewards");
+
+            process_create_rewards(accounts, instruction_data)
+        }
+        // 1 - ClaimReward
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ClaimReward");
+
+            process_claim_reward(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Returns the amount of the payment to `payment_account` made by the instruction
+/// preceding the current one in the `Instructions` sysvar.
+#[inline(always)]
+fn preceding_payment(
+    instructions_info: &AccountInfo,
+    payment_account: &Pubkey,
+) -> Result<u64, ProgramError> {
+    let instruction = Instructions::from_account_info(instructions_info)?
+        .load_relative_instruction(-1)?
+        .ok_or(ExtensionError::PaymentNotFound)?;
+
+    // Position of the destination account and the amount of the payment.
+    let (destination, amount) = match instruction.data {
+        // System `Transfer`: u32 (4) + u64 (8)
+        [2, 0, 0, 0, amount @ ..] if instruction.program_id == &system::ID && amount.len() == 8 => {
+            (1, amount)
+        }
+        // Token `Transfer`: u8 (1) + u64 (8)
+        [3, amount @ ..] if instruction.program_id == &TOKEN_PROGRAM_ID && amount.len() == 8 => {
+            (1, amount)
+        }
+        // Token `TransferChecked`: u8 (1) + u64 (8) + u8 (1)
+        [12, amount @ .., _decimals]
+            if instruction.program_id == &TOKEN_PROGRAM_ID && amount.len() == 8 =>
+        {
+            (2, amount)
+        }
+        _ => return Err(ExtensionError::PaymentNotFound.into()),
+    };
+
+    if instruction.account_key(destination) != Some(payment_account) {
+        return Err(ExtensionError::PaymentNotFound.into());
+    }
+
+    Ok(u64::from_le_bytes(
+        amount
+            .try_into()
+            .map_err(|_error| ExtensionError::PaymentNotFound)?,
+    ))
+}
@@ -0,0 +1,105 @@
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load, load_mut_unchecked, mint::Mint, RawType},
+};
+
+use super::MAX_ROYALTY_BPS;
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account},
+    state::royalty::{Royalty, MAX_CREATORS, ROYALTY_SEED},
+};
+
+/// Initializes the royalty config of an item mint.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the royalty account.
+/// 1. `[writable]` The royalty account.
+/// 2. `[]` The item mint.
+/// 3. `[signer]` The mint authority of the item mint.
+/// 4. `[]` The system program.
+/// 5. ..5+N `[]` The N creator accounts.
+#[inline(always)]
+pub fn process_initialize_royalty(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected u8 (1) + u16 (2) per creator
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+
+    let [payer_info, royalty_info, mint_info, mint_authority_info, _system_program_info, creators @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if creators.is_empty() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    if creators.len() > MAX_CREATORS {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if cursor.remaining().len() != creators.len() * size_of::<u16>() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // SAFETY: scoped immutable borrow to `mint_info` account data and
+    // `load` validates that the mint is initialized.
+    let mint = unsafe { load::<Mint>(mint_info.borrow_data_unchecked())? };
+
+    if mint.mint_authority() != Some(mint_authority_info.key()) {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(mint_authority_info);
+
+    // Creates the royalty account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        royalty_info,
+        &[ROYALTY_SEED, mint_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(ROYALTY_SEED),
+        Seed::from(mint_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(payer_info, royalty_info, Royalty::LEN, &seeds)?;
+
+    // Initializes the royalty config.
+
+    // SAFETY: single mutable borrow to `royalty_info` account data; the account was
+    // created above with the expected length.
+    let royalty =
+        unsafe { load_mut_unchecked::<Royalty>(royalty_info.borrow_mut_data_unchecked())? };
+
+    let mut total_bps = 0u16;
+
+    for (index, creator_info) in creators.iter().enumerate() {
+        let creator_bps = cursor.read_u16()?;
+
+        total_bps = total_bps
+            .checked_add(creator_bps)
+            .filter(|total_bps| *total_bps <= MAX_ROYALTY_BPS)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        royalty.creators[index] = *creator_info.key();
+        royalty.set_royalty_bps(index, creator_bps);
+    }
+
+    royalty.set_initialized();
+    royalty.bump = bump;
+    royalty.n = creators.len() as u8;
+    royalty.mint = *mint_info.key();
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! Royalty distribution on sale settlement.
+//!
+//! The mint authority of an item mint configures the creators receiving a royalty
+//! on each sale of the item. Marketplaces then `SettleSale` to split the sale
+//! payment between the creators and the seller in a single instruction.
+//!
+//! Instructions:
+//!
+//! - `0`: `InitializeRoyalty`
+//! - `1`: `SettleSale`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod initialize_royalty;
+pub mod settle_sale;
+
+pub use initialize_royalty::process_initialize_royalty;
+pub use settle_sale::process_settle_sale;
+
+/// Maximum royalty, in basis points, which is 100% of the sale amount.
+const MAX_ROYALTY_BPS: u16 = 10_000;
+
+/// Process a royalty instruction.
+#[inline(always)]
+pub fn process_royalty_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - InitializeRoyalty
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializeRoyalty");
+
+            process_initialize_royalty(accounts, instruction_data)
+        }
+        // 1 - SettleSale
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SettleSale");
+
+            process_settle_sale(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
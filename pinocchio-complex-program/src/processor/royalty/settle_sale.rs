@@ -0,0 +1,89 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load},
+};
+
+use crate::{
+    math::{self, Rounding},
+    parse::Cursor,
+    processor::{check_account_owner, shared},
+    state::royalty::Royalty,
+};
+
+/// Settles a sale of an item, paying the royalty of each creator and the remaining
+/// amount to the seller.
+///
+/// Royalties are rounded down, so any rounding remainder is paid to the seller.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The royalty account of the item mint.
+/// 1. `[writable]` The buyer payment token account.
+/// 2. `[writable]` The seller payment token account.
+/// 3. `[signer]` The buyer.
+/// 4. ..4+N `[writable]` The N creator payment token accounts, in the royalty order.
+#[inline(always)]
+pub fn process_settle_sale(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [royalty_info, source_account_info, seller_account_info, buyer_info, creator_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(royalty_info)?;
+    // SAFETY: scoped immutable borrow to `royalty_info` account data and
+    // `load` validates that the royalty config is initialized.
+    let royalty = unsafe { load::<Royalty>(royalty_info.borrow_data_unchecked())? };
+
+    let creator_accounts = creator_accounts
+        .get(..royalty.n as usize)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    // Pays the creator royalties.
+
+    let mut remaining_amount = amount;
+
+    for (index, creator_account_info) in creator_accounts.iter().enumerate() {
+        // SAFETY: scoped immutable borrow to `creator_account_info` account data and
+        // `load` validates that the account is initialized.
+        let creator_account =
+            unsafe { load::<Account>(creator_account_info.borrow_data_unchecked())? };
+
+        if creator_account.owner != royalty.creators[index] {
+            return Err(TokenError::OwnerMismatch.into());
+        }
+
+        // `royalty_bps <= MAX_ROYALTY_BPS`, so the royalty is at most `amount`.
+        let creator_amount = math::bps(amount, royalty.royalty_bps(index), Rounding::Down)?;
+
+        shared::transfer::process_transfer(
+            &[
+                source_account_info.clone(),
+                creator_account_info.clone(),
+                buyer_info.clone(),
+            ],
+            creator_amount,
+            None,
+        )?;
+
+        // The total royalty is at most `MAX_ROYALTY_BPS`, so this cannot underflow.
+        remaining_amount -= creator_amount;
+    }
+
+    // Pays the seller.
+
+    shared::transfer::process_transfer(
+        &[
+            source_account_info.clone(),
+            seller_account_info.clone(),
+            buyer_info.clone(),
+        ],
+        remaining_amount,
+        None,
+    )
+}
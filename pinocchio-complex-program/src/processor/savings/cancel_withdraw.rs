@@ -0,0 +1,29 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::error::TokenError;
+
+use super::load_savings_mut;
+
+/// Cancels the pending withdrawal of a savings account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The savings account.
+/// 1. `[signer]` The owner of the savings.
+#[inline(always)]
+pub fn process_cancel_withdraw(accounts: &[AccountInfo]) -> ProgramResult {
+    let [savings_info, owner_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `savings_info` account data.
+    let savings = unsafe { load_savings_mut(savings_info, owner_info)? };
+
+    if savings.pending_amount() == 0 {
+        return Err(TokenError::InvalidState.into());
+    }
+
+    savings.set_pending_amount(0);
+    savings.set_available_at(0);
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, shared},
+    state::savings::{Savings, SAVINGS_SEED},
+};
+
+/// Creates a new savings account.
+///
+/// The token vault must be an uninitialized account owned by the token program;
+/// it is initialized with the savings derived address as its owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The owner of the savings, paying for the savings
+///    account.
+/// 1. `[writable]` The savings account.
+/// 2. `[]` The mint of the savings.
+/// 3. `[writable]` The token vault.
+/// 4. `[]` The system program.
+#[inline(always)]
+pub fn process_create_savings(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let withdrawal_delay = cursor.read_i64()?;
+    cursor.finish()?;
+
+    if withdrawal_delay < 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [owner_info, savings_info, mint_info, token_vault_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the savings account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        savings_info,
+        &[
+            SAVINGS_SEED,
+            owner_info.key().as_ref(),
+            mint_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(SAVINGS_SEED),
+        Seed::from(owner_info.key()),
+        Seed::from(mint_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(owner_info, savings_info, Savings::LEN, &seeds)?;
+
+    // Initializes the token vault under the savings authority.
+
+    shared::initialize_account::process_initialize_account(
+        &[token_vault_info.clone(), mint_info.clone()],
+        Some(savings_info.key()),
+        false,
+    )?;
+
+    // Initializes the savings account.
+
+    // SAFETY: single mutable borrow to `savings_info` account data; the account was
+    // created above with the expected length.
+    let savings =
+        unsafe { load_mut_unchecked::<Savings>(savings_info.borrow_mut_data_unchecked())? };
+
+    savings.set_initialized();
+    savings.bump = bump;
+    savings.owner = *owner_info.key();
+    savings.mint = *mint_info.key();
+    savings.token_vault = *token_vault_info.key();
+    savings.set_withdrawal_delay(withdrawal_delay);
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::load;
+
+use super::parse_amount;
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, shared},
+    state::savings::Savings,
+};
+
+/// Deposits tokens into a savings account.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The savings account.
+/// 1. `[writable]` The token vault.
+/// 2. `[writable]` The source token account.
+/// 3. `[signer]` The source token account owner.
+#[inline(always)]
+pub fn process_deposit(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let amount = parse_amount(instruction_data)?;
+
+    let [savings_info, token_vault_info, source_account_info, authority_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(savings_info)?;
+    // SAFETY: scoped immutable borrow to `savings_info` account data and
+    // `load` validates that the savings account is initialized.
+    let savings = unsafe { load::<Savings>(savings_info.borrow_data_unchecked())? };
+
+    if token_vault_info.key() != &savings.token_vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // Moves the tokens into the token vault.
+
+    shared::transfer::process_transfer(
+        &[
+            source_account_info.clone(),
+            token_vault_info.clone(),
+            authority_info.clone(),
+        ],
+        amount,
+        None,
+    )
+}
@@ -0,0 +1,56 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::error::TokenError;
+
+use super::load_savings_mut;
+use crate::{error::ExtensionError, processor::shared::custody};
+
+/// Executes the pending withdrawal of a savings account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The savings account.
+/// 1. `[signer]` The owner of the savings.
+/// 2. `[writable]` The token vault.
+/// 3. `[writable]` The destination token account.
+#[inline(always)]
+pub fn process_execute_withdraw(accounts: &[AccountInfo]) -> ProgramResult {
+    let [savings_info, owner_info, token_vault_info, destination_account_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `savings_info` account data.
+    let savings = unsafe { load_savings_mut(savings_info, owner_info)? };
+
+    if token_vault_info.key() != &savings.token_vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    let amount = savings.pending_amount();
+
+    if amount == 0 {
+        return Err(TokenError::InvalidState.into());
+    }
+
+    if Clock::get()?.unix_timestamp < savings.available_at() {
+        return Err(ExtensionError::WithdrawalNotReady.into());
+    }
+
+    savings.set_pending_amount(0);
+    savings.set_available_at(0);
+
+    // Moves the tokens out of the token vault.
+
+    custody::transfer(
+        token_vault_info,
+        destination_account_info,
+        savings_info.key(),
+        amount,
+    )
+}
@@ -0,0 +1,119 @@
+//! Savings account with a withdrawal delay.
+//!
+//! Deposits into a savings account are instant, while withdrawals happen in two
+//! phases: the owner requests a withdrawal and can only execute it once the
+//! withdrawal delay has elapsed. A pending withdrawal can be cancelled at any time,
+//! giving the owner a window to react to an unexpected request.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateSavings`
+//! - `1`: `Deposit`
+//! - `2`: `RequestWithdraw`
+//! - `3`: `ExecuteWithdraw`
+//! - `4`: `CancelWithdraw`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use crate::{parse::Cursor, processor::check_account_owner, state::savings::Savings};
+
+pub mod cancel_withdraw;
+pub mod create_savings;
+pub mod deposit;
+pub mod execute_withdraw;
+pub mod request_withdraw;
+
+pub use cancel_withdraw::process_cancel_withdraw;
+pub use create_savings::process_create_savings;
+pub use deposit::process_deposit;
+pub use execute_withdraw::process_execute_withdraw;
+pub use request_withdraw::process_request_withdraw;
+
+/// Process a savings instruction.
+#[inline(always)]
+pub fn process_savings_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateSavings
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateSavings");
+
+            process_create_savings(accounts, instruction_data)
+        }
+        // 1 - Deposit
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Deposit");
+
+            process_deposit(accounts, instruction_data)
+        }
+        // 2 - RequestWithdraw
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: RequestWithdraw");
+
+            process_request_withdraw(accounts, instruction_data)
+        }
+        // 3 - ExecuteWithdraw
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ExecuteWithdraw");
+
+            process_execute_withdraw(accounts)
+        }
+        // 4 - CancelWithdraw
+        4 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CancelWithdraw");
+
+            process_cancel_withdraw(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Parses the amount from the instruction data.
+#[inline(always)]
+fn parse_amount(instruction_data: &[u8]) -> Result<u64, ProgramError> {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    cursor.finish()?;
+
+    if amount == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok(amount)
+}
+
+/// Loads a savings account, validating that `owner_info` is the savings owner and
+/// signed the instruction.
+///
+/// # Safety
+///
+/// The caller must ensure that there are no other borrows of `savings_info`
+/// account data while the returned reference is alive.
+#[inline(always)]
+unsafe fn load_savings_mut<'a>(
+    savings_info: &'a AccountInfo,
+    owner_info: &AccountInfo,
+) -> Result<&'a mut Savings, ProgramError> {
+    check_account_owner(savings_info)?;
+    let savings = load_mut::<Savings>(savings_info.borrow_mut_data_unchecked())?;
+
+    if &savings.owner != owner_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(owner_info);
+
+    Ok(savings)
+}
@@ -0,0 +1,43 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::error::TokenError;
+
+use super::{load_savings_mut, parse_amount};
+
+/// Requests a withdrawal from a savings account, executable once the withdrawal
+/// delay has elapsed.
+///
+/// A new request replaces any pending withdrawal, restarting the delay.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The savings account.
+/// 1. `[signer]` The owner of the savings.
+#[inline(always)]
+pub fn process_request_withdraw(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let amount = parse_amount(instruction_data)?;
+
+    let [savings_info, owner_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `savings_info` account data.
+    let savings = unsafe { load_savings_mut(savings_info, owner_info)? };
+
+    let available_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(savings.withdrawal_delay())
+        .ok_or(TokenError::Overflow)?;
+
+    savings.set_pending_amount(amount);
+    savings.set_available_at(available_at);
+
+    Ok(())
+}
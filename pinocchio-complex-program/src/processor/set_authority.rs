@@ -1,5 +1,3 @@
-use core::marker::PhantomData;
-
 use pinocchio::{
     account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
 };
@@ -12,13 +10,14 @@ use token_interface::{
 use super::validate_owner;
 
 #[inline(always)]
-pub fn process_set_authority(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+pub fn process_set_authority(
+    accounts: &[AccountInfo],
+    authority_type: u8,
+    new_authority: Option<&Pubkey>,
+) -> ProgramResult {
     // Validates the instruction data.
 
-    let args = SetAuthority::try_from_bytes(instruction_data)?;
-
-    let authority_type = args.authority_type()?;
-    let new_authority = args.new_authority();
+    let authority_type = AuthorityType::from(authority_type)?;
 
     // Validates the accounts.
 
@@ -110,44 +109,3 @@ pub fn process_set_authority(accounts: &[AccountInfo], instruction_data: &[u8])
 
     Ok(())
 }
-
-struct SetAuthority<'a> {
-    raw: *const u8,
-
-    _data: PhantomData<&'a [u8]>,
-}
-
-impl SetAuthority<'_> {
-    #[inline(always)]
-    pub fn try_from_bytes(bytes: &[u8]) -> Result<SetAuthority, ProgramError> {
-        // The minimum expected size of the instruction data.
-        // - authority_type (1 byte)
-        // - option + new_authority (1 byte + 32 bytes)
-        if bytes.len() < 2 || (bytes[1] == 1 && bytes.len() < 34) {
-            return Err(ProgramError::InvalidInstructionData);
-        }
-
-        Ok(SetAuthority {
-            raw: bytes.as_ptr(),
-            _data: PhantomData,
-        })
-    }
-
-    #[inline(always)]
-    pub fn authority_type(&self) -> Result<AuthorityType, ProgramError> {
-        // SAFETY: `bytes` length is validated in `try_from_bytes`.
-        unsafe { AuthorityType::from(*self.raw) }
-    }
-
-    #[inline(always)]
-    pub fn new_authority(&self) -> Option<&Pubkey> {
-        // SAFETY: `bytes` length is validated in `try_from_bytes`.
-        unsafe {
-            if *self.raw.add(1) == 0 {
-                Option::None
-            } else {
-                Option::Some(&*(self.raw.add(2) as *const Pubkey))
-            }
-        }
-    }
-}
@@ -4,7 +4,7 @@ use token_interface::{
     state::{account::Account, load, load_mut, mint::Mint},
 };
 
-use crate::processor::validate_owner;
+use crate::processor::{check_decimals, validate_owner};
 
 #[inline(always)]
 pub fn process_approve(
@@ -63,9 +63,7 @@ pub fn process_approve(
         // `load` validates that the mint is initialized.
         let mint = unsafe { load::<Mint>(mint_info.borrow_data_unchecked())? };
 
-        if expected_decimals != mint.decimals {
-            return Err(TokenError::MintDecimalsMismatch.into());
-        }
+        check_decimals(expected_decimals, mint)?;
     }
 
     validate_owner(&source_account.owner, owner_info, remaining)?;
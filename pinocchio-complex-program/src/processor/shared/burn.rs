@@ -4,7 +4,7 @@ use token_interface::{
     state::{account::Account, load_mut, mint::Mint},
 };
 
-use crate::processor::{check_account_owner, validate_owner};
+use crate::processor::{check_account_owner, check_amount, check_decimals, validate_owner};
 
 #[inline(always)]
 pub fn process_burn(
@@ -12,6 +12,8 @@ pub fn process_burn(
     amount: u64,
     expected_decimals: Option<u8>,
 ) -> ProgramResult {
+    check_amount(amount)?;
+
     let [source_account_info, mint_info, authority_info, remaining @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -44,9 +46,7 @@ pub fn process_burn(
     }
 
     if let Some(expected_decimals) = expected_decimals {
-        if expected_decimals != mint.decimals {
-            return Err(TokenError::MintDecimalsMismatch.into());
-        }
+        check_decimals(expected_decimals, mint)?;
     }
 
     if !source_account.is_owned_by_system_program_or_incinerator() {
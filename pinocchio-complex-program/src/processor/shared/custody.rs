@@ -0,0 +1,331 @@
+//! Token operations over accounts in program custody.
+//!
+//! Accounts in custody have a program derived address as their owner, delegate,
+//! mint authority or freeze authority, which cannot sign the instruction. Callers
+//! are responsible for validating that the authority is the expected derived
+//! address before using these functions.
+//!
+//! The token accounts and mints are required to be owned by the program, so an
+//! account of another program fails with `IncorrectProgramId` before any of them
+//! is modified, instead of failing after the instruction.
+
+use pinocchio::{account_info::AccountInfo, pubkey::Pubkey, ProgramResult};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, account_state::AccountState, load, load_mut, mint::Mint},
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, close_extension_account},
+};
+
+/// Transfers `amount` tokens from a token account owned by `authority`.
+#[inline(always)]
+pub fn transfer(
+    source_account_info: &AccountInfo,
+    destination_account_info: &AccountInfo,
+    authority: &Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    move_tokens(
+        source_account_info,
+        destination_account_info,
+        amount,
+        |source_account| {
+            if &source_account.owner != authority {
+                return Err(TokenError::OwnerMismatch.into());
+            }
+
+            Ok(())
+        },
+    )
+}
+
+/// Transfers `amount` tokens from a token account that approved `delegate`,
+/// spending the delegated amount.
+#[inline(always)]
+pub fn delegated_transfer(
+    source_account_info: &AccountInfo,
+    destination_account_info: &AccountInfo,
+    delegate: &Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    move_tokens(
+        source_account_info,
+        destination_account_info,
+        amount,
+        |source_account| {
+            if source_account.delegate() != Some(delegate) {
+                return Err(TokenError::OwnerMismatch.into());
+            }
+
+            let delegated_amount = source_account
+                .delegated_amount()
+                .checked_sub(amount)
+                .ok_or(TokenError::InsufficientFunds)?;
+            source_account.set_delegated_amount(delegated_amount);
+
+            if delegated_amount == 0 {
+                source_account.clear_delegate();
+            }
+
+            Ok(())
+        },
+    )
+}
+
+/// Closes an empty token account owned by `authority`, moving its lamports to the
+/// destination account.
+#[inline(always)]
+pub fn close_account(
+    source_account_info: &AccountInfo,
+    destination_account_info: &AccountInfo,
+    authority: &Pubkey,
+) -> ProgramResult {
+    if source_account_info.key() == destination_account_info.key() {
+        return Err(ExtensionError::DuplicateAccount.into());
+    }
+
+    check_account_owner(source_account_info)?;
+
+    // SAFETY: scoped immutable borrow to `source_account_info` account data and
+    // `load` validates that the account is initialized.
+    let source_account = unsafe { load::<Account>(source_account_info.borrow_data_unchecked())? };
+
+    if !source_account.is_native() && source_account.amount() != 0 {
+        return Err(TokenError::NonNativeHasBalance.into());
+    }
+
+    if source_account
+        .close_authority()
+        .unwrap_or(&source_account.owner)
+        != authority
+    {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    close_extension_account(source_account_info, destination_account_info)
+}
+
+/// Mints `amount` tokens from a mint whose mint authority is `authority`.
+#[inline(always)]
+pub fn mint_to(
+    mint_info: &AccountInfo,
+    destination_account_info: &AccountInfo,
+    authority: &Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    check_account_owner(mint_info)?;
+    check_account_owner(destination_account_info)?;
+
+    // SAFETY: single mutable borrow to `destination_account_info` account data and
+    // `load_mut` validates that the account is initialized.
+    let destination_account =
+        unsafe { load_mut::<Account>(destination_account_info.borrow_mut_data_unchecked())? };
+
+    if destination_account.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    if destination_account.is_native() {
+        return Err(TokenError::NativeNotSupported.into());
+    }
+
+    if mint_info.key() != &destination_account.mint {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    // SAFETY: single mutable borrow to `mint_info` account data and
+    // `load_mut` validates that the mint is initialized.
+    let mint = unsafe { load_mut::<Mint>(mint_info.borrow_mut_data_unchecked())? };
+
+    match mint.mint_authority() {
+        Some(mint_authority) if mint_authority == authority => (),
+        Some(_) => return Err(TokenError::OwnerMismatch.into()),
+        None => return Err(TokenError::FixedSupply.into()),
+    }
+
+    let destination_amount = destination_account
+        .amount()
+        .checked_add(amount)
+        .ok_or(TokenError::Overflow)?;
+    destination_account.set_amount(destination_amount);
+
+    let mint_supply = mint
+        .supply()
+        .checked_add(amount)
+        .ok_or(TokenError::Overflow)?;
+    mint.set_supply(mint_supply);
+
+    Ok(())
+}
+
+/// Burns `amount` tokens from a token account that approved `delegate`, spending
+/// the delegated amount.
+#[inline(always)]
+pub fn delegated_burn(
+    source_account_info: &AccountInfo,
+    mint_info: &AccountInfo,
+    delegate: &Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    check_account_owner(source_account_info)?;
+    check_account_owner(mint_info)?;
+
+    // SAFETY: single mutable borrow to `source_account_info` account data and
+    // `load_mut` validates that the account is initialized.
+    let source_account =
+        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+
+    if source_account.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    if mint_info.key() != &source_account.mint {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    if source_account.delegate() != Some(delegate) {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    let delegated_amount = source_account
+        .delegated_amount()
+        .checked_sub(amount)
+        .ok_or(TokenError::InsufficientFunds)?;
+    source_account.set_delegated_amount(delegated_amount);
+
+    if delegated_amount == 0 {
+        source_account.clear_delegate();
+    }
+
+    let remaining_amount = source_account
+        .amount()
+        .checked_sub(amount)
+        .ok_or(TokenError::InsufficientFunds)?;
+    source_account.set_amount(remaining_amount);
+
+    // SAFETY: single mutable borrow to `mint_info` account data and
+    // `load_mut` validates that the mint is initialized.
+    let mint = unsafe { load_mut::<Mint>(mint_info.borrow_mut_data_unchecked())? };
+
+    let mint_supply = mint
+        .supply()
+        .checked_sub(amount)
+        .ok_or(TokenError::Overflow)?;
+    mint.set_supply(mint_supply);
+
+    Ok(())
+}
+
+/// Freezes or thaws a token account of a mint whose freeze authority is
+/// `authority`.
+#[inline(always)]
+pub fn set_frozen(
+    account_info: &AccountInfo,
+    mint_info: &AccountInfo,
+    authority: &Pubkey,
+    freeze: bool,
+) -> ProgramResult {
+    check_account_owner(account_info)?;
+    check_account_owner(mint_info)?;
+
+    // SAFETY: single mutable borrow to `account_info` account data and
+    // `load_mut` validates that the account is initialized.
+    let account = unsafe { load_mut::<Account>(account_info.borrow_mut_data_unchecked())? };
+
+    if freeze == account.is_frozen() {
+        return Err(TokenError::InvalidState.into());
+    }
+
+    if mint_info.key() != &account.mint {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    // SAFETY: scoped immutable borrow to `mint_info` account data and
+    // `load` validates that the mint is initialized.
+    let mint = unsafe { load::<Mint>(mint_info.borrow_data_unchecked())? };
+
+    match mint.freeze_authority() {
+        Some(freeze_authority) if freeze_authority == authority => (),
+        Some(_) => return Err(TokenError::OwnerMismatch.into()),
+        None => return Err(TokenError::MintCannotFreeze.into()),
+    }
+
+    account.state = if freeze {
+        AccountState::Frozen
+    } else {
+        AccountState::Initialized
+    };
+
+    Ok(())
+}
+
+/// Moves `amount` tokens between token accounts once `validate_authority` accepts
+/// the source account.
+#[inline(always)]
+fn move_tokens(
+    source_account_info: &AccountInfo,
+    destination_account_info: &AccountInfo,
+    amount: u64,
+    validate_authority: impl FnOnce(&mut Account) -> ProgramResult,
+) -> ProgramResult {
+    if source_account_info.key() == destination_account_info.key() {
+        return Err(ExtensionError::DuplicateAccount.into());
+    }
+
+    check_account_owner(source_account_info)?;
+    check_account_owner(destination_account_info)?;
+
+    // SAFETY: single mutable borrow to `source_account_info` account data and
+    // `load_mut` validates that the account is initialized.
+    let source_account =
+        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+    // SAFETY: single mutable borrow to `destination_account_info` account data (the
+    // account is different than `source_account_info`) and `load_mut` validates that
+    // the account is initialized.
+    let destination_account =
+        unsafe { load_mut::<Account>(destination_account_info.borrow_mut_data_unchecked())? };
+
+    validate_authority(source_account)?;
+
+    if source_account.is_frozen() || destination_account.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    if source_account.mint != destination_account.mint {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    // Moves the tokens.
+
+    let remaining_amount = source_account
+        .amount()
+        .checked_sub(amount)
+        .ok_or(TokenError::InsufficientFunds)?;
+    source_account.set_amount(remaining_amount);
+
+    let destination_amount = destination_account
+        .amount()
+        .checked_add(amount)
+        .ok_or(TokenError::Overflow)?;
+    destination_account.set_amount(destination_amount);
+
+    if source_account.is_native() {
+        // SAFETY: single mutable borrow to `source_account_info` lamports.
+        let source_lamports = unsafe { source_account_info.borrow_mut_lamports_unchecked() };
+        *source_lamports = source_lamports
+            .checked_sub(amount)
+            .ok_or(TokenError::Overflow)?;
+
+        // SAFETY: single mutable borrow to `destination_account_info` lamports.
+        let destination_lamports =
+            unsafe { destination_account_info.borrow_mut_lamports_unchecked() };
+        *destination_lamports = destination_lamports
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+    }
+
+    Ok(())
+}
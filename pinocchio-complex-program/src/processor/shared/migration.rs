@@ -0,0 +1,139 @@
+//! Account layout migrations.
+//!
+//! Processors of [`Migratable`] account types reject accounts with older layouts
+//! through [`check_version`]. These accounts are upgraded by [`migrate_account`],
+//! which walks the layout versions sequentially up to the current one.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::check_account_owner,
+    state::migration::{Migratable, StateHeader},
+    system,
+};
+
+/// Checks that the account data has the current layout of `T`.
+#[inline(always)]
+pub fn check_version<T: Migratable>(data: &[u8]) -> ProgramResult {
+    match StateHeader::from_bytes(data) {
+        Some(header) if header.discriminator == T::DISCRIMINATOR as u8 => {
+            if header.version != T::VERSION {
+                return Err(ExtensionError::InvalidVersion.into());
+            }
+            Ok(())
+        }
+        _ => Err(ExtensionError::AccountTypeMismatch.into()),
+    }
+}
+
+/// Migrates an account of type `T` to its current layout.
+///
+/// The account is reallocated to the length of each intermediate layout, with
+/// the payer topping up its lamports to remain rent exempt.
+#[inline(always)]
+pub fn migrate_account<T: Migratable>(
+    account_info: &AccountInfo,
+    payer_info: &AccountInfo,
+) -> ProgramResult {
+    check_account_owner(account_info)?;
+
+    // SAFETY: scoped immutable borrow to `account_info` account data.
+    let mut version = match StateHeader::from_bytes(unsafe { account_info.borrow_data_unchecked() })
+    {
+        Some(header) if header.discriminator == T::DISCRIMINATOR as u8 => header.version,
+        _ => return Err(ExtensionError::AccountTypeMismatch.into()),
+    };
+
+    if version == 0 || version >= T::VERSION {
+        return Err(ExtensionError::InvalidVersion.into());
+    }
+
+    if account_info.data_len() != T::len(version)? {
+        return Err(ExtensionError::AccountTypeMismatch.into());
+    }
+
+    let rent = Rent::get()?;
+
+    while version < T::VERSION {
+        let current_len = T::len(version)?;
+        let next_len = T::len(version + 1)?;
+
+        // Grows the account before transforming the data.
+
+        if next_len > current_len {
+            let required_lamports = rent
+                .minimum_balance(next_len)
+                .saturating_sub(account_info.lamports());
+
+            if required_lamports > 0 {
+                system::transfer(payer_info, account_info, required_lamports, &[])?;
+            }
+
+            account_info.realloc(next_len, true)?;
+        }
+
+        // SAFETY: single mutable borrow to `account_info` account data.
+        let data = unsafe { account_info.borrow_mut_data_unchecked() };
+
+        T::migrate(version, data)?;
+
+        version += 1;
+        // SAFETY: the account data is at least as long as the header.
+        unsafe { StateHeader::from_bytes_mut(data).unwrap_unchecked() }.version = version;
+
+        // Shrinks the account after transforming the data.
+
+        if next_len < current_len {
+            account_info.realloc(next_len, false)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{
+        profile::{Profile, PROFILE_VERSION},
+        AccountDiscriminator,
+    };
+
+    #[test]
+    fn accepts_current_version() {
+        let data = [AccountDiscriminator::Profile as u8, PROFILE_VERSION];
+
+        assert_eq!(check_version::<Profile>(&data), Ok(()));
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        for version in [0, PROFILE_VERSION - 1, PROFILE_VERSION + 1] {
+            let data = [AccountDiscriminator::Profile as u8, version];
+
+            assert_eq!(
+                check_version::<Profile>(&data),
+                Err(ExtensionError::InvalidVersion.into())
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_other_account_types() {
+        let data = [AccountDiscriminator::Profile as u8 + 1, PROFILE_VERSION];
+
+        assert_eq!(
+            check_version::<Profile>(&data),
+            Err(ExtensionError::AccountTypeMismatch.into())
+        );
+        assert_eq!(
+            check_version::<Profile>(&data[..1]),
+            Err(ExtensionError::AccountTypeMismatch.into())
+        );
+    }
+}
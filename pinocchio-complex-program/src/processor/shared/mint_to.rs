@@ -4,7 +4,7 @@ use token_interface::{
     state::{account::Account, load_mut, mint::Mint},
 };
 
-use crate::processor::{check_account_owner, validate_owner};
+use crate::processor::{check_account_owner, check_amount, check_decimals, validate_owner};
 
 #[inline(always)]
 pub fn process_mint_to(
@@ -12,6 +12,8 @@ pub fn process_mint_to(
     amount: u64,
     expected_decimals: Option<u8>,
 ) -> ProgramResult {
+    check_amount(amount)?;
+
     let [mint_info, destination_account_info, owner_info, remaining @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -40,9 +42,7 @@ pub fn process_mint_to(
     let mint = unsafe { load_mut::<Mint>(mint_info.borrow_mut_data_unchecked())? };
 
     if let Some(expected_decimals) = expected_decimals {
-        if expected_decimals != mint.decimals {
-            return Err(TokenError::MintDecimalsMismatch.into());
-        }
+        check_decimals(expected_decimals, mint)?;
     }
 
     match mint.mint_authority() {
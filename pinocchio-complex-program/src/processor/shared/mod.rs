@@ -5,8 +5,11 @@
 
 pub mod approve;
 pub mod burn;
+pub mod custody;
 pub mod initialize_account;
 pub mod initialize_multisig;
+pub mod migration;
 pub mod mint_to;
+pub mod rate_limit;
 pub mod toggle_account_state;
 pub mod transfer;
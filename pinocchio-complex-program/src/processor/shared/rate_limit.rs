@@ -0,0 +1,87 @@
+//! Token bucket rate limiting.
+//!
+//! A rate limit account limits the operations over a subject account, such as the
+//! amount of tokens dripped by a faucet. Processors create it with [`initialize`]
+//! and spend units with [`consume`], which fails once the bucket is exhausted.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::state::{load_mut, load_mut_unchecked, RawType};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, check_derived_address, create_derived_account},
+    state::rate_limit::{RateLimit, RATE_LIMIT_SEED},
+};
+
+/// Creates a full rate limit bucket for `subject_info`.
+#[inline(always)]
+pub fn initialize(
+    payer_info: &AccountInfo,
+    rate_limit_info: &AccountInfo,
+    subject_info: &AccountInfo,
+    capacity: u64,
+    refill_rate: u64,
+    bump: u8,
+) -> ProgramResult {
+    let bump_seed = [bump];
+    check_derived_address(
+        rate_limit_info,
+        &[RATE_LIMIT_SEED, subject_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(RATE_LIMIT_SEED),
+        Seed::from(subject_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(payer_info, rate_limit_info, RateLimit::LEN, &seeds)?;
+
+    // SAFETY: single mutable borrow to `rate_limit_info` account data; the account
+    // was created above with the expected length.
+    let rate_limit =
+        unsafe { load_mut_unchecked::<RateLimit>(rate_limit_info.borrow_mut_data_unchecked())? };
+
+    rate_limit.set_initialized();
+    rate_limit.bump = bump;
+    rate_limit.subject = *subject_info.key();
+    rate_limit.set_capacity(capacity);
+    rate_limit.set_refill_rate(refill_rate);
+    rate_limit.set_available(capacity);
+    rate_limit.set_last_refill_slot(Clock::get()?.slot);
+
+    Ok(())
+}
+
+/// Consumes `amount` units from the rate limit bucket of `subject_info`.
+#[inline(always)]
+pub fn consume(
+    rate_limit_info: &AccountInfo,
+    subject_info: &AccountInfo,
+    amount: u64,
+) -> ProgramResult {
+    check_account_owner(rate_limit_info)?;
+    // SAFETY: single mutable borrow to `rate_limit_info` account data and
+    // `load_mut` validates that the rate limit is initialized.
+    let rate_limit = unsafe { load_mut::<RateLimit>(rate_limit_info.borrow_mut_data_unchecked())? };
+
+    if &rate_limit.subject != subject_info.key() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    rate_limit.refill(Clock::get()?.slot);
+
+    let available = rate_limit
+        .available()
+        .checked_sub(amount)
+        .ok_or(ExtensionError::RateLimitExceeded)?;
+    rate_limit.set_available(available);
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use token_interface::{
     state::{account::Account, load, load_mut, load_mut_unchecked, mint::Mint},
 };
 
-use crate::processor::{check_account_owner, validate_owner};
+use crate::processor::{check_account_owner, check_amount, check_decimals, validate_owner};
 
 #[inline(always)]
 pub fn process_transfer(
@@ -12,6 +12,8 @@ pub fn process_transfer(
     amount: u64,
     expected_decimals: Option<u8>,
 ) -> ProgramResult {
+    check_amount(amount)?;
+
     // Accounts expected depend on whether we have the mint `decimals` or not; when we have the
     // mint `decimals`, we expect the mint account to be present.
 
@@ -106,9 +108,7 @@ pub fn process_transfer(
         // `load` validates that the mint is initialized.
         let mint = unsafe { load::<Mint>(mint_info.borrow_data_unchecked())? };
 
-        if decimals != mint.decimals {
-            return Err(TokenError::MintDecimalsMismatch.into());
-        }
+        check_decimals(decimals, mint)?;
     }
 
     // Validates the authority (delegate or owner).
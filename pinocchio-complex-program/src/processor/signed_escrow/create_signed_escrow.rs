@@ -0,0 +1,97 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, shared},
+    state::signed_escrow::{SignedEscrow, SIGNED_ESCROW_SEED},
+};
+
+/// Creates a new signed escrow, funding its token vault.
+///
+/// The token vault must be an uninitialized account owned by the token program;
+/// it is initialized with the escrow derived address as its owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The depositor of the escrow, paying for the escrow
+///    account.
+/// 1. `[writable]` The escrow account.
+/// 2. `[]` The key used to derive the escrow address.
+/// 3. `[writable]` The token vault.
+/// 4. `[]` The mint of the escrow funds.
+/// 5. `[writable]` The depositor token account funding the escrow.
+/// 6. `[]` The system program.
+#[inline(always)]
+pub fn process_create_signed_escrow(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let amount = cursor.read_u64()?;
+    let signer = cursor.read_address()?;
+    cursor.finish()?;
+
+    let [depositor_info, escrow_info, create_key_info, token_vault_info, mint_info, source_account_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the escrow account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        escrow_info,
+        &[
+            SIGNED_ESCROW_SEED,
+            create_key_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(SIGNED_ESCROW_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(depositor_info, escrow_info, SignedEscrow::LEN, &seeds)?;
+
+    // Initializes the token vault under the escrow authority and funds it.
+
+    shared::initialize_account::process_initialize_account(
+        &[token_vault_info.clone(), mint_info.clone()],
+        Some(escrow_info.key()),
+        false,
+    )?;
+
+    shared::transfer::process_transfer(
+        &[
+            source_account_info.clone(),
+            token_vault_info.clone(),
+            depositor_info.clone(),
+        ],
+        amount,
+        None,
+    )?;
+
+    // Initializes the escrow.
+
+    // SAFETY: single mutable borrow to `escrow_info` account data; the account was
+    // created above with the expected length.
+    let escrow =
+        unsafe { load_mut_unchecked::<SignedEscrow>(escrow_info.borrow_mut_data_unchecked())? };
+
+    escrow.set_initialized();
+    escrow.bump = bump;
+    escrow.depositor = *depositor_info.key();
+    escrow.create_key = *create_key_info.key();
+    escrow.token_vault = *token_vault_info.key();
+    escrow.signer = *signer;
+
+    Ok(())
+}
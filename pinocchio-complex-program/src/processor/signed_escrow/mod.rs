@@ -0,0 +1,49 @@
+//! Escrows released by an Ed25519 signature.
+//!
+//! The depositor funds a token vault and designates the Ed25519 public key of a
+//! signer, which may be an off-chain key with no account of its own. The funds
+//! are released to a token account once the signer signs the escrow address
+//! followed by the address of that token account; the signature is verified by
+//! an Ed25519 program instruction immediately preceding the release instruction.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateSignedEscrow`
+//! - `1`: `Release`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod create_signed_escrow;
+pub mod release;
+
+pub use create_signed_escrow::process_create_signed_escrow;
+pub use release::process_release;
+
+/// Process a signed escrow instruction.
+#[inline(always)]
+pub fn process_signed_escrow_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateSignedEscrow
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateSignedEscrow");
+
+            process_create_signed_escrow(accounts, instruction_data)
+        }
+        // 1 - Release
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Release");
+
+            process_release(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
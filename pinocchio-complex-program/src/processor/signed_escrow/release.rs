@@ -0,0 +1,98 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::PUBKEY_BYTES, ProgramResult,
+};
+use token_interface::state::{account::Account, load};
+
+use crate::{
+    ed25519,
+    error::ExtensionError,
+    processor::{check_account_owner, close_extension_account, shared::custody},
+    state::signed_escrow::SignedEscrow,
+    sysvar::instructions::Instructions,
+};
+
+/// Releases the escrow funds to the destination account.
+///
+/// The instruction immediately preceding it in the transaction must be an Ed25519
+/// program instruction verifying the signature of the escrow signer over the
+/// escrow address followed by the destination account address.
+///
+/// The token vault and escrow accounts are closed, returning their lamports to
+/// the depositor.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The escrow account.
+/// 1. `[writable]` The token vault.
+/// 2. `[writable]` The token account receiving the escrow funds.
+/// 3. `[writable]` The depositor of the escrow.
+/// 4. `[]` The `Instructions` sysvar.
+/// 5. `[]` (optional) The SPL Noop program, emitting a release event when the
+///    `events` feature is enabled.
+#[inline(always)]
+pub fn process_release(accounts: &[AccountInfo]) -> ProgramResult {
+    let [escrow_info, token_vault_info, destination_account_info, depositor_info, instructions_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(escrow_info)?;
+    // SAFETY: scoped immutable borrow to `escrow_info` account data and
+    // `load` validates that the escrow is initialized.
+    let escrow = unsafe { load::<SignedEscrow>(escrow_info.borrow_data_unchecked())? };
+
+    if token_vault_info.key() != &escrow.token_vault || depositor_info.key() != &escrow.depositor {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // Checks that the signer authorized the release to the destination account.
+
+    let mut message = [0u8; PUBKEY_BYTES * 2];
+    message[..PUBKEY_BYTES].copy_from_slice(escrow_info.key());
+    message[PUBKEY_BYTES..].copy_from_slice(destination_account_info.key());
+
+    let instructions = Instructions::from_account_info(instructions_info)?;
+
+    let verified = instructions
+        .load_relative_instruction(-1)?
+        .is_some_and(|instruction| {
+            ed25519::is_verification_of(&instruction, &escrow.signer, &message)
+        });
+
+    if !verified {
+        return Err(ExtensionError::SignatureNotVerified.into());
+    }
+
+    // Moves the escrow funds to the destination account.
+
+    let amount = {
+        // SAFETY: scoped immutable borrow to `token_vault_info` account data and
+        // `load` validates that the account is initialized.
+        let token_vault = unsafe { load::<Account>(token_vault_info.borrow_data_unchecked())? };
+        token_vault.amount()
+    };
+
+    custody::transfer(
+        token_vault_info,
+        destination_account_info,
+        escrow_info.key(),
+        amount,
+    )?;
+
+    #[cfg(feature = "events")]
+    crate::events::emit(
+        accounts,
+        &crate::events::EscrowReleaseEvent {
+            escrow: escrow_info.key(),
+            destination: destination_account_info.key(),
+            amount,
+        },
+    )?;
+
+    // Closes the token vault and escrow accounts.
+
+    custody::close_account(token_vault_info, depositor_info, escrow_info.key())?;
+
+    close_extension_account(escrow_info, depositor_info)
+}
@@ -0,0 +1,47 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+use token_interface::error::TokenError;
+
+use super::{approve, load_recovery_mut};
+use crate::error::ExtensionError;
+
+/// Approves the pending recovery, rotating the authority once the number of
+/// approvals reaches the threshold.
+///
+/// The instruction data holds the proposed authority being approved, so an
+/// approval cannot apply to a proposal that replaced it.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The recovery account.
+/// 1. `[signer]` One of the guardians.
+#[inline(always)]
+pub fn process_approve_recovery(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected Pubkey (32)
+    let proposed_authority: Pubkey = instruction_data
+        .try_into()
+        .map_err(|_error| ProgramError::InvalidInstructionData)?;
+
+    let [recovery_info, guardian_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `recovery_info` account data.
+    let (recovery, position) = unsafe { load_recovery_mut(recovery_info, guardian_info)? };
+
+    if recovery.proposed_authority == Pubkey::default() {
+        return Err(TokenError::InvalidState.into());
+    }
+
+    if recovery.proposed_authority != proposed_authority {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    approve(recovery, position);
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use crate::{processor::check_account_owner, state::social_recovery::Recovery};
+
+/// Cancels the pending recovery.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The recovery account.
+/// 1. `[signer]` The current authority.
+#[inline(always)]
+pub fn process_cancel_recovery(accounts: &[AccountInfo]) -> ProgramResult {
+    let [recovery_info, authority_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(recovery_info)?;
+    // SAFETY: single mutable borrow to `recovery_info` account data and
+    // `load_mut` validates that the recovery account is initialized.
+    let recovery = unsafe { load_mut::<Recovery>(recovery_info.borrow_mut_data_unchecked())? };
+
+    if &recovery.authority != authority_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(authority_info);
+
+    recovery.clear_proposal();
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load_mut_unchecked, RawType},
+};
+
+use crate::{
+    processor::{check_derived_address, create_derived_account},
+    state::social_recovery::{Recovery, MAX_GUARDIANS, RECOVERY_SEED},
+};
+
+/// Creates a new recovery account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The authority, paying for the recovery account.
+/// 1. `[writable]` The recovery account.
+/// 2. `[]` The key used to derive the recovery address.
+/// 3. `[]` The system program.
+/// 4. ..4+N `[]` The N guardian accounts.
+#[inline(always)]
+pub fn process_create_recovery(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1) + u8 (1)
+    let [bump, threshold] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [authority_info, recovery_info, create_key_info, _system_program_info, guardians @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if guardians.is_empty() || guardians.len() > MAX_GUARDIANS {
+        return Err(TokenError::InvalidNumberOfProvidedSigners.into());
+    }
+    if *threshold == 0 || *threshold as usize > guardians.len() {
+        return Err(TokenError::InvalidNumberOfRequiredSigners.into());
+    }
+
+    // Creates the recovery account.
+
+    let bump_seed = [*bump];
+    check_derived_address(
+        recovery_info,
+        &[RECOVERY_SEED, create_key_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(RECOVERY_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(authority_info, recovery_info, Recovery::LEN, &seeds)?;
+
+    // Initializes the recovery account.
+
+    // SAFETY: single mutable borrow to `recovery_info` account data; the account was
+    // created above with the expected length.
+    let recovery =
+        unsafe { load_mut_unchecked::<Recovery>(recovery_info.borrow_mut_data_unchecked())? };
+
+    recovery.set_initialized();
+    recovery.bump = *bump;
+    recovery.threshold = *threshold;
+    recovery.guardians_len = guardians.len() as u8;
+    recovery.create_key = *create_key_info.key();
+    recovery.authority = *authority_info.key();
+
+    for (i, guardian_info) in guardians.iter().enumerate() {
+        recovery.guardians[i] = *guardian_info.key();
+    }
+
+    Ok(())
+}
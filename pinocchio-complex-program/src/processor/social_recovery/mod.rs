@@ -0,0 +1,109 @@
+//! Social recovery of an authority.
+//!
+//! A recovery account stores an authority key together with a list of guardians.
+//! Any guardian can propose a new authority and, once the number of guardians
+//! approving the proposal reaches the threshold, the stored authority is rotated
+//! to the proposed one. The current authority can cancel a pending proposal.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateRecovery`
+//! - `1`: `ProposeRecovery`
+//! - `2`: `ApproveRecovery`
+//! - `3`: `CancelRecovery`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use crate::{processor::check_account_owner, state::social_recovery::Recovery};
+
+pub mod approve_recovery;
+pub mod cancel_recovery;
+pub mod create_recovery;
+pub mod propose_recovery;
+
+pub use approve_recovery::process_approve_recovery;
+pub use cancel_recovery::process_cancel_recovery;
+pub use create_recovery::process_create_recovery;
+pub use propose_recovery::process_propose_recovery;
+
+/// Process a social recovery instruction.
+#[inline(always)]
+pub fn process_social_recovery_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateRecovery
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateRecovery");
+
+            process_create_recovery(accounts, instruction_data)
+        }
+        // 1 - ProposeRecovery
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ProposeRecovery");
+
+            process_propose_recovery(accounts, instruction_data)
+        }
+        // 2 - ApproveRecovery
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ApproveRecovery");
+
+            process_approve_recovery(accounts, instruction_data)
+        }
+        // 3 - CancelRecovery
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CancelRecovery");
+
+            process_cancel_recovery(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Loads a recovery account, validating that `guardian_info` is one of its
+/// guardians and signed the instruction.
+///
+/// Returns the recovery account and the position of the guardian.
+///
+/// # Safety
+///
+/// The caller must ensure that there are no other borrows of `recovery_info`
+/// account data while the returned reference is alive.
+#[inline(always)]
+unsafe fn load_recovery_mut<'a>(
+    recovery_info: &'a AccountInfo,
+    guardian_info: &AccountInfo,
+) -> Result<(&'a mut Recovery, usize), ProgramError> {
+    check_account_owner(recovery_info)?;
+    let recovery = load_mut::<Recovery>(recovery_info.borrow_mut_data_unchecked())?;
+
+    let position = recovery
+        .guardian_position(guardian_info.key())
+        .ok_or(TokenError::OwnerMismatch)?;
+
+    require_signer!(guardian_info);
+
+    Ok((recovery, position))
+}
+
+/// Records the approval of the guardian at `position`, rotating the authority when
+/// the threshold is reached.
+#[inline(always)]
+fn approve(recovery: &mut Recovery, position: usize) {
+    recovery.approve(position);
+
+    if recovery.approvals() >= recovery.threshold {
+        recovery.authority = recovery.proposed_authority;
+        recovery.clear_proposal();
+    }
+}
@@ -0,0 +1,42 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use super::{approve, load_recovery_mut};
+
+/// Proposes a new authority, counting as the approval of the proposing guardian.
+///
+/// A new proposal replaces any pending one, discarding its approvals.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The recovery account.
+/// 1. `[signer]` One of the guardians.
+#[inline(always)]
+pub fn process_propose_recovery(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected Pubkey (32)
+    let proposed_authority: Pubkey = instruction_data
+        .try_into()
+        .map_err(|_error| ProgramError::InvalidInstructionData)?;
+
+    if proposed_authority == Pubkey::default() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [recovery_info, guardian_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `recovery_info` account data.
+    let (recovery, position) = unsafe { load_recovery_mut(recovery_info, guardian_info)? };
+
+    recovery.clear_proposal();
+    recovery.proposed_authority = proposed_authority;
+
+    approve(recovery, position);
+
+    Ok(())
+}
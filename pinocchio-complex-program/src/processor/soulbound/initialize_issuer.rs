@@ -0,0 +1,79 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load, load_mut_unchecked, mint::Mint, RawType},
+};
+
+use crate::{
+    processor::{check_derived_address, create_derived_account},
+    state::soulbound::{SoulboundIssuer, SOULBOUND_ISSUER_SEED},
+};
+
+/// Initializes a new issuer of soulbound credentials.
+///
+/// The mint and freeze authorities of the mint must already be set to the issuer
+/// derived address.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The issuer authority, paying for the issuer account.
+/// 1. `[writable]` The issuer account.
+/// 2. `[]` The mint of the credentials.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_initialize_issuer(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected u8 (1)
+    let [bump] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [authority_info, issuer_info, mint_info, _system_program_info, _remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: scoped immutable borrow to `mint_info` account data and
+    // `load` validates that the mint is initialized.
+    let mint = unsafe { load::<Mint>(mint_info.borrow_data_unchecked())? };
+
+    if mint.mint_authority() != Some(issuer_info.key())
+        || mint.freeze_authority() != Some(issuer_info.key())
+    {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    // Creates the issuer account.
+
+    let bump_seed = [*bump];
+    check_derived_address(
+        issuer_info,
+        &[SOULBOUND_ISSUER_SEED, mint_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(SOULBOUND_ISSUER_SEED),
+        Seed::from(mint_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(authority_info, issuer_info, SoulboundIssuer::LEN, &seeds)?;
+
+    // Initializes the issuer.
+
+    // SAFETY: single mutable borrow to `issuer_info` account data; the account was
+    // created above with the expected length.
+    let issuer =
+        unsafe { load_mut_unchecked::<SoulboundIssuer>(issuer_info.borrow_mut_data_unchecked())? };
+
+    issuer.set_initialized();
+    issuer.bump = *bump;
+    issuer.authority = *authority_info.key();
+    issuer.mint = *mint_info.key();
+
+    Ok(())
+}
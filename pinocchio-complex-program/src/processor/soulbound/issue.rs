@@ -0,0 +1,50 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::{account::Account, load_mut};
+
+use super::validate_issuer;
+use crate::processor::shared::{self, custody};
+
+/// Issues a soulbound credential to a recipient.
+///
+/// The credential account must be an uninitialized account owned by the token
+/// program; it is initialized with the recipient as its owner and the issuer as
+/// its delegate, receives the credential and is frozen.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The issuer account.
+/// 1. `[writable]` The mint of the credentials.
+/// 2. `[writable]` The credential account.
+/// 3. `[]` The recipient.
+/// 4. `[signer]` The issuer authority.
+#[inline(always)]
+pub fn process_issue(accounts: &[AccountInfo]) -> ProgramResult {
+    let [issuer_info, mint_info, credential_info, recipient_info, authority_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    validate_issuer(issuer_info, mint_info, authority_info)?;
+
+    // Initializes the credential account, delegating the credential to the issuer.
+
+    shared::initialize_account::process_initialize_account(
+        &[credential_info.clone(), mint_info.clone()],
+        Some(recipient_info.key()),
+        false,
+    )?;
+
+    // SAFETY: single mutable borrow to `credential_info` account data and
+    // `load_mut` validates that the account is initialized.
+    let credential = unsafe { load_mut::<Account>(credential_info.borrow_mut_data_unchecked())? };
+
+    credential.set_delegate(issuer_info.key());
+    credential.set_delegated_amount(1);
+
+    // Mints the credential and freezes the account.
+
+    custody::mint_to(mint_info, credential_info, issuer_info.key(), 1)?;
+
+    custody::set_frozen(credential_info, mint_info, issuer_info.key(), true)
+}
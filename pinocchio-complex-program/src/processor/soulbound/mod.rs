@@ -0,0 +1,90 @@
+//! Soulbound credentials.
+//!
+//! The issuer mints a credential to a new token account of the recipient and
+//! freezes it, so the credential cannot be transferred. The issuer is also set as
+//! the delegate of the credential account; since a frozen account cannot revoke
+//! its delegate, the issuer can later revoke the credential by thawing and
+//! burning it.
+//!
+//! Instructions:
+//!
+//! - `0`: `InitializeIssuer`
+//! - `1`: `Issue`
+//! - `2`: `Revoke`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load};
+
+use crate::{
+    error::ExtensionError, processor::check_account_owner, state::soulbound::SoulboundIssuer,
+};
+
+pub mod initialize_issuer;
+pub mod issue;
+pub mod revoke;
+
+pub use initialize_issuer::process_initialize_issuer;
+pub use issue::process_issue;
+pub use revoke::process_revoke;
+
+/// Process a soulbound instruction.
+#[inline(always)]
+pub fn process_soulbound_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - InitializeIssuer
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializeIssuer");
+
+            process_initialize_issuer(accounts, instruction_data)
+        }
+        // 1 - Issue
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Issue");
+
+            process_issue(accounts)
+        }
+        // 2 - Revoke
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Revoke");
+
+            process_revoke(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Checks that `authority_info` is the issuer authority and signed the
+/// instruction, and that `mint_info` is the credential mint.
+#[inline(always)]
+fn validate_issuer(
+    issuer_info: &AccountInfo,
+    mint_info: &AccountInfo,
+    authority_info: &AccountInfo,
+) -> ProgramResult {
+    check_account_owner(issuer_info)?;
+    // SAFETY: scoped immutable borrow to `issuer_info` account data and
+    // `load` validates that the issuer is initialized.
+    let issuer = unsafe { load::<SoulboundIssuer>(issuer_info.borrow_data_unchecked())? };
+
+    if mint_info.key() != &issuer.mint {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if &issuer.authority != authority_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(authority_info);
+
+    Ok(())
+}
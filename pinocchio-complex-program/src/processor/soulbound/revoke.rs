@@ -0,0 +1,29 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use super::validate_issuer;
+use crate::processor::shared::custody;
+
+/// Revokes a soulbound credential, thawing the credential account and burning the
+/// credential.
+///
+/// The thawed account is left empty, so its owner can close it.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The issuer account.
+/// 1. `[writable]` The mint of the credentials.
+/// 2. `[writable]` The credential account.
+/// 3. `[signer]` The issuer authority.
+#[inline(always)]
+pub fn process_revoke(accounts: &[AccountInfo]) -> ProgramResult {
+    let [issuer_info, mint_info, credential_info, authority_info, _remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    validate_issuer(issuer_info, mint_info, authority_info)?;
+
+    custody::set_frozen(credential_info, mint_info, issuer_info.key(), false)?;
+
+    custody::delegated_burn(credential_info, mint_info, issuer_info.key(), 1)
+}
@@ -0,0 +1,80 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use super::set_recipients;
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, shared},
+    state::splitter::{Splitter, SPLITTER_SEED},
+};
+
+/// Creates a new payment splitter.
+///
+/// The token vault must be an uninitialized account owned by the token program; it
+/// is initialized with the splitter derived address as its owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the splitter account.
+/// 1. `[writable]` The splitter account.
+/// 2. `[]` The authority allowed to update the recipients.
+/// 3. `[]` The key used to derive the splitter address.
+/// 4. `[]` The system program.
+/// 5. `[writable]` The token vault.
+/// 6. `[]` The mint of the split tokens.
+/// 7. ..7+N `[]` The N recipient token accounts.
+#[inline(always)]
+pub fn process_create_splitter(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1) + u16 (2) per recipient
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+
+    let [payer_info, splitter_info, authority_info, create_key_info, _system_program_info, token_vault_info, mint_info, recipients @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the splitter account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        splitter_info,
+        &[SPLITTER_SEED, create_key_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(SPLITTER_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(payer_info, splitter_info, Splitter::LEN, &seeds)?;
+
+    // Initializes the token vault under the splitter authority.
+
+    shared::initialize_account::process_initialize_account(
+        &[token_vault_info.clone(), mint_info.clone()],
+        Some(splitter_info.key()),
+        false,
+    )?;
+
+    // Initializes the splitter.
+
+    // SAFETY: single mutable borrow to `splitter_info` account data; the account was
+    // created above with the expected length.
+    let splitter =
+        unsafe { load_mut_unchecked::<Splitter>(splitter_info.borrow_mut_data_unchecked())? };
+
+    set_recipients(splitter, recipients, cursor.remaining())?;
+
+    splitter.set_initialized();
+    splitter.bump = bump;
+    splitter.authority = *authority_info.key();
+    splitter.create_key = *create_key_info.key();
+    splitter.token_vault = *token_vault_info.key();
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load},
+};
+
+use crate::{
+    error::ExtensionError,
+    math::{self, Rounding},
+    processor::{check_account_owner, shared::custody, treasury::protocol_fee},
+    state::splitter::Splitter,
+};
+
+/// Splits the balance of the token vault across the recipients.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The splitter account.
+/// 1. `[writable]` The token vault.
+/// 2. ..2+N `[writable]` The N recipient token accounts, in the splitter order.
+/// 2+N. ..7+N The fee accounts, to take the protocol fee out of the balance
+///    before splitting it (see [`protocol_fee`]).
+#[inline(always)]
+pub fn process_distribute(accounts: &[AccountInfo]) -> ProgramResult {
+    let [splitter_info, token_vault_info, recipients @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(splitter_info)?;
+    // SAFETY: scoped immutable borrow to `splitter_info` account data and
+    // `load` validates that the splitter is initialized.
+    let splitter = unsafe { load::<Splitter>(splitter_info.borrow_data_unchecked())? };
+
+    if token_vault_info.key() != &splitter.token_vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if recipients.len() < splitter.n as usize {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let (recipients, fee_accounts) = recipients.split_at(splitter.n as usize);
+
+    // SAFETY: scoped immutable borrow to `token_vault_info` account data and
+    // `load` validates that the account is initialized.
+    let mut balance =
+        unsafe { load::<Account>(token_vault_info.borrow_data_unchecked())?.amount() };
+
+    // Takes the protocol fee out of the balance.
+
+    if let Some(fee) = protocol_fee(fee_accounts, splitter_info.key(), balance)? {
+        for (fee_account_info, amount) in fee.shares() {
+            custody::transfer(
+                token_vault_info,
+                fee_account_info,
+                splitter_info.key(),
+                amount,
+            )?;
+        }
+        balance = balance
+            .checked_sub(fee.amount()?)
+            .ok_or(TokenError::Overflow)?;
+    }
+
+    let total_weight = splitter.total_weight();
+
+    // `weight <= total_weight`, so the share is at most `balance`.
+    let share = |index: usize| {
+        math::mul_div(
+            balance,
+            splitter.weight(index) as u64,
+            total_weight,
+            Rounding::Down,
+        )
+    };
+
+    // Shares are rounded down, so their sum is at most `balance`.
+    let dust = balance
+        .checked_sub(
+            (0..recipients.len())
+                .map(share)
+                .sum::<Result<u64, ProgramError>>()?,
+        )
+        .ok_or(TokenError::Overflow)?;
+
+    // Moves the shares out of the token vault.
+
+    for (index, recipient_info) in recipients.iter().enumerate() {
+        if recipient_info.key() != &splitter.recipients[index] {
+            return Err(ExtensionError::AccountMismatch.into());
+        }
+
+        let amount = if index == 0 {
+            share(index)?
+                .checked_add(dust)
+                .ok_or(TokenError::Overflow)?
+        } else {
+            share(index)?
+        };
+
+        custody::transfer(
+            token_vault_info,
+            recipient_info,
+            splitter_info.key(),
+            amount,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! Payment splitter with persistent share configuration.
+//!
+//! Payments accumulate in the token vault of the splitter and anyone can
+//! `Distribute` the accumulated balance across the recipients in proportion to their
+//! share weights. Each share is rounded down and the remaining dust is paid to the
+//! first recipient, so every distribution empties the token vault.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateSplitter`
+//! - `1`: `UpdateRecipients`
+//! - `2`: `Distribute`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::state::splitter::{Splitter, MAX_RECIPIENTS};
+
+use crate::parse::Cursor;
+
+pub mod create_splitter;
+pub mod distribute;
+pub mod update_recipients;
+
+pub use create_splitter::process_create_splitter;
+pub use distribute::process_distribute;
+pub use update_recipients::process_update_recipients;
+
+/// Process a splitter instruction.
+#[inline(always)]
+pub fn process_splitter_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateSplitter
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateSplitter");
+
+            process_create_splitter(accounts, instruction_data)
+        }
+        // 1 - UpdateRecipients
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: UpdateRecipients");
+
+            process_update_recipients(accounts, instruction_data)
+        }
+        // 2 - Distribute
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Distribute");
+
+            process_distribute(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Sets the recipients of a splitter from the recipient token accounts and their
+/// weights:
+/// - weights (2 bytes each)
+#[inline(always)]
+fn set_recipients(
+    splitter: &mut Splitter,
+    recipients: &[AccountInfo],
+    weights: &[u8],
+) -> ProgramResult {
+    if recipients.is_empty() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    if recipients.len() > MAX_RECIPIENTS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // expected u16 (2) per recipient
+    if weights.len() != recipients.len() * 2 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut weights = Cursor::new(weights);
+
+    for (index, recipient_info) in recipients.iter().enumerate() {
+        let weight = weights.read_u16()?;
+
+        if weight == 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        splitter.recipients[index] = *recipient_info.key();
+        splitter.set_weight(index, weight);
+    }
+
+    splitter.n = recipients.len() as u8;
+
+    Ok(())
+}
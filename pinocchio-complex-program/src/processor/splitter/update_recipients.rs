@@ -0,0 +1,38 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use super::set_recipients;
+use crate::{processor::check_account_owner, state::splitter::Splitter};
+
+/// Replaces the recipients and weights of a splitter.
+///
+/// Balances accumulated before the update are split with the new weights, so the
+/// authority should distribute them first.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The splitter account.
+/// 1. `[signer]` The splitter authority.
+/// 2. ..2+N `[]` The N recipient token accounts.
+#[inline(always)]
+pub fn process_update_recipients(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [splitter_info, authority_info, recipients @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(splitter_info)?;
+    // SAFETY: single mutable borrow to `splitter_info` account data and
+    // `load_mut` validates that the splitter is initialized.
+    let splitter = unsafe { load_mut::<Splitter>(splitter_info.borrow_mut_data_unchecked())? };
+
+    if &splitter.authority != authority_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(authority_info);
+
+    set_recipients(splitter, recipients, instruction_data)
+}
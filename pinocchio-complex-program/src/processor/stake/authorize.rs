@@ -0,0 +1,46 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{
+    parse::Cursor,
+    stake::{self, StakeAuthorize},
+};
+
+/// Sets a new staker or withdrawer authority of a stake account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The stake account.
+/// 1. `[]` The clock sysvar.
+/// 2. `[signer]` The current staker or withdrawer authority.
+/// 3. `[]` The Stake program.
+#[inline(always)]
+pub fn process_authorize(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected Pubkey (32) + u8 (1)
+    if instruction_data.len() != 33 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut cursor = Cursor::new(instruction_data);
+    let new_authority = cursor.read_address()?;
+
+    let stake_authorize = match cursor.read_u8()? {
+        0 => StakeAuthorize::Staker,
+        1 => StakeAuthorize::Withdrawer,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    let [stake_info, clock_sysvar_info, authority_info, _stake_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    stake::authorize(
+        stake_info,
+        clock_sysvar_info,
+        authority_info,
+        new_authority,
+        stake_authorize,
+        &[],
+    )
+}
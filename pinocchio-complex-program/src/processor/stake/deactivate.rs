@@ -0,0 +1,22 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::stake;
+
+/// Deactivates the stake of a stake account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The stake account.
+/// 1. `[]` The clock sysvar.
+/// 2. `[signer]` The stake authority.
+/// 3. `[]` The Stake program.
+#[inline(always)]
+pub fn process_deactivate(accounts: &[AccountInfo]) -> ProgramResult {
+    let [stake_info, clock_sysvar_info, stake_authority_info, _stake_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    stake::deactivate(stake_info, clock_sysvar_info, stake_authority_info, &[])
+}
@@ -0,0 +1,33 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::stake;
+
+/// Delegates the stake of a stake account to a vote account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The stake account.
+/// 1. `[]` The vote account.
+/// 2. `[]` The clock sysvar.
+/// 3. `[]` The stake history sysvar.
+/// 4. `[]` The stake config account.
+/// 5. `[signer]` The stake authority.
+/// 6. `[]` The Stake program.
+#[inline(always)]
+pub fn process_delegate_stake(accounts: &[AccountInfo]) -> ProgramResult {
+    let [stake_info, vote_info, clock_sysvar_info, stake_history_sysvar_info, stake_config_info, stake_authority_info, _stake_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    stake::delegate_stake(
+        stake_info,
+        vote_info,
+        clock_sysvar_info,
+        stake_history_sysvar_info,
+        stake_config_info,
+        stake_authority_info,
+        &[],
+    )
+}
@@ -0,0 +1,64 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{
+    return_data,
+    stake::{self, StakeState},
+};
+
+/// Length of the return data payload.
+const PAYLOAD_LEN: usize = 185;
+
+/// Returns the authorities, lockup and delegation of a stake account.
+///
+/// The layout of the return data payload is:
+/// - [0]: variant (`1` for initialized, `2` for delegated)
+/// - [1..9]: rent exempt reserve
+/// - [9..41]: staker
+/// - [41..73]: withdrawer
+/// - [73..81]: lockup unix timestamp
+/// - [81..89]: lockup epoch
+/// - [89..121]: lockup custodian
+/// - [121..153]: delegated vote account
+/// - [153..161]: delegated stake
+/// - [161..169]: activation epoch
+/// - [169..177]: deactivation epoch
+/// - [177..185]: credits observed
+///
+/// The delegation fields are zero when the stake account is not delegated.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The stake account.
+#[inline(always)]
+pub fn process_get_stake_state(accounts: &[AccountInfo]) -> ProgramResult {
+    let [stake_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_owner!(stake_info, &stake::ID);
+
+    // SAFETY: scoped immutable borrow to `stake_info` account data and
+    // `from_bytes` validates the length and variant of the state.
+    let state = StakeState::from_bytes(unsafe { stake_info.borrow_data_unchecked() })?;
+
+    let mut payload = [0u8; PAYLOAD_LEN];
+
+    payload[0] = 1;
+    payload[1..9].copy_from_slice(&state.meta.rent_exempt_reserve().to_le_bytes());
+    payload[9..41].copy_from_slice(&state.meta.staker);
+    payload[41..73].copy_from_slice(&state.meta.withdrawer);
+    payload[73..81].copy_from_slice(&state.meta.lockup_unix_timestamp().to_le_bytes());
+    payload[81..89].copy_from_slice(&state.meta.lockup_epoch().to_le_bytes());
+    payload[89..121].copy_from_slice(&state.meta.custodian);
+
+    if let Some(stake) = state.stake() {
+        payload[0] = 2;
+        payload[121..153].copy_from_slice(&stake.voter);
+        payload[153..161].copy_from_slice(&stake.stake().to_le_bytes());
+        payload[161..169].copy_from_slice(&stake.activation_epoch().to_le_bytes());
+        payload[169..177].copy_from_slice(&stake.deactivation_epoch().to_le_bytes());
+        payload[177..185].copy_from_slice(&stake.credits_observed().to_le_bytes());
+    }
+
+    return_data::set(&payload)
+}
@@ -0,0 +1,44 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{
+    parse::Cursor,
+    stake::{self, Lockup},
+};
+
+/// Initializes a stake account with its authorities and lockup.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The stake account, owned by the Stake program.
+/// 1. `[]` The rent sysvar.
+/// 2. `[]` The Stake program.
+#[inline(always)]
+pub fn process_initialize(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected Pubkey (32) + Pubkey (32) + i64 (8) + u64 (8) + Pubkey (32)
+    if instruction_data.len() != 112 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [stake_info, rent_sysvar_info, _stake_program_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let mut cursor = Cursor::new(instruction_data);
+    let staker = cursor.read_address()?;
+    let withdrawer = cursor.read_address()?;
+    let unix_timestamp = cursor.read_i64()?;
+    let epoch = cursor.read_u64()?;
+    let custodian = cursor.read_address()?;
+
+    stake::initialize(
+        stake_info,
+        rent_sysvar_info,
+        staker,
+        withdrawer,
+        &Lockup {
+            unix_timestamp,
+            epoch,
+            custodian,
+        },
+    )
+}
@@ -0,0 +1,31 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::stake;
+
+/// Merges a source stake account into a destination stake account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The destination stake account.
+/// 1. `[writable]` The source stake account, which is closed.
+/// 2. `[]` The clock sysvar.
+/// 3. `[]` The stake history sysvar.
+/// 4. `[signer]` The stake authority of both accounts.
+/// 5. `[]` The Stake program.
+#[inline(always)]
+pub fn process_merge(accounts: &[AccountInfo]) -> ProgramResult {
+    let [destination_info, source_info, clock_sysvar_info, stake_history_sysvar_info, stake_authority_info, _stake_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    stake::merge(
+        destination_info,
+        source_info,
+        clock_sysvar_info,
+        stake_history_sysvar_info,
+        stake_authority_info,
+        &[],
+    )
+}
@@ -0,0 +1,106 @@
+//! Stake program instructions invoked through CPIs.
+//!
+//! The authorities of the stake account sign the outer instruction and their
+//! signatures are forwarded to the Stake program.
+//!
+//! Instructions:
+//!
+//! - `0`: `Initialize`
+//! - `1`: `DelegateStake`
+//! - `2`: `Deactivate`
+//! - `3`: `Withdraw`
+//! - `4`: `Split`
+//! - `5`: `Merge`
+//! - `6`: `Authorize`
+//! - `7`: `GetStakeState`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod authorize;
+pub mod deactivate;
+pub mod delegate_stake;
+pub mod get_stake_state;
+pub mod initialize;
+pub mod merge;
+pub mod split;
+pub mod withdraw;
+
+pub use authorize::process_authorize;
+pub use deactivate::process_deactivate;
+pub use delegate_stake::process_delegate_stake;
+pub use get_stake_state::process_get_stake_state;
+pub use initialize::process_initialize;
+pub use merge::process_merge;
+pub use split::process_split;
+pub use withdraw::process_withdraw;
+
+/// Process a stake instruction.
+#[inline(always)]
+pub fn process_stake_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - Initialize
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Initialize");
+
+            process_initialize(accounts, instruction_data)
+        }
+        // 1 - DelegateStake
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: DelegateStake");
+
+            process_delegate_stake(accounts)
+        }
+        // 2 - Deactivate
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Deactivate");
+
+            process_deactivate(accounts)
+        }
+        // 3 - Withdraw
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Withdraw");
+
+            process_withdraw(accounts, instruction_data)
+        }
+        // 4 - Split
+        4 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Split");
+
+            process_split(accounts, instruction_data)
+        }
+        // 5 - Merge
+        5 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Merge");
+
+            process_merge(accounts)
+        }
+        // 6 - Authorize
+        6 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Authorize");
+
+            process_authorize(accounts, instruction_data)
+        }
+        // 7 - GetStakeState
+        7 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: GetStakeState");
+
+            process_get_stake_state(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
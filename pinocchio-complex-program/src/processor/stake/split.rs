@@ -0,0 +1,32 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{parse::Cursor, stake};
+
+/// Splits lamports from a stake account into a new stake account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The stake account.
+/// 1. `[writable]` The uninitialized split stake account, owned by the Stake program.
+/// 2. `[signer]` The stake authority.
+/// 3. `[]` The Stake program.
+#[inline(always)]
+pub fn process_split(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let lamports = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [stake_info, split_stake_info, stake_authority_info, _stake_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    stake::split(
+        stake_info,
+        split_stake_info,
+        stake_authority_info,
+        lamports,
+        &[],
+    )
+}
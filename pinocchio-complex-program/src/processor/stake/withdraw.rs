@@ -0,0 +1,36 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{parse::Cursor, stake};
+
+/// Withdraws lamports from a stake account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The stake account.
+/// 1. `[writable]` The recipient account.
+/// 2. `[]` The clock sysvar.
+/// 3. `[]` The stake history sysvar.
+/// 4. `[signer]` The withdraw authority.
+/// 5. `[]` The Stake program.
+#[inline(always)]
+pub fn process_withdraw(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let lamports = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [stake_info, recipient_info, clock_sysvar_info, stake_history_sysvar_info, withdraw_authority_info, _stake_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    stake::withdraw(
+        stake_info,
+        recipient_info,
+        clock_sysvar_info,
+        stake_history_sysvar_info,
+        withdraw_authority_info,
+        lamports,
+        &[],
+    )
+}
@@ -0,0 +1,66 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::state::load_mut;
+
+use super::update_rewards;
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, shared, validate_owner},
+    state::staking::{StakePool, StakeRecord},
+};
+
+/// Claims the rewards accrued by a stake record.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The stake pool account.
+/// 1. `[writable]` The stake record account.
+/// 2. `[writable]` The reward vault.
+/// 3. `[writable]` The destination token account.
+/// 4. `[signer]` The stake record owner.
+/// 5. ..5+M `[signer]` M signer accounts.
+#[inline(always)]
+pub fn process_claim_rewards(accounts: &[AccountInfo]) -> ProgramResult {
+    let [stake_pool_info, stake_record_info, reward_vault_info, destination_account_info, owner_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(stake_pool_info)?;
+    // SAFETY: single mutable borrow to `stake_pool_info` account data and
+    // `load_mut` validates that the stake pool is initialized.
+    let stake_pool = unsafe { load_mut::<StakePool>(stake_pool_info.borrow_mut_data_unchecked())? };
+
+    check_account_owner(stake_record_info)?;
+    // SAFETY: single mutable borrow to `stake_record_info` account data and
+    // `load_mut` validates that the stake record is initialized.
+    let stake_record =
+        unsafe { load_mut::<StakeRecord>(stake_record_info.borrow_mut_data_unchecked())? };
+
+    if reward_vault_info.key() != &stake_pool.reward_vault
+        || &stake_record.stake_pool != stake_pool_info.key()
+    {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    validate_owner(&stake_record.owner, owner_info, remaining)?;
+
+    update_rewards(stake_pool, stake_record, Clock::get()?.slot)?;
+
+    let rewards = stake_record.rewards_owed();
+    stake_record.set_rewards_owed(0);
+
+    // Moves the rewards out of the reward vault.
+
+    shared::custody::transfer(
+        reward_vault_info,
+        destination_account_info,
+        stake_pool_info.key(),
+        rewards,
+    )
+}
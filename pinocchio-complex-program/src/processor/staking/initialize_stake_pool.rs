@@ -0,0 +1,101 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, shared},
+    state::staking::{StakePool, STAKE_POOL_SEED},
+};
+
+/// Initializes a new stake pool.
+///
+/// The vaults must be uninitialized accounts owned by the token program; they are
+/// initialized with the stake pool derived address as their owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The stake pool admin, paying for the stake pool account.
+/// 1. `[writable]` The stake pool account.
+/// 2. `[]` The mint of the staked token.
+/// 3. `[]` The mint of the reward token.
+/// 4. `[writable]` The stake vault.
+/// 5. `[writable]` The reward vault.
+/// 6. `[]` The system program.
+#[inline(always)]
+pub fn process_initialize_stake_pool(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let reward_rate = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [admin_info, stake_pool_info, stake_mint_info, reward_mint_info, stake_vault_info, reward_vault_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the stake pool account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        stake_pool_info,
+        &[
+            STAKE_POOL_SEED,
+            admin_info.key().as_ref(),
+            stake_mint_info.key().as_ref(),
+            reward_mint_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(STAKE_POOL_SEED),
+        Seed::from(admin_info.key()),
+        Seed::from(stake_mint_info.key()),
+        Seed::from(reward_mint_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(admin_info, stake_pool_info, StakePool::LEN, &seeds)?;
+
+    // Initializes the vaults under the stake pool authority.
+
+    shared::initialize_account::process_initialize_account(
+        &[stake_vault_info.clone(), stake_mint_info.clone()],
+        Some(stake_pool_info.key()),
+        false,
+    )?;
+    shared::initialize_account::process_initialize_account(
+        &[reward_vault_info.clone(), reward_mint_info.clone()],
+        Some(stake_pool_info.key()),
+        false,
+    )?;
+
+    // Initializes the stake pool.
+
+    // SAFETY: single mutable borrow to `stake_pool_info` account data; the account
+    // was created above with the expected length.
+    let stake_pool =
+        unsafe { load_mut_unchecked::<StakePool>(stake_pool_info.borrow_mut_data_unchecked())? };
+
+    stake_pool.set_initialized();
+    stake_pool.bump = bump;
+    stake_pool.admin = *admin_info.key();
+    stake_pool.stake_mint = *stake_mint_info.key();
+    stake_pool.reward_mint = *reward_mint_info.key();
+    stake_pool.stake_vault = *stake_vault_info.key();
+    stake_pool.reward_vault = *reward_vault_info.key();
+    stake_pool.set_reward_rate(reward_rate);
+    stake_pool.set_last_update_slot(Clock::get()?.slot);
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+//! Single-sided staking pool.
+//!
+//! Stakers deposit tokens into the pool stake vault and accrue rewards from the
+//! pool reward vault at a fixed rate per slot, shared proportionally to the amount
+//! staked. Each staker position is tracked in a stake record account. The pool
+//! admin can update the reward rate.
+//!
+//! Instructions:
+//!
+//! - `0`: `InitializeStakePool`
+//! - `1`: `Stake`
+//! - `2`: `Unstake`
+//! - `3`: `ClaimRewards`
+//! - `4`: `SetRewardRate`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::error::TokenError;
+
+use crate::state::staking::{StakePool, StakeRecord, REWARD_PRECISION};
+
+pub mod claim_rewards;
+pub mod initialize_stake_pool;
+pub mod set_reward_rate;
+pub mod stake;
+pub mod unstake;
+
+pub use claim_rewards::process_claim_rewards;
+pub use initialize_stake_pool::process_initialize_stake_pool;
+pub use set_reward_rate::process_set_reward_rate;
+pub use stake::process_stake;
+pub use unstake::process_unstake;
+
+/// Process a staking instruction.
+#[inline(always)]
+pub fn process_staking_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - InitializeStakePool
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializeStakePool");
+
+            process_initialize_stake_pool(accounts, instruction_data)
+        }
+        // 1 - Stake
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Stake");
+
+            process_stake(accounts, instruction_data)
+        }
+        // 2 - Unstake
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Unstake");
+
+            process_unstake(accounts, instruction_data)
+        }
+        // 3 - ClaimRewards
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ClaimRewards");
+
+            process_claim_rewards(accounts)
+        }
+        // 4 - SetRewardRate
+        4 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SetRewardRate");
+
+            process_set_reward_rate(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Accrues the pool rewards up to `slot`.
+///
+/// This must be called before the amount staked in the pool or the reward rate
+/// changes.
+#[inline(always)]
+fn accrue_rewards(stake_pool: &mut StakePool, slot: u64) -> ProgramResult {
+    let total_staked = stake_pool.total_staked();
+
+    if total_staked > 0 {
+        let elapsed = slot.saturating_sub(stake_pool.last_update_slot()) as u128;
+        let accrued = elapsed
+            .checked_mul(stake_pool.reward_rate() as u128)
+            .and_then(|rewards| rewards.checked_mul(REWARD_PRECISION))
+            .ok_or(TokenError::Overflow)?
+            / total_staked as u128;
+
+        stake_pool.set_reward_per_token(
+            stake_pool
+                .reward_per_token()
+                .checked_add(accrued)
+                .ok_or(TokenError::Overflow)?,
+        );
+    }
+
+    stake_pool.set_last_update_slot(slot);
+
+    Ok(())
+}
+
+/// Accrues the pool rewards up to `slot` and settles the pending rewards of the
+/// stake record.
+///
+/// This must be called before the amount staked in the record (or the pool) changes.
+#[inline(always)]
+fn update_rewards(
+    stake_pool: &mut StakePool,
+    stake_record: &mut StakeRecord,
+    slot: u64,
+) -> ProgramResult {
+    accrue_rewards(stake_pool, slot)?;
+
+    let pending = (stake_record.amount() as u128)
+        .checked_mul(
+            stake_pool
+                .reward_per_token()
+                .checked_sub(stake_record.reward_per_token_paid())
+                .ok_or(TokenError::Overflow)?,
+        )
+        .ok_or(TokenError::Overflow)?
+        / REWARD_PRECISION;
+
+    let rewards_owed = (stake_record.rewards_owed() as u128)
+        .checked_add(pending)
+        .and_then(|rewards_owed| u64::try_from(rewards_owed).ok())
+        .ok_or(TokenError::Overflow)?;
+
+    stake_record.set_rewards_owed(rewards_owed);
+    stake_record.set_reward_per_token_paid(stake_pool.reward_per_token());
+
+    Ok(())
+}
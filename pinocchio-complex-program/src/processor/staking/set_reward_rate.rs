@@ -0,0 +1,47 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{error::TokenError, state::load_mut};
+
+use super::accrue_rewards;
+use crate::{parse::Cursor, processor::check_account_owner, state::staking::StakePool};
+
+/// Updates the reward rate of a stake pool.
+///
+/// The rewards are accrued at the previous rate up to the current slot before the
+/// rate changes.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The stake pool account.
+/// 1. `[signer]` The stake pool admin.
+#[inline(always)]
+pub fn process_set_reward_rate(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let reward_rate = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [stake_pool_info, admin_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(stake_pool_info)?;
+    // SAFETY: single mutable borrow to `stake_pool_info` account data and
+    // `load_mut` validates that the stake pool is initialized.
+    let stake_pool = unsafe { load_mut::<StakePool>(stake_pool_info.borrow_mut_data_unchecked())? };
+
+    if &stake_pool.admin != admin_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(admin_info);
+
+    accrue_rewards(stake_pool, Clock::get()?.slot)?;
+
+    stake_pool.set_reward_rate(reward_rate);
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load_mut, load_mut_unchecked, RawType},
+};
+
+use super::update_rewards;
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_account_owner, check_derived_address, create_derived_account, shared},
+    state::staking::{StakePool, StakeRecord, STAKE_RECORD_SEED},
+};
+
+/// Stakes tokens into the stake pool.
+///
+/// The stake record of the owner is created on the first stake.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The stake pool account.
+/// 1. `[writable]` The stake record account.
+/// 2. `[writable]` The source token account.
+/// 3. `[writable]` The stake vault.
+/// 4. `[writable, signer]` The source account owner, paying for the stake record.
+/// 5. `[]` The system program.
+#[inline(always)]
+pub fn process_stake(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    let bump = cursor.read_u8()?;
+    cursor.finish()?;
+
+    let [stake_pool_info, stake_record_info, _source_account_info, stake_vault_info, owner_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(stake_pool_info)?;
+    // SAFETY: single mutable borrow to `stake_pool_info` account data and
+    // `load_mut` validates that the stake pool is initialized.
+    let stake_pool = unsafe { load_mut::<StakePool>(stake_pool_info.borrow_mut_data_unchecked())? };
+
+    if stake_vault_info.key() != &stake_pool.stake_vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // Loads (or creates) the stake record.
+
+    let stake_record = if stake_record_info.data_len() == 0 {
+        let bump_seed = [bump];
+        check_derived_address(
+            stake_record_info,
+            &[
+                STAKE_RECORD_SEED,
+                stake_pool_info.key().as_ref(),
+                owner_info.key().as_ref(),
+                &bump_seed,
+            ],
+        )?;
+
+        let seeds = [
+            Seed::from(STAKE_RECORD_SEED),
+            Seed::from(stake_pool_info.key()),
+            Seed::from(owner_info.key()),
+            Seed::from(&bump_seed),
+        ];
+
+        create_derived_account(owner_info, stake_record_info, StakeRecord::LEN, &seeds)?;
+
+        // SAFETY: single mutable borrow to `stake_record_info` account data; the
+        // account was created above with the expected length.
+        let stake_record = unsafe {
+            load_mut_unchecked::<StakeRecord>(stake_record_info.borrow_mut_data_unchecked())?
+        };
+
+        stake_record.set_initialized();
+        stake_record.bump = bump;
+        stake_record.stake_pool = *stake_pool_info.key();
+        stake_record.owner = *owner_info.key();
+        stake_record.set_reward_per_token_paid(stake_pool.reward_per_token());
+
+        stake_record
+    } else {
+        check_account_owner(stake_record_info)?;
+        // SAFETY: single mutable borrow to `stake_record_info` account data and
+        // `load_mut` validates that the stake record is initialized.
+        let stake_record =
+            unsafe { load_mut::<StakeRecord>(stake_record_info.borrow_mut_data_unchecked())? };
+
+        if &stake_record.stake_pool != stake_pool_info.key()
+            || &stake_record.owner != owner_info.key()
+        {
+            return Err(ExtensionError::AccountMismatch.into());
+        }
+
+        stake_record
+    };
+
+    update_rewards(stake_pool, stake_record, Clock::get()?.slot)?;
+
+    // Moves the tokens into the stake vault.
+
+    shared::transfer::process_transfer(&accounts[2..5], amount, None)?;
+
+    stake_record.set_amount(
+        stake_record
+            .amount()
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?,
+    );
+    stake_pool.set_total_staked(
+        stake_pool
+            .total_staked()
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?,
+    );
+
+    Ok(())
+}
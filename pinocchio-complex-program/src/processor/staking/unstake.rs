@@ -0,0 +1,81 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{error::TokenError, state::load_mut};
+
+use super::update_rewards;
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_account_owner, shared, validate_owner},
+    state::staking::{StakePool, StakeRecord},
+};
+
+/// Withdraws staked tokens from the stake pool.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The stake pool account.
+/// 1. `[writable]` The stake record account.
+/// 2. `[writable]` The stake vault.
+/// 3. `[writable]` The destination token account.
+/// 4. `[signer]` The stake record owner.
+/// 5. ..5+M `[signer]` M signer accounts.
+#[inline(always)]
+pub fn process_unstake(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [stake_pool_info, stake_record_info, stake_vault_info, destination_account_info, owner_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(stake_pool_info)?;
+    // SAFETY: single mutable borrow to `stake_pool_info` account data and
+    // `load_mut` validates that the stake pool is initialized.
+    let stake_pool = unsafe { load_mut::<StakePool>(stake_pool_info.borrow_mut_data_unchecked())? };
+
+    check_account_owner(stake_record_info)?;
+    // SAFETY: single mutable borrow to `stake_record_info` account data and
+    // `load_mut` validates that the stake record is initialized.
+    let stake_record =
+        unsafe { load_mut::<StakeRecord>(stake_record_info.borrow_mut_data_unchecked())? };
+
+    if stake_vault_info.key() != &stake_pool.stake_vault
+        || &stake_record.stake_pool != stake_pool_info.key()
+    {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    validate_owner(&stake_record.owner, owner_info, remaining)?;
+
+    update_rewards(stake_pool, stake_record, Clock::get()?.slot)?;
+
+    stake_record.set_amount(
+        stake_record
+            .amount()
+            .checked_sub(amount)
+            .ok_or(TokenError::InsufficientFunds)?,
+    );
+    stake_pool.set_total_staked(
+        stake_pool
+            .total_staked()
+            .checked_sub(amount)
+            .ok_or(TokenError::InsufficientFunds)?,
+    );
+
+    // Moves the tokens out of the stake vault.
+
+    shared::custody::transfer(
+        stake_vault_info,
+        destination_account_info,
+        stake_pool_info.key(),
+        amount,
+    )
+}
@@ -0,0 +1,43 @@
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+use token_interface::{error::TokenError, program::ID as TOKEN_PROGRAM_ID, state::load};
+use token_program_derive::Accounts;
+
+use crate::{processor::close_extension_account, state::subscription::Subscription};
+
+/// Accounts of the `CancelSubscription` instruction.
+#[derive(Accounts)]
+struct CancelSubscriptionAccounts<'a> {
+    /// The subscription account.
+    #[account(writable, owner = TOKEN_PROGRAM_ID)]
+    subscription: &'a AccountInfo,
+
+    /// The subscriber.
+    #[account(writable, signer)]
+    subscriber: &'a AccountInfo,
+}
+
+/// Cancels a subscription, closing the subscription account.
+///
+/// The subscriber should also revoke the delegate of the source token account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The subscription account.
+/// 1. `[writable, signer]` The subscriber.
+#[inline(always)]
+pub fn process_cancel_subscription(accounts: &[AccountInfo]) -> ProgramResult {
+    let CancelSubscriptionAccounts {
+        subscription: subscription_info,
+        subscriber: subscriber_info,
+    } = CancelSubscriptionAccounts::try_from(accounts)?;
+
+    // SAFETY: scoped immutable borrow to `subscription_info` account data and
+    // `load` validates that the subscription is initialized.
+    let subscription = unsafe { load::<Subscription>(subscription_info.borrow_data_unchecked())? };
+
+    if &subscription.subscriber != subscriber_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    close_extension_account(subscription_info, subscriber_info)
+}
@@ -0,0 +1,65 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{error::TokenError, state::load_mut};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, shared::custody},
+    state::subscription::Subscription,
+};
+
+/// Pulls the payment of the current period of a subscription.
+///
+/// Anyone can charge a subscription once the payment is due; the next payment is due
+/// one period later.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The subscription account.
+/// 1. `[writable]` The source token account.
+/// 2. `[writable]` The destination token account.
+#[inline(always)]
+pub fn process_charge(accounts: &[AccountInfo]) -> ProgramResult {
+    let [subscription_info, source_account_info, destination_account_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(subscription_info)?;
+    // SAFETY: single mutable borrow to `subscription_info` account data and
+    // `load_mut` validates that the subscription is initialized.
+    let subscription =
+        unsafe { load_mut::<Subscription>(subscription_info.borrow_mut_data_unchecked())? };
+
+    if &subscription.source != source_account_info.key()
+        || &subscription.destination != destination_account_info.key()
+    {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+
+    if now < subscription.next_charge() {
+        return Err(ExtensionError::ChargeNotDue.into());
+    }
+
+    // Missed periods are not charged retroactively.
+    subscription.set_next_charge(
+        now.checked_add(subscription.period())
+            .ok_or(TokenError::Overflow)?,
+    );
+
+    // Pulls the payment with the delegated amount.
+
+    custody::delegated_transfer(
+        source_account_info,
+        destination_account_info,
+        subscription_info.key(),
+        subscription.amount(),
+    )
+}
@@ -0,0 +1,115 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load, load_mut_unchecked, RawType},
+};
+
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account},
+    state::subscription::{Subscription, SUBSCRIPTION_SEED},
+};
+
+/// Creates a new subscription, chargeable immediately.
+///
+/// The subscription only pulls payments once the subscriber approves its address as
+/// the delegate of the source token account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The subscriber, paying for the subscription account.
+/// 1. `[writable]` The subscription account.
+/// 2. `[]` The source token account.
+/// 3. `[]` The destination token account.
+/// 4. `[]` The system program.
+#[inline(always)]
+pub fn process_create_subscription(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let amount = cursor.read_u64()?;
+    let period = cursor.read_i64()?;
+    cursor.finish()?;
+
+    let [subscriber_info, subscription_info, source_account_info, destination_account_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if amount == 0 || period <= 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // SAFETY: scoped immutable borrow to `source_account_info` account data and
+    // `load` validates that the account is initialized.
+    let source_account = unsafe { load::<Account>(source_account_info.borrow_data_unchecked())? };
+
+    if &source_account.owner != subscriber_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    // SAFETY: scoped immutable borrow to `destination_account_info` account data and
+    // `load` validates that the account is initialized.
+    let destination_account =
+        unsafe { load::<Account>(destination_account_info.borrow_data_unchecked())? };
+
+    if source_account.mint != destination_account.mint {
+        return Err(ExtensionError::MintMismatch.into());
+    }
+
+    // Creates the subscription account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        subscription_info,
+        &[
+            SUBSCRIPTION_SEED,
+            source_account_info.key().as_ref(),
+            destination_account_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(SUBSCRIPTION_SEED),
+        Seed::from(source_account_info.key()),
+        Seed::from(destination_account_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(
+        subscriber_info,
+        subscription_info,
+        Subscription::LEN,
+        &seeds,
+    )?;
+
+    // Initializes the subscription.
+
+    // SAFETY: single mutable borrow to `subscription_info` account data; the account
+    // was created above with the expected length.
+    let subscription = unsafe {
+        load_mut_unchecked::<Subscription>(subscription_info.borrow_mut_data_unchecked())?
+    };
+
+    subscription.set_initialized();
+    subscription.bump = bump;
+    subscription.subscriber = *subscriber_info.key();
+    subscription.source = *source_account_info.key();
+    subscription.destination = *destination_account_info.key();
+    subscription.set_amount(amount);
+    subscription.set_period(period);
+    subscription.set_next_charge(Clock::get()?.unix_timestamp);
+
+    Ok(())
+}
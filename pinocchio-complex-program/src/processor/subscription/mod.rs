@@ -0,0 +1,58 @@
+//! Subscription billing with delegated pull payments.
+//!
+//! The subscriber approves the subscription address as the delegate of the source
+//! token account; anyone can then crank `Charge` to pull the subscription amount into
+//! the merchant token account, at most once per period. Revoking the delegate or
+//! cancelling the subscription stops the payments.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateSubscription`
+//! - `1`: `Charge`
+//! - `2`: `CancelSubscription`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod cancel_subscription;
+pub mod charge;
+pub mod create_subscription;
+
+pub use cancel_subscription::process_cancel_subscription;
+pub use charge::process_charge;
+pub use create_subscription::process_create_subscription;
+
+/// Process a subscription instruction.
+#[inline(always)]
+pub fn process_subscription_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateSubscription
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateSubscription");
+
+            process_create_subscription(accounts, instruction_data)
+        }
+        // 1 - Charge
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Charge");
+
+            process_charge(accounts)
+        }
+        // 2 - CancelSubscription
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CancelSubscription");
+
+            process_cancel_subscription(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
@@ -0,0 +1,36 @@
+//! Dust consolidation.
+//!
+//! Moves the full balances of many token accounts of the same owner into a
+//! single destination account, optionally closing the emptied accounts.
+//!
+//! Instructions:
+//!
+//! - `0`: `SweepDust`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod sweep_dust;
+
+pub use sweep_dust::process_sweep_dust;
+
+/// Process a sweep instruction.
+#[inline(always)]
+pub fn process_sweep_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - SweepDust
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SweepDust");
+
+            process_sweep_dust(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
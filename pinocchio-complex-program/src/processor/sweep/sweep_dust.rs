@@ -0,0 +1,86 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_log::log;
+use token_interface::state::{account::Account, load};
+
+use crate::processor::{check_account_owner, shared::custody};
+
+/// Transfers the full balance of each source token account to the destination
+/// account.
+///
+/// When the close flag is set, the emptied source accounts are closed and their
+/// lamports are moved to the owner. All source accounts must be owned by the
+/// signing owner; multisig owners are not supported. When sweeping a source
+/// account fails, its index is logged before returning the error.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The destination token account.
+/// 1. `[writable, signer]` The owner of the source token accounts.
+/// 2. ..2+N `[writable]` The N source token accounts.
+#[inline(always)]
+pub fn process_sweep_dust(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let [destination_account_info, owner_info, sources @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // expected u8 (1)
+    let close = match instruction_data {
+        [0] => false,
+        [1] => true,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if sources.is_empty() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    require_signer!(owner_info);
+
+    // Sweeps each source account.
+
+    for (index, source_account_info) in sources.iter().enumerate() {
+        if let Err(error) = sweep(
+            source_account_info,
+            destination_account_info,
+            owner_info,
+            close,
+        ) {
+            log!("SweepDust: source {} failed", index);
+            return Err(error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Moves the balance of a source account and closes it when requested.
+#[inline(always)]
+fn sweep(
+    source_account_info: &AccountInfo,
+    destination_account_info: &AccountInfo,
+    owner_info: &AccountInfo,
+    close: bool,
+) -> ProgramResult {
+    check_account_owner(source_account_info)?;
+
+    let amount = {
+        // SAFETY: scoped immutable borrow to `source_account_info` account data and
+        // `load` validates that the account is initialized.
+        let source_account =
+            unsafe { load::<Account>(source_account_info.borrow_data_unchecked())? };
+        source_account.amount()
+    };
+
+    custody::transfer(
+        source_account_info,
+        destination_account_info,
+        owner_info.key(),
+        amount,
+    )?;
+
+    if close {
+        custody::close_account(source_account_info, owner_info, owner_info.key())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::MAX_SEED_LEN,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{error::ExtensionError, parse::Cursor, system};
+
+/// System program instruction supported by the proxy.
+enum SystemInstruction {
+    /// `0` - Creates a new account.
+    CreateAccount,
+
+    /// `1` - Assigns an account to a program.
+    Assign,
+
+    /// `2` - Transfers lamports.
+    Transfer,
+
+    /// `3` - Creates a new account at an address derived from a base and a seed.
+    CreateAccountWithSeed,
+
+    /// `8` - Allocates space for an account.
+    Allocate {
+        /// Number of bytes of the account data.
+        space: u64,
+    },
+
+    /// `9` - Allocates space for an account at an address derived from a base and
+    /// a seed.
+    AllocateWithSeed {
+        /// Number of bytes of the account data.
+        space: u64,
+    },
+}
+
+impl SystemInstruction {
+    /// Parses a System program instruction from its native (`bincode`) wire format.
+    ///
+    /// The instruction index is a little-endian `u32` and strings are encoded as
+    /// a little-endian `u64` length followed by their UTF-8 bytes.
+    #[inline(always)]
+    fn unpack(instruction_data: &[u8]) -> Result<Self, ProgramError> {
+        let mut cursor = Cursor::new(instruction_data);
+
+        let instruction = match cursor.read_u32()? {
+            0 => {
+                // lamports (8) + space (8) + owner (32)
+                cursor.read_u64()?;
+                cursor.read_u64()?;
+                cursor.read_address()?;
+                Self::CreateAccount
+            }
+            1 => {
+                // owner (32)
+                cursor.read_address()?;
+                Self::Assign
+            }
+            2 => {
+                // lamports (8)
+                cursor.read_u64()?;
+                Self::Transfer
+            }
+            3 => {
+                // base (32) + seed (8 + N) + lamports (8) + space (8) + owner (32)
+                cursor.read_address()?;
+                read_seed(&mut cursor)?;
+                cursor.read_u64()?;
+                cursor.read_u64()?;
+                cursor.read_address()?;
+                Self::CreateAccountWithSeed
+            }
+            8 => {
+                // space (8)
+                Self::Allocate {
+                    space: cursor.read_u64()?,
+                }
+            }
+            9 => {
+                // base (32) + seed (8 + N) + space (8) + owner (32)
+                cursor.read_address()?;
+                read_seed(&mut cursor)?;
+                let space = cursor.read_u64()?;
+                cursor.read_address()?;
+                Self::AllocateWithSeed { space }
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        cursor.finish()?;
+
+        Ok(instruction)
+    }
+}
+
+/// Reads a seed string, checking its length and UTF-8 encoding.
+///
+/// The System program rejects these seeds too, but checking them before the CPI
+/// fails with a specific error.
+#[inline(always)]
+fn read_seed(cursor: &mut Cursor) -> Result<(), ProgramError> {
+    let len = cursor.read_u64()?;
+
+    if len > MAX_SEED_LEN as u64 {
+        return Err(ProgramError::MaxSeedLengthExceeded);
+    }
+
+    core::str::from_utf8(cursor.read_bytes(len as usize)?)
+        .map_err(|_error| ExtensionError::InvalidSeedEncoding)?;
+
+    Ok(())
+}
+
+/// Checks that an account holds enough lamports to be rent exempt with `space`
+/// bytes of data.
+///
+/// The runtime rejects the transaction when the account is not rent exempt after
+/// the allocation, but checking it before the CPI fails with a specific error.
+/// With the `logging` feature, the missing lamports are logged.
+#[inline(always)]
+fn check_rent_exempt(account_info: &AccountInfo, space: u64) -> ProgramResult {
+    // Larger allocations are rejected by the System program.
+    if space > system::MAX_PERMITTED_DATA_LENGTH {
+        return Ok(());
+    }
+
+    let minimum_balance = Rent::get()?.minimum_balance(space as usize);
+
+    if account_info.lamports() < minimum_balance {
+        #[cfg(feature = "logging")]
+        pinocchio_log::log!(
+            "Not rent exempt: {} lamports missing",
+            minimum_balance - account_info.lamports()
+        );
+
+        return Err(ExtensionError::NeedsRentExemption.into());
+    }
+
+    Ok(())
+}
+
+/// Forwards a System program instruction in its native wire format to the System
+/// program.
+///
+/// The instruction data is the data of the System program instruction, as built
+/// by the `solana_sdk::system_instruction` builders. It is validated and forwarded
+/// unchanged, with the signer and writable flags of the accounts. The supported
+/// instructions are `CreateAccount`, `Assign`, `Transfer`, `CreateAccountWithSeed`,
+/// `Allocate` and `AllocateWithSeed`.
+///
+/// The account of an `Allocate` or `AllocateWithSeed` instruction must hold enough
+/// lamports to be rent exempt with the allocated space.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The System program.
+/// 1. ..1+N The N accounts of the System program instruction, in the order
+///    expected by the System program.
+#[inline(always)]
+pub fn process_system_proxy(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let [system_program_info, accounts @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    require_address!(
+        system_program_info,
+        &system::ID,
+        ProgramError::IncorrectProgramId
+    );
+
+    match SystemInstruction::unpack(instruction_data)? {
+        SystemInstruction::Assign => {
+            let [account_info, ..] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            system::forward([account_info], instruction_data)
+        }
+        SystemInstruction::Allocate { space } => {
+            let [account_info, ..] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            check_rent_exempt(account_info, space)?;
+
+            system::forward([account_info], instruction_data)
+        }
+        SystemInstruction::AllocateWithSeed { space } => {
+            let [account_info, base_info, ..] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            check_rent_exempt(account_info, space)?;
+
+            system::forward([account_info, base_info], instruction_data)
+        }
+        SystemInstruction::CreateAccount | SystemInstruction::Transfer => {
+            let [from_info, to_info, ..] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            system::forward([from_info, to_info], instruction_data)
+        }
+        // The base account is only present when it is not the funding account.
+        SystemInstruction::CreateAccountWithSeed => match accounts {
+            [from_info, to_info, base_info, ..] => {
+                system::forward([from_info, to_info, base_info], instruction_data)
+            }
+            [from_info, to_info] => system::forward([from_info, to_info], instruction_data),
+            _ => Err(ProgramError::NotEnoughAccountKeys),
+        },
+    }
+}
@@ -0,0 +1,99 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load, load_mut, load_mut_unchecked, mint::Mint, RawType},
+};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, shared},
+    state::ticket::{Ticket, TICKET_SEED},
+};
+
+/// Mints a new event ticket.
+///
+/// The ticket mint must have no decimals, no supply and the organizer as its mint
+/// authority. A single token is minted to the holder token account and the mint
+/// authority is removed, fixing the supply of the mint.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The event organizer, paying for the ticket account.
+/// 1. `[writable]` The ticket account.
+/// 2. `[writable]` The ticket mint.
+/// 3. `[writable]` The holder token account.
+/// 4. `[]` The system program.
+#[inline(always)]
+pub fn process_mint_ticket(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let event_id = cursor.read_u64()?;
+    let seat = cursor.read_u32()?;
+    cursor.finish()?;
+
+    let [organizer_info, ticket_info, mint_info, holder_account_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Mints the ticket token and fixes the supply of the mint.
+
+    {
+        // SAFETY: scoped immutable borrow to `mint_info` account data and
+        // `load` validates that the mint is initialized.
+        let mint = unsafe { load::<Mint>(mint_info.borrow_data_unchecked())? };
+
+        if mint.supply() != 0 {
+            return Err(TokenError::InvalidState.into());
+        }
+    }
+
+    shared::mint_to::process_mint_to(
+        &[
+            mint_info.clone(),
+            holder_account_info.clone(),
+            organizer_info.clone(),
+        ],
+        1,
+        Some(0),
+    )?;
+
+    // SAFETY: single mutable borrow to `mint_info` account data and
+    // `load_mut` validates that the mint is initialized.
+    let mint = unsafe { load_mut::<Mint>(mint_info.borrow_mut_data_unchecked())? };
+    mint.clear_mint_authority();
+
+    // Creates the ticket account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        ticket_info,
+        &[TICKET_SEED, mint_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(TICKET_SEED),
+        Seed::from(mint_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(organizer_info, ticket_info, Ticket::LEN, &seeds)?;
+
+    // Initializes the ticket.
+
+    // SAFETY: single mutable borrow to `ticket_info` account data; the account was
+    // created above with the expected length.
+    let ticket = unsafe { load_mut_unchecked::<Ticket>(ticket_info.borrow_mut_data_unchecked())? };
+
+    ticket.set_initialized();
+    ticket.bump = bump;
+    ticket.organizer = *organizer_info.key();
+    ticket.mint = *mint_info.key();
+    ticket.set_event_id(event_id);
+    ticket.set_seat(seat);
+
+    Ok(())
+}
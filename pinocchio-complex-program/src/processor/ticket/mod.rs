@@ -0,0 +1,58 @@
+//! Event tickets.
+//!
+//! A ticket is a token of a mint with a fixed supply of one, paired with a ticket
+//! account holding the event and seat of the ticket. Holding the token proves the
+//! ownership of the ticket, while the ticket account records whether it has been
+//! used.
+//!
+//! Instructions:
+//!
+//! - `0`: `MintTicket`
+//! - `1`: `Validate`
+//! - `2`: `Punch`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod mint_ticket;
+pub mod punch;
+pub mod validate;
+
+pub use mint_ticket::process_mint_ticket;
+pub use punch::process_punch;
+pub use validate::process_validate;
+
+/// Process a ticket instruction.
+#[inline(always)]
+pub fn process_ticket_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - MintTicket
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: MintTicket");
+
+            process_mint_ticket(accounts, instruction_data)
+        }
+        // 1 - Validate
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Validate");
+
+            process_validate(accounts)
+        }
+        // 2 - Punch
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Punch");
+
+            process_punch(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
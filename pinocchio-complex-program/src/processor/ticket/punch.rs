@@ -0,0 +1,97 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load, load_mut},
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, shared},
+    state::ticket::Ticket,
+};
+
+/// Punches a ticket, marking it as used.
+///
+/// The holder must sign to present the ticket. When requested, the ticket token
+/// is also burned.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The ticket account.
+/// 1. `[signer]` The event organizer.
+/// 2. `[writable]` The holder token account.
+/// 3. `[writable]` The ticket mint.
+/// 4. `[signer]` The ticket holder.
+#[inline(always)]
+pub fn process_punch(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1)
+    let [burn] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [ticket_info, organizer_info, holder_account_info, mint_info, holder_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(ticket_info)?;
+    // SAFETY: single mutable borrow to `ticket_info` account data and
+    // `load_mut` validates that the ticket is initialized.
+    let ticket = unsafe { load_mut::<Ticket>(ticket_info.borrow_mut_data_unchecked())? };
+
+    if &ticket.organizer != organizer_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(organizer_info);
+    require_signer!(holder_info);
+
+    if mint_info.key() != &ticket.mint {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if ticket.is_used() {
+        return Err(ExtensionError::TicketUsed.into());
+    }
+
+    // Checks that the holder presented the ticket.
+
+    {
+        check_account_owner(holder_account_info)?;
+        // SAFETY: scoped immutable borrow to `holder_account_info` account data and
+        // `load` validates that the account is initialized.
+        let holder_account =
+            unsafe { load::<Account>(holder_account_info.borrow_data_unchecked())? };
+
+        if holder_account.mint != ticket.mint {
+            return Err(ExtensionError::AccountMismatch.into());
+        }
+
+        if &holder_account.owner != holder_info.key() {
+            return Err(TokenError::OwnerMismatch.into());
+        }
+
+        if holder_account.amount() != 1 {
+            return Err(TokenError::InsufficientFunds.into());
+        }
+    }
+
+    ticket.set_used(true);
+
+    // Burns the ticket token.
+
+    if *burn != 0 {
+        shared::burn::process_burn(
+            &[
+                holder_account_info.clone(),
+                mint_info.clone(),
+                holder_info.clone(),
+            ],
+            1,
+            None,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load},
+};
+
+use crate::{
+    error::ExtensionError, processor::check_account_owner, return_data, state::ticket::Ticket,
+};
+
+/// Validates that a token account holds a ticket, without modifying any account.
+///
+/// The event id (`u64`), seat (`u32`) and used flag (`u8`) of the ticket are set
+/// as the payload of the return data envelope of the instruction, so other programs can check the ticket
+/// through a CPI.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The ticket account.
+/// 1. `[]` The holder token account.
+#[inline(always)]
+pub fn process_validate(accounts: &[AccountInfo]) -> ProgramResult {
+    let [ticket_info, holder_account_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(ticket_info)?;
+    // SAFETY: scoped immutable borrow to `ticket_info` account data and
+    // `load` validates that the ticket is initialized.
+    let ticket = unsafe { load::<Ticket>(ticket_info.borrow_data_unchecked())? };
+
+    check_account_owner(holder_account_info)?;
+    // SAFETY: scoped immutable borrow to `holder_account_info` account data and
+    // `load` validates that the account is initialized.
+    let holder_account = unsafe { load::<Account>(holder_account_info.borrow_data_unchecked())? };
+
+    if holder_account.mint != ticket.mint {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if holder_account.amount() != 1 {
+        return Err(TokenError::InsufficientFunds.into());
+    }
+
+    let mut payload = [0u8; 13];
+    payload[..8].copy_from_slice(&ticket.event_id().to_le_bytes());
+    payload[8..12].copy_from_slice(&ticket.seat().to_le_bytes());
+    payload[12] = ticket.is_used() as u8;
+
+    return_data::set(&payload)
+}
@@ -0,0 +1,53 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use super::shared;
+use crate::{error::ExtensionError, parse::Cursor, sysvar::clock};
+
+/// Transfers tokens once the clock reaches a threshold.
+///
+/// The clock value compared against the threshold is selected by the gate kind:
+///
+/// - `0`: Unix timestamp (the threshold is read as an `i64`)
+/// - `1`: slot
+/// - `2`: epoch
+///
+/// The instruction lets a signed transaction be submitted ahead of time and only
+/// take effect after the threshold, without holding the tokens in custody.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The source account.
+/// 1. `[writable]` The destination account.
+///
+/// Single owner/delegate:
+///
+/// 2. `[signer]` The source account's owner/delegate.
+///
+/// Multisignature owner/delegate:
+///
+/// 2. `[]` The source account's multisignature owner/delegate.
+/// 3. ..3+M `[signer]` M signer accounts.
+#[inline(always)]
+pub fn process_time_gated_transfer(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    let kind = cursor.read_u8()?;
+    let threshold = *cursor.read_array::<8>()?;
+    cursor.finish()?;
+
+    let reached = match kind {
+        0 => clock::unix_timestamp()? >= i64::from_le_bytes(threshold),
+        1 => clock::slot()? >= u64::from_le_bytes(threshold),
+        2 => clock::epoch()? >= u64::from_le_bytes(threshold),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if !reached {
+        return Err(ExtensionError::TimeGateNotReached.into());
+    }
+
+    shared::transfer::process_transfer(accounts, amount, None)
+}
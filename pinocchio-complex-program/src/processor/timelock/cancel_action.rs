@@ -0,0 +1,21 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use super::validate_action;
+
+/// Cancels a queued action, closing the action account.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The timelock account.
+/// 1. `[writable]` The action account.
+/// 2. `[writable, signer]` The timelock admin, which receives the account lamports.
+#[inline(always)]
+pub fn process_cancel_action(accounts: &[AccountInfo]) -> ProgramResult {
+    let [timelock_info, action_info, admin_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    validate_action(timelock_info, action_info, admin_info)?;
+
+    close_extension_account(action_info, admin_info)
+}
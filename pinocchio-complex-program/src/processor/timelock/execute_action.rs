@@ -0,0 +1,105 @@
+use core::{mem::MaybeUninit, slice::from_raw_parts};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Account, AccountMeta, Instruction, Seed, Signer},
+    program::invoke_signed_unchecked,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{program::ID as TOKEN_PROGRAM_ID, state::load};
+
+use super::{action_hash, is_action_signer, validate_action};
+use crate::{
+    error::ExtensionError,
+    processor::close_extension_account,
+    state::timelock::{Timelock, MAX_ACTION_ACCOUNTS, TIMELOCK_SEED},
+};
+
+/// An uninitialized account meta.
+const UNINIT_ACCOUNT_META: MaybeUninit<AccountMeta> = MaybeUninit::uninit();
+
+/// An uninitialized CPI account.
+const UNINIT_ACCOUNT: MaybeUninit<Account> = MaybeUninit::uninit();
+
+/// Executes a queued action once its execution time has been reached, closing the
+/// action account.
+///
+/// The instruction data is the data of the instruction of this program to execute,
+/// which is invoked with the timelock as a signer.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The timelock account.
+/// 1. `[writable]` The action account.
+/// 2. `[writable, signer]` The timelock admin, which receives the account lamports.
+/// 3. ..3+N `[]` The N accounts of the instruction.
+#[inline(always)]
+pub fn process_execute_action(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let [timelock_info, action_info, admin_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let (eta, hash) = validate_action(timelock_info, action_info, admin_info)?;
+
+    if action_hash(instruction_data, remaining, timelock_info.key())? != hash {
+        return Err(ExtensionError::InvalidActionHash.into());
+    }
+
+    if Clock::get()?.unix_timestamp < eta {
+        return Err(ExtensionError::TimelockNotElapsed.into());
+    }
+
+    // SAFETY: scoped immutable borrow to `timelock_info` account data, which was
+    // validated above.
+    let bump = unsafe { load::<Timelock>(timelock_info.borrow_data_unchecked())?.bump };
+
+    // Closes the action account before invoking the instruction to prevent it from
+    // being executed again through reentrancy.
+    close_extension_account(action_info, admin_info)?;
+
+    // Builds the instruction.
+
+    let mut account_metas = [UNINIT_ACCOUNT_META; MAX_ACTION_ACCOUNTS];
+    let mut cpi_accounts = [UNINIT_ACCOUNT; MAX_ACTION_ACCOUNTS];
+
+    for (i, account_info) in remaining.iter().enumerate() {
+        account_metas[i].write(AccountMeta::new(
+            account_info.key(),
+            account_info.is_writable(),
+            is_action_signer(account_info, timelock_info.key()),
+        ));
+        cpi_accounts[i].write(Account::from(account_info));
+    }
+
+    // SAFETY: the first `remaining.len()` elements of `account_metas` and
+    // `cpi_accounts` are initialized above; `action_hash` validates that there are
+    // at most `MAX_ACTION_ACCOUNTS` accounts.
+    let (account_metas, cpi_accounts) = unsafe {
+        (
+            from_raw_parts(
+                account_metas.as_ptr() as *const AccountMeta,
+                remaining.len(),
+            ),
+            from_raw_parts(cpi_accounts.as_ptr() as *const Account, remaining.len()),
+        )
+    };
+
+    let instruction = Instruction {
+        program_id: &TOKEN_PROGRAM_ID,
+        accounts: account_metas,
+        data: instruction_data,
+    };
+
+    // Invokes the instruction signed by the timelock.
+
+    let bump_seed = [bump];
+    let seeds = [
+        Seed::from(TIMELOCK_SEED),
+        Seed::from(admin_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    // SAFETY: the accounts of the instruction are not borrowed by this processor.
+    unsafe { invoke_signed_unchecked(&instruction, cpi_accounts, &[Signer::from(&seeds)]) }
+}
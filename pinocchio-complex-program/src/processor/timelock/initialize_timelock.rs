@@ -0,0 +1,66 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account},
+    state::timelock::{Timelock, TIMELOCK_SEED},
+};
+
+/// Initializes a new timelock.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The admin of the timelock, which pays for the account.
+/// 1. `[writable]` The timelock account.
+/// 2. `[]` The system program.
+#[inline(always)]
+pub fn process_initialize_timelock(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let delay = cursor.read_i64()?;
+    cursor.finish()?;
+
+    if delay < 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [admin_info, timelock_info, _system_program_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the timelock account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        timelock_info,
+        &[TIMELOCK_SEED, admin_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(TIMELOCK_SEED),
+        Seed::from(admin_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(admin_info, timelock_info, Timelock::LEN, &seeds)?;
+
+    // Initializes the timelock.
+
+    // SAFETY: single mutable borrow to `timelock_info` account data; the account was
+    // created above with the expected length.
+    let timelock =
+        unsafe { load_mut_unchecked::<Timelock>(timelock_info.borrow_mut_data_unchecked())? };
+
+    timelock.set_initialized();
+    timelock.bump = bump;
+    timelock.admin = *admin_info.key();
+    timelock.set_delay(delay);
+
+    Ok(())
+}
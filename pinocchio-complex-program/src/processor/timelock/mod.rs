@@ -0,0 +1,178 @@
+//! Timelock controller for admin actions.
+//!
+//! Instructions of this program are queued with their hash and an execution time,
+//! and can only be executed once the timelock delay has elapsed. Executed actions
+//! are invoked with the timelock as a signer, so any authority set to the timelock
+//! address (e.g. mint or account authorities) is subject to the delay.
+//!
+//! Instructions:
+//!
+//! - `0`: `InitializeTimelock`
+//! - `1`: `QueueAction`
+//! - `2`: `ExecuteAction`
+//! - `3`: `CancelAction`
+
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+use token_interface::{error::TokenError, state::load};
+
+use crate::{
+    error::ExtensionError,
+    hash::sha256,
+    processor::check_account_owner,
+    state::timelock::{Timelock, TimelockAction, MAX_ACTION_ACCOUNTS},
+};
+
+pub mod cancel_action;
+pub mod execute_action;
+pub mod initialize_timelock;
+pub mod queue_action;
+
+pub use cancel_action::process_cancel_action;
+pub use execute_action::process_execute_action;
+pub use initialize_timelock::process_initialize_timelock;
+pub use queue_action::process_queue_action;
+
+/// Flag of an action account that signs the instruction.
+const SIGNER_FLAG: u8 = 1;
+
+/// Flag of an action account that is writable.
+const WRITABLE_FLAG: u8 = 2;
+
+/// Process a timelock instruction.
+#[inline(always)]
+pub fn process_timelock_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - InitializeTimelock
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializeTimelock");
+
+            process_initialize_timelock(accounts, instruction_data)
+        }
+        // 1 - QueueAction
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: QueueAction");
+
+            process_queue_action(accounts, instruction_data)
+        }
+        // 2 - ExecuteAction
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ExecuteAction");
+
+            process_execute_action(accounts, instruction_data)
+        }
+        // 3 - CancelAction
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CancelAction");
+
+            process_cancel_action(accounts)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Computes the hash of an action, the SHA-256 of:
+///
+/// - the length of the instruction data (2 bytes, little-endian)
+/// - the instruction data
+/// - the number of instruction accounts (1 byte)
+/// - for each instruction account, its key followed by its flags (1 byte:
+///   `SIGNER_FLAG | WRITABLE_FLAG`)
+///
+/// The lengths make the hash identify a single instruction, and the flags are
+/// those the instruction is invoked with, so they cannot be changed by the
+/// executor of the action.
+#[inline(always)]
+fn action_hash(
+    data: &[u8],
+    accounts: &[AccountInfo],
+    timelock: &Pubkey,
+) -> Result<[u8; 32], ProgramError> {
+    if accounts.len() > MAX_ACTION_ACCOUNTS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let data_len = u16::try_from(data.len())
+        .map_err(|_error| ProgramError::InvalidInstructionData)?
+        .to_le_bytes();
+    let account_count = [accounts.len() as u8];
+
+    let mut flags = [0u8; MAX_ACTION_ACCOUNTS];
+
+    for (flag, account_info) in flags.iter_mut().zip(accounts.iter()) {
+        if is_action_signer(account_info, timelock) {
+            *flag |= SIGNER_FLAG;
+        }
+        if account_info.is_writable() {
+            *flag |= WRITABLE_FLAG;
+        }
+    }
+
+    let mut values: [&[u8]; 3 + 2 * MAX_ACTION_ACCOUNTS] = [&[]; 3 + 2 * MAX_ACTION_ACCOUNTS];
+    values[0] = &data_len;
+    values[1] = data;
+    values[2] = &account_count;
+
+    for ((value, account_info), flag) in values[3..]
+        .chunks_exact_mut(2)
+        .zip(accounts.iter())
+        .zip(flags.iter())
+    {
+        value[0] = account_info.key();
+        value[1] = core::slice::from_ref(flag);
+    }
+
+    Ok(sha256(&values[..3 + 2 * accounts.len()]))
+}
+
+/// Returns whether an action account signs the instruction, either as a signer of
+/// the transaction or as the timelock, which signs through its derived address.
+#[inline(always)]
+fn is_action_signer(account_info: &AccountInfo, timelock: &Pubkey) -> bool {
+    account_info.is_signer() || account_info.key() == timelock
+}
+
+/// Validates that the action belongs to the timelock and that the admin signed the
+/// instruction.
+///
+/// Returns the execution time and hash of the action.
+#[inline(always)]
+fn validate_action(
+    timelock_info: &AccountInfo,
+    action_info: &AccountInfo,
+    admin_info: &AccountInfo,
+) -> Result<(i64, [u8; 32]), ProgramError> {
+    check_account_owner(timelock_info)?;
+    // SAFETY: scoped immutable borrow to `timelock_info` account data and
+    // `load` validates that the timelock is initialized.
+    let timelock = unsafe { load::<Timelock>(timelock_info.borrow_data_unchecked())? };
+
+    if &timelock.admin != admin_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(admin_info);
+
+    check_account_owner(action_info)?;
+    // SAFETY: scoped immutable borrow to `action_info` account data and
+    // `load` validates that the action is initialized.
+    let action = unsafe { load::<TimelockAction>(action_info.borrow_data_unchecked())? };
+
+    if &action.timelock != timelock_info.key() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    Ok((action.eta(), action.hash))
+}
@@ -0,0 +1,97 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load, load_mut_unchecked, RawType},
+};
+
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_account_owner, check_derived_address, create_derived_account},
+    state::timelock::{Timelock, TimelockAction, TIMELOCK_ACTION_SEED},
+};
+
+/// Queues an action to be executed after the timelock delay.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The timelock account.
+/// 1. `[writable]` The action account.
+/// 2. `[writable, signer]` The timelock admin, which pays for the account.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_queue_action(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let hash = cursor.read_array::<32>()?;
+    let eta = cursor.read_i64()?;
+    cursor.finish()?;
+
+    let [timelock_info, action_info, admin_info, _system_program_info, _remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(timelock_info)?;
+    // SAFETY: single immutable borrow to `timelock_info` account data and
+    // `load` validates that the timelock is initialized.
+    let timelock = unsafe { load::<Timelock>(timelock_info.borrow_data_unchecked())? };
+
+    if &timelock.admin != admin_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(admin_info);
+
+    let earliest = Clock::get()?
+        .unix_timestamp
+        .checked_add(timelock.delay())
+        .ok_or(TokenError::Overflow)?;
+
+    if eta < earliest {
+        return Err(ExtensionError::InvalidEta.into());
+    }
+
+    // Creates the action account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        action_info,
+        &[
+            TIMELOCK_ACTION_SEED,
+            timelock_info.key().as_ref(),
+            hash,
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(TIMELOCK_ACTION_SEED),
+        Seed::from(timelock_info.key()),
+        Seed::from(hash),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(admin_info, action_info, TimelockAction::LEN, &seeds)?;
+
+    // Initializes the action.
+
+    // SAFETY: single mutable borrow to `action_info` account data; the account was
+    // created above with the expected length.
+    let action =
+        unsafe { load_mut_unchecked::<TimelockAction>(action_info.borrow_mut_data_unchecked())? };
+
+    action.set_initialized();
+    action.bump = bump;
+    action.timelock = *timelock_info.key();
+    action.hash = *hash;
+    action.set_eta(eta);
+
+    Ok(())
+}
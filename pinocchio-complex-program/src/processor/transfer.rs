@@ -1,14 +1,22 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio::{account_info::AccountInfo, ProgramResult};
 
 use super::shared;
 
 #[inline(always)]
-pub fn process_transfer(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
-    let amount = u64::from_le_bytes(
-        instruction_data
-            .try_into()
-            .map_err(|_error| ProgramError::InvalidInstructionData)?,
-    );
-
-    shared::transfer::process_transfer(accounts, amount, None)
+pub fn process_transfer(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    shared::transfer::process_transfer(accounts, amount, None)?;
+
+    #[cfg(feature = "events")]
+    if let [source_account_info, destination_account_info, ..] = accounts {
+        crate::events::emit(
+            accounts,
+            &crate::events::TransferEvent {
+                source: source_account_info.key(),
+                destination: destination_account_info.key(),
+                amount,
+            },
+        )?;
+    }
+
+    Ok(())
 }
@@ -0,0 +1,78 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+use token_interface::{
+    program::ID as TOKEN_PROGRAM_ID,
+    state::{load_mut_unchecked, RawType},
+};
+
+use super::validate_admin;
+use crate::{
+    error::ExtensionError,
+    processor::create_derived_account,
+    state::transfer_gate::{GateEntry, GATE_ENTRY_SEED},
+};
+
+/// Adds an address to the list of a transfer gate.
+///
+/// The entry account must be the canonical entry address, which is the address
+/// checked by `GatedTransfer`.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The transfer gate account.
+/// 1. `[writable]` The entry account.
+/// 2. `[writable, signer]` The gate admin, which pays for the account.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_add_entry(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected [u8; 32] (32)
+    let address: &Pubkey = instruction_data
+        .try_into()
+        .map_err(|_error| ProgramError::InvalidInstructionData)?;
+
+    let [gate_info, entry_info, admin_info, _system_program_info, _remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    validate_admin(gate_info, admin_info)?;
+
+    // Creates the entry account.
+
+    let (expected, bump) = find_program_address(
+        &[GATE_ENTRY_SEED, gate_info.key().as_ref(), address.as_ref()],
+        &TOKEN_PROGRAM_ID,
+    );
+
+    if entry_info.key() != &expected {
+        return Err(ExtensionError::DerivedAddressMismatch.into());
+    }
+
+    let bump_seed = [bump];
+    let seeds = [
+        Seed::from(GATE_ENTRY_SEED),
+        Seed::from(gate_info.key()),
+        Seed::from(address),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(admin_info, entry_info, GateEntry::LEN, &seeds)?;
+
+    // Initializes the entry.
+
+    // SAFETY: single mutable borrow to `entry_info` account data; the account was
+    // created above with the expected length.
+    let entry = unsafe { load_mut_unchecked::<GateEntry>(entry_info.borrow_mut_data_unchecked())? };
+
+    entry.set_initialized();
+    entry.bump = bump;
+    entry.transfer_gate = *gate_info.key();
+    entry.address = *address;
+
+    Ok(())
+}
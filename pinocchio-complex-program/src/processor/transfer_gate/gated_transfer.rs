@@ -0,0 +1,83 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::{account::Account, load};
+
+use super::is_listed;
+use crate::{
+    error::ExtensionError,
+    parse::Cursor,
+    processor::{check_account_owner, shared},
+    state::transfer_gate::{TransferGate, ALLOWLIST},
+};
+
+/// Transfers tokens with `TransferChecked` when the owners of the source and
+/// destination accounts are allowed by the transfer gate.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The transfer gate account.
+/// 1. `[]` The entry account of the source account owner.
+/// 2. `[]` The entry account of the destination account owner.
+/// 3. `[writable]` The source account.
+/// 4. `[]` The token mint.
+/// 5. `[writable]` The destination account.
+/// 6. `[signer]` The source account's owner/delegate.
+/// 7. ..7+M `[signer]` M signer accounts, if the authority is a multisig.
+#[inline(always)]
+pub fn process_gated_transfer(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    let decimals = cursor.read_u8()?;
+    cursor.finish()?;
+
+    let [gate_info, source_entry_info, destination_entry_info, transfer_accounts @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let [source_account_info, mint_info, destination_account_info, _authority_info, _remaining @ ..] =
+        transfer_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(gate_info)?;
+    // SAFETY: scoped immutable borrow to `gate_info` account data and
+    // `load` validates that the gate is initialized.
+    let gate = unsafe { load::<TransferGate>(gate_info.borrow_data_unchecked())? };
+
+    if &gate.mint != mint_info.key() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // Checks the owners of the source and destination accounts against the list.
+
+    check_account_owner(source_account_info)?;
+    // SAFETY: scoped immutable borrow to `source_account_info` account data and
+    // `load` validates that the account is initialized.
+    let source_owner =
+        unsafe { load::<Account>(source_account_info.borrow_data_unchecked())?.owner };
+
+    check_account_owner(destination_account_info)?;
+    // SAFETY: scoped immutable borrow to `destination_account_info` account data and
+    // `load` validates that the account is initialized.
+    let destination_owner =
+        unsafe { load::<Account>(destination_account_info.borrow_data_unchecked())?.owner };
+
+    let listed = (
+        is_listed(gate_info, source_entry_info, &source_owner)?,
+        is_listed(gate_info, destination_entry_info, &destination_owner)?,
+    );
+
+    let allowed = if gate.mode == ALLOWLIST {
+        listed == (true, true)
+    } else {
+        listed == (false, false)
+    };
+
+    if !allowed {
+        return Err(ExtensionError::TransferNotAllowed.into());
+    }
+
+    // The mint and decimals are validated by the transfer.
+    shared::transfer::process_transfer(transfer_accounts, amount, Some(decimals))
+}
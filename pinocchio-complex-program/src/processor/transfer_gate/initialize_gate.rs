@@ -0,0 +1,64 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    processor::{check_derived_address, create_derived_account},
+    state::transfer_gate::{TransferGate, DENYLIST, TRANSFER_GATE_SEED},
+};
+
+/// Initializes the transfer gate of a mint.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The admin of the gate, which pays for the account.
+/// 1. `[writable]` The transfer gate account.
+/// 2. `[]` The mint of the gated token.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_initialize_gate(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1) + u8 (1)
+    let [bump, mode] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    if *mode > DENYLIST {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [admin_info, gate_info, mint_info, _system_program_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the gate account.
+
+    let bump_seed = [*bump];
+    check_derived_address(
+        gate_info,
+        &[TRANSFER_GATE_SEED, mint_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(TRANSFER_GATE_SEED),
+        Seed::from(mint_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(admin_info, gate_info, TransferGate::LEN, &seeds)?;
+
+    // Initializes the gate.
+
+    // SAFETY: single mutable borrow to `gate_info` account data; the account was
+    // created above with the expected length.
+    let gate =
+        unsafe { load_mut_unchecked::<TransferGate>(gate_info.borrow_mut_data_unchecked())? };
+
+    gate.set_initialized();
+    gate.bump = *bump;
+    gate.mode = *mode;
+    gate.mint = *mint_info.key();
+    gate.admin = *admin_info.key();
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+//! Allowlist and denylist gate for token transfers.
+//!
+//! A transfer gate keeps a list of addresses for a mint, maintained by the gate
+//! admin, and the `GatedTransfer` instruction only performs a `TransferChecked` when
+//! the owners of the source and destination accounts satisfy the gate mode: both are
+//! listed for an allowlist, and neither is listed for a denylist.
+//!
+//! Note that the gate only applies to `GatedTransfer`; permissioned tokens are
+//! expected to keep their accounts frozen and move tokens through the gate.
+//!
+//! Instructions:
+//!
+//! - `0`: `InitializeGate`
+//! - `1`: `AddEntry`
+//! - `2`: `RemoveEntry`
+//! - `3`: `GatedTransfer`
+
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    program::ID as TOKEN_PROGRAM_ID,
+    state::{load, RawType},
+};
+
+use crate::{
+    error::ExtensionError,
+    processor::check_account_owner,
+    state::transfer_gate::{GateEntry, TransferGate, GATE_ENTRY_SEED},
+};
+
+pub mod add_entry;
+pub mod gated_transfer;
+pub mod initialize_gate;
+pub mod remove_entry;
+
+pub use add_entry::process_add_entry;
+pub use gated_transfer::process_gated_transfer;
+pub use initialize_gate::process_initialize_gate;
+pub use remove_entry::process_remove_entry;
+
+/// Process a transfer gate instruction.
+#[inline(always)]
+pub fn process_transfer_gate_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - InitializeGate
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializeGate");
+
+            process_initialize_gate(accounts, instruction_data)
+        }
+        // 1 - AddEntry
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: AddEntry");
+
+            process_add_entry(accounts, instruction_data)
+        }
+        // 2 - RemoveEntry
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: RemoveEntry");
+
+            process_remove_entry(accounts)
+        }
+        // 3 - GatedTransfer
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: GatedTransfer");
+
+            process_gated_transfer(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Checks that `admin_info` is the gate admin and signed the instruction.
+#[inline(always)]
+fn validate_admin(gate_info: &AccountInfo, admin_info: &AccountInfo) -> ProgramResult {
+    check_account_owner(gate_info)?;
+    // SAFETY: scoped immutable borrow to `gate_info` account data and
+    // `load` validates that the gate is initialized.
+    let gate = unsafe { load::<TransferGate>(gate_info.borrow_data_unchecked())? };
+
+    if &gate.admin != admin_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(admin_info);
+
+    Ok(())
+}
+
+/// Checks whether `address` is listed in the gate.
+///
+/// The entry account must be the canonical entry address, since an address is not
+/// listed when its entry account does not exist.
+#[inline(always)]
+fn is_listed(
+    gate_info: &AccountInfo,
+    entry_info: &AccountInfo,
+    address: &Pubkey,
+) -> Result<bool, ProgramError> {
+    let (expected, _) = find_program_address(
+        &[GATE_ENTRY_SEED, gate_info.key().as_ref(), address.as_ref()],
+        &TOKEN_PROGRAM_ID,
+    );
+
+    if entry_info.key() != &expected {
+        return Err(ExtensionError::DerivedAddressMismatch.into());
+    }
+
+    Ok(entry_info.owner() == &TOKEN_PROGRAM_ID && entry_info.data_len() == GateEntry::LEN)
+}
@@ -0,0 +1,36 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::load;
+
+use super::validate_admin;
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, close_extension_account},
+    state::transfer_gate::GateEntry,
+};
+
+/// Removes an address from the list of a transfer gate, closing the entry account.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The transfer gate account.
+/// 1. `[writable]` The entry account.
+/// 2. `[writable, signer]` The gate admin, which receives the account lamports.
+#[inline(always)]
+pub fn process_remove_entry(accounts: &[AccountInfo]) -> ProgramResult {
+    let [gate_info, entry_info, admin_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    validate_admin(gate_info, admin_info)?;
+
+    check_account_owner(entry_info)?;
+    // SAFETY: scoped immutable borrow to `entry_info` account data and
+    // `load` validates that the entry is initialized.
+    let entry = unsafe { load::<GateEntry>(entry_info.borrow_data_unchecked())? };
+
+    if &entry.transfer_gate != gate_info.key() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    close_extension_account(entry_info, admin_info)
+}
@@ -0,0 +1,73 @@
+use core::cmp::Ordering;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use super::shared;
+use crate::{error::ExtensionError, oracle, parse::Cursor, sysvar::clock};
+
+/// Transfers tokens when an oracle price meets a condition.
+///
+/// The price is read from an oracle price account, which must have been published
+/// at most `max_age` seconds ago and have a confidence interval of at most
+/// `max_conf_bps` basis points of the price. The condition compares the price with
+/// a threshold of `threshold * 10^threshold_exponent`:
+///
+/// - `0`: the price is greater than or equal to the threshold
+/// - `1`: the price is less than or equal to the threshold
+///
+/// Accounts expected:
+///
+/// 0. `[]` The oracle price account: a Pyth `PriceUpdateV2` account or, with the
+///    `switchboard` feature, a Switchboard pull feed account.
+/// 1. `[writable]` The source account.
+/// 2. `[writable]` The destination account.
+///
+/// Single owner/delegate:
+///
+/// 3. `[signer]` The source account's owner/delegate.
+///
+/// Multisignature owner/delegate:
+///
+/// 3. `[]` The source account's multisignature owner/delegate.
+/// 4. ..4+M `[signer]` M signer accounts.
+#[inline(always)]
+pub fn process_transfer_if_price(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    let feed_id = cursor.read_array::<32>()?;
+    let max_age = cursor.read_u64()?;
+    let max_conf_bps = cursor.read_u16()?;
+    let condition = cursor.read_u8()?;
+    let threshold = cursor.read_i64()?;
+    let threshold_exponent = cursor.read_i32()?;
+    cursor.finish()?;
+
+    let [price_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Checks the price condition.
+
+    let price = oracle::load_price(price_info, feed_id)?;
+
+    price.check_staleness(clock::unix_timestamp()?, max_age)?;
+    price.check_confidence(max_conf_bps)?;
+
+    let ordering = price
+        .compare(threshold, threshold_exponent)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let met = match condition {
+        0 => ordering != Ordering::Less,
+        1 => ordering != Ordering::Greater,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if !met {
+        return Err(ExtensionError::PriceConditionNotMet.into());
+    }
+
+    shared::transfer::process_transfer(remaining, amount, None)
+}
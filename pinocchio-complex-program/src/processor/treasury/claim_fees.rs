@@ -0,0 +1,49 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::error::TokenError;
+
+use super::check_treasury;
+use crate::processor::{config::load_config, shared::custody};
+
+use crate::parse::Cursor;
+
+/// Claims protocol fees from a treasury vault.
+///
+/// Accounts expected:
+///
+/// 0. `[]` The treasury account.
+/// 1. `[writable]` The treasury vault.
+/// 2. `[writable]` The destination token account.
+/// 3. `[]` The config account.
+/// 4. `[signer]` The config fee recipient.
+#[inline(always)]
+pub fn process_claim_fees(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [treasury_info, treasury_vault_info, destination_account_info, config_info, fee_authority_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: scoped immutable borrow to `config_info` account data.
+    let fee_recipient = unsafe { load_config(config_info)?.fee_recipient };
+
+    if &fee_recipient != fee_authority_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(fee_authority_info);
+
+    check_treasury(treasury_info)?;
+
+    // Moves the fees out of the treasury vault.
+
+    custody::transfer(
+        treasury_vault_info,
+        destination_account_info,
+        treasury_info.key(),
+        amount,
+    )
+}
@@ -0,0 +1,52 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    processor::{check_derived_address, create_derived_account},
+    state::treasury::{Treasury, TREASURY_SEED},
+};
+
+/// Creates the protocol fee treasury.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the treasury account.
+/// 1. `[writable]` The treasury account.
+/// 2. `[]` The system program.
+#[inline(always)]
+pub fn process_initialize_treasury(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected u8 (1)
+    let [bump] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [payer_info, treasury_info, _system_program_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the treasury account.
+
+    let bump_seed = [*bump];
+    check_derived_address(treasury_info, &[TREASURY_SEED, &bump_seed])?;
+
+    let seeds = [Seed::from(TREASURY_SEED), Seed::from(&bump_seed)];
+
+    create_derived_account(payer_info, treasury_info, Treasury::LEN, &seeds)?;
+
+    // Initializes the treasury.
+
+    // SAFETY: single mutable borrow to `treasury_info` account data; the account was
+    // created above with the expected length.
+    let treasury =
+        unsafe { load_mut_unchecked::<Treasury>(treasury_info.borrow_mut_data_unchecked())? };
+
+    treasury.set_initialized();
+    treasury.bump = *bump;
+
+    Ok(())
+}
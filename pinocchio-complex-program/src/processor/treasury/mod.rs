@@ -0,0 +1,227 @@
+//! Protocol fee treasury.
+//!
+//! Fee-charging instructions take the config fee, in basis points, and move it into
+//! a treasury vault, minus the referral share paid to the referrer of the fee
+//! payer, if any. The fee accounts are required after their own accounts (see
+//! [`protocol_fee`]); since the config and the treasury are single derived
+//! addresses of the program, they cannot be substituted to skip the fee. The
+//! config fee recipient, acting as the fee authority, claims the accumulated fees.
+//!
+//! Treasury vaults are regular token accounts with the treasury derived address as
+//! their owner.
+//!
+//! Instructions:
+//!
+//! - `0`: `InitializeTreasury`
+//! - `1`: `ClaimFees`
+//! - `2`: `SetReferralShare`
+
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load},
+};
+
+use crate::{
+    error::ExtensionError,
+    math::{self, Rounding},
+    processor::{check_account_owner, check_derived_address, config::load_config},
+    state::{
+        referral::{Referral, REFERRAL_SEED},
+        treasury::{Treasury, TREASURY_SEED},
+    },
+};
+
+pub mod claim_fees;
+pub mod initialize_treasury;
+pub mod set_referral_share;
+
+pub use claim_fees::process_claim_fees;
+pub use initialize_treasury::process_initialize_treasury;
+pub use set_referral_share::process_set_referral_share;
+
+/// Maximum protocol fee in basis points.
+const MAX_FEE_BPS: u16 = 10_000;
+
+/// Process a treasury instruction.
+#[inline(always)]
+pub fn process_treasury_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - InitializeTreasury
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializeTreasury");
+
+            process_initialize_treasury(accounts, instruction_data)
+        }
+        // 1 - ClaimFees
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ClaimFees");
+
+            process_claim_fees(accounts, instruction_data)
+        }
+        // 2 - SetReferralShare
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SetReferralShare");
+
+            process_set_referral_share(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Checks that `treasury_info` is the initialized treasury derived address,
+/// returning its referral share.
+#[inline(always)]
+fn check_treasury(treasury_info: &AccountInfo) -> Result<u16, ProgramError> {
+    check_account_owner(treasury_info)?;
+
+    // SAFETY: scoped immutable borrow to `treasury_info` account data and
+    // `load` validates that the treasury is initialized.
+    let treasury = unsafe { load::<Treasury>(treasury_info.borrow_data_unchecked())? };
+
+    check_derived_address(treasury_info, &[TREASURY_SEED, &[treasury.bump]])?;
+
+    Ok(treasury.referral_share_bps())
+}
+
+/// Protocol fee of an amount, split between the treasury and the referrer of the
+/// fee payer.
+pub struct ProtocolFee<'a> {
+    /// Treasury vault receiving the treasury share.
+    pub treasury_vault_info: &'a AccountInfo,
+
+    /// Treasury share of the fee.
+    pub treasury_amount: u64,
+
+    /// Referrer token account and referral share of the fee, if any.
+    pub referral: Option<(&'a AccountInfo, u64)>,
+}
+
+impl<'a> ProtocolFee<'a> {
+    /// Returns the total amount of the fee.
+    #[inline(always)]
+    pub fn amount(&self) -> Result<u64, ProgramError> {
+        self.treasury_amount
+            .checked_add(self.referral.map_or(0, |(_, amount)| amount))
+            .ok_or(TokenError::Overflow.into())
+    }
+
+    /// Returns the accounts receiving the fee with their shares.
+    #[inline(always)]
+    pub fn shares(&self) -> impl Iterator<Item = (&'a AccountInfo, u64)> {
+        core::iter::once((self.treasury_vault_info, self.treasury_amount)).chain(self.referral)
+    }
+}
+
+/// Returns the protocol fee of `amount` paid by `fee_payer`, or `None` when the
+/// config fee is zero.
+///
+/// Fee-charging instructions require the fee accounts after their own accounts:
+///
+/// 0. `[]` The config account.
+/// 1. `[]` The treasury account, unless the config fee is zero.
+/// 2. `[writable]` The treasury vault, unless the config fee is zero.
+/// 3. `[]` (Optional) The referral account of the fee payer.
+/// 4. `[writable]` (Optional) The referrer token account.
+///
+/// The config account is kept in the accounts of the fee-charging instructions, so
+/// the pause flag is checked here instead of before the instruction is dispatched.
+///
+/// The caller moves the fee shares, which validates that the receiving token
+/// accounts hold tokens of the expected mint.
+#[inline(always)]
+pub fn protocol_fee<'a>(
+    fee_accounts: &'a [AccountInfo],
+    fee_payer: &Pubkey,
+    amount: u64,
+) -> Result<Option<ProtocolFee<'a>>, ProgramError> {
+    let [config_info, treasury_accounts @ ..] = fee_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: scoped immutable borrow to `config_info` account data.
+    let config = unsafe { load_config(config_info)? };
+
+    if config.is_paused() {
+        return Err(ExtensionError::Paused.into());
+    }
+
+    let fee_bps = config.fee_bps();
+
+    if fee_bps == 0 {
+        return Ok(None);
+    }
+
+    let [treasury_info, treasury_vault_info, referral_accounts @ ..] = treasury_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let referral_share_bps = check_treasury(treasury_info)?;
+
+    // SAFETY: scoped immutable borrow to `treasury_vault_info` account data and
+    // `load` validates that the account is initialized.
+    let treasury_vault = unsafe { load::<Account>(treasury_vault_info.borrow_data_unchecked())? };
+
+    if &treasury_vault.owner != treasury_info.key() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // `fee_bps <= MAX_FEE_BPS`, so the fee is at most `amount`.
+    let fee = math::bps(amount, fee_bps, Rounding::Down)?;
+
+    // Splits the referral share to the referrer of the fee payer.
+
+    let referral = if let [referral_info, referrer_account_info, ..] = referral_accounts {
+        check_account_owner(referral_info)?;
+        // SAFETY: scoped immutable borrow to `referral_info` account data and
+        // `load` validates that the referral is initialized.
+        let referral = unsafe { load::<Referral>(referral_info.borrow_data_unchecked())? };
+
+        if &referral.referee != fee_payer {
+            return Err(ExtensionError::AccountMismatch.into());
+        }
+
+        check_derived_address(
+            referral_info,
+            &[REFERRAL_SEED, fee_payer.as_ref(), &[referral.bump]],
+        )?;
+
+        // SAFETY: scoped immutable borrow to `referrer_account_info` account data
+        // and `load` validates that the account is initialized.
+        let referrer_account =
+            unsafe { load::<Account>(referrer_account_info.borrow_data_unchecked())? };
+
+        if referrer_account.owner != referral.referrer {
+            return Err(TokenError::OwnerMismatch.into());
+        }
+
+        // `referral_share_bps <= MAX_FEE_BPS`, so the share is at most `fee`.
+        let share = math::bps(fee, referral_share_bps, Rounding::Down)?;
+
+        Some((referrer_account_info, share))
+    } else {
+        None
+    };
+
+    let treasury_amount = fee
+        .checked_sub(referral.map_or(0, |(_, share)| share))
+        .ok_or(TokenError::Overflow)?;
+
+    Ok(Some(ProtocolFee {
+        treasury_vault_info,
+        treasury_amount,
+        referral,
+    }))
+}
@@ -0,0 +1,49 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{error::TokenError, state::load_mut};
+
+use super::{check_treasury, MAX_FEE_BPS};
+use crate::{parse::Cursor, processor::config::load_config, state::treasury::Treasury};
+
+/// Sets the share of the protocol fees paid to referrers.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The treasury account.
+/// 1. `[]` The config account.
+/// 2. `[signer]` The config admin.
+#[inline(always)]
+pub fn process_set_referral_share(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let referral_share_bps = cursor.read_u16()?;
+    cursor.finish()?;
+
+    if referral_share_bps > MAX_FEE_BPS {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let [treasury_info, config_info, admin_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: scoped immutable borrow to `config_info` account data.
+    let admin = unsafe { load_config(config_info)?.admin };
+
+    if &admin != admin_info.key() {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    require_signer!(admin_info);
+
+    check_treasury(treasury_info)?;
+
+    // SAFETY: single mutable borrow to `treasury_info` account data and
+    // `load_mut` validates that the treasury is initialized.
+    let treasury = unsafe { load_mut::<Treasury>(treasury_info.borrow_mut_data_unchecked())? };
+
+    treasury.set_referral_share_bps(referral_share_bps);
+
+    Ok(())
+}
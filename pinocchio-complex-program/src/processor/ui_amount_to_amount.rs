@@ -1,4 +1,3 @@
-use core::str::from_utf8;
 use pinocchio::{
     account_info::AccountInfo, program::set_return_data, program_error::ProgramError, ProgramResult,
 };
@@ -10,13 +9,7 @@ use token_interface::{
 use super::{check_account_owner, try_ui_amount_into_amount};
 
 #[inline(always)]
-pub fn process_ui_amount_to_amount(
-    accounts: &[AccountInfo],
-    instruction_data: &[u8],
-) -> ProgramResult {
-    let ui_amount =
-        from_utf8(instruction_data).map_err(|_error| ProgramError::InvalidInstructionData)?;
-
+pub fn process_ui_amount_to_amount(accounts: &[AccountInfo], ui_amount: &str) -> ProgramResult {
     let mint_info = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
     check_account_owner(mint_info)?;
     // SAFETY: single immutable borrow to `mint_info` account data and
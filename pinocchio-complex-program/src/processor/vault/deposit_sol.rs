@@ -0,0 +1,71 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load_mut, RawType},
+};
+
+use super::{load_or_create_deposit, parse_deposit, shares_for_amount};
+use crate::{balance::LamportBalance, processor::check_account_owner, state::vault::Vault, system};
+
+/// Deposits SOL into the vault in exchange for SOL shares.
+///
+/// The deposit account of the depositor is created on the first deposit.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The vault account.
+/// 1. `[writable]` The deposit account.
+/// 2. `[writable, signer]` The depositor, paying for the deposit account.
+/// 3. `[]` The system program.
+#[inline(always)]
+pub fn process_deposit_sol(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let (amount, bump) = parse_deposit(instruction_data)?;
+
+    let [vault_info, deposit_info, owner_info, _system_program_info, _remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(vault_info)?;
+
+    // The vault assets exclude the lamports required for rent exemption.
+    let total_assets = vault_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(Vault::LEN));
+
+    let deposit = load_or_create_deposit(vault_info, deposit_info, owner_info, bump)?;
+
+    // Moves the lamports into the vault.
+
+    let vault_balance = LamportBalance::snapshot(vault_info);
+
+    system::transfer(owner_info, vault_info, amount, &[])?;
+
+    vault_balance.assert_increase(amount)?;
+
+    // SAFETY: single mutable borrow to `vault_info` account data and
+    // `load_mut` validates that the vault is initialized.
+    let vault = unsafe { load_mut::<Vault>(vault_info.borrow_mut_data_unchecked())? };
+
+    let shares = shares_for_amount(amount, total_assets, vault.total_sol_shares())?;
+
+    deposit.set_sol_shares(
+        deposit
+            .sol_shares()
+            .checked_add(shares)
+            .ok_or(TokenError::Overflow)?,
+    );
+    vault.set_total_sol_shares(
+        vault
+            .total_sol_shares()
+            .checked_add(shares)
+            .ok_or(TokenError::Overflow)?,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load, load_mut},
+};
+
+use super::{load_or_create_deposit, parse_deposit, shares_for_amount};
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, shared},
+    state::vault::Vault,
+};
+
+/// Deposits tokens into the token vault in exchange for token shares.
+///
+/// The deposit account of the depositor is created on the first deposit.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The vault account.
+/// 1. `[writable]` The deposit account.
+/// 2. `[writable]` The source token account.
+/// 3. `[writable]` The token vault.
+/// 4. `[writable, signer]` The source account owner, paying for the deposit account.
+/// 5. `[]` The system program.
+#[inline(always)]
+pub fn process_deposit_token(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let (amount, bump) = parse_deposit(instruction_data)?;
+
+    let [vault_info, deposit_info, _source_account_info, token_vault_info, owner_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(vault_info)?;
+    // SAFETY: single mutable borrow to `vault_info` account data and
+    // `load_mut` validates that the vault is initialized.
+    let vault = unsafe { load_mut::<Vault>(vault_info.borrow_mut_data_unchecked())? };
+
+    if !vault.has_token_vault() || token_vault_info.key() != &vault.token_vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // SAFETY: scoped immutable borrow to `token_vault_info` account data and
+    // `load` validates that the account is initialized.
+    let total_assets =
+        unsafe { load::<Account>(token_vault_info.borrow_data_unchecked())?.amount() };
+
+    let deposit = load_or_create_deposit(vault_info, deposit_info, owner_info, bump)?;
+
+    let shares = shares_for_amount(amount, total_assets, vault.total_token_shares())?;
+
+    // Moves the tokens into the token vault.
+
+    shared::transfer::process_transfer(&accounts[2..5], amount, None)?;
+
+    deposit.set_token_shares(
+        deposit
+            .token_shares()
+            .checked_add(shares)
+            .ok_or(TokenError::Overflow)?,
+    );
+    vault.set_total_token_shares(
+        vault
+            .total_token_shares()
+            .checked_add(shares)
+            .ok_or(TokenError::Overflow)?,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    processor::{check_derived_address, create_derived_account, shared},
+    state::vault::{Vault, VAULT_SEED},
+};
+
+/// Initializes a new vault.
+///
+/// When the token vault and mint accounts are provided, the token vault must be an
+/// uninitialized account owned by the token program; it is initialized with the
+/// vault derived address as its owner. Otherwise the vault only accepts SOL.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the vault account.
+/// 1. `[writable]` The vault account.
+/// 2. `[]` The key used to derive the vault address.
+/// 3. `[]` The system program.
+/// 4. `[writable]` (Optional) The token vault.
+/// 5. `[]` (Optional) The mint of the deposited tokens.
+#[inline(always)]
+pub fn process_initialize_vault(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected u8 (1)
+    let [bump] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [payer_info, vault_info, create_key_info, _system_program_info, remaining @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the vault account.
+
+    let bump_seed = [*bump];
+    check_derived_address(
+        vault_info,
+        &[VAULT_SEED, create_key_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(VAULT_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(payer_info, vault_info, Vault::LEN, &seeds)?;
+
+    // Initializes the token vault under the vault authority.
+
+    let token_vault = if let [token_vault_info, mint_info, ..] = remaining {
+        shared::initialize_account::process_initialize_account(
+            &[token_vault_info.clone(), mint_info.clone()],
+            Some(vault_info.key()),
+            false,
+        )?;
+
+        Some((*token_vault_info.key(), *mint_info.key()))
+    } else {
+        None
+    };
+
+    // Initializes the vault.
+
+    // SAFETY: single mutable borrow to `vault_info` account data; the account was
+    // created above with the expected length.
+    let vault = unsafe { load_mut_unchecked::<Vault>(vault_info.borrow_mut_data_unchecked())? };
+
+    vault.set_initialized();
+    vault.bump = *bump;
+    vault.create_key = *create_key_info.key();
+
+    if let Some((token_vault, mint)) = token_vault {
+        vault.token_vault = token_vault;
+        vault.mint = mint;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,248 @@
+//! SOL and token vault.
+//!
+//! Depositors receive shares of the vault SOL or tokens, which are recorded in a
+//! deposit account per depositor. Withdrawals redeem shares for their proportional
+//! amount of the vault assets, moved out of the custody of the vault derived address.
+//!
+//! Shares are priced with [`VIRTUAL_SHARES`] backed by [`VIRTUAL_ASSETS`], which
+//! are never owned by a depositor. A donation to the vault is mostly captured by
+//! the virtual shares, so inflating the share price to round later deposits down
+//! costs far more than it takes from them. Assets held by a vault without shares
+//! are not captured by the first depositor either.
+//!
+//! Instructions:
+//!
+//! - `0`: `InitializeVault`
+//! - `1`: `DepositSol`
+//! - `2`: `WithdrawSol`
+//! - `3`: `DepositToken`
+//! - `4`: `WithdrawToken`
+
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load_mut, load_mut_unchecked, RawType},
+};
+
+use crate::{
+    error::ExtensionError,
+    math::{self, Rounding},
+    parse::Cursor,
+    processor::{check_account_owner, check_derived_address, create_derived_account},
+    state::vault::{VaultDeposit, VAULT_DEPOSIT_SEED},
+};
+
+pub mod deposit_sol;
+pub mod deposit_token;
+pub mod initialize_vault;
+pub mod withdraw_sol;
+pub mod withdraw_token;
+
+pub use deposit_sol::process_deposit_sol;
+pub use deposit_token::process_deposit_token;
+pub use initialize_vault::process_initialize_vault;
+pub use withdraw_sol::process_withdraw_sol;
+pub use withdraw_token::process_withdraw_token;
+
+/// Process a vault instruction.
+#[inline(always)]
+pub fn process_vault_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - InitializeVault
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: InitializeVault");
+
+            process_initialize_vault(accounts, instruction_data)
+        }
+        // 1 - DepositSol
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: DepositSol");
+
+            process_deposit_sol(accounts, instruction_data)
+        }
+        // 2 - WithdrawSol
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: WithdrawSol");
+
+            process_withdraw_sol(accounts, instruction_data)
+        }
+        // 3 - DepositToken
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: DepositToken");
+
+            process_deposit_token(accounts, instruction_data)
+        }
+        // 4 - WithdrawToken
+        4 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: WithdrawToken");
+
+            process_withdraw_token(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Parses the instruction data of a deposit:
+/// - amount (8 bytes)
+/// - deposit bump (1 byte)
+#[inline(always)]
+fn parse_deposit(instruction_data: &[u8]) -> Result<(u64, u8), ProgramError> {
+    let mut cursor = Cursor::new(instruction_data);
+    let amount = cursor.read_u64()?;
+    let bump = cursor.read_u8()?;
+    cursor.finish()?;
+
+    Ok((amount, bump))
+}
+
+/// Parses the instruction data of a withdrawal:
+/// - shares (8 bytes)
+#[inline(always)]
+fn parse_withdraw(instruction_data: &[u8]) -> Result<u64, ProgramError> {
+    let mut cursor = Cursor::new(instruction_data);
+    let shares = cursor.read_u64()?;
+    cursor.finish()?;
+
+    Ok(shares)
+}
+
+/// Loads the deposit account of `owner_info`, creating it on the first deposit.
+///
+/// The owner pays for the deposit account.
+#[inline(always)]
+fn load_or_create_deposit<'a>(
+    vault_info: &AccountInfo,
+    deposit_info: &'a AccountInfo,
+    owner_info: &AccountInfo,
+    bump: u8,
+) -> Result<&'a mut VaultDeposit, ProgramError> {
+    if deposit_info.data_len() != 0 {
+        return load_deposit(vault_info, deposit_info, owner_info);
+    }
+
+    let bump_seed = [bump];
+    check_derived_address(
+        deposit_info,
+        &[
+            VAULT_DEPOSIT_SEED,
+            vault_info.key().as_ref(),
+            owner_info.key().as_ref(),
+            &bump_seed,
+        ],
+    )?;
+
+    let seeds = [
+        Seed::from(VAULT_DEPOSIT_SEED),
+        Seed::from(vault_info.key()),
+        Seed::from(owner_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(owner_info, deposit_info, VaultDeposit::LEN, &seeds)?;
+
+    // SAFETY: single mutable borrow to `deposit_info` account data; the account was
+    // created above with the expected length.
+    let deposit =
+        unsafe { load_mut_unchecked::<VaultDeposit>(deposit_info.borrow_mut_data_unchecked())? };
+
+    deposit.set_initialized();
+    deposit.bump = bump;
+    deposit.vault = *vault_info.key();
+    deposit.owner = *owner_info.key();
+
+    Ok(deposit)
+}
+
+/// Loads the deposit account of `owner_info`, validating that the owner signed the
+/// instruction.
+#[inline(always)]
+fn load_deposit<'a>(
+    vault_info: &AccountInfo,
+    deposit_info: &'a AccountInfo,
+    owner_info: &AccountInfo,
+) -> Result<&'a mut VaultDeposit, ProgramError> {
+    require_signer!(owner_info);
+
+    check_account_owner(deposit_info)?;
+    // SAFETY: single mutable borrow to `deposit_info` account data and
+    // `load_mut` validates that the deposit is initialized.
+    let deposit = unsafe { load_mut::<VaultDeposit>(deposit_info.borrow_mut_data_unchecked())? };
+
+    if &deposit.vault != vault_info.key() || &deposit.owner != owner_info.key() {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    Ok(deposit)
+}
+
+/// Number of virtual shares counted in the shares outstanding.
+const VIRTUAL_SHARES: u64 = 1_000;
+
+/// Amount of virtual assets backing the [`VIRTUAL_SHARES`].
+const VIRTUAL_ASSETS: u64 = 1_000;
+
+/// Computes the shares minted for depositing `amount` into a vault holding
+/// `total_assets` with `total_shares` outstanding.
+#[inline(always)]
+fn shares_for_amount(
+    amount: u64,
+    total_assets: u64,
+    total_shares: u64,
+) -> Result<u64, ProgramError> {
+    let shares = math::mul_div(
+        amount,
+        total_shares
+            .checked_add(VIRTUAL_SHARES)
+            .ok_or(TokenError::Overflow)?,
+        total_assets
+            .checked_add(VIRTUAL_ASSETS)
+            .ok_or(TokenError::Overflow)?,
+        Rounding::Down,
+    )?;
+
+    if shares == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(shares)
+}
+
+/// Computes the amount redeemed for `shares` of a vault holding `total_assets` with
+/// `total_shares` outstanding.
+#[inline(always)]
+fn amount_for_shares(
+    shares: u64,
+    total_assets: u64,
+    total_shares: u64,
+) -> Result<u64, ProgramError> {
+    if shares > total_shares {
+        return Err(TokenError::InsufficientFunds.into());
+    }
+
+    // Both divisions round down, so a share is never worth less than one unit of the
+    // assets and the amount is at most `total_assets`.
+    math::mul_div(
+        shares,
+        total_assets
+            .checked_add(VIRTUAL_ASSETS)
+            .ok_or(TokenError::Overflow)?,
+        total_shares
+            .checked_add(VIRTUAL_SHARES)
+            .ok_or(TokenError::Overflow)?,
+        Rounding::Down,
+    )
+}
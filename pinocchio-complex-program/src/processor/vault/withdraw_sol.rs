@@ -0,0 +1,78 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load_mut, RawType},
+};
+
+use super::{amount_for_shares, load_deposit, parse_withdraw};
+use crate::{processor::check_account_owner, state::vault::Vault};
+
+/// Redeems SOL shares for their amount of the vault SOL.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The vault account.
+/// 1. `[writable]` The deposit account.
+/// 2. `[writable, signer]` The deposit owner, receiving the SOL.
+#[inline(always)]
+pub fn process_withdraw_sol(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let shares = parse_withdraw(instruction_data)?;
+
+    let [vault_info, deposit_info, owner_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let deposit = load_deposit(vault_info, deposit_info, owner_info)?;
+
+    if deposit.sol_shares() < shares {
+        return Err(TokenError::InsufficientFunds.into());
+    }
+
+    check_account_owner(vault_info)?;
+
+    // The vault assets exclude the lamports required for rent exemption.
+    let total_assets = vault_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(Vault::LEN));
+
+    // SAFETY: single mutable borrow to `vault_info` account data and
+    // `load_mut` validates that the vault is initialized.
+    let vault = unsafe { load_mut::<Vault>(vault_info.borrow_mut_data_unchecked())? };
+
+    let amount = amount_for_shares(shares, total_assets, vault.total_sol_shares())?;
+
+    deposit.set_sol_shares(
+        deposit
+            .sol_shares()
+            .checked_sub(shares)
+            .ok_or(TokenError::InsufficientFunds)?,
+    );
+    vault.set_total_sol_shares(
+        vault
+            .total_sol_shares()
+            .checked_sub(shares)
+            .ok_or(TokenError::InsufficientFunds)?,
+    );
+
+    // Moves the lamports out of the vault.
+
+    // SAFETY: single mutable borrow to `vault_info` and `owner_info` lamports; the
+    // vault account is owned by the token program.
+    unsafe {
+        *vault_info.borrow_mut_lamports_unchecked() = vault_info
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(TokenError::InsufficientFunds)?;
+        *owner_info.borrow_mut_lamports_unchecked() = owner_info
+            .lamports()
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::{
+    error::TokenError,
+    state::{account::Account, load, load_mut},
+};
+
+use super::{amount_for_shares, load_deposit, parse_withdraw};
+use crate::{
+    error::ExtensionError,
+    processor::{check_account_owner, shared::custody},
+    state::vault::Vault,
+};
+
+/// Redeems token shares for their amount of the token vault tokens.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The vault account.
+/// 1. `[writable]` The deposit account.
+/// 2. `[writable]` The token vault.
+/// 3. `[writable]` The destination token account.
+/// 4. `[signer]` The deposit owner.
+#[inline(always)]
+pub fn process_withdraw_token(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let shares = parse_withdraw(instruction_data)?;
+
+    let [vault_info, deposit_info, token_vault_info, destination_account_info, owner_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let deposit = load_deposit(vault_info, deposit_info, owner_info)?;
+
+    if deposit.token_shares() < shares {
+        return Err(TokenError::InsufficientFunds.into());
+    }
+
+    check_account_owner(vault_info)?;
+    // SAFETY: single mutable borrow to `vault_info` account data and
+    // `load_mut` validates that the vault is initialized.
+    let vault = unsafe { load_mut::<Vault>(vault_info.borrow_mut_data_unchecked())? };
+
+    if !vault.has_token_vault() || token_vault_info.key() != &vault.token_vault {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    // SAFETY: scoped immutable borrow to `token_vault_info` account data and
+    // `load` validates that the account is initialized.
+    let total_assets =
+        unsafe { load::<Account>(token_vault_info.borrow_data_unchecked())?.amount() };
+
+    let amount = amount_for_shares(shares, total_assets, vault.total_token_shares())?;
+
+    deposit.set_token_shares(
+        deposit
+            .token_shares()
+            .checked_sub(shares)
+            .ok_or(TokenError::InsufficientFunds)?,
+    );
+    vault.set_total_token_shares(
+        vault
+            .total_token_shares()
+            .checked_sub(shares)
+            .ok_or(TokenError::InsufficientFunds)?,
+    );
+
+    // Moves the tokens out of the token vault.
+
+    custody::transfer(
+        token_vault_info,
+        destination_account_info,
+        vault_info.key(),
+        amount,
+    )
+}
@@ -0,0 +1,41 @@
+use pinocchio::{program_error::ProgramError, ProgramResult};
+
+use crate::{
+    error::ExtensionError,
+    hash::{blake3, keccak256, sha256, HASH_BYTES},
+    parse::Cursor,
+};
+
+/// Verifies that a preimage hashes to the expected hash.
+///
+/// The instruction fails when the preimage does not match, which lets hash-locked
+/// flows (e.g. hashed time-locked contracts) check a revealed secret in the same
+/// transaction as the actions it unlocks. The hash algorithm is selected by the
+/// first byte of the instruction data:
+///
+/// - `0`: SHA-256
+/// - `1`: Keccak-256
+/// - `2`: BLAKE3
+///
+/// Accounts expected: none.
+#[inline(always)]
+pub fn process_verify_preimage(instruction_data: &[u8]) -> ProgramResult {
+    // expected u8 (1) + [u8; 32] (32) + preimage (variable length)
+    let mut cursor = Cursor::new(instruction_data);
+    let algorithm = cursor.read_u8()?;
+    let expected_hash = cursor.read_array::<HASH_BYTES>()?;
+    let preimage = cursor.remaining();
+
+    let hash = match algorithm {
+        0 => sha256(&[preimage]),
+        1 => keccak256(&[preimage]),
+        2 => blake3(&[preimage]),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if &hash != expected_hash {
+        return Err(ExtensionError::PreimageMismatch.into());
+    }
+
+    Ok(())
+}
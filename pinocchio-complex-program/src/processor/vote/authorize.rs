@@ -0,0 +1,46 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{
+    parse::Cursor,
+    vote::{self, VoteAuthorize},
+};
+
+/// Sets a new voter or withdrawer authority of a vote account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The vote account.
+/// 1. `[]` The clock sysvar.
+/// 2. `[signer]` The current voter or withdrawer authority.
+/// 3. `[]` The Vote program.
+#[inline(always)]
+pub fn process_authorize(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected Pubkey (32) + u8 (1)
+    if instruction_data.len() != 33 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut cursor = Cursor::new(instruction_data);
+    let new_authority = cursor.read_address()?;
+
+    let vote_authorize = match cursor.read_u8()? {
+        0 => VoteAuthorize::Voter,
+        1 => VoteAuthorize::Withdrawer,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    let [vote_info, clock_sysvar_info, authority_info, _vote_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    vote::authorize(
+        vote_info,
+        clock_sysvar_info,
+        authority_info,
+        new_authority,
+        vote_authorize,
+        &[],
+    )
+}
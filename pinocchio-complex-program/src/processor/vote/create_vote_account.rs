@@ -0,0 +1,71 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    parse::Cursor,
+    system,
+    vote::{self, VoteInit, VOTE_ACCOUNT_LEN},
+};
+
+/// Creates and initializes a rent-exempt vote account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The payer of the vote account.
+/// 1. `[writable, signer]` The vote account.
+/// 2. `[signer]` The validator identity.
+/// 3. `[]` The rent sysvar.
+/// 4. `[]` The clock sysvar.
+/// 5. `[]` The System program.
+/// 6. `[]` The Vote program.
+#[inline(always)]
+pub fn process_create_vote_account(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected Pubkey (32) + Pubkey (32) + u8 (1)
+    if instruction_data.len() != 65 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut cursor = Cursor::new(instruction_data);
+    let authorized_voter = cursor.read_address()?;
+    let authorized_withdrawer = cursor.read_address()?;
+    let commission = cursor.read_u8()?;
+
+    let [payer_info, vote_info, node_info, rent_sysvar_info, clock_sysvar_info, _system_program_info, _vote_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the vote account.
+
+    system::create_account(
+        payer_info,
+        vote_info,
+        Rent::get()?.minimum_balance(VOTE_ACCOUNT_LEN),
+        VOTE_ACCOUNT_LEN as u64,
+        &vote::ID,
+        &[],
+    )?;
+
+    // Initializes the vote account.
+
+    vote::initialize_account(
+        vote_info,
+        rent_sysvar_info,
+        clock_sysvar_info,
+        node_info,
+        &VoteInit {
+            authorized_voter,
+            authorized_withdrawer,
+            commission,
+        },
+        &[],
+    )
+}
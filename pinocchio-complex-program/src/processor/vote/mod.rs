@@ -0,0 +1,66 @@
+//! Vote program instructions invoked through CPIs.
+//!
+//! The authorities of the vote account sign the outer instruction and their
+//! signatures are forwarded to the Vote program.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateVoteAccount`
+//! - `1`: `Authorize`
+//! - `2`: `UpdateCommission`
+//! - `3`: `Withdraw`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod authorize;
+pub mod create_vote_account;
+pub mod update_commission;
+pub mod withdraw;
+
+pub use authorize::process_authorize;
+pub use create_vote_account::process_create_vote_account;
+pub use update_commission::process_update_commission;
+pub use withdraw::process_withdraw;
+
+/// Process a vote instruction.
+#[inline(always)]
+pub fn process_vote_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateVoteAccount
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateVoteAccount");
+
+            process_create_vote_account(accounts, instruction_data)
+        }
+        // 1 - Authorize
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Authorize");
+
+            process_authorize(accounts, instruction_data)
+        }
+        // 2 - UpdateCommission
+        2 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: UpdateCommission");
+
+            process_update_commission(accounts, instruction_data)
+        }
+        // 3 - Withdraw
+        3 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Withdraw");
+
+            process_withdraw(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
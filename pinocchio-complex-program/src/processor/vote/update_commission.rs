@@ -0,0 +1,27 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::vote;
+
+/// Updates the commission of a vote account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The vote account.
+/// 1. `[signer]` The withdraw authority.
+/// 2. `[]` The Vote program.
+#[inline(always)]
+pub fn process_update_commission(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // expected u8 (1)
+    let [commission] = instruction_data else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    let [vote_info, withdraw_authority_info, _vote_program_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    vote::update_commission(vote_info, withdraw_authority_info, *commission, &[])
+}
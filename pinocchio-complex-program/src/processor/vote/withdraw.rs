@@ -0,0 +1,32 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{parse::Cursor, vote};
+
+/// Withdraws lamports from a vote account.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The vote account.
+/// 1. `[writable]` The recipient account.
+/// 2. `[signer]` The withdraw authority.
+/// 3. `[]` The Vote program.
+#[inline(always)]
+pub fn process_withdraw(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let lamports = cursor.read_u64()?;
+    cursor.finish()?;
+
+    let [vote_info, recipient_info, withdraw_authority_info, _vote_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    vote::withdraw(
+        vote_info,
+        recipient_info,
+        withdraw_authority_info,
+        lamports,
+        &[],
+    )
+}
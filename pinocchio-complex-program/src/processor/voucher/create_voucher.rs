@@ -0,0 +1,90 @@
+use pinocchio::{
+    account_info::AccountInfo, instruction::Seed, program_error::ProgramError, ProgramResult,
+};
+use token_interface::state::{load_mut_unchecked, RawType};
+
+use crate::{
+    parse::Cursor,
+    processor::{check_derived_address, create_derived_account, shared},
+    state::voucher::{Voucher, VOUCHER_SEED},
+};
+
+/// Creates a new voucher, funding its token vault.
+///
+/// The token vault must be an uninitialized account owned by the token program;
+/// it is initialized with the voucher derived address as its owner.
+///
+/// Accounts expected:
+///
+/// 0. `[writable, signer]` The issuer of the voucher, paying for the voucher
+///    account.
+/// 1. `[writable]` The voucher account.
+/// 2. `[]` The key used to derive the voucher address.
+/// 3. `[writable]` The token vault.
+/// 4. `[]` The mint of the voucher funds.
+/// 5. `[writable]` The issuer token account funding the voucher.
+/// 6. `[]` The system program.
+#[inline(always)]
+pub fn process_create_voucher(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let mut cursor = Cursor::new(instruction_data);
+    let bump = cursor.read_u8()?;
+    let amount = cursor.read_u64()?;
+    let redemption_hash = cursor.read_array::<32>()?;
+    cursor.finish()?;
+
+    let [issuer_info, voucher_info, create_key_info, token_vault_info, mint_info, source_account_info, _system_program_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Creates the voucher account.
+
+    let bump_seed = [bump];
+    check_derived_address(
+        voucher_info,
+        &[VOUCHER_SEED, create_key_info.key().as_ref(), &bump_seed],
+    )?;
+
+    let seeds = [
+        Seed::from(VOUCHER_SEED),
+        Seed::from(create_key_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    create_derived_account(issuer_info, voucher_info, Voucher::LEN, &seeds)?;
+
+    // Initializes the token vault under the voucher authority and funds it.
+
+    shared::initialize_account::process_initialize_account(
+        &[token_vault_info.clone(), mint_info.clone()],
+        Some(voucher_info.key()),
+        false,
+    )?;
+
+    shared::transfer::process_transfer(
+        &[
+            source_account_info.clone(),
+            token_vault_info.clone(),
+            issuer_info.clone(),
+        ],
+        amount,
+        None,
+    )?;
+
+    // Initializes the voucher.
+
+    // SAFETY: single mutable borrow to `voucher_info` account data; the account was
+    // created above with the expected length.
+    let voucher =
+        unsafe { load_mut_unchecked::<Voucher>(voucher_info.borrow_mut_data_unchecked())? };
+
+    voucher.set_initialized();
+    voucher.bump = bump;
+    voucher.issuer = *issuer_info.key();
+    voucher.create_key = *create_key_info.key();
+    voucher.token_vault = *token_vault_info.key();
+    voucher.redemption_hash = *redemption_hash;
+
+    Ok(())
+}
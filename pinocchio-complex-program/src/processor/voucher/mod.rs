@@ -0,0 +1,49 @@
+//! Vouchers redeemable with a secret code.
+//!
+//! The issuer funds a token vault and stores the SHA-256 hash of a redemption
+//! code. Anyone presenting the code (the preimage of the hash) can redeem the
+//! voucher, receiving its funds. Since the code is revealed by the redeem
+//! instruction, a voucher is meant to be redeemed by the first party to know it,
+//! the same as a gift card.
+//!
+//! Instructions:
+//!
+//! - `0`: `CreateVoucher`
+//! - `1`: `Redeem`
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+pub mod create_voucher;
+pub mod redeem;
+
+pub use create_voucher::process_create_voucher;
+pub use redeem::process_redeem;
+
+/// Process a voucher instruction.
+#[inline(always)]
+pub fn process_voucher_instruction(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (discriminator, instruction_data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    match *discriminator {
+        // 0 - CreateVoucher
+        0 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CreateVoucher");
+
+            process_create_voucher(accounts, instruction_data)
+        }
+        // 1 - Redeem
+        1 => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: Redeem");
+
+            process_redeem(accounts, instruction_data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
@@ -0,0 +1,66 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use token_interface::state::{account::Account, load};
+
+use crate::{
+    error::ExtensionError,
+    hash::sha256,
+    processor::{check_account_owner, close_extension_account, shared::custody},
+    state::voucher::Voucher,
+};
+
+/// Redeems a voucher with its redemption code, moving the voucher funds to the
+/// destination account.
+///
+/// The token vault and voucher accounts are closed, returning their lamports to
+/// the issuer.
+///
+/// Accounts expected:
+///
+/// 0. `[writable]` The voucher account.
+/// 1. `[writable]` The token vault.
+/// 2. `[writable]` The token account receiving the voucher funds.
+/// 3. `[writable]` The issuer of the voucher.
+#[inline(always)]
+pub fn process_redeem(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // expected redemption code (variable length)
+    let [voucher_info, token_vault_info, destination_account_info, issuer_info, _remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(voucher_info)?;
+    // SAFETY: scoped immutable borrow to `voucher_info` account data and
+    // `load` validates that the voucher is initialized.
+    let voucher = unsafe { load::<Voucher>(voucher_info.borrow_data_unchecked())? };
+
+    if token_vault_info.key() != &voucher.token_vault || issuer_info.key() != &voucher.issuer {
+        return Err(ExtensionError::AccountMismatch.into());
+    }
+
+    if sha256(&[instruction_data]) != voucher.redemption_hash {
+        return Err(ExtensionError::InvalidRedemptionCode.into());
+    }
+
+    // Moves the voucher funds to the destination account.
+
+    let amount = {
+        // SAFETY: scoped immutable borrow to `token_vault_info` account data and
+        // `load` validates that the account is initialized.
+        let token_vault = unsafe { load::<Account>(token_vault_info.borrow_data_unchecked())? };
+        token_vault.amount()
+    };
+
+    custody::transfer(
+        token_vault_info,
+        destination_account_info,
+        voucher_info.key(),
+        amount,
+    )?;
+
+    // Closes the token vault and voucher accounts.
+
+    custody::close_account(token_vault_info, issuer_info, voucher_info.key())?;
+
+    close_extension_account(voucher_info, issuer_info)
+}
@@ -0,0 +1,135 @@
+//! Return data of the query instructions.
+//!
+//! Query instructions of the extensions return their result in an envelope made
+//! of a version byte followed by the payload, so the layout of a payload can
+//! change without breaking callers: a caller checks the version before reading the
+//! payload. The token instructions returning data (`GetAccountDataSize`,
+//! `AmountToUiAmount` and `UiAmountToAmount`) keep the SPL Token layout and do not
+//! use the envelope.
+//!
+//! `set` is used by the program to return data, while `get` is used by a program
+//! invoking a query instruction through a CPI to read it. Off-chain clients read
+//! the return data of a simulation with `unpack`.
+
+use pinocchio::{
+    program::{get_return_data, set_return_data, ReturnData},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Version of the return data envelope.
+pub const VERSION: u8 = 1;
+
+/// Length of the envelope header (version byte).
+pub const HEADER_LEN: usize = 1;
+
+/// Maximum length of the return data of an instruction.
+const MAX_RETURN_DATA: usize = 1024;
+
+/// Maximum length of the payload of the envelope.
+pub const MAX_PAYLOAD_LEN: usize = MAX_RETURN_DATA - HEADER_LEN;
+
+/// Sets the return data of the instruction to an envelope with the `payload`.
+#[inline(always)]
+pub fn set(payload: &[u8]) -> Result<(), ProgramError> {
+    if payload.len() > MAX_PAYLOAD_LEN {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut return_data = [0u8; MAX_RETURN_DATA];
+    return_data[0] = VERSION;
+    return_data[HEADER_LEN..HEADER_LEN + payload.len()].copy_from_slice(payload);
+
+    set_return_data(&return_data[..HEADER_LEN + payload.len()]);
+
+    Ok(())
+}
+
+/// Returns the payload of an envelope, checking its version.
+#[inline(always)]
+pub fn unpack(return_data: &[u8]) -> Result<&[u8], ProgramError> {
+    match return_data.split_first() {
+        Some((&VERSION, payload)) => Ok(payload),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Return data envelope read from a CPI.
+pub struct Envelope {
+    /// Return data of the invoked instruction.
+    return_data: ReturnData,
+}
+
+impl Envelope {
+    /// Returns the payload of the envelope.
+    #[inline(always)]
+    pub fn payload(&self) -> &[u8] {
+        &self.return_data.as_slice()[HEADER_LEN..]
+    }
+}
+
+/// Reads the envelope returned by the last instruction invoked through a CPI,
+/// checking that it was set by `program_id`.
+#[inline(always)]
+pub fn get(program_id: &Pubkey) -> Result<Envelope, ProgramError> {
+    let return_data = get_return_data().ok_or(ProgramError::InvalidInstructionData)?;
+
+    check(return_data.program_id(), return_data.as_slice(), program_id)?;
+
+    Ok(Envelope { return_data })
+}
+
+/// Checks that `return_data`, set by `setter`, is an envelope set by `program_id`.
+#[inline(always)]
+fn check(setter: &Pubkey, return_data: &[u8], program_id: &Pubkey) -> Result<(), ProgramError> {
+    if setter != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    unpack(return_data).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unpacks_payload() {
+        assert_eq!(unpack(&[VERSION, 1, 2, 3]), Ok(&[1, 2, 3][..]));
+        assert_eq!(unpack(&[VERSION]), Ok(&[][..]));
+    }
+
+    #[test]
+    fn rejects_unknown_version() {
+        assert_eq!(
+            unpack(&[VERSION + 1, 1, 2, 3]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(unpack(&[]), Err(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn checks_envelope_of_program() {
+        assert_eq!(check(&[1; 32], &[VERSION, 1, 2, 3], &[1; 32]), Ok(()));
+        assert_eq!(
+            check(&[1; 32], &[VERSION + 1, 1, 2, 3], &[1; 32]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn rejects_envelope_of_other_program() {
+        assert_eq!(
+            check(&[2; 32], &[VERSION, 1, 2, 3], &[1; 32]),
+            Err(ProgramError::IncorrectProgramId)
+        );
+    }
+
+    #[test]
+    fn rejects_missing_return_data() {
+        assert_eq!(
+            get(&[1; 32]).err(),
+            Some(ProgramError::InvalidInstructionData)
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! Helpers to recover the signers of secp256k1 signatures.
+
+use pinocchio::program_error::ProgramError;
+
+use crate::hash::keccak256;
+
+/// Length of a secp256k1 signature: the `r` and `s` values followed by the
+/// recovery id.
+pub const SIGNATURE_LEN: usize = 65;
+
+/// Length of an Ethereum address.
+pub const ETH_ADDRESS_LEN: usize = 20;
+
+/// Prefix of messages signed by Ethereum wallets (`personal_sign`) for a 32-byte
+/// message.
+const ETH_MESSAGE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n32";
+
+/// Recovers the Ethereum address that signed `message` with `signature`.
+///
+/// The message is signed the same way as Ethereum wallets sign messages: the
+/// Keccak-256 hash of the message is prefixed with `ETH_MESSAGE_PREFIX` and hashed
+/// again. The recovery id of the signature can be either `0`/`1` or the `27`/`28`
+/// used by Ethereum.
+#[inline(always)]
+pub fn recover_eth_address(
+    message: &[u8],
+    signature: &[u8; SIGNATURE_LEN],
+) -> Result<[u8; ETH_ADDRESS_LEN], ProgramError> {
+    let message_hash = keccak256(&[message]);
+    let hash = keccak256(&[ETH_MESSAGE_PREFIX, &message_hash]);
+
+    let recovery_id = match signature[64] {
+        id @ (0 | 1) => id,
+        id @ (27 | 28) => id - 27,
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+
+    let mut pubkey = [0u8; 64];
+
+    #[cfg(target_os = "solana")]
+    // SAFETY: `hash` has the length of a Keccak-256 hash, `signature` is at least
+    // 64 bytes long and `pubkey` has the length of an uncompressed public key
+    // without its prefix byte.
+    let result = unsafe {
+        pinocchio::syscalls::sol_secp256k1_recover(
+            hash.as_ptr(),
+            recovery_id as u64,
+            signature.as_ptr(),
+            pubkey.as_mut_ptr(),
+        )
+    };
+
+    #[cfg(not(target_os = "solana"))]
+    let result = {
+        core::hint::black_box((hash, recovery_id, &mut pubkey));
+        0
+    };
+
+    if result != 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // The address is the last 20 bytes of the Keccak-256 hash of the public key.
+
+    let pubkey_hash = keccak256(&[&pubkey]);
+    let mut address = [0u8; ETH_ADDRESS_LEN];
+    address.copy_from_slice(&pubkey_hash[32 - ETH_ADDRESS_LEN..]);
+
+    Ok(address)
+}
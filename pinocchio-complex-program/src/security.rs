@@ -0,0 +1,35 @@
+//! Checks against privilege escalation in the admin instructions.
+//!
+//! The account validation of the processors checks the accounts they use; these
+//! checks also reject accounts an admin instruction does not expect, so an admin
+//! instruction cannot be invoked with more privileges than it needs.
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::error::ExtensionError;
+
+/// Checks that none of the accounts is writable.
+#[inline(always)]
+pub fn assert_no_unexpected_writable<'a>(
+    accounts: impl IntoIterator<Item = &'a AccountInfo>,
+) -> ProgramResult {
+    if accounts.into_iter().any(AccountInfo::is_writable) {
+        return Err(ExtensionError::UnexpectedWritableAccount.into());
+    }
+
+    Ok(())
+}
+
+/// Checks that exactly `expected` accounts are supplied.
+#[inline(always)]
+pub fn assert_expected_account_count(accounts: &[AccountInfo], expected: usize) -> ProgramResult {
+    if accounts.len() < expected {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    if accounts.len() > expected {
+        return Err(ExtensionError::UnexpectedAccounts.into());
+    }
+
+    Ok(())
+}
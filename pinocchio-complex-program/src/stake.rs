@@ -0,0 +1,458 @@
+//! Helpers to invoke the Stake program.
+
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::error::ExtensionError;
+
+/// Stake program (`Stake11111111111111111111111111111111111111`) address.
+pub const ID: Pubkey = [
+    6, 161, 216, 23, 145, 55, 84, 42, 152, 52, 55, 189, 254, 42, 122, 178, 85, 127, 83, 92, 138,
+    120, 114, 43, 104, 164, 157, 192, 0, 0, 0, 0,
+];
+
+/// Lockup of a stake account.
+///
+/// Withdrawals are not allowed until both the `unix_timestamp` and the `epoch`
+/// are reached, unless signed by the `custodian`.
+pub struct Lockup<'a> {
+    /// Unix timestamp at which the lockup ends.
+    pub unix_timestamp: i64,
+
+    /// Epoch at which the lockup ends.
+    pub epoch: u64,
+
+    /// Custodian allowed to withdraw during the lockup.
+    pub custodian: &'a Pubkey,
+}
+
+/// Type of a stake account authority.
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum StakeAuthorize {
+    /// Authority allowed to delegate, deactivate, split and merge the stake.
+    Staker,
+
+    /// Authority allowed to withdraw and to change either authority.
+    Withdrawer,
+}
+
+/// Initializes a stake account with its staker and withdrawer authorities.
+#[inline(always)]
+pub fn initialize(
+    stake: &AccountInfo,
+    rent_sysvar: &AccountInfo,
+    staker: &Pubkey,
+    withdrawer: &Pubkey,
+    lockup: &Lockup,
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(stake.key()),
+        AccountMeta::readonly(rent_sysvar.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (0)
+    // - [4..36]: staker
+    // - [36..68]: withdrawer
+    // - [68..76]: lockup unix timestamp
+    // - [76..84]: lockup epoch
+    // - [84..116]: lockup custodian
+    let mut instruction_data = [0u8; 116];
+    instruction_data[4..36].copy_from_slice(staker);
+    instruction_data[36..68].copy_from_slice(withdrawer);
+    instruction_data[68..76].copy_from_slice(&lockup.unix_timestamp.to_le_bytes());
+    instruction_data[76..84].copy_from_slice(&lockup.epoch.to_le_bytes());
+    instruction_data[84..116].copy_from_slice(lockup.custodian);
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(&instruction, &[stake, rent_sysvar], &[])
+}
+
+/// Sets a new `stake_authorize` authority of a stake account.
+///
+/// The `signers` are used to sign for a derived `authority` address.
+#[inline(always)]
+pub fn authorize(
+    stake: &AccountInfo,
+    clock_sysvar: &AccountInfo,
+    authority: &AccountInfo,
+    new_authority: &Pubkey,
+    stake_authorize: StakeAuthorize,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(stake.key()),
+        AccountMeta::readonly(clock_sysvar.key()),
+        AccountMeta::readonly_signer(authority.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (1)
+    // - [4..36]: new authority
+    // - [36..40]: stake authorize
+    let mut instruction_data = [0u8; 40];
+    instruction_data[0] = 1;
+    instruction_data[4..36].copy_from_slice(new_authority);
+    instruction_data[36] = stake_authorize as u8;
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(&instruction, &[stake, clock_sysvar, authority], signers)
+}
+
+/// Delegates the stake of a stake account to a vote account.
+///
+/// The `signers` are used to sign for a derived `stake_authority` address.
+#[inline(always)]
+pub fn delegate_stake(
+    stake: &AccountInfo,
+    vote: &AccountInfo,
+    clock_sysvar: &AccountInfo,
+    stake_history_sysvar: &AccountInfo,
+    stake_config: &AccountInfo,
+    stake_authority: &AccountInfo,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(stake.key()),
+        AccountMeta::readonly(vote.key()),
+        AccountMeta::readonly(clock_sysvar.key()),
+        AccountMeta::readonly(stake_history_sysvar.key()),
+        AccountMeta::readonly(stake_config.key()),
+        AccountMeta::readonly_signer(stake_authority.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (2)
+    let instruction_data = [2, 0, 0, 0];
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(
+        &instruction,
+        &[
+            stake,
+            vote,
+            clock_sysvar,
+            stake_history_sysvar,
+            stake_config,
+            stake_authority,
+        ],
+        signers,
+    )
+}
+
+/// Splits `lamports` from a stake account into an uninitialized stake account.
+///
+/// The `signers` are used to sign for a derived `stake_authority` address.
+#[inline(always)]
+pub fn split(
+    stake: &AccountInfo,
+    split_stake: &AccountInfo,
+    stake_authority: &AccountInfo,
+    lamports: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(stake.key()),
+        AccountMeta::writable(split_stake.key()),
+        AccountMeta::readonly_signer(stake_authority.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (3)
+    // - [4..12]: lamports
+    let mut instruction_data = [0u8; 12];
+    instruction_data[0] = 3;
+    instruction_data[4..12].copy_from_slice(&lamports.to_le_bytes());
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(
+        &instruction,
+        &[stake, split_stake, stake_authority],
+        signers,
+    )
+}
+
+/// Withdraws `lamports` from a stake account.
+///
+/// The `signers` are used to sign for a derived `withdraw_authority` address.
+#[inline(always)]
+pub fn withdraw(
+    stake: &AccountInfo,
+    recipient: &AccountInfo,
+    clock_sysvar: &AccountInfo,
+    stake_history_sysvar: &AccountInfo,
+    withdraw_authority: &AccountInfo,
+    lamports: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(stake.key()),
+        AccountMeta::writable(recipient.key()),
+        AccountMeta::readonly(clock_sysvar.key()),
+        AccountMeta::readonly(stake_history_sysvar.key()),
+        AccountMeta::readonly_signer(withdraw_authority.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (4)
+    // - [4..12]: lamports
+    let mut instruction_data = [0u8; 12];
+    instruction_data[0] = 4;
+    instruction_data[4..12].copy_from_slice(&lamports.to_le_bytes());
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(
+        &instruction,
+        &[
+            stake,
+            recipient,
+            clock_sysvar,
+            stake_history_sysvar,
+            withdraw_authority,
+        ],
+        signers,
+    )
+}
+
+/// Deactivates the stake of a stake account.
+///
+/// The `signers` are used to sign for a derived `stake_authority` address.
+#[inline(always)]
+pub fn deactivate(
+    stake: &AccountInfo,
+    clock_sysvar: &AccountInfo,
+    stake_authority: &AccountInfo,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(stake.key()),
+        AccountMeta::readonly(clock_sysvar.key()),
+        AccountMeta::readonly_signer(stake_authority.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (5)
+    let instruction_data = [5, 0, 0, 0];
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(
+        &instruction,
+        &[stake, clock_sysvar, stake_authority],
+        signers,
+    )
+}
+
+/// Merges the `source` stake account into the `destination` stake account.
+///
+/// The `signers` are used to sign for a derived `stake_authority` address.
+#[inline(always)]
+pub fn merge(
+    destination: &AccountInfo,
+    source: &AccountInfo,
+    clock_sysvar: &AccountInfo,
+    stake_history_sysvar: &AccountInfo,
+    stake_authority: &AccountInfo,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(destination.key()),
+        AccountMeta::writable(source.key()),
+        AccountMeta::readonly(clock_sysvar.key()),
+        AccountMeta::readonly(stake_history_sysvar.key()),
+        AccountMeta::readonly_signer(stake_authority.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (7)
+    let instruction_data = [7, 0, 0, 0];
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(
+        &instruction,
+        &[
+            destination,
+            source,
+            clock_sysvar,
+            stake_history_sysvar,
+            stake_authority,
+        ],
+        signers,
+    )
+}
+
+/// Zero-copy view of the `StakeStateV2` data of a stake account.
+///
+/// Only the `Initialized` and `Stake` variants are readable; the `Meta` is shared
+/// by both variants and the `Stake` is only valid for delegated accounts.
+#[repr(C)]
+pub struct StakeState {
+    /// Variant of the state: `1` for `Initialized` and `2` for `Stake`.
+    tag: [u8; 4],
+
+    /// Meta of the stake account.
+    pub meta: Meta,
+
+    /// Delegated stake, only valid for the `Stake` variant.
+    stake: Stake,
+}
+
+/// Authorities and lockup of a stake account.
+#[repr(C)]
+pub struct Meta {
+    /// Lamports reserved for rent exemption.
+    rent_exempt_reserve: [u8; 8],
+
+    /// Staker authority.
+    pub staker: Pubkey,
+
+    /// Withdrawer authority.
+    pub withdrawer: Pubkey,
+
+    /// Unix timestamp at which the lockup ends.
+    lockup_unix_timestamp: [u8; 8],
+
+    /// Epoch at which the lockup ends.
+    lockup_epoch: [u8; 8],
+
+    /// Custodian allowed to withdraw during the lockup.
+    pub custodian: Pubkey,
+}
+
+/// Delegation of a stake account.
+#[repr(C)]
+pub struct Stake {
+    /// Vote account the stake is delegated to.
+    pub voter: Pubkey,
+
+    /// Delegated lamports.
+    stake: [u8; 8],
+
+    /// Epoch at which the stake was activated.
+    activation_epoch: [u8; 8],
+
+    /// Epoch at which the stake was deactivated, `u64::MAX` when active.
+    deactivation_epoch: [u8; 8],
+
+    /// Deprecated warmup and cooldown rate.
+    _warmup_cooldown_rate: [u8; 8],
+
+    /// Credits observed of the vote account.
+    credits_observed: [u8; 8],
+}
+
+impl StakeState {
+    /// Length of the readable part of the state.
+    pub const LEN: usize = size_of::<StakeState>();
+
+    /// Returns a view of the state of a stake account data.
+    ///
+    /// The data must be at least [`StakeState::LEN`] bytes long and hold either
+    /// the `Initialized` or `Stake` variant.
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<&StakeState, ProgramError> {
+        if data.len() < Self::LEN {
+            return Err(ExtensionError::MalformedAccountData.into());
+        }
+
+        // SAFETY: `data` length is validated above and `StakeState` has an alignment
+        // of 1.
+        let state = unsafe { &*(data.as_ptr() as *const StakeState) };
+
+        match u32::from_le_bytes(state.tag) {
+            1 | 2 => Ok(state),
+            _ => Err(ExtensionError::AccountTypeMismatch.into()),
+        }
+    }
+
+    /// Returns the delegated stake, if the account is delegated.
+    #[inline(always)]
+    pub fn stake(&self) -> Option<&Stake> {
+        if u32::from_le_bytes(self.tag) == 2 {
+            Some(&self.stake)
+        } else {
+            None
+        }
+    }
+}
+
+impl Meta {
+    #[inline(always)]
+    pub fn rent_exempt_reserve(&self) -> u64 {
+        u64::from_le_bytes(self.rent_exempt_reserve)
+    }
+
+    #[inline(always)]
+    pub fn lockup_unix_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.lockup_unix_timestamp)
+    }
+
+    #[inline(always)]
+    pub fn lockup_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.lockup_epoch)
+    }
+}
+
+impl Stake {
+    #[inline(always)]
+    pub fn stake(&self) -> u64 {
+        u64::from_le_bytes(self.stake)
+    }
+
+    #[inline(always)]
+    pub fn activation_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.activation_epoch)
+    }
+
+    #[inline(always)]
+    pub fn deactivation_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.deactivation_epoch)
+    }
+
+    #[inline(always)]
+    pub fn credits_observed(&self) -> u64 {
+        u64::from_le_bytes(self.credits_observed)
+    }
+}
@@ -0,0 +1,50 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the airdrop derived address.
+pub const AIRDROP_SEED: &[u8] = b"airdrop";
+
+/// Maximum number of recipients of a single `MintToMany` instruction.
+pub const MAX_RECIPIENTS: usize = 20;
+
+/// Airdrop data.
+///
+/// The airdrop account is a PDA derived from `[AIRDROP_SEED, mint]` and it is the
+/// mint authority of the airdropped mint.
+#[repr(C)]
+pub struct Airdrop {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the airdrop derived address.
+    pub bump: u8,
+
+    /// Authority allowed to mint airdrops.
+    pub authority: Pubkey,
+
+    /// Mint of the airdropped tokens.
+    pub mint: Pubkey,
+}
+
+impl Airdrop {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Airdrop as u8;
+    }
+}
+
+impl RawType for Airdrop {
+    const LEN: usize = size_of::<Airdrop>();
+}
+
+impl Initializable for Airdrop {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Airdrop as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Airdrop::LEN));
@@ -0,0 +1,108 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the auction derived address.
+pub const AUCTION_SEED: &[u8] = b"auction";
+
+/// English auction data.
+///
+/// The auction account is a PDA derived from `[AUCTION_SEED, create_key]`. It is the
+/// owner of the item vault and it holds the highest bid in its own lamports.
+#[repr(C)]
+pub struct Auction {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the auction derived address.
+    pub bump: u8,
+
+    /// Seller of the item, receiving the proceeds.
+    pub seller: Pubkey,
+
+    /// Token account holding the auctioned item.
+    pub item_vault: Pubkey,
+
+    /// Bidder of the highest bid, if any.
+    pub highest_bidder: Pubkey,
+
+    /// Highest bid, in lamports.
+    highest_bid: [u8; 8],
+
+    /// Minimum bid, in lamports.
+    min_bid: [u8; 8],
+
+    /// Amount of item tokens auctioned.
+    item_amount: [u8; 8],
+
+    /// Slot at which the auction ends.
+    end_slot: [u8; 8],
+}
+
+impl Auction {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Auction as u8;
+    }
+
+    /// Indicates whether the auction has received a bid.
+    #[inline(always)]
+    pub fn has_bid(&self) -> bool {
+        self.highest_bid() > 0
+    }
+
+    #[inline(always)]
+    pub fn set_highest_bid(&mut self, bid: u64) {
+        self.highest_bid = bid.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn highest_bid(&self) -> u64 {
+        u64::from_le_bytes(self.highest_bid)
+    }
+
+    #[inline(always)]
+    pub fn set_min_bid(&mut self, bid: u64) {
+        self.min_bid = bid.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn min_bid(&self) -> u64 {
+        u64::from_le_bytes(self.min_bid)
+    }
+
+    #[inline(always)]
+    pub fn set_item_amount(&mut self, amount: u64) {
+        self.item_amount = amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn item_amount(&self) -> u64 {
+        u64::from_le_bytes(self.item_amount)
+    }
+
+    #[inline(always)]
+    pub fn set_end_slot(&mut self, slot: u64) {
+        self.end_slot = slot.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn end_slot(&self) -> u64 {
+        u64::from_le_bytes(self.end_slot)
+    }
+}
+
+impl RawType for Auction {
+    const LEN: usize = size_of::<Auction>();
+}
+
+impl Initializable for Auction {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Auction as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Auction::LEN));
@@ -0,0 +1,92 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the bonding curve derived address.
+pub const BONDING_CURVE_SEED: &[u8] = b"bonding_curve";
+
+/// Curve with a price increasing by a fixed amount per token.
+pub const LINEAR: u8 = 0;
+
+/// Curve with a price increasing by a fixed rate per token.
+pub const EXPONENTIAL: u8 = 1;
+
+/// Bonding curve data.
+///
+/// The bonding curve account is a PDA derived from `[BONDING_CURVE_SEED, mint]`. It
+/// is the mint authority of the curve tokens and it holds the lamports reserve.
+#[repr(C)]
+pub struct BondingCurve {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the bonding curve derived address.
+    pub bump: u8,
+
+    /// Type of the curve, either [`LINEAR`] or [`EXPONENTIAL`].
+    pub curve_type: u8,
+
+    /// Mint of the curve tokens.
+    pub mint: Pubkey,
+
+    /// Price of the first token, in lamports.
+    base_price: [u8; 8],
+
+    /// Fixed-point slope of a linear curve or growth rate of an exponential curve.
+    factor: [u8; 8],
+
+    /// Supply of tokens minted by the curve.
+    supply: [u8; 8],
+}
+
+impl BondingCurve {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::BondingCurve as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_base_price(&mut self, base_price: u64) {
+        self.base_price = base_price.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn base_price(&self) -> u64 {
+        u64::from_le_bytes(self.base_price)
+    }
+
+    #[inline(always)]
+    pub fn set_factor(&mut self, factor: u64) {
+        self.factor = factor.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn factor(&self) -> u64 {
+        u64::from_le_bytes(self.factor)
+    }
+
+    #[inline(always)]
+    pub fn set_supply(&mut self, supply: u64) {
+        self.supply = supply.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn supply(&self) -> u64 {
+        u64::from_le_bytes(self.supply)
+    }
+}
+
+impl RawType for BondingCurve {
+    const LEN: usize = size_of::<BondingCurve>();
+}
+
+impl Initializable for BondingCurve {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::BondingCurve as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(BondingCurve::LEN));
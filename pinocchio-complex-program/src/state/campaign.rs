@@ -0,0 +1,153 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the campaign derived address.
+pub const CAMPAIGN_SEED: &[u8] = b"campaign";
+
+/// Seed prefix of the contribution derived address.
+pub const CONTRIBUTION_SEED: &[u8] = b"campaign_contribution";
+
+/// Crowdfunding campaign data.
+///
+/// The campaign account is a PDA derived from `[CAMPAIGN_SEED, create_key]`. SOL
+/// contributions are held in its own lamports; token contributions are held in a
+/// token vault owned by the campaign address.
+#[repr(C)]
+pub struct Campaign {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the campaign derived address.
+    pub bump: u8,
+
+    /// Key used to derive the campaign address.
+    pub create_key: Pubkey,
+
+    /// Creator of the campaign, receiving the funds when the goal is met.
+    pub creator: Pubkey,
+
+    /// Token account holding the contributed tokens, if the campaign raises tokens.
+    pub token_vault: Pubkey,
+
+    /// Mint of the contributed tokens, if the campaign raises tokens.
+    pub mint: Pubkey,
+
+    /// Amount to raise.
+    goal: [u8; 8],
+
+    /// Amount raised.
+    raised: [u8; 8],
+
+    /// Unix timestamp at which the campaign ends.
+    deadline: [u8; 8],
+}
+
+impl Campaign {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Campaign as u8;
+    }
+
+    /// Indicates whether the campaign raises tokens instead of SOL.
+    #[inline(always)]
+    pub fn has_token_vault(&self) -> bool {
+        self.token_vault != Pubkey::default()
+    }
+
+    #[inline(always)]
+    pub fn set_goal(&mut self, goal: u64) {
+        self.goal = goal.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn goal(&self) -> u64 {
+        u64::from_le_bytes(self.goal)
+    }
+
+    #[inline(always)]
+    pub fn set_raised(&mut self, raised: u64) {
+        self.raised = raised.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn raised(&self) -> u64 {
+        u64::from_le_bytes(self.raised)
+    }
+
+    #[inline(always)]
+    pub fn set_deadline(&mut self, deadline: i64) {
+        self.deadline = deadline.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn deadline(&self) -> i64 {
+        i64::from_le_bytes(self.deadline)
+    }
+}
+
+impl RawType for Campaign {
+    const LEN: usize = size_of::<Campaign>();
+}
+
+impl Initializable for Campaign {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Campaign as u8
+    }
+}
+
+/// Contribution of a contributor to a campaign.
+///
+/// The contribution account is a PDA derived from `[CONTRIBUTION_SEED, campaign,
+/// contributor]`.
+#[repr(C)]
+pub struct Contribution {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the contribution derived address.
+    pub bump: u8,
+
+    /// Campaign of the contribution.
+    pub campaign: Pubkey,
+
+    /// Contributor.
+    pub contributor: Pubkey,
+
+    /// Amount contributed.
+    amount: [u8; 8],
+}
+
+impl Contribution {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Contribution as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_amount(&mut self, amount: u64) {
+        self.amount = amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn amount(&self) -> u64 {
+        u64::from_le_bytes(self.amount)
+    }
+}
+
+impl RawType for Contribution {
+    const LEN: usize = size_of::<Contribution>();
+}
+
+impl Initializable for Contribution {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Contribution as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Campaign::LEN));
+const _: () = assert!(is_valid_extension_len(Contribution::LEN));
@@ -0,0 +1,101 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed of the config derived address.
+pub const CONFIG_SEED: &[u8] = b"config";
+
+/// Current version of the config layout.
+pub const CONFIG_VERSION: u8 = 1;
+
+/// Program config data.
+///
+/// The config account is a PDA derived from `[CONFIG_SEED]`, so there is a single
+/// config per program.
+#[repr(C)]
+pub struct Config {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Version of the config layout.
+    pub version: u8,
+
+    /// Bump seed of the config derived address.
+    pub bump: u8,
+
+    /// Admin allowed to update the config.
+    pub admin: Pubkey,
+
+    /// Account receiving the program fees.
+    pub fee_recipient: Pubkey,
+
+    /// Program fee, in basis points.
+    fee_bps: [u8; 2],
+
+    /// Bitmask of the enabled features.
+    features: [u8; 8],
+
+    /// Admin proposed to replace the current admin, which must accept the role.
+    pub pending_admin: Pubkey,
+
+    /// Indicates whether the state-changing instructions are paused.
+    paused: u8,
+}
+
+impl Config {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Config as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_fee_bps(&mut self, fee_bps: u16) {
+        self.fee_bps = fee_bps.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn fee_bps(&self) -> u16 {
+        u16::from_le_bytes(self.fee_bps)
+    }
+
+    #[inline(always)]
+    pub fn set_features(&mut self, features: u64) {
+        self.features = features.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn features(&self) -> u64 {
+        u64::from_le_bytes(self.features)
+    }
+
+    #[inline(always)]
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused as u8;
+    }
+
+    #[inline(always)]
+    pub fn is_paused(&self) -> bool {
+        self.paused != 0
+    }
+
+    /// Checks whether all the features in `feature` are enabled.
+    #[inline(always)]
+    pub fn is_feature_enabled(&self, feature: u64) -> bool {
+        self.features() & feature == feature
+    }
+}
+
+impl RawType for Config {
+    const LEN: usize = size_of::<Config>();
+}
+
+impl Initializable for Config {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Config as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Config::LEN));
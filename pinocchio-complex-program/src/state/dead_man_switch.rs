@@ -0,0 +1,86 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the dead man's switch derived address.
+pub const DEAD_MAN_SWITCH_SEED: &[u8] = b"dead_man_switch";
+
+/// Dead man's switch data.
+///
+/// The switch account is a PDA derived from `[DEAD_MAN_SWITCH_SEED, create_key]`
+/// and it is the owner of the token vault.
+#[repr(C)]
+pub struct DeadManSwitch {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the switch derived address.
+    pub bump: u8,
+
+    /// Owner of the switch, expected to ping it periodically.
+    pub owner: Pubkey,
+
+    /// Beneficiary allowed to sweep the token vault once the owner is inactive.
+    pub beneficiary: Pubkey,
+
+    /// Key used to derive the switch address.
+    pub create_key: Pubkey,
+
+    /// Token account holding the funds of the switch.
+    pub token_vault: Pubkey,
+
+    /// Period in seconds without pings after which the owner is inactive.
+    inactivity_window: [u8; 8],
+
+    /// Timestamp of the last ping of the owner.
+    last_ping: [u8; 8],
+}
+
+impl DeadManSwitch {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::DeadManSwitch as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_inactivity_window(&mut self, inactivity_window: i64) {
+        self.inactivity_window = inactivity_window.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn inactivity_window(&self) -> i64 {
+        i64::from_le_bytes(self.inactivity_window)
+    }
+
+    #[inline(always)]
+    pub fn set_last_ping(&mut self, last_ping: i64) {
+        self.last_ping = last_ping.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn last_ping(&self) -> i64 {
+        i64::from_le_bytes(self.last_ping)
+    }
+
+    /// Indicates whether the owner has not pinged the switch within the inactivity
+    /// window at `timestamp`.
+    #[inline(always)]
+    pub fn is_expired(&self, timestamp: i64) -> bool {
+        timestamp.saturating_sub(self.last_ping()) > self.inactivity_window()
+    }
+}
+
+impl RawType for DeadManSwitch {
+    const LEN: usize = size_of::<DeadManSwitch>();
+}
+
+impl Initializable for DeadManSwitch {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::DeadManSwitch as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(DeadManSwitch::LEN));
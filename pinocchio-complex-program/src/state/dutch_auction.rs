@@ -0,0 +1,208 @@
+use core::mem::size_of;
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use token_interface::{
+    error::TokenError,
+    state::{Initializable, RawType},
+};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+use crate::math::{self, Rounding};
+
+/// Seed prefix of the Dutch auction derived address.
+pub const DUTCH_AUCTION_SEED: &[u8] = b"dutch_auction";
+
+/// Dutch auction data.
+///
+/// The auction account is a PDA derived from `[DUTCH_AUCTION_SEED, create_key]` and
+/// it is the owner of the item vault.
+///
+/// The price decays linearly from the start price to the end price between the start
+/// and end times. When the step duration is not zero, the price only decreases at the
+/// end of each step.
+#[repr(C)]
+pub struct DutchAuction {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the auction derived address.
+    pub bump: u8,
+
+    /// Seller of the item, receiving the proceeds.
+    pub seller: Pubkey,
+
+    /// Token account holding the auctioned item.
+    pub item_vault: Pubkey,
+
+    /// Amount of item tokens auctioned.
+    item_amount: [u8; 8],
+
+    /// Price at the start time, in lamports.
+    start_price: [u8; 8],
+
+    /// Price at the end time, in lamports.
+    end_price: [u8; 8],
+
+    /// Unix timestamp at which the price starts to decay.
+    start_time: [u8; 8],
+
+    /// Unix timestamp at which the price reaches the end price.
+    end_time: [u8; 8],
+
+    /// Duration of each price step, in seconds.
+    step_duration: [u8; 8],
+}
+
+impl DutchAuction {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::DutchAuction as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_item_amount(&mut self, amount: u64) {
+        self.item_amount = amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn item_amount(&self) -> u64 {
+        u64::from_le_bytes(self.item_amount)
+    }
+
+    #[inline(always)]
+    pub fn set_start_price(&mut self, price: u64) {
+        self.start_price = price.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn start_price(&self) -> u64 {
+        u64::from_le_bytes(self.start_price)
+    }
+
+    #[inline(always)]
+    pub fn set_end_price(&mut self, price: u64) {
+        self.end_price = price.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn end_price(&self) -> u64 {
+        u64::from_le_bytes(self.end_price)
+    }
+
+    #[inline(always)]
+    pub fn set_start_time(&mut self, time: i64) {
+        self.start_time = time.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn start_time(&self) -> i64 {
+        i64::from_le_bytes(self.start_time)
+    }
+
+    #[inline(always)]
+    pub fn set_end_time(&mut self, time: i64) {
+        self.end_time = time.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn end_time(&self) -> i64 {
+        i64::from_le_bytes(self.end_time)
+    }
+
+    #[inline(always)]
+    pub fn set_step_duration(&mut self, duration: u64) {
+        self.step_duration = duration.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn step_duration(&self) -> u64 {
+        u64::from_le_bytes(self.step_duration)
+    }
+
+    /// Computes the price at the given unix timestamp.
+    ///
+    /// This assumes that the start price is not lower than the end price and that
+    /// the end time is after the start time, which is validated when the auction is
+    /// created; otherwise the price computation fails with `Overflow`.
+    pub fn price_at(&self, timestamp: i64) -> Result<u64, ProgramError> {
+        if timestamp <= self.start_time() {
+            return Ok(self.start_price());
+        }
+        if timestamp >= self.end_time() {
+            return Ok(self.end_price());
+        }
+
+        let duration = self.end_time().abs_diff(self.start_time());
+        let mut elapsed = timestamp.abs_diff(self.start_time());
+
+        if let Some(remainder) = elapsed.checked_rem(self.step_duration()) {
+            elapsed = elapsed.saturating_sub(remainder);
+        }
+
+        let price_range = self
+            .start_price()
+            .checked_sub(self.end_price())
+            .ok_or(TokenError::Overflow)?;
+
+        // `decay` is at most the price range since `elapsed < duration`.
+        let decay = math::mul_div(price_range, elapsed, duration, Rounding::Down)?;
+
+        Ok(self
+            .start_price()
+            .checked_sub(decay)
+            .ok_or(TokenError::Overflow)?)
+    }
+}
+
+impl RawType for DutchAuction {
+    const LEN: usize = size_of::<DutchAuction>();
+}
+
+impl Initializable for DutchAuction {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::DutchAuction as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(DutchAuction::LEN));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use token_interface::state::load_mut_unchecked;
+
+    #[test]
+    fn decays_price_by_step() {
+        let mut data = [0; DutchAuction::LEN];
+        // SAFETY: `data` has the length of the auction.
+        let auction = unsafe { load_mut_unchecked::<DutchAuction>(&mut data).unwrap() };
+
+        auction.set_start_price(1_000);
+        auction.set_end_price(100);
+        auction.set_start_time(0);
+        auction.set_end_time(100);
+
+        assert_eq!(auction.price_at(-1), Ok(1_000));
+        assert_eq!(auction.price_at(55), Ok(505));
+        assert_eq!(auction.price_at(100), Ok(100));
+
+        auction.set_step_duration(10);
+
+        assert_eq!(auction.price_at(55), Ok(550));
+        assert_eq!(auction.price_at(99), Ok(190));
+    }
+
+    #[test]
+    fn rejects_inverted_prices() {
+        let mut data = [0; DutchAuction::LEN];
+        // SAFETY: `data` has the length of the auction.
+        let auction = unsafe { load_mut_unchecked::<DutchAuction>(&mut data).unwrap() };
+
+        auction.set_start_price(100);
+        auction.set_end_price(1_000);
+        auction.set_start_time(0);
+        auction.set_end_time(100);
+
+        assert_eq!(auction.price_at(50), Err(TokenError::Overflow.into()));
+    }
+}
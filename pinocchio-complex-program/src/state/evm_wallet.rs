@@ -0,0 +1,61 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+use crate::secp256k1::ETH_ADDRESS_LEN;
+
+/// Seed prefix of the EVM wallet derived address.
+pub const EVM_WALLET_SEED: &[u8] = b"evm_wallet";
+
+/// EVM wallet data.
+///
+/// The wallet account is a PDA derived from `[EVM_WALLET_SEED, create_key]` and it
+/// is the owner of the token accounts controlled by the Ethereum address.
+#[repr(C)]
+pub struct EvmWallet {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the wallet derived address.
+    pub bump: u8,
+
+    /// Key used to derive the wallet address.
+    pub create_key: Pubkey,
+
+    /// Ethereum address authorizing the wallet actions.
+    pub eth_address: [u8; ETH_ADDRESS_LEN],
+
+    /// Nonce of the next authorized action, preventing signature replays.
+    nonce: [u8; 8],
+}
+
+impl EvmWallet {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::EvmWallet as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_nonce(&mut self, nonce: u64) {
+        self.nonce = nonce.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn nonce(&self) -> u64 {
+        u64::from_le_bytes(self.nonce)
+    }
+}
+
+impl RawType for EvmWallet {
+    const LEN: usize = size_of::<EvmWallet>();
+}
+
+impl Initializable for EvmWallet {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::EvmWallet as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(EvmWallet::LEN));
@@ -0,0 +1,153 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the faucet derived address.
+pub const FAUCET_SEED: &[u8] = b"faucet";
+
+/// Seed prefix of the faucet claim derived address.
+pub const FAUCET_CLAIM_SEED: &[u8] = b"faucet_claim";
+
+/// Token faucet data.
+///
+/// The faucet account is a PDA derived from `[FAUCET_SEED, mint]` and it is the mint
+/// authority of the dripped mint. When rate limited, the total amount dripped is
+/// limited by the rate limit of the faucet.
+#[repr(C)]
+pub struct Faucet {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the faucet derived address.
+    pub bump: u8,
+
+    /// Indicates whether claims are paused.
+    paused: u8,
+
+    /// Indicates whether claims are limited by the faucet rate limit.
+    rate_limited: u8,
+
+    /// Admin of the faucet.
+    pub admin: Pubkey,
+
+    /// Mint of the dripped tokens.
+    pub mint: Pubkey,
+
+    /// Amount of tokens minted per claim.
+    drip_amount: [u8; 8],
+
+    /// Minimum time between two claims of the same user, in seconds.
+    cooldown: [u8; 8],
+}
+
+impl Faucet {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Faucet as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused as u8;
+    }
+
+    #[inline(always)]
+    pub fn is_paused(&self) -> bool {
+        self.paused != 0
+    }
+
+    #[inline(always)]
+    pub fn set_rate_limited(&mut self) {
+        self.rate_limited = 1;
+    }
+
+    #[inline(always)]
+    pub fn is_rate_limited(&self) -> bool {
+        self.rate_limited != 0
+    }
+
+    #[inline(always)]
+    pub fn set_drip_amount(&mut self, drip_amount: u64) {
+        self.drip_amount = drip_amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn drip_amount(&self) -> u64 {
+        u64::from_le_bytes(self.drip_amount)
+    }
+
+    #[inline(always)]
+    pub fn set_cooldown(&mut self, cooldown: i64) {
+        self.cooldown = cooldown.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn cooldown(&self) -> i64 {
+        i64::from_le_bytes(self.cooldown)
+    }
+}
+
+impl RawType for Faucet {
+    const LEN: usize = size_of::<Faucet>();
+}
+
+impl Initializable for Faucet {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Faucet as u8
+    }
+}
+
+/// Last claim of a user from a faucet.
+///
+/// The claim account is a PDA derived from `[FAUCET_CLAIM_SEED, faucet, user]`.
+#[repr(C)]
+pub struct FaucetClaim {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the claim derived address.
+    pub bump: u8,
+
+    /// Faucet of the claim.
+    pub faucet: Pubkey,
+
+    /// User claiming from the faucet.
+    pub user: Pubkey,
+
+    /// Unix timestamp of the last claim.
+    last_claim: [u8; 8],
+}
+
+impl FaucetClaim {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::FaucetClaim as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_last_claim(&mut self, last_claim: i64) {
+        self.last_claim = last_claim.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn last_claim(&self) -> i64 {
+        i64::from_le_bytes(self.last_claim)
+    }
+}
+
+impl RawType for FaucetClaim {
+    const LEN: usize = size_of::<FaucetClaim>();
+}
+
+impl Initializable for FaucetClaim {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::FaucetClaim as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Faucet::LEN));
+const _: () = assert!(is_valid_extension_len(FaucetClaim::LEN));
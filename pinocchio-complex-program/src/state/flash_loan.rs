@@ -0,0 +1,76 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the flash loan pool derived address.
+pub const FLASH_LOAN_POOL_SEED: &[u8] = b"flash_loan_pool";
+
+/// Flash loan pool data.
+///
+/// The pool account is a PDA derived from `[FLASH_LOAN_POOL_SEED, create_key]` and
+/// it is the owner of the token vault lending the tokens.
+#[repr(C)]
+pub struct FlashLoanPool {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the pool derived address.
+    pub bump: u8,
+
+    /// Loan fee, in basis points of the borrowed amount.
+    fee_bps: [u8; 2],
+
+    /// Key used to derive the pool address.
+    pub create_key: Pubkey,
+
+    /// Token account lending the tokens.
+    pub token_vault: Pubkey,
+
+    /// Mint of the lent tokens.
+    pub mint: Pubkey,
+
+    /// Amount to repay for the loan in progress, zero when there is none.
+    repay_amount: [u8; 8],
+}
+
+impl FlashLoanPool {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::FlashLoanPool as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_fee_bps(&mut self, fee_bps: u16) {
+        self.fee_bps = fee_bps.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn fee_bps(&self) -> u16 {
+        u16::from_le_bytes(self.fee_bps)
+    }
+
+    #[inline(always)]
+    pub fn set_repay_amount(&mut self, repay_amount: u64) {
+        self.repay_amount = repay_amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn repay_amount(&self) -> u64 {
+        u64::from_le_bytes(self.repay_amount)
+    }
+}
+
+impl RawType for FlashLoanPool {
+    const LEN: usize = size_of::<FlashLoanPool>();
+}
+
+impl Initializable for FlashLoanPool {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::FlashLoanPool as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(FlashLoanPool::LEN));
@@ -0,0 +1,309 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, multisig_wallet::TransactionAccount, AccountDiscriminator};
+
+/// Seed prefix of the governance derived address.
+pub const GOVERNANCE_SEED: &[u8] = b"governance";
+
+/// Seed prefix of the governance authority derived address.
+pub const GOVERNANCE_AUTHORITY_SEED: &[u8] = b"governance_authority";
+
+/// Seed prefix of the proposal derived address.
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+
+/// Seed prefix of the vote record derived address.
+pub const VOTE_RECORD_SEED: &[u8] = b"vote_record";
+
+/// Maximum number of accounts of a proposal instruction.
+pub const MAX_PROPOSAL_ACCOUNTS: usize = 16;
+
+/// Maximum length of a proposal instruction data.
+pub const MAX_PROPOSAL_DATA: usize = 512;
+
+/// Governance data.
+///
+/// The governance account is a PDA derived from `[GOVERNANCE_SEED, mint]`, where
+/// `mint` is the mint of the voting token. Passed proposals are signed by the
+/// governance authority, a PDA derived from `[GOVERNANCE_AUTHORITY_SEED,
+/// governance]`. Voting tokens are escrowed in a token account owned by the
+/// governance account, which never signs proposals.
+#[repr(C)]
+pub struct Governance {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the governance derived address.
+    pub bump: u8,
+
+    /// Bump seed of the authority derived address.
+    pub authority_bump: u8,
+
+    /// Mint of the voting token.
+    pub mint: Pubkey,
+
+    /// Token account escrowing the voting tokens until the voting periods end.
+    pub escrow: Pubkey,
+
+    /// Duration, in seconds, of the voting period of a proposal.
+    voting_period: [u8; 8],
+
+    /// Minimum number of votes in favour for a proposal to pass.
+    quorum: [u8; 8],
+
+    /// Index of the next proposal.
+    proposal_index: [u8; 8],
+}
+
+impl Governance {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Governance as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_voting_period(&mut self, voting_period: i64) {
+        self.voting_period = voting_period.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn voting_period(&self) -> i64 {
+        i64::from_le_bytes(self.voting_period)
+    }
+
+    #[inline(always)]
+    pub fn set_quorum(&mut self, quorum: u64) {
+        self.quorum = quorum.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn quorum(&self) -> u64 {
+        u64::from_le_bytes(self.quorum)
+    }
+
+    #[inline(always)]
+    pub fn set_proposal_index(&mut self, proposal_index: u64) {
+        self.proposal_index = proposal_index.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn proposal_index(&self) -> u64 {
+        u64::from_le_bytes(self.proposal_index)
+    }
+}
+
+impl RawType for Governance {
+    const LEN: usize = size_of::<Governance>();
+}
+
+impl Initializable for Governance {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Governance as u8
+    }
+}
+
+/// Proposal data.
+///
+/// The proposal account is a PDA derived from `[PROPOSAL_SEED, governance, index]`
+/// and it stores a single instruction to be executed once the voting period ends,
+/// if the proposal passed.
+#[repr(C)]
+pub struct Proposal {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the proposal derived address.
+    pub bump: u8,
+
+    /// Indicates whether the proposal was executed.
+    executed: u8,
+
+    /// Number of accounts of the instruction.
+    pub accounts_len: u8,
+
+    /// Governance of the proposal.
+    pub governance: Pubkey,
+
+    /// Index of the proposal.
+    index: [u8; 8],
+
+    /// Unix timestamp at which the voting period ends.
+    voting_ends_at: [u8; 8],
+
+    /// Total weight of the votes in favour.
+    yes_votes: [u8; 8],
+
+    /// Total weight of the votes against.
+    no_votes: [u8; 8],
+
+    /// Length of the instruction data.
+    data_len: [u8; 2],
+
+    /// Program to invoke.
+    pub program_id: Pubkey,
+
+    /// Accounts of the instruction.
+    pub accounts: [TransactionAccount; MAX_PROPOSAL_ACCOUNTS],
+
+    /// Instruction data.
+    data: [u8; MAX_PROPOSAL_DATA],
+}
+
+impl Proposal {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Proposal as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_executed(&mut self) {
+        self.executed = 1;
+    }
+
+    #[inline(always)]
+    pub fn is_executed(&self) -> bool {
+        self.executed != 0
+    }
+
+    #[inline(always)]
+    pub fn set_index(&mut self, index: u64) {
+        self.index = index.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn index(&self) -> u64 {
+        u64::from_le_bytes(self.index)
+    }
+
+    #[inline(always)]
+    pub fn set_voting_ends_at(&mut self, voting_ends_at: i64) {
+        self.voting_ends_at = voting_ends_at.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn voting_ends_at(&self) -> i64 {
+        i64::from_le_bytes(self.voting_ends_at)
+    }
+
+    #[inline(always)]
+    pub fn set_yes_votes(&mut self, yes_votes: u64) {
+        self.yes_votes = yes_votes.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn yes_votes(&self) -> u64 {
+        u64::from_le_bytes(self.yes_votes)
+    }
+
+    #[inline(always)]
+    pub fn set_no_votes(&mut self, no_votes: u64) {
+        self.no_votes = no_votes.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn no_votes(&self) -> u64 {
+        u64::from_le_bytes(self.no_votes)
+    }
+
+    /// Indicates whether the votes in favour reach `quorum` and outweigh the votes
+    /// against.
+    #[inline(always)]
+    pub fn is_passed(&self, quorum: u64) -> bool {
+        self.yes_votes() >= quorum && self.yes_votes() > self.no_votes()
+    }
+
+    /// Sets the instruction data.
+    ///
+    /// The caller must guarantee that `data` is at most [`MAX_PROPOSAL_DATA`]
+    /// bytes long.
+    #[inline(always)]
+    pub fn set_data(&mut self, data: &[u8]) {
+        self.data[..data.len()].copy_from_slice(data);
+        self.data_len = (data.len() as u16).to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn data(&self) -> &[u8] {
+        &self.data[..u16::from_le_bytes(self.data_len) as usize]
+    }
+}
+
+impl RawType for Proposal {
+    const LEN: usize = size_of::<Proposal>();
+}
+
+impl Initializable for Proposal {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Proposal as u8
+    }
+}
+
+/// Vote record data.
+///
+/// The vote record account is a PDA derived from `[VOTE_RECORD_SEED, proposal,
+/// voter]`, so each voter can only vote once on a proposal.
+#[repr(C)]
+pub struct VoteRecord {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the vote record derived address.
+    pub bump: u8,
+
+    /// Indicates whether the vote is in favour of the proposal.
+    approve: u8,
+
+    /// Proposal of the vote.
+    pub proposal: Pubkey,
+
+    /// Voter.
+    pub voter: Pubkey,
+
+    /// Weight of the vote, the voter token balance escrowed when voting.
+    weight: [u8; 8],
+}
+
+impl VoteRecord {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::VoteRecord as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_approve(&mut self, approve: bool) {
+        self.approve = approve as u8;
+    }
+
+    #[inline(always)]
+    pub fn is_approve(&self) -> bool {
+        self.approve != 0
+    }
+
+    #[inline(always)]
+    pub fn set_weight(&mut self, weight: u64) {
+        self.weight = weight.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn weight(&self) -> u64 {
+        u64::from_le_bytes(self.weight)
+    }
+}
+
+impl RawType for VoteRecord {
+    const LEN: usize = size_of::<VoteRecord>();
+}
+
+impl Initializable for VoteRecord {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::VoteRecord as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Governance::LEN));
+const _: () = assert!(is_valid_extension_len(Proposal::LEN));
+const _: () = assert!(is_valid_extension_len(VoteRecord::LEN));
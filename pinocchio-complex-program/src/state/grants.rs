@@ -0,0 +1,98 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the grant derived address.
+pub const GRANT_SEED: &[u8] = b"grant";
+
+/// Maximum number of milestones of a grant.
+pub const MAX_MILESTONES: usize = 8;
+
+/// Milestone-based grant data.
+///
+/// The grant account is a PDA derived from `[GRANT_SEED, create_key]` and it is the
+/// owner of the token vault escrowing the grant funds. Milestones are released in
+/// order.
+#[repr(C)]
+pub struct Grant {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the grant derived address.
+    pub bump: u8,
+
+    /// Number of milestones.
+    pub milestones_len: u8,
+
+    /// Number of milestones released.
+    pub released: u8,
+
+    /// Key used to derive the grant address.
+    pub create_key: Pubkey,
+
+    /// Funder of the grant, receiving the refund of unreleased milestones.
+    pub funder: Pubkey,
+
+    /// Approver allowed to release milestones.
+    pub approver: Pubkey,
+
+    /// Token account of the grantee receiving the released milestones.
+    pub grantee_account: Pubkey,
+
+    /// Token account escrowing the grant funds.
+    pub token_vault: Pubkey,
+
+    /// Timestamp after which milestones can no longer be released and the funder
+    /// can be refunded.
+    deadline: [u8; 8],
+
+    /// Amount of each milestone.
+    milestones: [[u8; 8]; MAX_MILESTONES],
+}
+
+impl Grant {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Grant as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_deadline(&mut self, deadline: i64) {
+        self.deadline = deadline.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn deadline(&self) -> i64 {
+        i64::from_le_bytes(self.deadline)
+    }
+
+    #[inline(always)]
+    pub fn set_milestone(&mut self, index: usize, amount: u64) {
+        self.milestones[index] = amount.to_le_bytes();
+    }
+
+    /// Returns the amount of the next milestone to release, if any.
+    #[inline(always)]
+    pub fn next_milestone(&self) -> Option<u64> {
+        if self.released < self.milestones_len {
+            Some(u64::from_le_bytes(self.milestones[self.released as usize]))
+        } else {
+            None
+        }
+    }
+}
+
+impl RawType for Grant {
+    const LEN: usize = size_of::<Grant>();
+}
+
+impl Initializable for Grant {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Grant as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Grant::LEN));
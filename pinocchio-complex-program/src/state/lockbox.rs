@@ -0,0 +1,72 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the lockbox derived address.
+pub const LOCKBOX_SEED: &[u8] = b"lockbox";
+
+/// Time-locked lockbox data.
+///
+/// The lockbox account is a PDA derived from `[LOCKBOX_SEED, create_key]`. It holds
+/// the locked SOL in its own lamports and it is the owner of the token vault.
+#[repr(C)]
+pub struct Lockbox {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the lockbox derived address.
+    pub bump: u8,
+
+    /// Owner of the lockbox, allowed to withdraw once it is unlocked.
+    pub owner: Pubkey,
+
+    /// Key used to derive the lockbox address.
+    pub create_key: Pubkey,
+
+    /// Token account holding the locked tokens, if any.
+    pub token_vault: Pubkey,
+
+    /// Mint of the locked tokens, if the lockbox has a token vault.
+    pub mint: Pubkey,
+
+    /// Unix timestamp after which the lockbox can be withdrawn.
+    unlock_timestamp: [u8; 8],
+}
+
+impl Lockbox {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Lockbox as u8;
+    }
+
+    /// Indicates whether the lockbox accepts token deposits.
+    #[inline(always)]
+    pub fn has_token_vault(&self) -> bool {
+        self.token_vault != Pubkey::default()
+    }
+
+    #[inline(always)]
+    pub fn set_unlock_timestamp(&mut self, unlock_timestamp: i64) {
+        self.unlock_timestamp = unlock_timestamp.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn unlock_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.unlock_timestamp)
+    }
+}
+
+impl RawType for Lockbox {
+    const LEN: usize = size_of::<Lockbox>();
+}
+
+impl Initializable for Lockbox {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Lockbox as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Lockbox::LEN));
@@ -0,0 +1,70 @@
+//! Versioned account layouts.
+//!
+//! Versioned extension accounts start with a [`StateHeader`], recording the
+//! account type and the version of its layout. Types implementing [`Migratable`]
+//! describe every layout version, so accounts with older layouts can be upgraded
+//! one version at a time.
+
+use pinocchio::{program_error::ProgramError, ProgramResult};
+use token_interface::state::RawType;
+
+use super::AccountDiscriminator;
+
+/// Header prefixing the data of versioned extension accounts.
+#[repr(C)]
+pub struct StateHeader {
+    /// Account discriminator.
+    pub discriminator: u8,
+
+    /// Version of the account layout.
+    pub version: u8,
+}
+
+impl StateHeader {
+    /// Length of the header.
+    pub const LEN: usize = core::mem::size_of::<StateHeader>();
+
+    /// Returns the header of the account data, if the data is long enough.
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Option<&StateHeader> {
+        if data.len() < Self::LEN {
+            return None;
+        }
+        // SAFETY: the data is at least as long as the header, which has an
+        // alignment of 1.
+        Some(unsafe { &*(data.as_ptr() as *const StateHeader) })
+    }
+
+    /// Returns the mutable header of the account data, if the data is long enough.
+    #[inline(always)]
+    pub fn from_bytes_mut(data: &mut [u8]) -> Option<&mut StateHeader> {
+        if data.len() < Self::LEN {
+            return None;
+        }
+        // SAFETY: the data is at least as long as the header, which has an
+        // alignment of 1.
+        Some(unsafe { &mut *(data.as_mut_ptr() as *mut StateHeader) })
+    }
+}
+
+/// Account type with a versioned layout.
+///
+/// `RawType::LEN` is the length of the current layout, of version
+/// [`Migratable::VERSION`].
+pub trait Migratable: RawType {
+    /// Discriminator of the account type.
+    const DISCRIMINATOR: AccountDiscriminator;
+
+    /// Current version of the layout.
+    const VERSION: u8;
+
+    /// Returns the length of the layout of `version`.
+    fn len(version: u8) -> Result<usize, ProgramError>;
+
+    /// Transforms the account data from the layout of `version` to the layout of
+    /// `version + 1`.
+    ///
+    /// The data is as long as the longest of both layouts, with any additional
+    /// bytes zeroed. The header version is updated by the caller.
+    fn migrate(version: u8, data: &mut [u8]) -> ProgramResult;
+}
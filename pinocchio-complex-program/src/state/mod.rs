@@ -0,0 +1,111 @@
+//! State of the accounts owned by the extension instructions.
+//!
+//! Extension accounts are owned by the token program, the same as mint, token and
+//! multisig accounts. To avoid one type being mistaken for another, every extension
+//! account starts with an [`AccountDiscriminator`] byte and its length must differ
+//! from the length of the token program account types.
+
+use token_interface::state::{account::Account, mint::Mint, multisig::Multisig, RawType};
+
+pub mod airdrop;
+pub mod auction;
+pub mod bonding_curve;
+pub mod campaign;
+pub mod config;
+pub mod dead_man_switch;
+pub mod dutch_auction;
+pub mod evm_wallet;
+pub mod faucet;
+pub mod flash_loan;
+pub mod governance;
+pub mod grants;
+pub mod lockbox;
+pub mod migration;
+pub mod multisig_wallet;
+pub mod orderbook;
+pub mod otc;
+pub mod points;
+pub mod pool;
+pub mod profile;
+pub mod raffle;
+pub mod rate_limit;
+pub mod referral;
+pub mod registry;
+pub mod rewards;
+pub mod royalty;
+pub mod savings;
+pub mod signed_escrow;
+pub mod social_recovery;
+pub mod soulbound;
+pub mod splitter;
+pub mod staking;
+pub mod subscription;
+pub mod ticket;
+pub mod timelock;
+pub mod transfer_gate;
+pub mod treasury;
+pub mod vault;
+pub mod voucher;
+
+/// Discriminator of the extension account types.
+///
+/// The value `0` is reserved for uninitialized accounts.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AccountDiscriminator {
+    Pool = 1,
+    StakePool,
+    StakeRecord,
+    MultisigWallet,
+    MultisigTransaction,
+    Timelock,
+    TimelockAction,
+    Config,
+    TransferGate,
+    GateEntry,
+    Vault,
+    VaultDeposit,
+    Auction,
+    DutchAuction,
+    Raffle,
+    RaffleTicket,
+    Campaign,
+    Contribution,
+    Subscription,
+    Splitter,
+    Royalty,
+    Faucet,
+    FaucetClaim,
+    RateLimit,
+    FlashLoanPool,
+    Market,
+    OtcOffer,
+    Lockbox,
+    Profile,
+    Treasury,
+    Referral,
+    BondingCurve,
+    SoulboundIssuer,
+    Ticket,
+    Voucher,
+    NameRecord,
+    PointsProgram,
+    PointsLedger,
+    Savings,
+    DeadManSwitch,
+    Recovery,
+    Grant,
+    Rewards,
+    Airdrop,
+    Governance,
+    Proposal,
+    VoteRecord,
+    SignedEscrow,
+    EvmWallet,
+}
+
+/// Checks that the length of an extension account type does not match any of the
+/// token program account types.
+pub(crate) const fn is_valid_extension_len(len: usize) -> bool {
+    len != Account::LEN && len != Mint::LEN && len != Multisig::LEN
+}
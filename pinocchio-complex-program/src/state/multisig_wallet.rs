@@ -0,0 +1,212 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the multisig wallet derived address.
+pub const MULTISIG_WALLET_SEED: &[u8] = b"multisig_wallet";
+
+/// Seed prefix of the multisig vault derived address.
+pub const MULTISIG_VAULT_SEED: &[u8] = b"multisig_vault";
+
+/// Seed prefix of the multisig transaction derived address.
+pub const MULTISIG_TRANSACTION_SEED: &[u8] = b"multisig_transaction";
+
+/// Maximum number of owners of a multisig wallet.
+pub const MAX_OWNERS: usize = 10;
+
+/// Maximum number of accounts of a multisig transaction instruction.
+pub const MAX_TRANSACTION_ACCOUNTS: usize = 16;
+
+/// Maximum length of a multisig transaction instruction data.
+pub const MAX_TRANSACTION_DATA: usize = 512;
+
+/// Multisig wallet data.
+///
+/// The wallet account is a PDA derived from `[MULTISIG_WALLET_SEED, create_key]`.
+/// Approved transactions are signed by the wallet vault, a PDA derived from
+/// `[MULTISIG_VAULT_SEED, wallet]`.
+#[repr(C)]
+pub struct MultisigWallet {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the wallet derived address.
+    pub bump: u8,
+
+    /// Bump seed of the vault derived address.
+    pub vault_bump: u8,
+
+    /// Number of approvals required.
+    pub m: u8,
+
+    /// Number of owners.
+    pub n: u8,
+
+    /// Key used to derive the wallet address.
+    pub create_key: Pubkey,
+
+    /// Index of the next transaction.
+    transaction_index: [u8; 8],
+
+    /// Owner keys.
+    pub owners: [Pubkey; MAX_OWNERS],
+}
+
+impl MultisigWallet {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::MultisigWallet as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_transaction_index(&mut self, transaction_index: u64) {
+        self.transaction_index = transaction_index.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn transaction_index(&self) -> u64 {
+        u64::from_le_bytes(self.transaction_index)
+    }
+
+    /// Returns the position of `key` in the owners list.
+    #[inline(always)]
+    pub fn owner_position(&self, key: &Pubkey) -> Option<usize> {
+        self.owners[..self.n as usize]
+            .iter()
+            .position(|owner| owner == key)
+    }
+}
+
+impl RawType for MultisigWallet {
+    const LEN: usize = size_of::<MultisigWallet>();
+}
+
+impl Initializable for MultisigWallet {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::MultisigWallet as u8
+    }
+}
+
+/// Account of a multisig transaction instruction.
+#[repr(C)]
+pub struct TransactionAccount {
+    /// Address of the account.
+    pub pubkey: Pubkey,
+
+    /// Indicates whether the account is a signer.
+    pub is_signer: u8,
+
+    /// Indicates whether the account is writable.
+    pub is_writable: u8,
+}
+
+/// Multisig transaction data.
+///
+/// The transaction account is a PDA derived from `[MULTISIG_TRANSACTION_SEED,
+/// wallet, index]` and it stores a single instruction to be executed once the
+/// number of approvals reaches the wallet threshold.
+#[repr(C)]
+pub struct MultisigTransaction {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the transaction derived address.
+    pub bump: u8,
+
+    /// Indicates whether the transaction was executed.
+    executed: u8,
+
+    /// Number of accounts of the instruction.
+    pub accounts_len: u8,
+
+    /// Wallet of the transaction.
+    pub wallet: Pubkey,
+
+    /// Index of the transaction.
+    index: [u8; 8],
+
+    /// Bitmap of the owners that approved the transaction.
+    approvals: [u8; 2],
+
+    /// Length of the instruction data.
+    data_len: [u8; 2],
+
+    /// Program to invoke.
+    pub program_id: Pubkey,
+
+    /// Accounts of the instruction.
+    pub accounts: [TransactionAccount; MAX_TRANSACTION_ACCOUNTS],
+
+    /// Instruction data.
+    data: [u8; MAX_TRANSACTION_DATA],
+}
+
+impl MultisigTransaction {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::MultisigTransaction as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_executed(&mut self) {
+        self.executed = 1;
+    }
+
+    #[inline(always)]
+    pub fn is_executed(&self) -> bool {
+        self.executed != 0
+    }
+
+    #[inline(always)]
+    pub fn set_index(&mut self, index: u64) {
+        self.index = index.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn index(&self) -> u64 {
+        u64::from_le_bytes(self.index)
+    }
+
+    #[inline(always)]
+    pub fn approve(&mut self, position: usize) {
+        self.approvals = (u16::from_le_bytes(self.approvals) | (1 << position)).to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn approvals(&self) -> u8 {
+        u16::from_le_bytes(self.approvals).count_ones() as u8
+    }
+
+    /// Sets the instruction data.
+    ///
+    /// The caller must guarantee that `data` is at most [`MAX_TRANSACTION_DATA`]
+    /// bytes long.
+    #[inline(always)]
+    pub fn set_data(&mut self, data: &[u8]) {
+        self.data[..data.len()].copy_from_slice(data);
+        self.data_len = (data.len() as u16).to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn data(&self) -> &[u8] {
+        &self.data[..u16::from_le_bytes(self.data_len) as usize]
+    }
+}
+
+impl RawType for MultisigTransaction {
+    const LEN: usize = size_of::<MultisigTransaction>();
+}
+
+impl Initializable for MultisigTransaction {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::MultisigTransaction as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(MultisigWallet::LEN));
+const _: () = assert!(is_valid_extension_len(MultisigTransaction::LEN));
+const _: () = assert!(MAX_OWNERS <= u16::BITS as usize);
@@ -0,0 +1,189 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the market derived address.
+pub const MARKET_SEED: &[u8] = b"market";
+
+/// Maximum number of resting orders on each side of a market.
+pub const MAX_ORDERS: usize = 16;
+
+/// Side of an order buying base tokens with quote tokens.
+pub const BID: u8 = 0;
+
+/// Side of an order selling base tokens for quote tokens.
+pub const ASK: u8 = 1;
+
+/// Resting order of a market.
+///
+/// The price is the amount of quote tokens per base token. Fills are credited to
+/// the order and withdrawn by its owner, so an order with no remaining quantity
+/// is only an empty slot once its proceeds are settled.
+#[repr(C)]
+pub struct Order {
+    /// Sequential id of the order, giving its time priority.
+    id: [u8; 8],
+
+    /// Owner of the order.
+    pub owner: Pubkey,
+
+    /// Token account of the owner receiving base tokens.
+    pub base_account: Pubkey,
+
+    /// Token account of the owner receiving quote tokens.
+    pub quote_account: Pubkey,
+
+    /// Price of the order.
+    price: [u8; 8],
+
+    /// Remaining quantity of base tokens.
+    quantity: [u8; 8],
+
+    /// Base tokens owed to the owner by fills.
+    base_owed: [u8; 8],
+
+    /// Quote tokens owed to the owner by fills and price refunds.
+    quote_owed: [u8; 8],
+}
+
+impl Order {
+    #[inline(always)]
+    pub fn set_id(&mut self, id: u64) {
+        self.id = id.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn id(&self) -> u64 {
+        u64::from_le_bytes(self.id)
+    }
+
+    #[inline(always)]
+    pub fn set_price(&mut self, price: u64) {
+        self.price = price.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn price(&self) -> u64 {
+        u64::from_le_bytes(self.price)
+    }
+
+    #[inline(always)]
+    pub fn set_quantity(&mut self, quantity: u64) {
+        self.quantity = quantity.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn quantity(&self) -> u64 {
+        u64::from_le_bytes(self.quantity)
+    }
+
+    #[inline(always)]
+    pub fn set_base_owed(&mut self, base_owed: u64) {
+        self.base_owed = base_owed.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn base_owed(&self) -> u64 {
+        u64::from_le_bytes(self.base_owed)
+    }
+
+    #[inline(always)]
+    pub fn set_quote_owed(&mut self, quote_owed: u64) {
+        self.quote_owed = quote_owed.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn quote_owed(&self) -> u64 {
+        u64::from_le_bytes(self.quote_owed)
+    }
+
+    /// Returns whether the order has a remaining quantity to be matched.
+    #[inline(always)]
+    pub fn is_resting(&self) -> bool {
+        self.quantity() != 0
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        !self.is_resting() && self.base_owed() == 0 && self.quote_owed() == 0
+    }
+}
+
+/// Order book market data.
+///
+/// The market account is a PDA derived from `[MARKET_SEED, create_key]` and it is
+/// the owner of the base and quote vaults escrowing the funds of resting orders.
+#[repr(C)]
+pub struct Market {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the market derived address.
+    pub bump: u8,
+
+    /// Key used to derive the market address.
+    pub create_key: Pubkey,
+
+    /// Token account escrowing the base tokens of asks.
+    pub base_vault: Pubkey,
+
+    /// Token account escrowing the quote tokens of bids.
+    pub quote_vault: Pubkey,
+
+    /// Mint of the base tokens.
+    pub base_mint: Pubkey,
+
+    /// Mint of the quote tokens.
+    pub quote_mint: Pubkey,
+
+    /// Id of the next order.
+    next_order_id: [u8; 8],
+
+    /// Resting bids.
+    pub bids: [Order; MAX_ORDERS],
+
+    /// Resting asks.
+    pub asks: [Order; MAX_ORDERS],
+}
+
+impl Market {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Market as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_next_order_id(&mut self, next_order_id: u64) {
+        self.next_order_id = next_order_id.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn next_order_id(&self) -> u64 {
+        u64::from_le_bytes(self.next_order_id)
+    }
+
+    /// Returns the resting orders of `side`.
+    #[inline(always)]
+    pub fn orders_mut(&mut self, side: u8) -> Option<&mut [Order; MAX_ORDERS]> {
+        match side {
+            BID => Some(&mut self.bids),
+            ASK => Some(&mut self.asks),
+            _ => None,
+        }
+    }
+}
+
+impl RawType for Market {
+    const LEN: usize = size_of::<Market>();
+}
+
+impl Initializable for Market {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Market as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Market::LEN));
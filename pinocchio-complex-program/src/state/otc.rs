@@ -0,0 +1,88 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the OTC offer derived address.
+pub const OTC_OFFER_SEED: &[u8] = b"otc_offer";
+
+/// Two-party OTC swap offer.
+///
+/// The offer account is a PDA derived from `[OTC_OFFER_SEED, create_key]` and it is
+/// the owner of the escrow holding the offered tokens.
+#[repr(C)]
+pub struct OtcOffer {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the offer derived address.
+    pub bump: u8,
+
+    /// Maker of the offer.
+    pub maker: Pubkey,
+
+    /// Key used to derive the offer address.
+    pub create_key: Pubkey,
+
+    /// Token account escrowing the offered tokens.
+    pub escrow: Pubkey,
+
+    /// Token account of the maker receiving the requested tokens.
+    pub maker_receive_account: Pubkey,
+
+    /// Only taker allowed to fill the offer, if any.
+    pub taker: Pubkey,
+
+    /// Amount of offered tokens.
+    offered_amount: [u8; 8],
+
+    /// Amount of requested tokens.
+    requested_amount: [u8; 8],
+}
+
+impl OtcOffer {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::OtcOffer as u8;
+    }
+
+    /// Indicates whether the offer can be filled by `taker`.
+    #[inline(always)]
+    pub fn is_fillable_by(&self, taker: &Pubkey) -> bool {
+        self.taker == Pubkey::default() || &self.taker == taker
+    }
+
+    #[inline(always)]
+    pub fn set_offered_amount(&mut self, offered_amount: u64) {
+        self.offered_amount = offered_amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn offered_amount(&self) -> u64 {
+        u64::from_le_bytes(self.offered_amount)
+    }
+
+    #[inline(always)]
+    pub fn set_requested_amount(&mut self, requested_amount: u64) {
+        self.requested_amount = requested_amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn requested_amount(&self) -> u64 {
+        u64::from_le_bytes(self.requested_amount)
+    }
+}
+
+impl RawType for OtcOffer {
+    const LEN: usize = size_of::<OtcOffer>();
+}
+
+impl Initializable for OtcOffer {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::OtcOffer as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(OtcOffer::LEN));
@@ -0,0 +1,102 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the points program derived address.
+pub const POINTS_PROGRAM_SEED: &[u8] = b"points_program";
+
+/// Seed prefix of the points ledger derived address.
+pub const POINTS_LEDGER_SEED: &[u8] = b"points_ledger";
+
+/// Points program data.
+///
+/// The points program account is a PDA derived from
+/// `[POINTS_PROGRAM_SEED, create_key]`.
+#[repr(C)]
+pub struct PointsProgram {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the points program derived address.
+    pub bump: u8,
+
+    /// Admin of the points program, allowed to set the issuer.
+    pub admin: Pubkey,
+
+    /// Issuer allowed to award and deduct points.
+    pub issuer: Pubkey,
+}
+
+impl PointsProgram {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::PointsProgram as u8;
+    }
+}
+
+impl RawType for PointsProgram {
+    const LEN: usize = size_of::<PointsProgram>();
+}
+
+impl Initializable for PointsProgram {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::PointsProgram as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(PointsProgram::LEN));
+
+/// Points ledger of a user.
+///
+/// The points ledger account is a PDA derived from
+/// `[POINTS_LEDGER_SEED, points_program, user]`.
+#[repr(C)]
+pub struct PointsLedger {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the points ledger derived address.
+    pub bump: u8,
+
+    /// Points program of the ledger.
+    pub points_program: Pubkey,
+
+    /// User holding the points.
+    pub user: Pubkey,
+
+    /// Current points balance.
+    balance: [u8; 8],
+}
+
+impl PointsLedger {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::PointsLedger as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_balance(&mut self, balance: u64) {
+        self.balance = balance.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn balance(&self) -> u64 {
+        u64::from_le_bytes(self.balance)
+    }
+}
+
+impl RawType for PointsLedger {
+    const LEN: usize = size_of::<PointsLedger>();
+}
+
+impl Initializable for PointsLedger {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::PointsLedger as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(PointsLedger::LEN));
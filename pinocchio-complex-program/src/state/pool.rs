@@ -0,0 +1,69 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the pool derived address.
+pub const POOL_SEED: &[u8] = b"pool";
+
+/// Constant-product pool data.
+///
+/// The pool account is a PDA derived from `[POOL_SEED, mint_a, mint_b]` and it is
+/// the owner of both vaults and the mint authority of the LP mint.
+#[repr(C)]
+pub struct Pool {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the pool derived address.
+    pub bump: u8,
+
+    /// Swap fee in basis points.
+    fee_bps: [u8; 2],
+
+    /// Mint of the token A.
+    pub mint_a: Pubkey,
+
+    /// Mint of the token B.
+    pub mint_b: Pubkey,
+
+    /// Token account holding the token A reserves.
+    pub vault_a: Pubkey,
+
+    /// Token account holding the token B reserves.
+    pub vault_b: Pubkey,
+
+    /// Mint of the liquidity provider tokens.
+    pub lp_mint: Pubkey,
+}
+
+impl Pool {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Pool as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_fee_bps(&mut self, fee_bps: u16) {
+        self.fee_bps = fee_bps.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn fee_bps(&self) -> u16 {
+        u16::from_le_bytes(self.fee_bps)
+    }
+}
+
+impl RawType for Pool {
+    const LEN: usize = size_of::<Pool>();
+}
+
+impl Initializable for Pool {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Pool as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Pool::LEN));
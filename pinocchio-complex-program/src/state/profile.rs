@@ -0,0 +1,114 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, migration::StateHeader, AccountDiscriminator};
+
+/// Seed prefix of the profile derived address.
+pub const PROFILE_SEED: &[u8] = b"profile";
+
+/// Current version of the profile layout.
+pub const PROFILE_VERSION: u8 = 2;
+
+/// Profile data.
+///
+/// The profile account is a PDA derived from `[PROFILE_SEED, authority]`. Every
+/// layout starts with a [`StateHeader`], so the layout of an account can be
+/// determined before loading it.
+#[repr(C)]
+pub struct Profile {
+    /// Header of the profile, recording the version of its layout.
+    pub header: StateHeader,
+
+    /// Bump seed of the profile derived address.
+    pub bump: u8,
+
+    /// Authority of the profile.
+    pub authority: Pubkey,
+
+    /// Score of the profile.
+    score: [u8; 8],
+
+    /// Slot of the last score update.
+    last_update_slot: [u8; 8],
+}
+
+impl Profile {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.header.discriminator = AccountDiscriminator::Profile as u8;
+        self.header.version = PROFILE_VERSION;
+    }
+
+    #[inline(always)]
+    pub fn set_score(&mut self, score: u64) {
+        self.score = score.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn score(&self) -> u64 {
+        u64::from_le_bytes(self.score)
+    }
+
+    #[inline(always)]
+    pub fn set_last_update_slot(&mut self, slot: u64) {
+        self.last_update_slot = slot.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn last_update_slot(&self) -> u64 {
+        u64::from_le_bytes(self.last_update_slot)
+    }
+}
+
+impl RawType for Profile {
+    const LEN: usize = size_of::<Profile>();
+}
+
+impl Initializable for Profile {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.header.discriminator == AccountDiscriminator::Profile as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Profile::LEN));
+
+/// Profile data of version `1`.
+///
+/// The score was stored as a `u32` and the slot of the last update was not
+/// recorded. Accounts with this layout must be migrated before they can be used.
+#[repr(C)]
+pub struct ProfileV1 {
+    /// Header of the profile, recording the version of its layout.
+    pub header: StateHeader,
+
+    /// Bump seed of the profile derived address.
+    pub bump: u8,
+
+    /// Authority of the profile.
+    pub authority: Pubkey,
+
+    /// Score of the profile.
+    score: [u8; 4],
+}
+
+impl ProfileV1 {
+    #[inline(always)]
+    pub fn score(&self) -> u32 {
+        u32::from_le_bytes(self.score)
+    }
+}
+
+impl RawType for ProfileV1 {
+    const LEN: usize = size_of::<ProfileV1>();
+}
+
+impl Initializable for ProfileV1 {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.header.discriminator == AccountDiscriminator::Profile as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(ProfileV1::LEN));
@@ -0,0 +1,163 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the raffle derived address.
+pub const RAFFLE_SEED: &[u8] = b"raffle";
+
+/// Seed prefix of the raffle ticket derived address.
+pub const RAFFLE_TICKET_SEED: &[u8] = b"raffle_ticket";
+
+/// Raffle data.
+///
+/// The raffle account is a PDA derived from `[RAFFLE_SEED, create_key]`. It holds the
+/// ticket proceeds in its own lamports, which are the prize of the winner.
+#[repr(C)]
+pub struct Raffle {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the raffle derived address.
+    pub bump: u8,
+
+    /// Indicates whether the winner has been drawn.
+    drawn: u8,
+
+    /// Key used to derive the raffle address.
+    pub create_key: Pubkey,
+
+    /// Price of a ticket, in lamports.
+    ticket_price: [u8; 8],
+
+    /// Number of tickets sold.
+    tickets_sold: [u8; 8],
+
+    /// Slot after which no tickets are sold, whose hash selects the winner.
+    end_slot: [u8; 8],
+
+    /// Index of the winning ticket, once drawn.
+    winning_ticket: [u8; 8],
+}
+
+impl Raffle {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Raffle as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_drawn(&mut self) {
+        self.drawn = 1;
+    }
+
+    #[inline(always)]
+    pub fn is_drawn(&self) -> bool {
+        self.drawn != 0
+    }
+
+    #[inline(always)]
+    pub fn set_ticket_price(&mut self, price: u64) {
+        self.ticket_price = price.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn ticket_price(&self) -> u64 {
+        u64::from_le_bytes(self.ticket_price)
+    }
+
+    #[inline(always)]
+    pub fn set_tickets_sold(&mut self, tickets: u64) {
+        self.tickets_sold = tickets.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn tickets_sold(&self) -> u64 {
+        u64::from_le_bytes(self.tickets_sold)
+    }
+
+    #[inline(always)]
+    pub fn set_end_slot(&mut self, slot: u64) {
+        self.end_slot = slot.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn end_slot(&self) -> u64 {
+        u64::from_le_bytes(self.end_slot)
+    }
+
+    #[inline(always)]
+    pub fn set_winning_ticket(&mut self, index: u64) {
+        self.winning_ticket = index.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn winning_ticket(&self) -> u64 {
+        u64::from_le_bytes(self.winning_ticket)
+    }
+}
+
+impl RawType for Raffle {
+    const LEN: usize = size_of::<Raffle>();
+}
+
+impl Initializable for Raffle {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Raffle as u8
+    }
+}
+
+/// Raffle ticket.
+///
+/// The ticket account is a PDA derived from `[RAFFLE_TICKET_SEED, raffle, index]`,
+/// where `index` is the little-endian ticket index.
+#[repr(C)]
+pub struct RaffleTicket {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the ticket derived address.
+    pub bump: u8,
+
+    /// Raffle of the ticket.
+    pub raffle: Pubkey,
+
+    /// Owner of the ticket.
+    pub owner: Pubkey,
+
+    /// Index of the ticket.
+    index: [u8; 8],
+}
+
+impl RaffleTicket {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::RaffleTicket as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_index(&mut self, index: u64) {
+        self.index = index.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn index(&self) -> u64 {
+        u64::from_le_bytes(self.index)
+    }
+}
+
+impl RawType for RaffleTicket {
+    const LEN: usize = size_of::<RaffleTicket>();
+}
+
+impl Initializable for RaffleTicket {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::RaffleTicket as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Raffle::LEN));
+const _: () = assert!(is_valid_extension_len(RaffleTicket::LEN));
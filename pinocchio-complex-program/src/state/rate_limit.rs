@@ -0,0 +1,112 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the rate limit derived address.
+pub const RATE_LIMIT_SEED: &[u8] = b"rate_limit";
+
+/// Token bucket rate limit.
+///
+/// The rate limit account is a PDA derived from `[RATE_LIMIT_SEED, subject]`, where
+/// the subject is the account whose operations are limited. The bucket holds up to
+/// `capacity` units and refills `refill_rate` units per slot.
+#[repr(C)]
+pub struct RateLimit {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the rate limit derived address.
+    pub bump: u8,
+
+    /// Account whose operations are limited.
+    pub subject: Pubkey,
+
+    /// Maximum number of units in the bucket.
+    capacity: [u8; 8],
+
+    /// Number of units added to the bucket per slot.
+    refill_rate: [u8; 8],
+
+    /// Number of units in the bucket at the last refill.
+    available: [u8; 8],
+
+    /// Slot of the last refill.
+    last_refill_slot: [u8; 8],
+}
+
+impl RateLimit {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::RateLimit as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_capacity(&mut self, capacity: u64) {
+        self.capacity = capacity.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn capacity(&self) -> u64 {
+        u64::from_le_bytes(self.capacity)
+    }
+
+    #[inline(always)]
+    pub fn set_refill_rate(&mut self, refill_rate: u64) {
+        self.refill_rate = refill_rate.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn refill_rate(&self) -> u64 {
+        u64::from_le_bytes(self.refill_rate)
+    }
+
+    #[inline(always)]
+    pub fn set_available(&mut self, available: u64) {
+        self.available = available.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn available(&self) -> u64 {
+        u64::from_le_bytes(self.available)
+    }
+
+    #[inline(always)]
+    pub fn set_last_refill_slot(&mut self, last_refill_slot: u64) {
+        self.last_refill_slot = last_refill_slot.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn last_refill_slot(&self) -> u64 {
+        u64::from_le_bytes(self.last_refill_slot)
+    }
+
+    /// Refills the bucket with the units accrued since the last refill.
+    #[inline(always)]
+    pub fn refill(&mut self, slot: u64) {
+        let accrued = slot
+            .saturating_sub(self.last_refill_slot())
+            .saturating_mul(self.refill_rate());
+
+        self.set_available(
+            self.available()
+                .saturating_add(accrued)
+                .min(self.capacity()),
+        );
+        self.set_last_refill_slot(slot);
+    }
+}
+
+impl RawType for RateLimit {
+    const LEN: usize = size_of::<RateLimit>();
+}
+
+impl Initializable for RateLimit {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::RateLimit as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(RateLimit::LEN));
@@ -0,0 +1,47 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the referral derived address.
+pub const REFERRAL_SEED: &[u8] = b"referral";
+
+/// Referral binding a referee to its referrer.
+///
+/// The referral account is a PDA derived from `[REFERRAL_SEED, referee]`, so a
+/// referee has a single referrer.
+#[repr(C)]
+pub struct Referral {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the referral derived address.
+    pub bump: u8,
+
+    /// Referred user.
+    pub referee: Pubkey,
+
+    /// Referrer of the user, receiving a share of its protocol fees.
+    pub referrer: Pubkey,
+}
+
+impl Referral {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Referral as u8;
+    }
+}
+
+impl RawType for Referral {
+    const LEN: usize = size_of::<Referral>();
+}
+
+impl Initializable for Referral {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Referral as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Referral::LEN));
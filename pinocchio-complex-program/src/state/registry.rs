@@ -0,0 +1,70 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the name record derived address.
+pub const NAME_RECORD_SEED: &[u8] = b"name";
+
+/// Maximum length of a name in bytes.
+pub const MAX_NAME_LEN: usize = 32;
+
+/// Name record data, mapping a name to an address.
+///
+/// The name record account is a PDA derived from `[NAME_RECORD_SEED, hash]`, where
+/// `hash` is the SHA-256 hash of the name.
+#[repr(C)]
+pub struct NameRecord {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the name record derived address.
+    pub bump: u8,
+
+    /// Owner of the name, allowed to update, transfer and close the record.
+    pub owner: Pubkey,
+
+    /// Address the name resolves to.
+    pub target: Pubkey,
+
+    /// Length of the name.
+    name_len: u8,
+
+    /// UTF-8 bytes of the name, padded with zeros.
+    name: [u8; MAX_NAME_LEN],
+}
+
+impl NameRecord {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::NameRecord as u8;
+    }
+
+    /// Sets the name of the record.
+    ///
+    /// The caller must ensure that `name` is at most `MAX_NAME_LEN` bytes long.
+    #[inline(always)]
+    pub fn set_name(&mut self, name: &[u8]) {
+        self.name[..name.len()].copy_from_slice(name);
+        self.name_len = name.len() as u8;
+    }
+
+    #[inline(always)]
+    pub fn name(&self) -> &[u8] {
+        &self.name[..self.name_len as usize]
+    }
+}
+
+impl RawType for NameRecord {
+    const LEN: usize = size_of::<NameRecord>();
+}
+
+impl Initializable for NameRecord {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::NameRecord as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(NameRecord::LEN));
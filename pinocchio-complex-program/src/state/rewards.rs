@@ -0,0 +1,64 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the rewards derived address.
+pub const REWARDS_SEED: &[u8] = b"rewards";
+
+/// Purchase rewards data.
+///
+/// The rewards account is a PDA derived from `[REWARDS_SEED, mint]` and it is the
+/// mint authority of the loyalty token mint.
+#[repr(C)]
+pub struct Rewards {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the rewards derived address.
+    pub bump: u8,
+
+    /// Reward, in basis points of the payment amount.
+    reward_bps: [u8; 2],
+
+    /// Merchant of the rewards.
+    pub merchant: Pubkey,
+
+    /// Account receiving the qualifying payments, either a system account for SOL
+    /// payments or a token account for token payments.
+    pub payment_account: Pubkey,
+
+    /// Mint of the loyalty tokens.
+    pub mint: Pubkey,
+}
+
+impl Rewards {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Rewards as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_reward_bps(&mut self, reward_bps: u16) {
+        self.reward_bps = reward_bps.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn reward_bps(&self) -> u16 {
+        u16::from_le_bytes(self.reward_bps)
+    }
+}
+
+impl RawType for Rewards {
+    const LEN: usize = size_of::<Rewards>();
+}
+
+impl Initializable for Rewards {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Rewards as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Rewards::LEN));
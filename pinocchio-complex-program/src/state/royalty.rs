@@ -0,0 +1,65 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the royalty config derived address.
+pub const ROYALTY_SEED: &[u8] = b"royalty";
+
+/// Maximum number of creators of a royalty config.
+pub const MAX_CREATORS: usize = 5;
+
+/// Royalty config of an item mint.
+///
+/// The royalty account is a PDA derived from `[ROYALTY_SEED, mint]`.
+#[repr(C)]
+pub struct Royalty {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the royalty derived address.
+    pub bump: u8,
+
+    /// Number of creators.
+    pub n: u8,
+
+    /// Mint of the item paying royalties.
+    pub mint: Pubkey,
+
+    /// Creators receiving royalties.
+    pub creators: [Pubkey; MAX_CREATORS],
+
+    /// Royalty of each creator, in basis points of the sale amount.
+    royalty_bps: [[u8; 2]; MAX_CREATORS],
+}
+
+impl Royalty {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Royalty as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_royalty_bps(&mut self, index: usize, royalty_bps: u16) {
+        self.royalty_bps[index] = royalty_bps.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn royalty_bps(&self, index: usize) -> u16 {
+        u16::from_le_bytes(self.royalty_bps[index])
+    }
+}
+
+impl RawType for Royalty {
+    const LEN: usize = size_of::<Royalty>();
+}
+
+impl Initializable for Royalty {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Royalty as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Royalty::LEN));
@@ -0,0 +1,89 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the savings account derived address.
+pub const SAVINGS_SEED: &[u8] = b"savings";
+
+/// Savings account data.
+///
+/// The savings account is a PDA derived from `[SAVINGS_SEED, owner, mint]` and it
+/// is the owner of the token vault holding the savings.
+#[repr(C)]
+pub struct Savings {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the savings account derived address.
+    pub bump: u8,
+
+    /// Owner of the savings.
+    pub owner: Pubkey,
+
+    /// Mint of the savings.
+    pub mint: Pubkey,
+
+    /// Token account holding the savings.
+    pub token_vault: Pubkey,
+
+    /// Delay in seconds between a withdrawal request and its execution.
+    withdrawal_delay: [u8; 8],
+
+    /// Amount of the pending withdrawal, `0` when there is none.
+    pending_amount: [u8; 8],
+
+    /// Timestamp from which the pending withdrawal can be executed.
+    available_at: [u8; 8],
+}
+
+impl Savings {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Savings as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_withdrawal_delay(&mut self, withdrawal_delay: i64) {
+        self.withdrawal_delay = withdrawal_delay.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn withdrawal_delay(&self) -> i64 {
+        i64::from_le_bytes(self.withdrawal_delay)
+    }
+
+    #[inline(always)]
+    pub fn set_pending_amount(&mut self, pending_amount: u64) {
+        self.pending_amount = pending_amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn pending_amount(&self) -> u64 {
+        u64::from_le_bytes(self.pending_amount)
+    }
+
+    #[inline(always)]
+    pub fn set_available_at(&mut self, available_at: i64) {
+        self.available_at = available_at.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn available_at(&self) -> i64 {
+        i64::from_le_bytes(self.available_at)
+    }
+}
+
+impl RawType for Savings {
+    const LEN: usize = size_of::<Savings>();
+}
+
+impl Initializable for Savings {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Savings as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Savings::LEN));
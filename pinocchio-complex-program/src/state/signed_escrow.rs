@@ -0,0 +1,53 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the signed escrow derived address.
+pub const SIGNED_ESCROW_SEED: &[u8] = b"signed_escrow";
+
+/// Signed escrow data.
+///
+/// The escrow account is a PDA derived from `[SIGNED_ESCROW_SEED, create_key]` and
+/// it is the owner of the token vault holding the escrow funds.
+#[repr(C)]
+pub struct SignedEscrow {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the escrow derived address.
+    pub bump: u8,
+
+    /// Depositor of the escrow funds, receiving the rent of the closed accounts.
+    pub depositor: Pubkey,
+
+    /// Key used to derive the escrow address.
+    pub create_key: Pubkey,
+
+    /// Token account holding the escrow funds.
+    pub token_vault: Pubkey,
+
+    /// Ed25519 public key authorizing the release of the funds.
+    pub signer: Pubkey,
+}
+
+impl SignedEscrow {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::SignedEscrow as u8;
+    }
+}
+
+impl RawType for SignedEscrow {
+    const LEN: usize = size_of::<SignedEscrow>();
+}
+
+impl Initializable for SignedEscrow {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::SignedEscrow as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(SignedEscrow::LEN));
@@ -0,0 +1,91 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the recovery derived address.
+pub const RECOVERY_SEED: &[u8] = b"recovery";
+
+/// Maximum number of guardians of a recovery account.
+pub const MAX_GUARDIANS: usize = 10;
+
+/// Recoverable authority data.
+///
+/// The recovery account is a PDA derived from `[RECOVERY_SEED, create_key]`. It
+/// stores an authority key that the guardians can rotate once enough of them
+/// approve the same recovery proposal.
+#[repr(C)]
+pub struct Recovery {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the recovery derived address.
+    pub bump: u8,
+
+    /// Number of guardian approvals required.
+    pub threshold: u8,
+
+    /// Number of guardians.
+    pub guardians_len: u8,
+
+    /// Key used to derive the recovery address.
+    pub create_key: Pubkey,
+
+    /// Current authority.
+    pub authority: Pubkey,
+
+    /// Authority proposed by the pending recovery, default when there is none.
+    pub proposed_authority: Pubkey,
+
+    /// Bitmap of the guardians that approved the pending recovery.
+    approvals: [u8; 2],
+
+    /// Guardian keys.
+    pub guardians: [Pubkey; MAX_GUARDIANS],
+}
+
+impl Recovery {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Recovery as u8;
+    }
+
+    #[inline(always)]
+    pub fn approve(&mut self, position: usize) {
+        self.approvals = (u16::from_le_bytes(self.approvals) | (1 << position)).to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn approvals(&self) -> u8 {
+        u16::from_le_bytes(self.approvals).count_ones() as u8
+    }
+
+    /// Clears the pending recovery.
+    #[inline(always)]
+    pub fn clear_proposal(&mut self) {
+        self.proposed_authority = Pubkey::default();
+        self.approvals = [0; 2];
+    }
+
+    /// Returns the position of `key` in the guardians list.
+    #[inline(always)]
+    pub fn guardian_position(&self, key: &Pubkey) -> Option<usize> {
+        self.guardians[..self.guardians_len as usize]
+            .iter()
+            .position(|guardian| guardian == key)
+    }
+}
+
+impl RawType for Recovery {
+    const LEN: usize = size_of::<Recovery>();
+}
+
+impl Initializable for Recovery {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Recovery as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Recovery::LEN));
@@ -0,0 +1,48 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the soulbound issuer derived address.
+pub const SOULBOUND_ISSUER_SEED: &[u8] = b"soulbound_issuer";
+
+/// Issuer of soulbound credentials.
+///
+/// The issuer account is a PDA derived from `[SOULBOUND_ISSUER_SEED, mint]`. It is
+/// the mint and freeze authority of the credential mint, and the delegate of every
+/// credential account it issues.
+#[repr(C)]
+pub struct SoulboundIssuer {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the issuer derived address.
+    pub bump: u8,
+
+    /// Authority allowed to issue and revoke credentials.
+    pub authority: Pubkey,
+
+    /// Mint of the credentials.
+    pub mint: Pubkey,
+}
+
+impl SoulboundIssuer {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::SoulboundIssuer as u8;
+    }
+}
+
+impl RawType for SoulboundIssuer {
+    const LEN: usize = size_of::<SoulboundIssuer>();
+}
+
+impl Initializable for SoulboundIssuer {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::SoulboundIssuer as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(SoulboundIssuer::LEN));
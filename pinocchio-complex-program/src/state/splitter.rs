@@ -0,0 +1,80 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the splitter derived address.
+pub const SPLITTER_SEED: &[u8] = b"splitter";
+
+/// Maximum number of recipients of a splitter.
+pub const MAX_RECIPIENTS: usize = 10;
+
+/// Payment splitter data.
+///
+/// The splitter account is a PDA derived from `[SPLITTER_SEED, create_key]` and it
+/// is the owner of the token vault accumulating the payments.
+#[repr(C)]
+pub struct Splitter {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the splitter derived address.
+    pub bump: u8,
+
+    /// Number of recipients.
+    pub n: u8,
+
+    /// Authority allowed to update the recipients.
+    pub authority: Pubkey,
+
+    /// Key used to derive the splitter address.
+    pub create_key: Pubkey,
+
+    /// Token account accumulating the payments.
+    pub token_vault: Pubkey,
+
+    /// Recipient token accounts.
+    pub recipients: [Pubkey; MAX_RECIPIENTS],
+
+    /// Share weight of each recipient.
+    weights: [[u8; 2]; MAX_RECIPIENTS],
+}
+
+impl Splitter {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Splitter as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_weight(&mut self, index: usize, weight: u16) {
+        self.weights[index] = weight.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn weight(&self, index: usize) -> u16 {
+        u16::from_le_bytes(self.weights[index])
+    }
+
+    /// Returns the sum of the recipient weights.
+    #[inline(always)]
+    pub fn total_weight(&self) -> u64 {
+        (0..self.n as usize)
+            .map(|index| self.weight(index) as u64)
+            .sum()
+    }
+}
+
+impl RawType for Splitter {
+    const LEN: usize = size_of::<Splitter>();
+}
+
+impl Initializable for Splitter {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Splitter as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Splitter::LEN));
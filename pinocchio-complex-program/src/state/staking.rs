@@ -0,0 +1,191 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the stake pool derived address.
+pub const STAKE_POOL_SEED: &[u8] = b"stake_pool";
+
+/// Seed prefix of the stake record derived address.
+pub const STAKE_RECORD_SEED: &[u8] = b"stake_record";
+
+/// Precision of the accumulated reward per staked token.
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+/// Single-sided staking pool data.
+///
+/// The stake pool account is a PDA derived from `[STAKE_POOL_SEED, admin,
+/// stake_mint, reward_mint]` and it is the owner of both vaults.
+#[repr(C)]
+pub struct StakePool {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the stake pool derived address.
+    pub bump: u8,
+
+    /// Admin of the stake pool, allowed to update the reward rate.
+    pub admin: Pubkey,
+
+    /// Mint of the staked token.
+    pub stake_mint: Pubkey,
+
+    /// Mint of the reward token.
+    pub reward_mint: Pubkey,
+
+    /// Token account holding the staked tokens.
+    pub stake_vault: Pubkey,
+
+    /// Token account holding the rewards to distribute.
+    pub reward_vault: Pubkey,
+
+    /// Rewards distributed per slot across all stakers.
+    reward_rate: [u8; 8],
+
+    /// Total amount of tokens staked.
+    total_staked: [u8; 8],
+
+    /// Accumulated rewards per staked token, scaled by [`REWARD_PRECISION`].
+    reward_per_token: [u8; 16],
+
+    /// Slot of the last reward update.
+    last_update_slot: [u8; 8],
+}
+
+impl StakePool {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::StakePool as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_reward_rate(&mut self, reward_rate: u64) {
+        self.reward_rate = reward_rate.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn reward_rate(&self) -> u64 {
+        u64::from_le_bytes(self.reward_rate)
+    }
+
+    #[inline(always)]
+    pub fn set_total_staked(&mut self, total_staked: u64) {
+        self.total_staked = total_staked.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn total_staked(&self) -> u64 {
+        u64::from_le_bytes(self.total_staked)
+    }
+
+    #[inline(always)]
+    pub fn set_reward_per_token(&mut self, reward_per_token: u128) {
+        self.reward_per_token = reward_per_token.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn reward_per_token(&self) -> u128 {
+        u128::from_le_bytes(self.reward_per_token)
+    }
+
+    #[inline(always)]
+    pub fn set_last_update_slot(&mut self, slot: u64) {
+        self.last_update_slot = slot.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn last_update_slot(&self) -> u64 {
+        u64::from_le_bytes(self.last_update_slot)
+    }
+}
+
+impl RawType for StakePool {
+    const LEN: usize = size_of::<StakePool>();
+}
+
+impl Initializable for StakePool {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::StakePool as u8
+    }
+}
+
+/// Stake record of an owner in a stake pool.
+///
+/// The stake record account is a PDA derived from `[STAKE_RECORD_SEED, stake_pool,
+/// owner]`.
+#[repr(C)]
+pub struct StakeRecord {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the stake record derived address.
+    pub bump: u8,
+
+    /// Stake pool of the record.
+    pub stake_pool: Pubkey,
+
+    /// Owner of the staked tokens.
+    pub owner: Pubkey,
+
+    /// Amount of tokens staked.
+    amount: [u8; 8],
+
+    /// Value of the pool reward per token at the last update of the record.
+    reward_per_token_paid: [u8; 16],
+
+    /// Rewards accrued and not yet claimed.
+    rewards_owed: [u8; 8],
+}
+
+impl StakeRecord {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::StakeRecord as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_amount(&mut self, amount: u64) {
+        self.amount = amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn amount(&self) -> u64 {
+        u64::from_le_bytes(self.amount)
+    }
+
+    #[inline(always)]
+    pub fn set_reward_per_token_paid(&mut self, reward_per_token: u128) {
+        self.reward_per_token_paid = reward_per_token.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn reward_per_token_paid(&self) -> u128 {
+        u128::from_le_bytes(self.reward_per_token_paid)
+    }
+
+    #[inline(always)]
+    pub fn set_rewards_owed(&mut self, rewards_owed: u64) {
+        self.rewards_owed = rewards_owed.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn rewards_owed(&self) -> u64 {
+        u64::from_le_bytes(self.rewards_owed)
+    }
+}
+
+impl RawType for StakeRecord {
+    const LEN: usize = size_of::<StakeRecord>();
+}
+
+impl Initializable for StakeRecord {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::StakeRecord as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(StakePool::LEN));
+const _: () = assert!(is_valid_extension_len(StakeRecord::LEN));
@@ -0,0 +1,90 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the subscription derived address.
+pub const SUBSCRIPTION_SEED: &[u8] = b"subscription";
+
+/// Recurring payment from a subscriber token account to a merchant token account.
+///
+/// The subscription account is a PDA derived from `[SUBSCRIPTION_SEED, source,
+/// destination]`. The subscriber approves it as the delegate of the source token
+/// account, allowing it to pull one payment per period.
+#[repr(C)]
+pub struct Subscription {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the subscription derived address.
+    pub bump: u8,
+
+    /// Owner of the source token account.
+    pub subscriber: Pubkey,
+
+    /// Token account the payments are pulled from.
+    pub source: Pubkey,
+
+    /// Token account receiving the payments.
+    pub destination: Pubkey,
+
+    /// Amount of tokens charged per period.
+    amount: [u8; 8],
+
+    /// Length of a billing period, in seconds.
+    period: [u8; 8],
+
+    /// Unix timestamp from which the next payment can be charged.
+    next_charge: [u8; 8],
+}
+
+impl Subscription {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Subscription as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_amount(&mut self, amount: u64) {
+        self.amount = amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn amount(&self) -> u64 {
+        u64::from_le_bytes(self.amount)
+    }
+
+    #[inline(always)]
+    pub fn set_period(&mut self, period: i64) {
+        self.period = period.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn period(&self) -> i64 {
+        i64::from_le_bytes(self.period)
+    }
+
+    #[inline(always)]
+    pub fn set_next_charge(&mut self, next_charge: i64) {
+        self.next_charge = next_charge.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn next_charge(&self) -> i64 {
+        i64::from_le_bytes(self.next_charge)
+    }
+}
+
+impl RawType for Subscription {
+    const LEN: usize = size_of::<Subscription>();
+}
+
+impl Initializable for Subscription {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Subscription as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Subscription::LEN));
@@ -0,0 +1,86 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the ticket derived address.
+pub const TICKET_SEED: &[u8] = b"ticket";
+
+/// Event ticket data.
+///
+/// The ticket account is a PDA derived from `[TICKET_SEED, mint]`, where the mint
+/// has a fixed supply of a single token held by the ticket holder.
+#[repr(C)]
+pub struct Ticket {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the ticket derived address.
+    pub bump: u8,
+
+    /// Indicates whether the ticket has been punched.
+    used: u8,
+
+    /// Organizer of the event, allowed to punch the ticket.
+    pub organizer: Pubkey,
+
+    /// Mint of the ticket token.
+    pub mint: Pubkey,
+
+    /// Id of the event.
+    event_id: [u8; 8],
+
+    /// Seat of the ticket.
+    seat: [u8; 4],
+}
+
+impl Ticket {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Ticket as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_used(&mut self, used: bool) {
+        self.used = used as u8;
+    }
+
+    #[inline(always)]
+    pub fn is_used(&self) -> bool {
+        self.used != 0
+    }
+
+    #[inline(always)]
+    pub fn set_event_id(&mut self, event_id: u64) {
+        self.event_id = event_id.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn event_id(&self) -> u64 {
+        u64::from_le_bytes(self.event_id)
+    }
+
+    #[inline(always)]
+    pub fn set_seat(&mut self, seat: u32) {
+        self.seat = seat.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn seat(&self) -> u32 {
+        u32::from_le_bytes(self.seat)
+    }
+}
+
+impl RawType for Ticket {
+    const LEN: usize = size_of::<Ticket>();
+}
+
+impl Initializable for Ticket {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Ticket as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Ticket::LEN));
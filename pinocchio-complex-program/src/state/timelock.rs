@@ -0,0 +1,115 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the timelock derived address.
+pub const TIMELOCK_SEED: &[u8] = b"timelock";
+
+/// Seed prefix of the queued action derived address.
+pub const TIMELOCK_ACTION_SEED: &[u8] = b"timelock_action";
+
+/// Maximum number of accounts of a queued action.
+pub const MAX_ACTION_ACCOUNTS: usize = 16;
+
+/// Timelock data.
+///
+/// The timelock account is a PDA derived from `[TIMELOCK_SEED, admin]`. Authorities
+/// set to the timelock address can only be exercised through queued actions.
+#[repr(C)]
+pub struct Timelock {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the timelock derived address.
+    pub bump: u8,
+
+    /// Admin allowed to queue, execute and cancel actions.
+    pub admin: Pubkey,
+
+    /// Minimum delay, in seconds, between queueing and executing an action.
+    delay: [u8; 8],
+}
+
+impl Timelock {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Timelock as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_delay(&mut self, delay: i64) {
+        self.delay = delay.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn delay(&self) -> i64 {
+        i64::from_le_bytes(self.delay)
+    }
+}
+
+impl RawType for Timelock {
+    const LEN: usize = size_of::<Timelock>();
+}
+
+impl Initializable for Timelock {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Timelock as u8
+    }
+}
+
+/// Action queued in a timelock.
+///
+/// The action account is a PDA derived from `[TIMELOCK_ACTION_SEED, timelock,
+/// hash]`, where `hash` is the SHA-256 of the instruction data followed by the
+/// account keys of the instruction to execute.
+#[repr(C)]
+pub struct TimelockAction {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the action derived address.
+    pub bump: u8,
+
+    /// Timelock of the action.
+    pub timelock: Pubkey,
+
+    /// Hash of the instruction to execute.
+    pub hash: [u8; 32],
+
+    /// Unix timestamp from which the action can be executed.
+    eta: [u8; 8],
+}
+
+impl TimelockAction {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::TimelockAction as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_eta(&mut self, eta: i64) {
+        self.eta = eta.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn eta(&self) -> i64 {
+        i64::from_le_bytes(self.eta)
+    }
+}
+
+impl RawType for TimelockAction {
+    const LEN: usize = size_of::<TimelockAction>();
+}
+
+impl Initializable for TimelockAction {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::TimelockAction as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Timelock::LEN));
+const _: () = assert!(is_valid_extension_len(TimelockAction::LEN));
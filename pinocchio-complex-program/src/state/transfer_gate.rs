@@ -0,0 +1,96 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the transfer gate derived address.
+pub const TRANSFER_GATE_SEED: &[u8] = b"transfer_gate";
+
+/// Seed prefix of the gate entry derived address.
+pub const GATE_ENTRY_SEED: &[u8] = b"gate_entry";
+
+/// Mode of a gate where only listed addresses can transfer.
+pub const ALLOWLIST: u8 = 0;
+
+/// Mode of a gate where listed addresses cannot transfer.
+pub const DENYLIST: u8 = 1;
+
+/// Transfer gate data.
+///
+/// The transfer gate account is a PDA derived from `[TRANSFER_GATE_SEED, mint]`.
+#[repr(C)]
+pub struct TransferGate {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the transfer gate derived address.
+    pub bump: u8,
+
+    /// Mode of the gate, either [`ALLOWLIST`] or [`DENYLIST`].
+    pub mode: u8,
+
+    /// Mint of the gated token.
+    pub mint: Pubkey,
+
+    /// Admin allowed to maintain the list.
+    pub admin: Pubkey,
+}
+
+impl TransferGate {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::TransferGate as u8;
+    }
+}
+
+impl RawType for TransferGate {
+    const LEN: usize = size_of::<TransferGate>();
+}
+
+impl Initializable for TransferGate {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::TransferGate as u8
+    }
+}
+
+/// Entry of an address in the list of a transfer gate.
+///
+/// The entry account is a PDA derived from `[GATE_ENTRY_SEED, transfer_gate,
+/// address]`; an address is listed when its entry account exists.
+#[repr(C)]
+pub struct GateEntry {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the entry derived address.
+    pub bump: u8,
+
+    /// Transfer gate of the entry.
+    pub transfer_gate: Pubkey,
+
+    /// Listed address.
+    pub address: Pubkey,
+}
+
+impl GateEntry {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::GateEntry as u8;
+    }
+}
+
+impl RawType for GateEntry {
+    const LEN: usize = size_of::<GateEntry>();
+}
+
+impl Initializable for GateEntry {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::GateEntry as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(TransferGate::LEN));
+const _: () = assert!(is_valid_extension_len(GateEntry::LEN));
@@ -0,0 +1,54 @@
+use core::mem::size_of;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed of the treasury derived address.
+pub const TREASURY_SEED: &[u8] = b"treasury";
+
+/// Protocol fee treasury data.
+///
+/// The treasury account is a PDA derived from `[TREASURY_SEED]`, so there is a
+/// single treasury per program. It is the owner of the treasury vaults, one token
+/// account per mint, accumulating the protocol fees.
+#[repr(C)]
+pub struct Treasury {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the treasury derived address.
+    pub bump: u8,
+
+    /// Share of the protocol fees paid to referrers, in basis points.
+    referral_share_bps: [u8; 2],
+}
+
+impl Treasury {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Treasury as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_referral_share_bps(&mut self, referral_share_bps: u16) {
+        self.referral_share_bps = referral_share_bps.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn referral_share_bps(&self) -> u16 {
+        u16::from_le_bytes(self.referral_share_bps)
+    }
+}
+
+impl RawType for Treasury {
+    const LEN: usize = size_of::<Treasury>();
+}
+
+impl Initializable for Treasury {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Treasury as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Treasury::LEN));
@@ -0,0 +1,152 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the vault derived address.
+pub const VAULT_SEED: &[u8] = b"vault";
+
+/// Seed prefix of the vault deposit derived address.
+pub const VAULT_DEPOSIT_SEED: &[u8] = b"vault_deposit";
+
+/// SOL and token vault data.
+///
+/// The vault account is a PDA derived from `[VAULT_SEED, create_key]`. It holds the
+/// deposited SOL in its own lamports and it is the owner of the token vault.
+#[repr(C)]
+pub struct Vault {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the vault derived address.
+    pub bump: u8,
+
+    /// Key used to derive the vault address.
+    pub create_key: Pubkey,
+
+    /// Mint of the deposited tokens, if the vault has a token vault.
+    pub mint: Pubkey,
+
+    /// Token account holding the deposited tokens, if any.
+    pub token_vault: Pubkey,
+
+    /// Total amount of SOL shares.
+    total_sol_shares: [u8; 8],
+
+    /// Total amount of token shares.
+    total_token_shares: [u8; 8],
+}
+
+impl Vault {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Vault as u8;
+    }
+
+    /// Indicates whether the vault accepts token deposits.
+    #[inline(always)]
+    pub fn has_token_vault(&self) -> bool {
+        self.token_vault != Pubkey::default()
+    }
+
+    #[inline(always)]
+    pub fn set_total_sol_shares(&mut self, shares: u64) {
+        self.total_sol_shares = shares.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn total_sol_shares(&self) -> u64 {
+        u64::from_le_bytes(self.total_sol_shares)
+    }
+
+    #[inline(always)]
+    pub fn set_total_token_shares(&mut self, shares: u64) {
+        self.total_token_shares = shares.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn total_token_shares(&self) -> u64 {
+        u64::from_le_bytes(self.total_token_shares)
+    }
+}
+
+impl RawType for Vault {
+    const LEN: usize = size_of::<Vault>();
+}
+
+impl Initializable for Vault {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Vault as u8
+    }
+}
+
+/// Shares of a depositor in a vault.
+///
+/// The deposit account is a PDA derived from `[VAULT_DEPOSIT_SEED, vault, owner]`.
+#[repr(C)]
+pub struct VaultDeposit {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the deposit derived address.
+    pub bump: u8,
+
+    /// Vault of the deposit.
+    pub vault: Pubkey,
+
+    /// Owner of the shares.
+    pub owner: Pubkey,
+
+    /// Amount of SOL shares.
+    sol_shares: [u8; 8],
+
+    /// Amount of token shares.
+    token_shares: [u8; 8],
+
+    /// Padding to keep the account length distinct from the token program account
+    /// types.
+    _padding: [u8; 8],
+}
+
+impl VaultDeposit {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::VaultDeposit as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_sol_shares(&mut self, shares: u64) {
+        self.sol_shares = shares.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn sol_shares(&self) -> u64 {
+        u64::from_le_bytes(self.sol_shares)
+    }
+
+    #[inline(always)]
+    pub fn set_token_shares(&mut self, shares: u64) {
+        self.token_shares = shares.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn token_shares(&self) -> u64 {
+        u64::from_le_bytes(self.token_shares)
+    }
+}
+
+impl RawType for VaultDeposit {
+    const LEN: usize = size_of::<VaultDeposit>();
+}
+
+impl Initializable for VaultDeposit {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::VaultDeposit as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Vault::LEN));
+const _: () = assert!(is_valid_extension_len(VaultDeposit::LEN));
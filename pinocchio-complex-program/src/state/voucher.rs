@@ -0,0 +1,53 @@
+use core::mem::size_of;
+use pinocchio::pubkey::Pubkey;
+use token_interface::state::{Initializable, RawType};
+
+use super::{is_valid_extension_len, AccountDiscriminator};
+
+/// Seed prefix of the voucher derived address.
+pub const VOUCHER_SEED: &[u8] = b"voucher";
+
+/// Voucher data.
+///
+/// The voucher account is a PDA derived from `[VOUCHER_SEED, create_key]` and it is
+/// the owner of the token vault holding the voucher funds.
+#[repr(C)]
+pub struct Voucher {
+    /// Account discriminator.
+    discriminator: u8,
+
+    /// Bump seed of the voucher derived address.
+    pub bump: u8,
+
+    /// Issuer of the voucher, receiving the rent of the closed accounts.
+    pub issuer: Pubkey,
+
+    /// Key used to derive the voucher address.
+    pub create_key: Pubkey,
+
+    /// Token account holding the voucher funds.
+    pub token_vault: Pubkey,
+
+    /// SHA-256 hash of the redemption code.
+    pub redemption_hash: [u8; 32],
+}
+
+impl Voucher {
+    #[inline(always)]
+    pub fn set_initialized(&mut self) {
+        self.discriminator = AccountDiscriminator::Voucher as u8;
+    }
+}
+
+impl RawType for Voucher {
+    const LEN: usize = size_of::<Voucher>();
+}
+
+impl Initializable for Voucher {
+    #[inline(always)]
+    fn is_initialized(&self) -> bool {
+        self.discriminator == AccountDiscriminator::Voucher as u8
+    }
+}
+
+const _: () = assert!(is_valid_extension_len(Voucher::LEN));
@@ -0,0 +1,106 @@
+//! Helpers to invoke the System program.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::{invoke, invoke_signed},
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// System program (`11111111111111111111111111111111`) address.
+pub const ID: Pubkey = [0; 32];
+
+/// Maximum length of the data of an account allocated by the System program.
+pub const MAX_PERMITTED_DATA_LENGTH: u64 = 10 * 1024 * 1024;
+
+/// Creates a new account owned by `owner`.
+///
+/// The `signers` are used to sign for derived `from` or `to` addresses.
+#[inline(always)]
+pub fn create_account(
+    from: &AccountInfo,
+    to: &AccountInfo,
+    lamports: u64,
+    space: u64,
+    owner: &Pubkey,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable_signer(from.key()),
+        AccountMeta::writable_signer(to.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (0)
+    // - [4..12]: lamports
+    // - [12..20]: space
+    // - [20..52]: owner
+    let mut instruction_data = [0u8; 52];
+    instruction_data[4..12].copy_from_slice(&lamports.to_le_bytes());
+    instruction_data[12..20].copy_from_slice(&space.to_le_bytes());
+    instruction_data[20..52].copy_from_slice(owner);
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(&instruction, &[from, to], signers)
+}
+
+/// Transfers `lamports` between system accounts.
+///
+/// The `signers` are used to sign for a derived `from` address.
+#[inline(always)]
+pub fn transfer(
+    from: &AccountInfo,
+    to: &AccountInfo,
+    lamports: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable_signer(from.key()),
+        AccountMeta::writable(to.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (2)
+    // - [4..12]: lamports
+    let mut instruction_data = [0u8; 12];
+    instruction_data[0] = 2;
+    instruction_data[4..12].copy_from_slice(&lamports.to_le_bytes());
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(&instruction, &[from, to], signers)
+}
+
+/// Invokes the System program with `instruction_data` in its native wire format,
+/// forwarding the signer and writable flags of the accounts.
+#[inline(always)]
+pub fn forward<const ACCOUNTS: usize>(
+    accounts: [&AccountInfo; ACCOUNTS],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let account_metas = accounts.map(|account_info| {
+        AccountMeta::new(
+            account_info.key(),
+            account_info.is_writable(),
+            account_info.is_signer(),
+        )
+    });
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: instruction_data,
+    };
+
+    invoke(&instruction, &accounts)
+}
@@ -0,0 +1,27 @@
+//! Getters of the `Clock` sysvar.
+//!
+//! Each getter reads the sysvar through the `sol_get_clock_sysvar` syscall, so the
+//! sysvar account does not need to be passed to the instruction.
+
+use pinocchio::{
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+
+/// Returns the current Unix timestamp, in seconds.
+#[inline(always)]
+pub fn unix_timestamp() -> Result<i64, ProgramError> {
+    Ok(Clock::get()?.unix_timestamp)
+}
+
+/// Returns the current slot.
+#[inline(always)]
+pub fn slot() -> Result<u64, ProgramError> {
+    Ok(Clock::get()?.slot)
+}
+
+/// Returns the current epoch.
+#[inline(always)]
+pub fn epoch() -> Result<u64, ProgramError> {
+    Ok(Clock::get()?.epoch)
+}
@@ -0,0 +1,85 @@
+//! Zero-copy reader of the `EpochRewards` sysvar.
+//!
+//! The sysvar tracks the distribution of the staking rewards of an epoch, which is
+//! spread over several blocks at the start of the following epoch.
+
+use core::mem::size_of;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::error::ExtensionError;
+
+/// `EpochRewards` sysvar (`SysvarEpochRewards1111111111111111111111111`) address.
+pub const ID: Pubkey = [
+    6, 167, 213, 23, 24, 220, 63, 238, 2, 165, 88, 191, 131, 206, 102, 225, 68, 66, 42, 28, 52,
+    149, 11, 39, 193, 134, 155, 90, 156, 0, 0, 0,
+];
+
+/// Rewards distribution status.
+#[repr(C)]
+pub struct EpochRewards {
+    /// Block height at which the distribution starts.
+    distribution_starting_block_height: [u8; 8],
+
+    /// Number of partitions of the distribution.
+    _num_partitions: [u8; 8],
+
+    /// Blockhash of the parent block of the first distribution block.
+    _parent_blockhash: [u8; 32],
+
+    /// Total points of the epoch.
+    _total_points: [u8; 16],
+
+    /// Total rewards of the epoch, in lamports.
+    total_rewards: [u8; 8],
+
+    /// Rewards distributed so far, in lamports.
+    distributed_rewards: [u8; 8],
+
+    /// Whether the rewards are being distributed.
+    active: u8,
+}
+
+impl EpochRewards {
+    /// Length of the sysvar data.
+    pub const LEN: usize = size_of::<EpochRewards>();
+
+    /// Returns the rewards distribution status from the `EpochRewards` sysvar
+    /// account.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that there are no mutable borrows of `account_info`
+    /// account data while the returned reference is alive.
+    #[inline(always)]
+    pub unsafe fn from_account_info(account_info: &AccountInfo) -> Result<&Self, ProgramError> {
+        require_address!(account_info, &ID);
+
+        let data = account_info.borrow_data_unchecked();
+
+        if data.len() < Self::LEN {
+            return Err(ExtensionError::MalformedAccountData.into());
+        }
+
+        Ok(&*(data.as_ptr() as *const Self))
+    }
+
+    #[inline(always)]
+    pub fn distribution_starting_block_height(&self) -> u64 {
+        u64::from_le_bytes(self.distribution_starting_block_height)
+    }
+
+    #[inline(always)]
+    pub fn total_rewards(&self) -> u64 {
+        u64::from_le_bytes(self.total_rewards)
+    }
+
+    #[inline(always)]
+    pub fn distributed_rewards(&self) -> u64 {
+        u64::from_le_bytes(self.distributed_rewards)
+    }
+
+    #[inline(always)]
+    pub fn is_active(&self) -> bool {
+        self.active != 0
+    }
+}
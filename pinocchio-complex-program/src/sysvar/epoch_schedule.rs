@@ -0,0 +1,85 @@
+//! Reader of the `EpochSchedule` sysvar.
+
+use core::mem::MaybeUninit;
+use pinocchio::program_error::ProgramError;
+
+/// Minimum number of slots of an epoch, the length of the first warmup epoch.
+const MINIMUM_SLOTS_PER_EPOCH: u64 = 32;
+
+/// Schedule of the epochs of the cluster.
+///
+/// The layout matches the in-memory layout written by the `sol_get_epoch_schedule_sysvar`
+/// syscall.
+#[repr(C)]
+pub struct EpochSchedule {
+    /// Number of slots of each epoch after the warmup epochs.
+    slots_per_epoch: u64,
+
+    /// Number of slots before the epoch start to compute the leader schedule.
+    _leader_schedule_slot_offset: u64,
+
+    /// Whether the epochs start short and double in length until reaching
+    /// `slots_per_epoch`.
+    warmup: bool,
+
+    /// First epoch with `slots_per_epoch` slots.
+    first_normal_epoch: u64,
+
+    /// First slot of `first_normal_epoch`.
+    first_normal_slot: u64,
+}
+
+impl EpochSchedule {
+    /// Reads the epoch schedule through the `sol_get_epoch_schedule_sysvar` syscall.
+    #[inline(always)]
+    pub fn get() -> Result<Self, ProgramError> {
+        let mut epoch_schedule = MaybeUninit::<Self>::uninit();
+
+        #[cfg(target_os = "solana")]
+        // SAFETY: `epoch_schedule` has the layout of the sysvar written by the syscall.
+        let result = unsafe {
+            pinocchio::syscalls::sol_get_epoch_schedule_sysvar(
+                epoch_schedule.as_mut_ptr() as *mut u8
+            )
+        };
+
+        #[cfg(not(target_os = "solana"))]
+        let result = {
+            core::hint::black_box(&mut epoch_schedule);
+            1
+        };
+
+        if result != 0 {
+            return Err(ProgramError::UnsupportedSysvar);
+        }
+
+        // SAFETY: `epoch_schedule` was written by the syscall.
+        Ok(unsafe { epoch_schedule.assume_init() })
+    }
+
+    /// Returns the number of slots of `epoch`.
+    #[inline(always)]
+    pub fn slots_in_epoch(&self, epoch: u64) -> u64 {
+        if self.warmup && epoch < self.first_normal_epoch {
+            MINIMUM_SLOTS_PER_EPOCH << epoch
+        } else {
+            self.slots_per_epoch
+        }
+    }
+
+    /// Returns the first slot of `epoch`.
+    #[inline(always)]
+    pub fn first_slot_in_epoch(&self, epoch: u64) -> u64 {
+        if self.warmup && epoch <= self.first_normal_epoch {
+            ((1u64 << epoch) - 1) * MINIMUM_SLOTS_PER_EPOCH
+        } else {
+            (epoch - self.first_normal_epoch) * self.slots_per_epoch + self.first_normal_slot
+        }
+    }
+
+    /// Returns the last slot of `epoch`.
+    #[inline(always)]
+    pub fn last_slot_in_epoch(&self, epoch: u64) -> u64 {
+        self.first_slot_in_epoch(epoch) + self.slots_in_epoch(epoch) - 1
+    }
+}
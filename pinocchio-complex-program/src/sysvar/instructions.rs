@@ -0,0 +1,155 @@
+//! Introspection of the `Instructions` sysvar.
+//!
+//! The sysvar data is a `u16` number of instructions, followed by the `u16` offset
+//! of each instruction and the serialized instructions, and it ends with the `u16`
+//! index of the current instruction. Each serialized instruction is a `u16` number
+//! of accounts, followed by a flags byte and the key of each account, the program
+//! id, a `u16` data length and the data.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+
+use crate::error::ExtensionError;
+
+/// `Instructions` sysvar (`Sysvar1nstructions1111111111111111111111111`) address.
+pub const ID: Pubkey = [
+    6, 167, 213, 23, 24, 123, 209, 102, 53, 218, 212, 4, 85, 253, 194, 192, 193, 36, 198, 143, 33,
+    86, 117, 165, 219, 186, 203, 95, 8, 0, 0, 0,
+];
+
+/// Length of each serialized account: a flags byte followed by the key.
+const ACCOUNT_LEN: usize = 1 + PUBKEY_BYTES;
+
+/// Instructions of the transaction, read from the `Instructions` sysvar.
+pub struct Instructions<'a> {
+    /// Data of the sysvar account.
+    data: &'a [u8],
+}
+
+impl<'a> Instructions<'a> {
+    /// Reads the instructions from the `Instructions` sysvar account.
+    #[inline(always)]
+    pub fn from_account_info(account_info: &'a AccountInfo) -> Result<Self, ProgramError> {
+        require_address!(account_info, &ID);
+
+        // SAFETY: immutable borrow to `account_info` account data; sysvar accounts
+        // cannot be modified by the program.
+        let data = unsafe { account_info.borrow_data_unchecked() };
+
+        Ok(Self { data })
+    }
+
+    /// Returns the number of instructions of the transaction.
+    #[inline(always)]
+    pub fn num_instructions(&self) -> Result<usize, ProgramError> {
+        read_u16(self.data, 0)
+    }
+
+    /// Returns the index of the currently executing instruction.
+    #[inline(always)]
+    pub fn current_index(&self) -> Result<usize, ProgramError> {
+        read_u16(self.data, self.data.len().saturating_sub(2))
+    }
+
+    /// Returns the instruction at `index` of the transaction.
+    #[inline(always)]
+    pub fn load_instruction_at(
+        &self,
+        index: usize,
+    ) -> Result<IntrospectedInstruction<'a>, ProgramError> {
+        if index >= self.num_instructions()? {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let offset = read_u16(self.data, 2 + index * 2)?;
+
+        let accounts_len = read_u16(self.data, offset)?;
+        let accounts_offset = offset + 2;
+        let program_id_offset = accounts_offset + accounts_len * ACCOUNT_LEN;
+        let data_offset = program_id_offset + PUBKEY_BYTES + 2;
+        let data_len = read_u16(self.data, program_id_offset + PUBKEY_BYTES)?;
+
+        let accounts = self
+            .data
+            .get(accounts_offset..program_id_offset)
+            .ok_or(ExtensionError::MalformedAccountData)?;
+        let program_id = self
+            .data
+            .get(program_id_offset..program_id_offset + PUBKEY_BYTES)
+            .and_then(|key| key.try_into().ok())
+            .ok_or(ExtensionError::MalformedAccountData)?;
+        let data = self
+            .data
+            .get(data_offset..data_offset + data_len)
+            .ok_or(ExtensionError::MalformedAccountData)?;
+
+        Ok(IntrospectedInstruction {
+            accounts,
+            program_id,
+            data,
+        })
+    }
+
+    /// Returns the instruction at `offset` relative to the current instruction, if
+    /// it exists.
+    #[inline(always)]
+    pub fn load_relative_instruction(
+        &self,
+        offset: isize,
+    ) -> Result<Option<IntrospectedInstruction<'a>>, ProgramError> {
+        match self.current_index()?.checked_add_signed(offset) {
+            Some(index) if index < self.num_instructions()? => {
+                self.load_instruction_at(index).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Instruction read from the `Instructions` sysvar.
+pub struct IntrospectedInstruction<'a> {
+    /// Serialized accounts of the instruction.
+    accounts: &'a [u8],
+
+    /// Program invoked by the instruction.
+    pub program_id: &'a Pubkey,
+
+    /// Data of the instruction.
+    pub data: &'a [u8],
+}
+
+impl IntrospectedInstruction<'_> {
+    /// Returns the number of accounts of the instruction.
+    #[inline(always)]
+    pub fn accounts_len(&self) -> usize {
+        self.accounts.len() / ACCOUNT_LEN
+    }
+
+    /// Returns the key of the account at `index` of the instruction.
+    #[inline(always)]
+    pub fn account_key(&self, index: usize) -> Option<&Pubkey> {
+        let offset = index * ACCOUNT_LEN + 1;
+
+        self.accounts
+            .get(offset..offset + PUBKEY_BYTES)
+            .and_then(|key| key.try_into().ok())
+    }
+
+    /// Indicates whether the instruction invokes `program_id` with data starting
+    /// with `data_prefix`.
+    #[inline(always)]
+    pub fn is_invocation_of(&self, program_id: &Pubkey, data_prefix: &[u8]) -> bool {
+        self.program_id == program_id && self.data.starts_with(data_prefix)
+    }
+}
+
+/// Reads a `u16` from the `Instructions` sysvar data at `offset`.
+#[inline(always)]
+fn read_u16(data: &[u8], offset: usize) -> Result<usize, ProgramError> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]) as usize)
+        .ok_or(ExtensionError::MalformedAccountData)
+}
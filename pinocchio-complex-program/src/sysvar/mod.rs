@@ -0,0 +1,7 @@
+//! Helpers to read sysvar accounts.
+
+pub mod clock;
+pub mod epoch_rewards;
+pub mod epoch_schedule;
+pub mod instructions;
+pub mod slot_hashes;
@@ -0,0 +1,95 @@
+//! Zero-copy reader of the `SlotHashes` sysvar.
+//!
+//! The sysvar data is a `u64` number of entries, followed by `(slot, hash)` entries
+//! sorted from the newest to the oldest slot. Each entry is a `u64` slot followed by
+//! the 32-byte hash of the bank at that slot.
+
+use core::cmp::Ordering;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::error::ExtensionError;
+
+/// `SlotHashes` sysvar (`SysvarS1otHashes111111111111111111111111111`) address.
+pub const ID: Pubkey = [
+    6, 167, 213, 23, 25, 47, 10, 175, 198, 242, 101, 227, 251, 119, 204, 122, 218, 130, 197, 41,
+    208, 190, 59, 19, 110, 45, 0, 85, 32, 0, 0, 0,
+];
+
+/// Length of each entry: slot (8 bytes) + hash (32 bytes).
+const ENTRY_LEN: usize = 40;
+
+/// Entries of the `SlotHashes` sysvar.
+pub struct SlotHashes<'a> {
+    /// Serialized entries of the sysvar.
+    entries: &'a [u8],
+}
+
+impl<'a> SlotHashes<'a> {
+    /// Reads the entries from the `SlotHashes` sysvar account.
+    #[inline(always)]
+    pub fn from_account_info(account_info: &'a AccountInfo) -> Result<Self, ProgramError> {
+        require_address!(account_info, &ID);
+
+        // SAFETY: immutable borrow to `account_info` account data; sysvar accounts
+        // cannot be modified by the program.
+        let data = unsafe { account_info.borrow_data_unchecked() };
+
+        let (len, entries) = data
+            .split_first_chunk::<8>()
+            .ok_or(ExtensionError::MalformedAccountData)?;
+        let len = u64::from_le_bytes(*len) as usize;
+
+        let entries = entries
+            .get(..len.saturating_mul(ENTRY_LEN))
+            .ok_or(ExtensionError::MalformedAccountData)?;
+
+        Ok(Self { entries })
+    }
+
+    /// Returns the number of entries.
+    #[inline(always)]
+    pub fn num_entries(&self) -> usize {
+        self.entries.len() / ENTRY_LEN
+    }
+
+    /// Returns an iterator over the `(slot, hash)` entries, from the newest to the
+    /// oldest slot.
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &'a [u8; 32])> {
+        self.entries.chunks_exact(ENTRY_LEN).filter_map(read_entry)
+    }
+
+    /// Returns the hash of `slot`, if it is one of the entries.
+    ///
+    /// Since the entries are sorted, the slot is found with a binary search.
+    #[inline(always)]
+    pub fn get(&self, slot: u64) -> Option<&'a [u8; 32]> {
+        let (mut low, mut high) = (0, self.num_entries());
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let offset = middle * ENTRY_LEN;
+            let (entry_slot, hash) = read_entry(self.entries.get(offset..offset + ENTRY_LEN)?)?;
+
+            match entry_slot.cmp(&slot) {
+                Ordering::Equal => return Some(hash),
+                // Entries are sorted by descending slot, so older slots are after.
+                Ordering::Greater => low = middle + 1,
+                Ordering::Less => high = middle,
+            }
+        }
+
+        None
+    }
+}
+
+/// Reads the slot and hash of an entry: an 8-byte slot followed by a 32-byte hash.
+///
+/// Returns `None` if the `entry` is not `ENTRY_LEN` bytes long.
+#[inline(always)]
+fn read_entry(entry: &[u8]) -> Option<(u64, &[u8; 32])> {
+    let slot = entry.get(..8)?.try_into().ok()?;
+    let hash = entry.get(8..ENTRY_LEN)?.try_into().ok()?;
+
+    Some((u64::from_le_bytes(slot), hash))
+}
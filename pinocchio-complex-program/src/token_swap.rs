@@ -0,0 +1,170 @@
+//! Helpers to invoke the legacy SPL Token Swap program.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// SPL Token Swap program (`SwaPpA9LAaLfeLi3a68M4DjnLqgtticKg6CnyNwgAC8`) address.
+pub const ID: Pubkey = [
+    6, 165, 58, 174, 54, 191, 72, 111, 181, 217, 56, 38, 78, 230, 69, 215, 75, 96, 22, 224, 244,
+    122, 235, 179, 236, 22, 67, 139, 247, 191, 251, 225,
+];
+
+/// SPL Token program (`TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`) address.
+pub const SPL_TOKEN_ID: Pubkey = [
+    6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237,
+    95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
+];
+
+/// SPL Token-2022 program (`TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`) address.
+pub const TOKEN_2022_ID: Pubkey = [
+    6, 221, 246, 225, 238, 117, 143, 222, 24, 66, 93, 188, 228, 108, 205, 218, 182, 26, 252, 77,
+    131, 185, 13, 39, 254, 189, 249, 40, 216, 161, 139, 252,
+];
+
+/// Accounts of a swap.
+pub struct SwapAccounts<'a> {
+    /// Token swap pool state.
+    pub swap: &'a AccountInfo,
+
+    /// Authority of the pool token accounts, derived from the pool state.
+    pub swap_authority: &'a AccountInfo,
+
+    /// Authority of the user source token account.
+    pub user_transfer_authority: &'a AccountInfo,
+
+    /// User token account sending the tokens.
+    pub source: &'a AccountInfo,
+
+    /// Pool token account receiving the tokens.
+    pub swap_source: &'a AccountInfo,
+
+    /// Pool token account sending the swapped tokens.
+    pub swap_destination: &'a AccountInfo,
+
+    /// User token account receiving the swapped tokens.
+    pub destination: &'a AccountInfo,
+
+    /// Mint of the pool tokens.
+    pub pool_mint: &'a AccountInfo,
+
+    /// Pool token account receiving the trading fees.
+    pub pool_fee: &'a AccountInfo,
+
+    /// Token program of the pool token accounts.
+    pub token_program: &'a AccountInfo,
+
+    /// Pool token account of the host receiving part of the trading fees.
+    pub host_fee: Option<&'a AccountInfo>,
+}
+
+/// Swaps `amount_in` tokens for at least `minimum_amount_out` tokens.
+///
+/// The `signers` are used to sign for a derived `user_transfer_authority` address.
+#[inline(always)]
+pub fn swap(
+    accounts: &SwapAccounts,
+    amount_in: u64,
+    minimum_amount_out: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    // Instruction data layout:
+    // - [0]: discriminator (1)
+    // - [1..9]: amount in
+    // - [9..17]: minimum amount out
+    let mut instruction_data = [0u8; 17];
+    instruction_data[0] = 1;
+    instruction_data[1..9].copy_from_slice(&amount_in.to_le_bytes());
+    instruction_data[9..17].copy_from_slice(&minimum_amount_out.to_le_bytes());
+
+    let swap = accounts.swap;
+    let swap_authority = accounts.swap_authority;
+    let user_transfer_authority = accounts.user_transfer_authority;
+    let source = accounts.source;
+    let swap_source = accounts.swap_source;
+    let swap_destination = accounts.swap_destination;
+    let destination = accounts.destination;
+    let pool_mint = accounts.pool_mint;
+    let pool_fee = accounts.pool_fee;
+    let token_program = accounts.token_program;
+
+    if let Some(host_fee) = accounts.host_fee {
+        let account_metas = [
+            AccountMeta::readonly(swap.key()),
+            AccountMeta::readonly(swap_authority.key()),
+            AccountMeta::readonly_signer(user_transfer_authority.key()),
+            AccountMeta::writable(source.key()),
+            AccountMeta::writable(swap_source.key()),
+            AccountMeta::writable(swap_destination.key()),
+            AccountMeta::writable(destination.key()),
+            AccountMeta::writable(pool_mint.key()),
+            AccountMeta::writable(pool_fee.key()),
+            AccountMeta::readonly(token_program.key()),
+            AccountMeta::writable(host_fee.key()),
+        ];
+
+        let instruction = Instruction {
+            program_id: &ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                swap,
+                swap_authority,
+                user_transfer_authority,
+                source,
+                swap_source,
+                swap_destination,
+                destination,
+                pool_mint,
+                pool_fee,
+                token_program,
+                host_fee,
+            ],
+            signers,
+        )
+    } else {
+        let account_metas = [
+            AccountMeta::readonly(swap.key()),
+            AccountMeta::readonly(swap_authority.key()),
+            AccountMeta::readonly_signer(user_transfer_authority.key()),
+            AccountMeta::writable(source.key()),
+            AccountMeta::writable(swap_source.key()),
+            AccountMeta::writable(swap_destination.key()),
+            AccountMeta::writable(destination.key()),
+            AccountMeta::writable(pool_mint.key()),
+            AccountMeta::writable(pool_fee.key()),
+            AccountMeta::readonly(token_program.key()),
+        ];
+
+        let instruction = Instruction {
+            program_id: &ID,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                swap,
+                swap_authority,
+                user_transfer_authority,
+                source,
+                swap_source,
+                swap_destination,
+                destination,
+                pool_mint,
+                pool_fee,
+                token_program,
+            ],
+            signers,
+        )
+    }
+}
@@ -0,0 +1,187 @@
+//! Helpers to invoke the Vote program.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// Vote program (`Vote111111111111111111111111111111111111111`) address.
+pub const ID: Pubkey = [
+    7, 97, 72, 29, 53, 116, 116, 187, 124, 77, 118, 36, 235, 211, 189, 179, 216, 53, 94, 115, 209,
+    16, 67, 252, 13, 163, 83, 128, 0, 0, 0, 0,
+];
+
+/// Size of a vote account.
+pub const VOTE_ACCOUNT_LEN: usize = 3762;
+
+/// Type of a vote account authority.
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum VoteAuthorize {
+    /// Authority allowed to vote.
+    Voter,
+
+    /// Authority allowed to withdraw and to change the commission.
+    Withdrawer,
+}
+
+/// Authorities and commission of a new vote account.
+pub struct VoteInit<'a> {
+    /// Authority allowed to vote.
+    pub authorized_voter: &'a Pubkey,
+
+    /// Authority allowed to withdraw and to change the commission.
+    pub authorized_withdrawer: &'a Pubkey,
+
+    /// Percentage of the rewards kept by the validator.
+    pub commission: u8,
+}
+
+/// Initializes a vote account of the `node` validator identity.
+///
+/// The `signers` are used to sign for a derived `node` address.
+#[inline(always)]
+pub fn initialize_account(
+    vote: &AccountInfo,
+    rent_sysvar: &AccountInfo,
+    clock_sysvar: &AccountInfo,
+    node: &AccountInfo,
+    vote_init: &VoteInit,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(vote.key()),
+        AccountMeta::readonly(rent_sysvar.key()),
+        AccountMeta::readonly(clock_sysvar.key()),
+        AccountMeta::readonly_signer(node.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (0)
+    // - [4..36]: node
+    // - [36..68]: authorized voter
+    // - [68..100]: authorized withdrawer
+    // - [100]: commission
+    let mut instruction_data = [0u8; 101];
+    instruction_data[4..36].copy_from_slice(node.key());
+    instruction_data[36..68].copy_from_slice(vote_init.authorized_voter);
+    instruction_data[68..100].copy_from_slice(vote_init.authorized_withdrawer);
+    instruction_data[100] = vote_init.commission;
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(
+        &instruction,
+        &[vote, rent_sysvar, clock_sysvar, node],
+        signers,
+    )
+}
+
+/// Sets a new `vote_authorize` authority of a vote account.
+///
+/// The `signers` are used to sign for a derived `authority` address.
+#[inline(always)]
+pub fn authorize(
+    vote: &AccountInfo,
+    clock_sysvar: &AccountInfo,
+    authority: &AccountInfo,
+    new_authority: &Pubkey,
+    vote_authorize: VoteAuthorize,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(vote.key()),
+        AccountMeta::readonly(clock_sysvar.key()),
+        AccountMeta::readonly_signer(authority.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (1)
+    // - [4..36]: new authority
+    // - [36..40]: vote authorize
+    let mut instruction_data = [0u8; 40];
+    instruction_data[0] = 1;
+    instruction_data[4..36].copy_from_slice(new_authority);
+    instruction_data[36] = vote_authorize as u8;
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(&instruction, &[vote, clock_sysvar, authority], signers)
+}
+
+/// Withdraws `lamports` from a vote account.
+///
+/// The `signers` are used to sign for a derived `withdraw_authority` address.
+#[inline(always)]
+pub fn withdraw(
+    vote: &AccountInfo,
+    recipient: &AccountInfo,
+    withdraw_authority: &AccountInfo,
+    lamports: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(vote.key()),
+        AccountMeta::writable(recipient.key()),
+        AccountMeta::readonly_signer(withdraw_authority.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (3)
+    // - [4..12]: lamports
+    let mut instruction_data = [0u8; 12];
+    instruction_data[0] = 3;
+    instruction_data[4..12].copy_from_slice(&lamports.to_le_bytes());
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(
+        &instruction,
+        &[vote, recipient, withdraw_authority],
+        signers,
+    )
+}
+
+/// Updates the commission of a vote account.
+///
+/// The `signers` are used to sign for a derived `withdraw_authority` address.
+#[inline(always)]
+pub fn update_commission(
+    vote: &AccountInfo,
+    withdraw_authority: &AccountInfo,
+    commission: u8,
+    signers: &[Signer],
+) -> ProgramResult {
+    let account_metas = [
+        AccountMeta::writable(vote.key()),
+        AccountMeta::readonly_signer(withdraw_authority.key()),
+    ];
+
+    // Instruction data layout:
+    // - [0..4]: discriminator (5)
+    // - [4]: commission
+    let instruction_data = [5, 0, 0, 0, commission];
+
+    let instruction = Instruction {
+        program_id: &ID,
+        accounts: &account_metas,
+        data: &instruction_data,
+    };
+
+    invoke_signed(&instruction, &[vote, withdraw_authority], signers)
+}
@@ -0,0 +1,157 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+async fn token_amount(context: &mut ProgramTestContext, account: Pubkey) -> u64 {
+    let account = context.banks_client.get_account(account).await.unwrap();
+    spl_token::state::Account::unpack(&account.unwrap().data)
+        .unwrap()
+        .amount
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn mint_to_many(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an airdrop of a mint with 4 decimals.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let (airdrop, bump) =
+        Pubkey::find_program_address(&[b"airdrop", mint.as_ref()], &token_program);
+
+    let mut set_authority_ix = spl_token::instruction::set_authority(
+        &spl_token::ID,
+        &mint,
+        Some(&airdrop),
+        spl_token::instruction::AuthorityType::MintTokens,
+        &mint_authority.pubkey(),
+        &[],
+    )
+    .unwrap();
+    set_authority_ix.program_id = token_program;
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(airdrop, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: vec![133, 0, bump],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_authority_ix, initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And 3 recipients.
+
+    let mut recipients = Vec::new();
+
+    for _ in 0..3 {
+        recipients.push(
+            account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await,
+        );
+    }
+
+    let payer = context.payer.pubkey();
+
+    let mint_to_many_ix = |recipients: &[Pubkey]| {
+        let mut accounts = vec![
+            AccountMeta::new_readonly(airdrop, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new_readonly(payer, true),
+        ];
+        accounts.extend(
+            recipients
+                .iter()
+                .map(|recipient| AccountMeta::new(*recipient, false)),
+        );
+
+        let mut data = vec![133, 1, 4];
+
+        for amount in 1..=recipients.len() as u64 {
+            data.extend_from_slice(&(amount * 10).to_le_bytes());
+        }
+
+        Instruction {
+            program_id: token_program,
+            accounts,
+            data,
+        }
+    };
+
+    // When the authority mints to the recipients.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_to_many_ix(&recipients)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then each recipient receives its amount.
+
+    assert_eq!(token_amount(&mut context, recipients[0]).await, 10);
+    assert_eq!(token_amount(&mut context, recipients[1]).await, 20);
+    assert_eq!(token_amount(&mut context, recipients[2]).await, 30);
+
+    let account = context.banks_client.get_account(mint).await.unwrap();
+    let mint_account = spl_token::state::Mint::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(mint_account.supply, 60);
+
+    // And minting fails when a recipient has a different mint.
+
+    let other_mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+    let other_recipient = account::initialize(
+        &mut context,
+        &other_mint,
+        &Pubkey::new_unique(),
+        &token_program,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_to_many_ix(&[recipients[0], other_recipient])],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(3) // MintMismatch
+        )
+    );
+}
@@ -0,0 +1,757 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, config, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+struct PoolAccounts {
+    pool: Pubkey,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    vault_a: Pubkey,
+    vault_b: Pubkey,
+    lp_mint: Pubkey,
+    config: Pubkey,
+    mint_authority: Keypair,
+}
+
+/// Initializes a pool with a 30 bps swap fee, and the program config with a
+/// `protocol_fee_bps` protocol fee.
+async fn initialize_pool(
+    context: &mut ProgramTestContext,
+    token_program: &Pubkey,
+    protocol_fee_bps: u16,
+) -> PoolAccounts {
+    let config = config::initialize(context, protocol_fee_bps, token_program).await;
+
+    let mint_authority = Keypair::new();
+
+    let mint_a = mint::initialize(context, mint_authority.pubkey(), None, token_program)
+        .await
+        .unwrap();
+    let mint_b = mint::initialize(context, mint_authority.pubkey(), None, token_program)
+        .await
+        .unwrap();
+
+    let vault_a = account::create(context, spl_token::state::Account::LEN, token_program).await;
+    let vault_b = account::create(context, spl_token::state::Account::LEN, token_program).await;
+    let lp_mint = account::create(context, spl_token::state::Mint::LEN, token_program).await;
+
+    let (pool, bump) =
+        Pubkey::find_program_address(&[b"pool", mint_a.as_ref(), mint_b.as_ref()], token_program);
+
+    // 30 bps swap fee.
+    let mut data = vec![100, 0, bump];
+    data.extend_from_slice(&30u16.to_le_bytes());
+
+    let initialize_pool_ix = Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(pool, false),
+            AccountMeta::new_readonly(mint_a, false),
+            AccountMeta::new_readonly(mint_b, false),
+            AccountMeta::new(vault_a, false),
+            AccountMeta::new(vault_b, false),
+            AccountMeta::new(lp_mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_pool_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    PoolAccounts {
+        pool,
+        mint_a,
+        mint_b,
+        vault_a,
+        vault_b,
+        lp_mint,
+        config,
+        mint_authority,
+    }
+}
+
+async fn token_amount(context: &mut ProgramTestContext, account: Pubkey) -> u64 {
+    let account = context.banks_client.get_account(account).await.unwrap();
+    spl_token::state::Account::unpack(&account.unwrap().data)
+        .unwrap()
+        .amount
+}
+
+struct Provider {
+    owner: Keypair,
+    account_a: Pubkey,
+    account_b: Pubkey,
+    account_lp: Pubkey,
+}
+
+async fn fund_provider(
+    context: &mut ProgramTestContext,
+    pool: &PoolAccounts,
+    amount: u64,
+    token_program: &Pubkey,
+) -> Provider {
+    let owner = Keypair::new();
+
+    let account_a =
+        account::initialize(context, &pool.mint_a, &owner.pubkey(), token_program).await;
+    let account_b =
+        account::initialize(context, &pool.mint_b, &owner.pubkey(), token_program).await;
+    let account_lp =
+        account::initialize(context, &pool.lp_mint, &owner.pubkey(), token_program).await;
+
+    for (mint, account) in [(pool.mint_a, account_a), (pool.mint_b, account_b)] {
+        mint::mint(
+            context,
+            &mint,
+            &account,
+            &pool.mint_authority,
+            amount,
+            token_program,
+        )
+        .await
+        .unwrap();
+    }
+
+    Provider {
+        owner,
+        account_a,
+        account_b,
+        account_lp,
+    }
+}
+
+fn add_liquidity_ix(
+    token_program: &Pubkey,
+    pool: &PoolAccounts,
+    provider: &Provider,
+    maximum_amount_a: u64,
+    maximum_amount_b: u64,
+) -> Instruction {
+    let mut data = vec![100, 1];
+    data.extend_from_slice(&maximum_amount_a.to_le_bytes());
+    data.extend_from_slice(&maximum_amount_b.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(pool.pool, false),
+            AccountMeta::new(pool.lp_mint, false),
+            AccountMeta::new(provider.account_lp, false),
+            AccountMeta::new(pool.vault_a, false),
+            AccountMeta::new(pool.vault_b, false),
+            AccountMeta::new(provider.account_a, false),
+            AccountMeta::new(provider.account_b, false),
+            AccountMeta::new_readonly(provider.owner.pubkey(), true),
+        ],
+        data,
+    }
+}
+
+fn swap_ix(
+    token_program: &Pubkey,
+    pool: &PoolAccounts,
+    provider: &Provider,
+    amount_in: u64,
+    minimum_amount_out: u64,
+) -> Instruction {
+    let mut data = vec![100, 3];
+    data.extend_from_slice(&amount_in.to_le_bytes());
+    data.extend_from_slice(&minimum_amount_out.to_le_bytes());
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(pool.pool, false),
+            AccountMeta::new(provider.account_a, false),
+            AccountMeta::new(pool.vault_a, false),
+            AccountMeta::new_readonly(provider.owner.pubkey(), true),
+            AccountMeta::new(pool.vault_b, false),
+            AccountMeta::new(provider.account_b, false),
+            AccountMeta::new_readonly(pool.config, false),
+        ],
+        data,
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn initialize_pool_and_swap(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a pool.
+
+    let pool = initialize_pool(&mut context, &token_program, 0).await;
+
+    // And a liquidity provider with 1_000_000 tokens A and B.
+
+    let owner = Keypair::new();
+
+    let account_a =
+        account::initialize(&mut context, &pool.mint_a, &owner.pubkey(), &token_program).await;
+    let account_b =
+        account::initialize(&mut context, &pool.mint_b, &owner.pubkey(), &token_program).await;
+    let account_lp =
+        account::initialize(&mut context, &pool.lp_mint, &owner.pubkey(), &token_program).await;
+
+    for (mint, account) in [(pool.mint_a, account_a), (pool.mint_b, account_b)] {
+        mint::mint(
+            &mut context,
+            &mint,
+            &account,
+            &pool.mint_authority,
+            1_000_000,
+            &token_program,
+        )
+        .await
+        .unwrap();
+    }
+
+    // When liquidity is added to the pool.
+
+    let mut data = vec![100, 1];
+    data.extend_from_slice(&100_000u64.to_le_bytes());
+    data.extend_from_slice(&100_000u64.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let add_liquidity_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(pool.pool, false),
+            AccountMeta::new(pool.lp_mint, false),
+            AccountMeta::new(account_lp, false),
+            AccountMeta::new(pool.vault_a, false),
+            AccountMeta::new(pool.vault_b, false),
+            AccountMeta::new(account_a, false),
+            AccountMeta::new(account_b, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data,
+    };
+
+    // And tokens A are swapped for tokens B.
+
+    let mut data = vec![100, 3];
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&1u64.to_le_bytes());
+
+    let swap_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(pool.pool, false),
+            AccountMeta::new(account_a, false),
+            AccountMeta::new(pool.vault_a, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+            AccountMeta::new(pool.vault_b, false),
+            AccountMeta::new(account_b, false),
+            AccountMeta::new_readonly(pool.config, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_liquidity_ix, swap_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the liquidity provider received the LP tokens, minus the locked minimum
+    // liquidity.
+
+    assert_eq!(token_amount(&mut context, account_lp).await, 99_000);
+
+    // And the swap moved the tokens through the vaults.
+
+    // 1_000 tokens A minus the 30 bps fee: 100_000 * 997 / (100_000 + 997) = 987
+    assert_eq!(token_amount(&mut context, pool.vault_a).await, 101_000);
+    assert_eq!(token_amount(&mut context, pool.vault_b).await, 99_013);
+    assert_eq!(token_amount(&mut context, account_a).await, 899_000);
+    assert_eq!(token_amount(&mut context, account_b).await, 900_987);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn remove_liquidity(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a pool with 50_000 tokens A and B of liquidity.
+
+    let pool = initialize_pool(&mut context, &token_program, 0).await;
+
+    let owner = Keypair::new();
+
+    let account_a =
+        account::initialize(&mut context, &pool.mint_a, &owner.pubkey(), &token_program).await;
+    let account_b =
+        account::initialize(&mut context, &pool.mint_b, &owner.pubkey(), &token_program).await;
+    let account_lp =
+        account::initialize(&mut context, &pool.lp_mint, &owner.pubkey(), &token_program).await;
+
+    for (mint, account) in [(pool.mint_a, account_a), (pool.mint_b, account_b)] {
+        mint::mint(
+            &mut context,
+            &mint,
+            &account,
+            &pool.mint_authority,
+            50_000,
+            &token_program,
+        )
+        .await
+        .unwrap();
+    }
+
+    let mut data = vec![100, 1];
+    data.extend_from_slice(&50_000u64.to_le_bytes());
+    data.extend_from_slice(&50_000u64.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let add_liquidity_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(pool.pool, false),
+            AccountMeta::new(pool.lp_mint, false),
+            AccountMeta::new(account_lp, false),
+            AccountMeta::new(pool.vault_a, false),
+            AccountMeta::new(pool.vault_b, false),
+            AccountMeta::new(account_a, false),
+            AccountMeta::new(account_b, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data,
+    };
+
+    // When all the LP tokens are burned.
+
+    let mut data = vec![100, 2];
+    data.extend_from_slice(&49_000u64.to_le_bytes());
+    data.extend_from_slice(&49_000u64.to_le_bytes());
+    data.extend_from_slice(&49_000u64.to_le_bytes());
+
+    let remove_liquidity_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(pool.pool, false),
+            AccountMeta::new(account_lp, false),
+            AccountMeta::new(pool.lp_mint, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+            AccountMeta::new(pool.vault_a, false),
+            AccountMeta::new(pool.vault_b, false),
+            AccountMeta::new(account_a, false),
+            AccountMeta::new(account_b, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_liquidity_ix, remove_liquidity_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the tokens are back in the owner accounts, except the share of the
+    // locked minimum liquidity.
+
+    assert_eq!(token_amount(&mut context, account_lp).await, 0);
+    assert_eq!(token_amount(&mut context, account_a).await, 49_000);
+    assert_eq!(token_amount(&mut context, account_b).await, 49_000);
+    assert_eq!(token_amount(&mut context, pool.vault_a).await, 1_000);
+    assert_eq!(token_amount(&mut context, pool.vault_b).await, 1_000);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn add_liquidity_transfers_proportional_amounts(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a pool with 100_000 tokens A and B of liquidity.
+
+    let pool = initialize_pool(&mut context, &token_program, 0).await;
+    let provider = fund_provider(&mut context, &pool, 200_000, &token_program).await;
+
+    // When up to 10_000 tokens A and 20_000 tokens B are deposited.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            add_liquidity_ix(&token_program, &pool, &provider, 100_000, 100_000),
+            add_liquidity_ix(&token_program, &pool, &provider, 10_000, 20_000),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &provider.owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then only 10_000 tokens B are deposited, matching the share of tokens A.
+
+    assert_eq!(token_amount(&mut context, pool.vault_a).await, 110_000);
+    assert_eq!(token_amount(&mut context, pool.vault_b).await, 110_000);
+    assert_eq!(token_amount(&mut context, provider.account_b).await, 90_000);
+    assert_eq!(
+        token_amount(&mut context, provider.account_lp).await,
+        109_000
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_first_deposit_below_minimum_liquidity(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an empty pool.
+
+    let pool = initialize_pool(&mut context, &token_program, 0).await;
+    let provider = fund_provider(&mut context, &pool, 1_000, &token_program).await;
+
+    // When the first deposit is worth only the locked minimum liquidity.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_liquidity_ix(
+            &token_program,
+            &pool,
+            &provider,
+            1_000,
+            1_000,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &provider.owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then no LP tokens can be minted.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(103) // InsufficientLiquidity
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_swap_below_minimum_amount_out(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a pool with 100_000 tokens A and B of liquidity.
+
+    let pool = initialize_pool(&mut context, &token_program, 0).await;
+    let provider = fund_provider(&mut context, &pool, 200_000, &token_program).await;
+
+    // When 1_000 tokens A are swapped for at least 988 tokens B, while the swap
+    // outputs 987 tokens B.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            add_liquidity_ix(&token_program, &pool, &provider, 100_000, 100_000),
+            swap_ix(&token_program, &pool, &provider, 1_000, 988),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &provider.owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the swap fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(102) // SlippageExceeded
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_wrong_vault(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a pool with 100_000 tokens A and B of liquidity.
+
+    let pool = initialize_pool(&mut context, &token_program, 0).await;
+    let provider = fund_provider(&mut context, &pool, 200_000, &token_program).await;
+
+    // And a token B account that is not the pool vault.
+
+    let other_vault =
+        account::initialize(&mut context, &pool.mint_b, &pool.pool, &token_program).await;
+
+    // When tokens are swapped with that account as the output vault.
+
+    let mut swap_ix = swap_ix(&token_program, &pool, &provider, 1_000, 1);
+    swap_ix.accounts[4] = AccountMeta::new(other_vault, false);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            add_liquidity_ix(&token_program, &pool, &provider, 100_000, 100_000),
+            swap_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &provider.owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the swap fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(101) // AccountMismatch
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_wrong_lp_mint(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a pool.
+
+    let pool = initialize_pool(&mut context, &token_program, 0).await;
+    let provider = fund_provider(&mut context, &pool, 100_000, &token_program).await;
+
+    // And a mint that is not the pool LP mint.
+
+    let other_mint = mint::initialize(
+        &mut context,
+        pool.mint_authority.pubkey(),
+        None,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When liquidity is added with that mint as the LP mint.
+
+    let mut add_liquidity_ix = add_liquidity_ix(&token_program, &pool, &provider, 10_000, 10_000);
+    add_liquidity_ix.accounts[1] = AccountMeta::new(other_mint, false);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_liquidity_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &provider.owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the deposit fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(101) // AccountMismatch
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn swap_without_protocol_fee(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a config without protocol fee and a pool with 100_000 tokens A and B of
+    // liquidity.
+
+    let pool = initialize_pool(&mut context, &token_program, 0).await;
+    let provider = fund_provider(&mut context, &pool, 200_000, &token_program).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_liquidity_ix(
+            &token_program,
+            &pool,
+            &provider,
+            100_000,
+            100_000,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &provider.owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When tokens are swapped with the config account as the only fee account.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix(&token_program, &pool, &provider, 1_000, 1)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &provider.owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the whole input amount goes through the pool.
+
+    // 1_000 tokens A minus the 30 bps fee: 100_000 * 997 / (100_000 + 997) = 987
+    assert_eq!(token_amount(&mut context, pool.vault_a).await, 101_000);
+    assert_eq!(token_amount(&mut context, pool.vault_b).await, 99_013);
+    assert_eq!(token_amount(&mut context, provider.account_a).await, 99_000);
+    assert_eq!(
+        token_amount(&mut context, provider.account_b).await,
+        100_987
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_swap_while_paused(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a config without protocol fee and a pool with 100_000 tokens A and B of
+    // liquidity.
+
+    let pool = initialize_pool(&mut context, &token_program, 0).await;
+    let provider = fund_provider(&mut context, &pool, 200_000, &token_program).await;
+
+    // And the program is paused.
+
+    let pause_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(pool.config, false),
+            AccountMeta::new_readonly(context.payer.pubkey(), true),
+        ],
+        data: vec![104, 4],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            add_liquidity_ix(&token_program, &pool, &provider, 100_000, 100_000),
+            pause_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &provider.owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When tokens are swapped.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix(&token_program, &pool, &provider, 1_000, 1)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &provider.owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the swap fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(110) // Paused
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_swap_without_protocol_fee_accounts(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a config with a 1% protocol fee and a pool with 100_000 tokens A and B
+    // of liquidity.
+
+    let pool = initialize_pool(&mut context, &token_program, 100).await;
+    let provider = fund_provider(&mut context, &pool, 200_000, &token_program).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_liquidity_ix(
+            &token_program,
+            &pool,
+            &provider,
+            100_000,
+            100_000,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &provider.owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When tokens are swapped without the treasury accounts.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix(&token_program, &pool, &provider, 1_000, 1)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &provider.owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the swap fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+
+    // And it also fails without the config account.
+
+    let mut swap_ix = swap_ix(&token_program, &pool, &provider, 1_000, 1);
+    swap_ix.accounts.pop();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &provider.owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
@@ -0,0 +1,183 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::Transaction,
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn settle_auction(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an auction of 1 item token ending in 100 slots.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let seller = Keypair::new();
+    let bidders = [Keypair::new(), Keypair::new()];
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &seller.pubkey(), 1_000_000_000),
+            system_instruction::transfer(
+                &context.payer.pubkey(),
+                &bidders[0].pubkey(),
+                1_000_000_000,
+            ),
+            system_instruction::transfer(
+                &context.payer.pubkey(),
+                &bidders[1].pubkey(),
+                1_000_000_000,
+            ),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let seller_account =
+        account::initialize(&mut context, &mint, &seller.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &seller_account,
+        &mint_authority,
+        1,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let item_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let create_key = Pubkey::new_unique();
+    let (auction, bump) =
+        Pubkey::find_program_address(&[b"auction", create_key.as_ref()], &token_program);
+
+    let slot = context.banks_client.get_root_slot().await.unwrap();
+
+    let mut data = vec![107, 0, bump];
+    data.extend_from_slice(&1u64.to_le_bytes());
+    data.extend_from_slice(&100_000_000u64.to_le_bytes());
+    data.extend_from_slice(&(slot + 100).to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(seller.pubkey(), true),
+            AccountMeta::new(auction, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(seller_account, false),
+            AccountMeta::new(item_vault, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &seller],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When two bids are placed.
+
+    let bid_ixs = [(0, 200_000_000u64), (1, 300_000_000u64)].map(|(i, amount)| {
+        let mut data = vec![107, 1];
+        data.extend_from_slice(&amount.to_le_bytes());
+
+        Instruction {
+            program_id: token_program,
+            accounts: vec![
+                AccountMeta::new(auction, false),
+                AccountMeta::new(bidders[i].pubkey(), true),
+                AccountMeta::new(bidders[0].pubkey(), false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+            data,
+        }
+    });
+
+    let tx = Transaction::new_signed_with_payer(
+        &bid_ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &bidders[0], &bidders[1]],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And the auction is settled after the end slot.
+
+    context.warp_to_slot(slot + 101).unwrap();
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let winner_account =
+        account::initialize(&mut context, &mint, &bidders[1].pubkey(), &token_program).await;
+
+    let settle_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(auction, false),
+            AccountMeta::new(item_vault, false),
+            AccountMeta::new(winner_account, false),
+            AccountMeta::new(seller.pubkey(), false),
+        ],
+        data: vec![107, 2],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[settle_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the highest bidder received the item.
+
+    let account = context
+        .banks_client
+        .get_account(winner_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 1);
+
+    // And the first bidder was refunded.
+
+    let account = context
+        .banks_client
+        .get_account(bidders[0].pubkey())
+        .await
+        .unwrap();
+
+    assert_eq!(account.unwrap().lamports, 1_000_000_000);
+
+    // And the auction account was closed.
+
+    let account = context.banks_client.get_account(auction).await.unwrap();
+
+    assert!(account.is_none());
+}
@@ -0,0 +1,188 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use std::mem::size_of;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+use token_interface::state::{account::Account, mint::Mint};
+
+/// Encodes an instruction of a batch, referencing its accounts by their index in
+/// the accounts of the batch.
+fn batch_entry(instruction: &Instruction, metas: &[AccountMeta]) -> Vec<u8> {
+    let mut entry = (instruction.data.len() as u16).to_le_bytes().to_vec();
+    entry.extend_from_slice(&instruction.data);
+    entry.push(instruction.accounts.len() as u8);
+
+    for account in &instruction.accounts {
+        let index = metas
+            .iter()
+            .position(|meta| meta.pubkey == account.pubkey)
+            .unwrap();
+        entry.push(index as u8);
+    }
+
+    entry
+}
+
+fn batch_ix(token_program: &Pubkey, metas: Vec<AccountMeta>, entries: &[Vec<u8>]) -> Instruction {
+    let mut data = vec![152, entries.len() as u8];
+
+    for entry in entries {
+        data.extend_from_slice(entry);
+    }
+
+    Instruction {
+        program_id: *token_program,
+        accounts: metas,
+        data,
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn batch(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given uninitialized mint and token accounts.
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mint = Keypair::new();
+    let token_account = Keypair::new();
+    let mint_authority = Keypair::new();
+    let owner = Pubkey::new_unique();
+
+    let create_ixs = [
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &mint.pubkey(),
+            rent.minimum_balance(size_of::<Mint>()),
+            size_of::<Mint>() as u64,
+            &token_program,
+        ),
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &token_account.pubkey(),
+            rent.minimum_balance(size_of::<Account>()),
+            size_of::<Account>() as u64,
+            &token_program,
+        ),
+    ];
+
+    // When the mint and token account are initialized and tokens are minted in
+    // a single batch.
+
+    let metas = vec![
+        AccountMeta::new(mint.pubkey(), false),
+        AccountMeta::new(token_account.pubkey(), false),
+        AccountMeta::new_readonly(mint_authority.pubkey(), true),
+    ];
+
+    let initialize_mint_ix = spl_token::instruction::initialize_mint2(
+        &spl_token::ID,
+        &mint.pubkey(),
+        &mint_authority.pubkey(),
+        None,
+        6,
+    )
+    .unwrap();
+    let initialize_account_ix = spl_token::instruction::initialize_account3(
+        &spl_token::ID,
+        &token_account.pubkey(),
+        &mint.pubkey(),
+        &owner,
+    )
+    .unwrap();
+    let mint_to_ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        &mint.pubkey(),
+        &token_account.pubkey(),
+        &mint_authority.pubkey(),
+        &[],
+        1_000,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_ixs[0].clone(),
+            create_ixs[1].clone(),
+            batch_ix(
+                &token_program,
+                metas.clone(),
+                &[
+                    batch_entry(&initialize_mint_ix, &metas),
+                    batch_entry(&initialize_account_ix, &metas),
+                    batch_entry(&mint_to_ix, &metas),
+                ],
+            ),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint, &token_account, &mint_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the token account holds the minted tokens.
+
+    let account = context
+        .banks_client
+        .get_account(token_account.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert_eq!(account.amount, 1_000);
+    assert_eq!(account.owner, owner);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_nested_batch(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a batch containing another batch.
+
+    let nested = Instruction {
+        program_id: token_program,
+        accounts: vec![],
+        data: vec![152, 0],
+    };
+
+    // When the batch is executed.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[batch_ix(
+            &token_program,
+            vec![],
+            &[batch_entry(&nested, &[])],
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the batch is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+    );
+}
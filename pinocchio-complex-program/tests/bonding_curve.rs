@@ -0,0 +1,145 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::Transaction,
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn buy_and_sell(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a linear curve with a price of `1 + 2 * supply` lamports, so the
+    // reserve for a supply `s` is `s + s^2`.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let (curve, bump) =
+        Pubkey::find_program_address(&[b"bonding_curve", mint.as_ref()], &token_program);
+
+    let mut set_authority_ix = spl_token::instruction::set_authority(
+        &spl_token::ID,
+        &mint,
+        Some(&curve),
+        spl_token::instruction::AuthorityType::MintTokens,
+        &mint_authority.pubkey(),
+        &[],
+    )
+    .unwrap();
+    set_authority_ix.program_id = token_program;
+
+    let mut data = vec![122, 0, bump, 0];
+    data.extend_from_slice(&1u64.to_le_bytes());
+    data.extend_from_slice(&2_000_000_000u64.to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(curve, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_authority_ix, create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When a trader buys 1000 tokens.
+
+    let trader = Keypair::new();
+    let trader_account =
+        account::initialize(&mut context, &mint, &trader.pubkey(), &token_program).await;
+
+    let curve_lamports = context.banks_client.get_balance(curve).await.unwrap();
+
+    let mut data = vec![122, 1];
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&u64::MAX.to_le_bytes());
+
+    let buy_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(curve, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new(trader_account, false),
+            AccountMeta::new(trader.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &trader.pubkey(), 1_000_000_000),
+            buy_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &trader],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the reserve received the area under the curve.
+
+    let lamports = context.banks_client.get_balance(curve).await.unwrap();
+    assert_eq!(lamports - curve_lamports, 1_001_000);
+
+    // And selling 500 tokens returns the reserve backing them.
+
+    let mut data = vec![122, 2];
+    data.extend_from_slice(&500u64.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let sell_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(curve, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new(trader_account, false),
+            AccountMeta::new(trader.pubkey(), true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sell_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &trader],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let lamports = context.banks_client.get_balance(curve).await.unwrap();
+    assert_eq!(lamports - curve_lamports, 250_500);
+
+    let account = context
+        .banks_client
+        .get_account(trader_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 500);
+}
@@ -0,0 +1,277 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+/// Creates a SOL campaign with a goal of 1 SOL ending in 1 hour and funds the
+/// contributor with `contribution` lamports.
+async fn create_campaign(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    contributor: &Keypair,
+    contribution: u64,
+    token_program: &Pubkey,
+) -> (Pubkey, Pubkey, i64) {
+    let create_key = Pubkey::new_unique();
+    let (campaign, bump) =
+        Pubkey::find_program_address(&[b"campaign", create_key.as_ref()], token_program);
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let deadline = clock.unix_timestamp + 3_600;
+
+    let mut data = vec![110, 0, bump];
+    data.extend_from_slice(&1_000_000_000u64.to_le_bytes());
+    data.extend_from_slice(&deadline.to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(creator.pubkey(), true),
+            AccountMeta::new(campaign, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let (contribution_account, bump) = Pubkey::find_program_address(
+        &[
+            b"campaign_contribution",
+            campaign.as_ref(),
+            contributor.pubkey().as_ref(),
+        ],
+        token_program,
+    );
+
+    let mut data = vec![110, 1];
+    data.extend_from_slice(&contribution.to_le_bytes());
+    data.push(bump);
+
+    let contribute_ix = Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(campaign, false),
+            AccountMeta::new(contribution_account, false),
+            AccountMeta::new(contributor.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &creator.pubkey(), 1_000_000_000),
+            system_instruction::transfer(
+                &context.payer.pubkey(),
+                &contributor.pubkey(),
+                contribution + 1_000_000_000,
+            ),
+            create_ix,
+            contribute_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, creator, contributor],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    (campaign, contribution_account, deadline)
+}
+
+/// Moves the clock past `deadline`.
+async fn warp_past(context: &mut ProgramTestContext, deadline: i64) {
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = deadline + 1;
+    context.set_sysvar(&clock);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn claim(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a campaign that reached its goal of 1 SOL.
+
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+
+    let (campaign, contribution, deadline) = create_campaign(
+        &mut context,
+        &creator,
+        &contributor,
+        1_000_000_000,
+        &token_program,
+    )
+    .await;
+
+    // And the contributor cannot be refunded after the deadline.
+
+    warp_past(&mut context, deadline).await;
+
+    let refund_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(campaign, false),
+            AccountMeta::new(contribution, false),
+            AccountMeta::new(contributor.pubkey(), false),
+        ],
+        data: vec![110, 3],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[refund_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(124))
+    );
+
+    // When the creator claims the funds.
+
+    let creator_starting_lamports = context
+        .banks_client
+        .get_account(creator.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let claim_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(campaign, false),
+            AccountMeta::new(creator.pubkey(), true),
+        ],
+        data: vec![110, 2],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &creator],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the creator received the SOL raised.
+
+    let account = context
+        .banks_client
+        .get_account(creator.pubkey())
+        .await
+        .unwrap();
+
+    assert!(account.unwrap().lamports > creator_starting_lamports + 1_000_000_000);
+
+    // And the campaign account was closed.
+
+    let account = context.banks_client.get_account(campaign).await.unwrap();
+
+    assert!(account.is_none());
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn refund(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a campaign that missed its goal of 1 SOL.
+
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+
+    let (campaign, contribution, deadline) = create_campaign(
+        &mut context,
+        &creator,
+        &contributor,
+        400_000_000,
+        &token_program,
+    )
+    .await;
+
+    // And the creator cannot claim the funds after the deadline.
+
+    warp_past(&mut context, deadline).await;
+
+    let claim_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(campaign, false),
+            AccountMeta::new(creator.pubkey(), true),
+        ],
+        data: vec![110, 2],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &creator],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(123))
+    );
+
+    // When the contributor is refunded.
+
+    let refund_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(campaign, false),
+            AccountMeta::new(contribution, false),
+            AccountMeta::new(contributor.pubkey(), false),
+        ],
+        data: vec![110, 3],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[refund_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the contributor received the contribution back.
+
+    let account = context
+        .banks_client
+        .get_account(contributor.pubkey())
+        .await
+        .unwrap();
+
+    assert!(account.unwrap().lamports > 1_000_000_000);
+
+    // And the contribution account was closed.
+
+    let account = context
+        .banks_client
+        .get_account(contribution)
+        .await
+        .unwrap();
+
+    assert!(account.is_none());
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    clock::Clock,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Signer,
+    slot_hashes::SlotHashes,
+    sysvar,
+    transaction::{Transaction, TransactionError},
+};
+
+fn check_slot_hash_ix(token_program: &Pubkey, slot: u64, hash: &Hash) -> Instruction {
+    let mut data = vec![145];
+    data.extend_from_slice(&slot.to_le_bytes());
+    data.extend_from_slice(hash.as_ref());
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![AccountMeta::new_readonly(sysvar::slot_hashes::ID, false)],
+        data,
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn check_slot_hash(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a few slots in the `SlotHashes` sysvar.
+
+    let slot = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot;
+    context.warp_to_slot(slot + 5).unwrap();
+    context.warp_to_slot(slot + 6).unwrap();
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let slot_hashes = context
+        .banks_client
+        .get_sysvar::<SlotHashes>()
+        .await
+        .unwrap();
+    let (recent_slot, recent_hash) = *slot_hashes.first().unwrap();
+
+    // When a recent slot is checked with its hash.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[check_slot_hash_ix(
+            &token_program,
+            recent_slot,
+            &recent_hash,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        last_blockhash,
+    );
+
+    // Then the check succeeds.
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When a recent slot is checked with another hash.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[check_slot_hash_ix(
+            &token_program,
+            recent_slot,
+            &Hash::new_unique(),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the check fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(149) // SlotHashMismatch
+        )
+    );
+
+    // When a slot that is not in the sysvar is checked.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[check_slot_hash_ix(
+            &token_program,
+            recent_slot + 1_000,
+            &recent_hash,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the check fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(116) // SlotHashUnavailable
+        )
+    );
+}
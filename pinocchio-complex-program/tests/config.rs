@@ -0,0 +1,678 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, config, mint, query, TOKEN_PROGRAM_ID};
+use solana_program_test::{processor, tokio, ProgramTest};
+use solana_sdk::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program::invoke,
+    program_error::ProgramError,
+    pubkey,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program, sysvar,
+    transaction::{Transaction, TransactionError},
+};
+
+/// Squads v4 multisig program, allowed to invoke the admin instructions.
+const SQUADS_ID: Pubkey = pubkey!("SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf");
+
+/// Invokes the program of the first account with the remaining accounts and the
+/// instruction data.
+fn process_relay(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let (program_info, instruction_accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let instruction = Instruction {
+        program_id: *program_info.key,
+        accounts: instruction_accounts
+            .iter()
+            .map(|account_info| AccountMeta {
+                pubkey: *account_info.key,
+                is_signer: account_info.is_signer,
+                is_writable: account_info.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+
+    invoke(&instruction, accounts)
+}
+
+/// Returns a `Pause` instruction relayed through the `relays` programs, from the
+/// outermost one.
+fn relayed_pause_ix(
+    relays: &[Pubkey],
+    token_program: &Pubkey,
+    config: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let mut accounts = relays[1..]
+        .iter()
+        .map(|relay| AccountMeta::new_readonly(*relay, false))
+        .collect::<Vec<_>>();
+    accounts.extend([
+        AccountMeta::new_readonly(*token_program, false),
+        AccountMeta::new(*config, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(sysvar::instructions::ID, false),
+    ]);
+
+    Instruction {
+        program_id: relays[0],
+        accounts,
+        data: vec![104, 4],
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn update_config(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an initialized config.
+
+    let admin = context.payer.pubkey();
+    let program_data = config::set_upgrade_authority(&mut context, &admin, &token_program);
+    let (config, bump) = Pubkey::find_program_address(&[b"config"], &token_program);
+
+    let mut data = vec![104, 0, bump];
+    data.extend_from_slice(admin.as_ref());
+    data.extend_from_slice(&100u16.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(program_data, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    // When the fee parameters and features are updated.
+
+    let fee_recipient = Pubkey::new_unique();
+
+    let mut data = vec![104, 1];
+    data.extend_from_slice(fee_recipient.as_ref());
+    data.extend_from_slice(&250u16.to_le_bytes());
+    data.extend_from_slice(&1u64.to_le_bytes());
+
+    let update_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(admin, true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix, update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the config stores the new values.
+
+    let account = context.banks_client.get_account(config).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+
+    assert_eq!(&account.data[3..35], admin.as_ref());
+    assert_eq!(&account.data[35..67], fee_recipient.as_ref());
+    assert_eq!(&account.data[67..69], &250u16.to_le_bytes());
+    assert_eq!(&account.data[69..77], &1u64.to_le_bytes());
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn accept_admin(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an initialized config.
+
+    let admin = context.payer.pubkey();
+    let program_data = config::set_upgrade_authority(&mut context, &admin, &token_program);
+    let (config, bump) = Pubkey::find_program_address(&[b"config"], &token_program);
+
+    let mut data = vec![104, 0, bump];
+    data.extend_from_slice(admin.as_ref());
+    data.extend_from_slice(&100u16.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(program_data, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    // When a new admin is proposed.
+
+    let new_admin = Keypair::new();
+
+    let mut data = vec![104, 2];
+    data.extend_from_slice(new_admin.pubkey().as_ref());
+
+    let propose_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(admin, true),
+        ],
+        data,
+    };
+
+    // And the new admin accepts the role.
+
+    let accept_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(new_admin.pubkey(), true),
+        ],
+        data: vec![104, 3],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix, propose_ix, accept_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &new_admin],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the config admin is the new admin.
+
+    let account = context.banks_client.get_account(config).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+
+    assert_eq!(&account.data[3..35], new_admin.pubkey().as_ref());
+    assert_eq!(&account.data[77..109], &[0; 32]);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn paused(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a paused program.
+
+    let admin = context.payer.pubkey();
+    let program_data = config::set_upgrade_authority(&mut context, &admin, &token_program);
+    let (config, bump) = Pubkey::find_program_address(&[b"config"], &token_program);
+
+    let mut data = vec![104, 0, bump];
+    data.extend_from_slice(admin.as_ref());
+    data.extend_from_slice(&100u16.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(program_data, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let pause_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(admin, true),
+        ],
+        data: vec![104, 4],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix, pause_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And a mint and a token account.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+    let account =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // When tokens are minted with the config account supplied.
+
+    let mut mint_ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        &mint,
+        &account,
+        &mint_authority.pubkey(),
+        &[],
+        1_000,
+    )
+    .unwrap();
+    mint_ix.program_id = token_program;
+    mint_ix
+        .accounts
+        .push(AccountMeta::new_readonly(config, false));
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint_authority],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the instruction fails with the paused error.
+
+    assert_matches!(
+        error.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(110))
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn get_config(token_program: Pubkey) {
+    let reader = Pubkey::new_unique();
+
+    let mut program_test = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None);
+    program_test.add_program("reader", reader, processor!(query::process_query));
+    let mut context = program_test.start_with_context().await;
+
+    // Given an initialized config.
+
+    let config = config::initialize(&mut context, 100, &token_program).await;
+
+    // When a program reads the config through a CPI.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: reader,
+            accounts: vec![
+                AccountMeta::new_readonly(token_program, false),
+                AccountMeta::new_readonly(config, false),
+            ],
+            data: vec![104, 6],
+        }],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let return_data = context
+        .banks_client
+        .simulate_transaction(tx)
+        .await
+        .unwrap()
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap();
+
+    // Then the program reads the payload of the envelope returned by the query.
+
+    assert_eq!(return_data.program_id, reader);
+
+    // The runtime trims the trailing zeros of the return data.
+    let mut payload = return_data.data;
+    payload.resize(75, 0);
+
+    let admin = context.payer.pubkey();
+
+    assert_eq!(&payload[0..32], admin.as_ref());
+    assert_eq!(&payload[32..64], admin.as_ref());
+    assert_eq!(&payload[64..66], &100u16.to_le_bytes());
+    assert_eq!(&payload[66..74], &0u64.to_le_bytes());
+
+    // And the program is not paused.
+
+    assert_eq!(payload[74], 0);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_unexpected_accounts(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an initialized config.
+
+    let admin = context.payer.pubkey();
+    let program_data = config::set_upgrade_authority(&mut context, &admin, &token_program);
+    let (config, bump) = Pubkey::find_program_address(&[b"config"], &token_program);
+
+    let mut data = vec![104, 0, bump];
+    data.extend_from_slice(admin.as_ref());
+    data.extend_from_slice(&100u16.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(program_data, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    // When the config is paused with an extra writable account.
+
+    let pause_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new(Pubkey::new_unique(), false),
+        ],
+        data: vec![104, 4],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix, pause_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the extra account is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(163) // UnexpectedAccounts
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_unexpected_writable_account(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a config initialization with a writable account in place of the system
+    // program, since the runtime demotes the system program to read-only.
+
+    let admin = context.payer.pubkey();
+    let program_data = config::set_upgrade_authority(&mut context, &admin, &token_program);
+    let (config, bump) = Pubkey::find_program_address(&[b"config"], &token_program);
+
+    let mut data = vec![104, 0, bump];
+    data.extend_from_slice(admin.as_ref());
+    data.extend_from_slice(&100u16.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(program_data, false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+        ],
+        data,
+    };
+
+    // When the config is initialized.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the writable account is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(162) // UnexpectedWritableAccount
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_initialize_without_upgrade_authority(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a program with a different upgrade authority than the payer.
+
+    let admin = context.payer.pubkey();
+    let program_data =
+        config::set_upgrade_authority(&mut context, &Pubkey::new_unique(), &token_program);
+    let (config, bump) = Pubkey::find_program_address(&[b"config"], &token_program);
+
+    // When the payer initializes the config.
+
+    let mut data = vec![104, 0, bump];
+    data.extend_from_slice(admin.as_ref());
+    data.extend_from_slice(&100u16.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(program_data, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the payer is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(4) // OwnerMismatch
+        )
+    );
+
+    // And the config was not created.
+
+    let account = context.banks_client.get_account(config).await.unwrap();
+
+    assert!(account.is_none());
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_missing_accounts(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an initialized config.
+
+    let config = config::initialize(&mut context, 100, &token_program).await;
+
+    // When the config is paused without the admin account.
+
+    let pause_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![AccountMeta::new(config, false)],
+        data: vec![104, 4],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[pause_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the instruction fails with not enough accounts.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn pause_through_allowlisted_caller(token_program: Pubkey) {
+    let mut program_test = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None);
+    program_test.add_program("squads", SQUADS_ID, processor!(process_relay));
+    let mut context = program_test.start_with_context().await;
+
+    // Given an initialized config.
+
+    let admin = context.payer.pubkey();
+    let config = config::initialize(&mut context, 100, &token_program).await;
+
+    // When the config is paused through a CPI from the allowlisted program.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[relayed_pause_ix(
+            &[SQUADS_ID],
+            &token_program,
+            &config,
+            &admin,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    // Then the instruction succeeds.
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_caller_not_allowed(token_program: Pubkey) {
+    let relay = Pubkey::new_unique();
+
+    let mut program_test = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None);
+    program_test.add_program("relay", relay, processor!(process_relay));
+    let mut context = program_test.start_with_context().await;
+
+    // Given an initialized config.
+
+    let admin = context.payer.pubkey();
+    let config = config::initialize(&mut context, 100, &token_program).await;
+
+    // When the config is paused through a CPI from a program not allowlisted.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[relayed_pause_ix(&[relay], &token_program, &config, &admin)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the caller is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(154) // CallerNotAllowed
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_nested_caller_of_allowlisted_program(token_program: Pubkey) {
+    let relay = Pubkey::new_unique();
+
+    let mut program_test = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None);
+    program_test.add_program("squads", SQUADS_ID, processor!(process_relay));
+    program_test.add_program("relay", relay, processor!(process_relay));
+    let mut context = program_test.start_with_context().await;
+
+    // Given an initialized config.
+
+    let admin = context.payer.pubkey();
+    let config = config::initialize(&mut context, 100, &token_program).await;
+
+    // When the allowlisted program invokes another program, which pauses the
+    // config.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[relayed_pause_ix(
+            &[SQUADS_ID, relay],
+            &token_program,
+            &config,
+            &admin,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the nested caller is rejected, although the top-level program is
+    // allowlisted.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(154) // CallerNotAllowed
+        )
+    );
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn sweep(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a switch with a 1 day inactivity window holding 100 tokens.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+    let owner_account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &owner_account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let beneficiary = Keypair::new();
+    let beneficiary_account =
+        account::initialize(&mut context, &mint, &beneficiary.pubkey(), &token_program).await;
+
+    let create_key = Pubkey::new_unique();
+    let (switch, bump) =
+        Pubkey::find_program_address(&[b"dead_man_switch", create_key.as_ref()], &token_program);
+
+    let token_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let mut data = vec![129, 0, bump];
+    data.extend_from_slice(&86_400i64.to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(owner.pubkey(), true),
+            AccountMeta::new(switch, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(beneficiary.pubkey(), false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let mut data = vec![129, 1];
+    data.extend_from_slice(&100u64.to_le_bytes());
+
+    let deposit_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(switch, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new(owner_account, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &owner.pubkey(), 1_000_000_000),
+            system_instruction::transfer(&context.payer.pubkey(), &beneficiary.pubkey(), 1_000_000),
+            create_ix,
+            deposit_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the beneficiary sweeps the switch while the owner is active.
+
+    let sweep_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(switch, false),
+            AccountMeta::new(beneficiary.pubkey(), true),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new(beneficiary_account, false),
+        ],
+        data: vec![129, 4],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &beneficiary],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the sweep fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(137) // SwitchActive
+        )
+    );
+
+    // When the owner is inactive for longer than the window.
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += 86_401;
+    context.set_sysvar(&clock);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &beneficiary],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the beneficiary receives the tokens and the switch is closed.
+
+    let account = context
+        .banks_client
+        .get_account(beneficiary_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 100);
+
+    let account = context.banks_client.get_account(switch).await.unwrap();
+    assert!(account.is_none());
+}
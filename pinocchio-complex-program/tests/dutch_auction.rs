@@ -0,0 +1,156 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::Transaction,
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn buy(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a Dutch auction halfway from 0.2 SOL to 0.1 SOL.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let seller = Keypair::new();
+    let buyer = Keypair::new();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &seller.pubkey(), 1_000_000_000),
+            system_instruction::transfer(&context.payer.pubkey(), &buyer.pubkey(), 1_000_000_000),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let seller_account =
+        account::initialize(&mut context, &mint, &seller.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &seller_account,
+        &mint_authority,
+        1,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let item_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let create_key = Pubkey::new_unique();
+    let (auction, bump) =
+        Pubkey::find_program_address(&[b"dutch_auction", create_key.as_ref()], &token_program);
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    let mut data = vec![108, 0, bump];
+    data.extend_from_slice(&1u64.to_le_bytes());
+    data.extend_from_slice(&200_000_000u64.to_le_bytes());
+    data.extend_from_slice(&100_000_000u64.to_le_bytes());
+    data.extend_from_slice(&(clock.unix_timestamp - 1_000).to_le_bytes());
+    data.extend_from_slice(&(clock.unix_timestamp + 1_000).to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(seller.pubkey(), true),
+            AccountMeta::new(auction, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(seller_account, false),
+            AccountMeta::new(item_vault, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &seller],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the item is bought.
+
+    let buyer_account =
+        account::initialize(&mut context, &mint, &buyer.pubkey(), &token_program).await;
+
+    let mut data = vec![108, 1];
+    data.extend_from_slice(&200_000_000u64.to_le_bytes());
+
+    let buy_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(auction, false),
+            AccountMeta::new(item_vault, false),
+            AccountMeta::new(buyer_account, false),
+            AccountMeta::new(buyer.pubkey(), true),
+            AccountMeta::new(seller.pubkey(), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[buy_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the buyer received the item.
+
+    let account = context
+        .banks_client
+        .get_account(buyer_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 1);
+
+    // And paid a decayed price.
+
+    let account = context
+        .banks_client
+        .get_account(buyer.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let price = 1_000_000_000 - account.lamports;
+
+    assert!(price > 100_000_000 && price < 200_000_000);
+
+    // And the auction account was closed.
+
+    let account = context.banks_client.get_account(auction).await.unwrap();
+
+    assert!(account.is_none());
+}
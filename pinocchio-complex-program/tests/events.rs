@@ -0,0 +1,133 @@
+#![cfg(all(feature = "test-sbf", feature = "events"))]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+/// Anchor `EVENT_IX_TAG` (`0x1d9acb512ea545e4` little-endian).
+const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn emit_self_cpi_event(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let destination_account =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // When the tokens are transferred with the event authority and the token
+    // program as the last accounts.
+
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &token_program);
+
+    let mut transfer_ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &account,
+        &destination_account,
+        &owner.pubkey(),
+        &[],
+        100,
+    )
+    .unwrap();
+    transfer_ix.program_id = token_program;
+    transfer_ix
+        .accounts
+        .push(AccountMeta::new_readonly(event_authority, false));
+    transfer_ix
+        .accounts
+        .push(AccountMeta::new_readonly(token_program, false));
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+
+    // Then the transfer succeeds and the event is emitted through a CPI to the
+    // token program.
+
+    assert!(result.result.is_ok());
+
+    let log_messages = result.metadata.unwrap().log_messages;
+    let invoke = format!("Program {} invoke [2]", token_program);
+
+    assert!(log_messages.iter().any(|message| message == &invoke));
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn fail_emit_event_without_event_authority_signature(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given the event authority of the token program.
+
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &token_program);
+
+    // When an event instruction is invoked directly.
+
+    let mut data = EVENT_IX_TAG.to_vec();
+    data.extend_from_slice(&[0; 8]);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: token_program,
+            accounts: vec![AccountMeta::new_readonly(event_authority, false)],
+            data,
+        }],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the instruction fails, since the event authority did not sign it.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(166) // MissingSigner
+        )
+    );
+}
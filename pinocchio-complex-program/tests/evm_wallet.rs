@@ -0,0 +1,183 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use libsecp256k1::{Message, PublicKey, SecretKey};
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    keccak,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+/// Returns the Ethereum address of `secret_key`.
+fn eth_address(secret_key: &SecretKey) -> [u8; 20] {
+    let pubkey = PublicKey::from_secret_key(secret_key).serialize();
+    let hash = keccak::hash(&pubkey[1..]);
+
+    hash.0[12..].try_into().unwrap()
+}
+
+/// Signs `message` the same way as the `personal_sign` method of Ethereum wallets.
+fn personal_sign(secret_key: &SecretKey, message: &[u8]) -> [u8; 65] {
+    let hash = keccak::hashv(&[
+        b"\x19Ethereum Signed Message:\n32",
+        keccak::hash(message).as_ref(),
+    ]);
+    let (signature, recovery_id) = libsecp256k1::sign(&Message::parse(&hash.0), secret_key);
+
+    let mut bytes = [0u8; 65];
+    bytes[..64].copy_from_slice(&signature.serialize());
+    bytes[64] = recovery_id.serialize() + 27;
+    bytes
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn signed_transfer(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a wallet controlled by an Ethereum address.
+
+    let secret_key = SecretKey::parse(&[7; 32]).unwrap();
+
+    let create_key = Pubkey::new_unique();
+    let (wallet, bump) =
+        Pubkey::find_program_address(&[b"evm_wallet", create_key.as_ref()], &token_program);
+
+    let mut data = vec![142, 0, bump];
+    data.extend_from_slice(&eth_address(&secret_key));
+
+    let payer = context.payer.pubkey();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: token_program,
+            accounts: vec![
+                AccountMeta::new(payer, true),
+                AccountMeta::new(wallet, false),
+                AccountMeta::new_readonly(create_key, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+            data,
+        }],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And a token account of the wallet with 100 tokens.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let source = account::initialize(&mut context, &mint, &wallet, &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &source,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    let transfer_ix = |amount: u64, nonce: u64, secret_key: &SecretKey| {
+        let mut message = wallet.to_bytes().to_vec();
+        message.extend_from_slice(source.as_ref());
+        message.extend_from_slice(destination.as_ref());
+        message.extend_from_slice(&amount.to_le_bytes());
+        message.extend_from_slice(&nonce.to_le_bytes());
+
+        let mut data = vec![142, 1];
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.extend_from_slice(&personal_sign(secret_key, &message));
+
+        Instruction {
+            program_id: token_program,
+            accounts: vec![
+                AccountMeta::new(wallet, false),
+                AccountMeta::new(source, false),
+                AccountMeta::new(destination, false),
+            ],
+            data,
+        }
+    };
+
+    // When a transfer is signed by another Ethereum address.
+
+    let other_key = SecretKey::parse(&[8; 32]).unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix(40, 0, &other_key)],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the transfer fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(146) // EthAddressMismatch
+        )
+    );
+
+    // When a transfer is signed by the wallet Ethereum address.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix(40, 0, &secret_key)],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the destination receives the tokens.
+
+    let account = context.banks_client.get_account(destination).await.unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 40);
+
+    // When the same signature is replayed.
+
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix(40, 0, &secret_key)],
+        Some(&payer),
+        &[&context.payer],
+        last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the transfer fails, since the nonce was incremented.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(146) // EthAddressMismatch
+        )
+    );
+}
@@ -0,0 +1,256 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+/// Creates a faucet dripping 100 tokens with the given cooldown.
+async fn initialize_faucet(
+    context: &mut ProgramTestContext,
+    cooldown: i64,
+    token_program: &Pubkey,
+) -> (Pubkey, Pubkey) {
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(context, mint_authority.pubkey(), None, token_program)
+        .await
+        .unwrap();
+
+    let (faucet, bump) = Pubkey::find_program_address(&[b"faucet", mint.as_ref()], token_program);
+
+    let mut set_authority_ix = spl_token::instruction::set_authority(
+        &spl_token::ID,
+        &mint,
+        Some(&faucet),
+        spl_token::instruction::AuthorityType::MintTokens,
+        &mint_authority.pubkey(),
+        &[],
+    )
+    .unwrap();
+    set_authority_ix.program_id = *token_program;
+
+    let mut data = vec![114, 0, bump];
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(&cooldown.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(faucet, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_authority_ix, initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    (mint, faucet)
+}
+
+/// Funds a new user with a token account and returns the user and its claim
+/// instruction.
+async fn claim_instruction(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    faucet: &Pubkey,
+    rate_limit: Option<Pubkey>,
+    token_program: &Pubkey,
+) -> (Keypair, Pubkey, Instruction) {
+    let user = Keypair::new();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(
+            &context.payer.pubkey(),
+            &user.pubkey(),
+            1_000_000_000,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let user_account = account::initialize(context, mint, &user.pubkey(), token_program).await;
+
+    let (claim, bump) = Pubkey::find_program_address(
+        &[b"faucet_claim", faucet.as_ref(), user.pubkey().as_ref()],
+        token_program,
+    );
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*faucet, false),
+        AccountMeta::new(claim, false),
+        AccountMeta::new(*mint, false),
+        AccountMeta::new(user_account, false),
+        AccountMeta::new(user.pubkey(), true),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    if let Some(rate_limit) = rate_limit {
+        accounts.push(AccountMeta::new(rate_limit, false));
+    }
+
+    let claim_ix = Instruction {
+        program_id: *token_program,
+        accounts,
+        data: vec![114, 1, bump],
+    };
+
+    (user, user_account, claim_ix)
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn claim(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a faucet dripping 100 tokens with a 1 hour cooldown.
+
+    let (mint, faucet) = initialize_faucet(&mut context, 3_600, &token_program).await;
+
+    // When a user claims from the faucet.
+
+    let (user, user_account, claim_ix) =
+        claim_instruction(&mut context, &mint, &faucet, None, &token_program).await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &user],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the user received the drip amount.
+
+    let account = context
+        .banks_client
+        .get_account(user_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 100);
+
+    // And the user cannot claim again before the cooldown.
+
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &user],
+        last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(126))
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn claim_rate_limited(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a faucet dripping 100 tokens.
+
+    let (mint, faucet) = initialize_faucet(&mut context, 0, &token_program).await;
+
+    // And a rate limit of 150 tokens without refill.
+
+    let (rate_limit, bump) =
+        Pubkey::find_program_address(&[b"rate_limit", faucet.as_ref()], &token_program);
+
+    let mut data = vec![114, 5, bump];
+    data.extend_from_slice(&150u64.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let set_rate_limit_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(faucet, false),
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(rate_limit, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_rate_limit_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And a first user claimed from the faucet.
+
+    let (user, _, claim_ix) = claim_instruction(
+        &mut context,
+        &mint,
+        &faucet,
+        Some(rate_limit),
+        &token_program,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &user],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When a second user claims from the faucet.
+
+    let (user, _, claim_ix) = claim_instruction(
+        &mut context,
+        &mint,
+        &faucet,
+        Some(rate_limit),
+        &token_program,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &user],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the claim fails with the rate limit exhausted.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(127))
+    );
+}
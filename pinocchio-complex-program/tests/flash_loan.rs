@@ -0,0 +1,159 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program, sysvar,
+    transaction::{Transaction, TransactionError},
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn borrow_and_repay(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a flash loan pool with a 1% fee and 1000 tokens of liquidity.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let token_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let create_key = Pubkey::new_unique();
+    let (pool, bump) =
+        Pubkey::find_program_address(&[b"flash_loan_pool", create_key.as_ref()], &token_program);
+
+    let mut data = vec![115, 0, bump];
+    data.extend_from_slice(&100u16.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(pool, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new_readonly(mint, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &token_vault,
+        &mint_authority,
+        1000,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a borrower with 10 tokens to pay the fee.
+
+    let borrower = Keypair::new();
+
+    let borrower_account =
+        account::initialize(&mut context, &mint, &borrower.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &borrower_account,
+        &mint_authority,
+        10,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let mut data = vec![115, 1];
+    data.extend_from_slice(&500u64.to_le_bytes());
+
+    let borrow_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(pool, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new(borrower_account, false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        ],
+        data,
+    };
+
+    // When the borrower borrows without repaying.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[borrow_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the loan is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(128))
+    );
+
+    // When the borrower borrows and repays in the same transaction.
+
+    let repay_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(pool, false),
+            AccountMeta::new(borrower_account, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new_readonly(borrower.pubkey(), true),
+        ],
+        data: vec![115, 2],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[borrow_ix, repay_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &borrower],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the pool received the loan fee.
+
+    let account = context.banks_client.get_account(token_vault).await.unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 1005);
+
+    let account = context
+        .banks_client
+        .get_account(borrower_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 5);
+}
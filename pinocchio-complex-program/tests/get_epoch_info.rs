@@ -0,0 +1,85 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    clock::Clock,
+    epoch_schedule::EpochSchedule,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+    sysvar,
+    transaction::Transaction,
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn get_epoch_info(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a cluster a few epochs after genesis.
+
+    let epoch_schedule = context
+        .banks_client
+        .get_sysvar::<EpochSchedule>()
+        .await
+        .unwrap();
+
+    let slot = epoch_schedule.get_first_slot_in_epoch(3) + 5;
+    context.warp_to_slot(slot).unwrap();
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    // When the epoch info is queried.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: token_program,
+            accounts: vec![AccountMeta::new_readonly(sysvar::epoch_rewards::ID, false)],
+            data: vec![146],
+        }],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        last_blockhash,
+    );
+    let return_data = context
+        .banks_client
+        .simulate_transaction(tx)
+        .await
+        .unwrap()
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap()
+        .data;
+
+    // Then the return data has the boundaries of the current epoch.
+
+    let (version, payload) = return_data.split_first().unwrap();
+
+    assert_eq!(*version, 1);
+
+    let read_u64 =
+        |offset: usize| u64::from_le_bytes(payload[offset..offset + 8].try_into().unwrap());
+
+    assert_eq!(payload.len(), 57);
+    assert_eq!(read_u64(0), clock.epoch);
+    assert_eq!(
+        read_u64(8),
+        epoch_schedule.get_first_slot_in_epoch(clock.epoch)
+    );
+    assert_eq!(
+        read_u64(16),
+        epoch_schedule.get_last_slot_in_epoch(clock.epoch)
+    );
+    assert_eq!(read_u64(24), clock.slot);
+
+    // And the rewards are not being distributed.
+
+    assert_eq!(payload[32], 0);
+}
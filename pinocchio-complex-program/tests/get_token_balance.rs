@@ -0,0 +1,88 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, query, TOKEN_PROGRAM_ID};
+use solana_program_test::{processor, tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+/// Length of the return data payload.
+const PAYLOAD_LEN: usize = 73;
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn get_token_balance(token_program: Pubkey) {
+    let reader = Pubkey::new_unique();
+
+    let mut program_test = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None);
+    program_test.add_program("reader", reader, processor!(query::process_query));
+    let mut context = program_test.start_with_context().await;
+
+    // Given a token account with 100 tokens.
+
+    let mint_authority = Keypair::new();
+    let owner = Pubkey::new_unique();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let account = account::initialize(&mut context, &mint, &owner, &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When a program reads the balance of the account through a CPI.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: reader,
+            accounts: vec![
+                AccountMeta::new_readonly(token_program, false),
+                AccountMeta::new_readonly(account, false),
+            ],
+            data: vec![153],
+        }],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let return_data = context
+        .banks_client
+        .simulate_transaction(tx)
+        .await
+        .unwrap()
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap();
+
+    // Then the program reads the payload of the envelope returned by the query.
+
+    assert_eq!(return_data.program_id, reader);
+
+    // The runtime trims the trailing zeros of the return data.
+    let mut payload = return_data.data;
+    payload.resize(PAYLOAD_LEN, 0);
+
+    assert_eq!(&payload[0..32], mint.as_ref());
+    assert_eq!(&payload[32..64], owner.as_ref());
+    assert_eq!(&payload[64..72], &100u64.to_le_bytes());
+
+    // And the account is not frozen.
+
+    assert_eq!(payload[72], 0);
+}
@@ -0,0 +1,595 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+struct Governance {
+    governance: Pubkey,
+    authority: Pubkey,
+    mint: Pubkey,
+    escrow: Pubkey,
+    mint_authority: Keypair,
+}
+
+struct Voter {
+    keypair: Keypair,
+    account: Pubkey,
+    vote_record: Pubkey,
+}
+
+/// Creates a governance with a 60 seconds voting period and a quorum of 100 tokens,
+/// and funds its authority with 1 SOL.
+async fn create_governance(context: &mut ProgramTestContext, token_program: &Pubkey) -> Governance {
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(context, mint_authority.pubkey(), None, token_program)
+        .await
+        .unwrap();
+
+    let escrow = account::create(context, spl_token::state::Account::LEN, token_program).await;
+
+    let (governance, bump) =
+        Pubkey::find_program_address(&[b"governance", mint.as_ref()], token_program);
+    let (authority, authority_bump) = Pubkey::find_program_address(
+        &[b"governance_authority", governance.as_ref()],
+        token_program,
+    );
+
+    let mut data = vec![135, 0, bump, authority_bump];
+    data.extend_from_slice(&60i64.to_le_bytes());
+    data.extend_from_slice(&100u64.to_le_bytes());
+
+    let create_governance_ix = Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(governance, false),
+            AccountMeta::new_readonly(authority, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(escrow, false),
+        ],
+        data,
+    };
+    let fund_authority_ix =
+        system_instruction::transfer(&context.payer.pubkey(), &authority, 1_000_000_000);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_governance_ix, fund_authority_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    Governance {
+        governance,
+        authority,
+        mint,
+        escrow,
+        mint_authority,
+    }
+}
+
+/// Proposes a transfer of 0.5 SOL from the governance authority to `recipient`.
+async fn create_proposal(
+    context: &mut ProgramTestContext,
+    governance: &Governance,
+    recipient: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    let transfer_ix = system_instruction::transfer(&governance.authority, recipient, 500_000_000);
+
+    let (proposal, bump) = Pubkey::find_program_address(
+        &[
+            b"proposal",
+            governance.governance.as_ref(),
+            &0u64.to_le_bytes(),
+        ],
+        token_program,
+    );
+
+    let mut data = vec![135, 1, bump];
+    data.extend_from_slice(transfer_ix.program_id.as_ref());
+    data.push(transfer_ix.accounts.len() as u8);
+    for account in transfer_ix.accounts.iter() {
+        data.extend_from_slice(account.pubkey.as_ref());
+        data.push(account.is_signer as u8);
+        data.push(account.is_writable as u8);
+    }
+    data.extend_from_slice(&transfer_ix.data);
+
+    let create_proposal_ix = Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(governance.governance, false),
+            AccountMeta::new(proposal, false),
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_proposal_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    proposal
+}
+
+/// Votes on the proposal with a new voter holding `amount` tokens.
+async fn vote(
+    context: &mut ProgramTestContext,
+    governance: &Governance,
+    proposal: &Pubkey,
+    amount: u64,
+    approve: bool,
+    token_program: &Pubkey,
+) -> Voter {
+    let voter = Keypair::new();
+    let voter_account =
+        account::initialize(context, &governance.mint, &voter.pubkey(), token_program).await;
+    mint::mint(
+        context,
+        &governance.mint,
+        &voter_account,
+        &governance.mint_authority,
+        amount,
+        token_program,
+    )
+    .await
+    .unwrap();
+
+    let (vote_record, bump) = Pubkey::find_program_address(
+        &[b"vote_record", proposal.as_ref(), voter.pubkey().as_ref()],
+        token_program,
+    );
+
+    let fund_voter_ix =
+        system_instruction::transfer(&context.payer.pubkey(), &voter.pubkey(), 100_000_000);
+    let vote_ix = vote_ix(
+        governance,
+        proposal,
+        &vote_record,
+        bump,
+        &voter.pubkey(),
+        &voter_account,
+        approve,
+        token_program,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[fund_voter_ix, vote_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &voter],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    Voter {
+        keypair: voter,
+        account: voter_account,
+        vote_record,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn vote_ix(
+    governance: &Governance,
+    proposal: &Pubkey,
+    vote_record: &Pubkey,
+    bump: u8,
+    voter: &Pubkey,
+    voter_account: &Pubkey,
+    approve: bool,
+    token_program: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(governance.governance, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new(*vote_record, false),
+            AccountMeta::new(*voter, true),
+            AccountMeta::new(*voter_account, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(governance.escrow, false),
+        ],
+        data: vec![135, 2, bump, approve as u8],
+    }
+}
+
+fn execute_proposal_ix(
+    governance: &Governance,
+    proposal: &Pubkey,
+    recipient: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(governance.governance, false),
+            AccountMeta::new(*proposal, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(governance.authority, false),
+            AccountMeta::new(*recipient, false),
+        ],
+        data: vec![135, 3],
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn execute_passed_proposal(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a governance with a quorum of 100 tokens.
+
+    let governance = create_governance(&mut context, &token_program).await;
+
+    // And a proposal to transfer lamports from the governance authority.
+
+    let recipient = Pubkey::new_unique();
+    let proposal = create_proposal(&mut context, &governance, &recipient, &token_program).await;
+
+    // And votes of 120 tokens in favour and 50 tokens against.
+
+    vote(
+        &mut context,
+        &governance,
+        &proposal,
+        120,
+        true,
+        &token_program,
+    )
+    .await;
+    vote(
+        &mut context,
+        &governance,
+        &proposal,
+        50,
+        false,
+        &token_program,
+    )
+    .await;
+
+    // When the proposal is executed before the voting period ends.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_proposal_ix(
+            &governance,
+            &proposal,
+            &recipient,
+            &token_program,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the execution fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(142) // VotingNotEnded
+        )
+    );
+
+    // And the proposal is executed after the voting period ends.
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += 61;
+    context.set_sysvar(&clock);
+
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_proposal_ix(
+            &governance,
+            &proposal,
+            &recipient,
+            &token_program,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context.banks_client.get_account(recipient).await.unwrap();
+
+    assert!(account.is_some());
+    assert_eq!(account.unwrap().lamports, 500_000_000);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn execute_proposal_without_quorum(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a governance with a quorum of 100 tokens.
+
+    let governance = create_governance(&mut context, &token_program).await;
+
+    // And a proposal with a single vote of 80 tokens in favour.
+
+    let recipient = Pubkey::new_unique();
+    let proposal = create_proposal(&mut context, &governance, &recipient, &token_program).await;
+
+    vote(
+        &mut context,
+        &governance,
+        &proposal,
+        80,
+        true,
+        &token_program,
+    )
+    .await;
+
+    // When the proposal is executed after the voting period ends.
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += 61;
+    context.set_sysvar(&clock);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_proposal_ix(
+            &governance,
+            &proposal,
+            &recipient,
+            &token_program,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the execution fails since the quorum was not reached.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(143) // ProposalNotPassed
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_vote_with_transferred_tokens(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a proposal with a vote of 120 tokens in favour.
+
+    let governance = create_governance(&mut context, &token_program).await;
+
+    let recipient = Pubkey::new_unique();
+    let proposal = create_proposal(&mut context, &governance, &recipient, &token_program).await;
+
+    let voter = vote(
+        &mut context,
+        &governance,
+        &proposal,
+        120,
+        true,
+        &token_program,
+    )
+    .await;
+
+    // When the voter transfers the tokens to another wallet, which votes again.
+
+    let other_voter = Keypair::new();
+    let other_account = account::initialize(
+        &mut context,
+        &governance.mint,
+        &other_voter.pubkey(),
+        &token_program,
+    )
+    .await;
+
+    let mut transfer_ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &voter.account,
+        &other_account,
+        &voter.keypair.pubkey(),
+        &[],
+        120,
+    )
+    .unwrap();
+    transfer_ix.program_id = token_program;
+
+    let (vote_record, bump) = Pubkey::find_program_address(
+        &[
+            b"vote_record",
+            proposal.as_ref(),
+            other_voter.pubkey().as_ref(),
+        ],
+        &token_program,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            transfer_ix,
+            system_instruction::transfer(
+                &context.payer.pubkey(),
+                &other_voter.pubkey(),
+                100_000_000,
+            ),
+            vote_ix(
+                &governance,
+                &proposal,
+                &vote_record,
+                bump,
+                &other_voter.pubkey(),
+                &other_account,
+                true,
+                &token_program,
+            ),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &voter.keypair, &other_voter],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the transfer fails, since the tokens are escrowed.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(1) // InsufficientFunds
+        )
+    );
+
+    // And the other wallet cannot vote without tokens.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(
+                &context.payer.pubkey(),
+                &other_voter.pubkey(),
+                100_000_000,
+            ),
+            vote_ix(
+                &governance,
+                &proposal,
+                &vote_record,
+                bump,
+                &other_voter.pubkey(),
+                &other_account,
+                true,
+                &token_program,
+            ),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &other_voter],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(1) // InsufficientFunds
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn withdraw_vote_after_voting_ends(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a proposal with a vote of 120 tokens in favour.
+
+    let governance = create_governance(&mut context, &token_program).await;
+
+    let recipient = Pubkey::new_unique();
+    let proposal = create_proposal(&mut context, &governance, &recipient, &token_program).await;
+
+    let voter = vote(
+        &mut context,
+        &governance,
+        &proposal,
+        120,
+        true,
+        &token_program,
+    )
+    .await;
+
+    let withdraw_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(governance.governance, false),
+            AccountMeta::new_readonly(proposal, false),
+            AccountMeta::new(voter.vote_record, false),
+            AccountMeta::new(voter.keypair.pubkey(), false),
+            AccountMeta::new(governance.escrow, false),
+            AccountMeta::new(voter.account, false),
+        ],
+        data: vec![135, 4],
+    };
+
+    // When the vote is withdrawn before the voting period ends.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[withdraw_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the withdrawal fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(142) // VotingNotEnded
+        )
+    );
+
+    // And the tokens are returned after the voting period ends.
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += 61;
+    context.set_sysvar(&clock);
+
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[withdraw_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(voter.account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 120);
+
+    let account = context
+        .banks_client
+        .get_account(voter.vote_record)
+        .await
+        .unwrap();
+
+    assert!(account.is_none());
+}
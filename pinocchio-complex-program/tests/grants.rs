@@ -0,0 +1,194 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+async fn token_amount(context: &mut ProgramTestContext, account: Pubkey) -> u64 {
+    let account = context.banks_client.get_account(account).await.unwrap();
+    spl_token::state::Account::unpack(&account.unwrap().data)
+        .unwrap()
+        .amount
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn release_and_refund(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a grant with milestones of 30 and 70 tokens and a 1 day deadline.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let funder = Keypair::new();
+    let funder_account =
+        account::initialize(&mut context, &mint, &funder.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &funder_account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let approver = Keypair::new();
+    let grantee_account =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    let create_key = Pubkey::new_unique();
+    let (grant, bump) =
+        Pubkey::find_program_address(&[b"grant", create_key.as_ref()], &token_program);
+
+    let token_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let deadline = clock.unix_timestamp + 86_400;
+
+    let mut data = vec![131, 0, bump];
+    data.extend_from_slice(&deadline.to_le_bytes());
+    data.extend_from_slice(&30u64.to_le_bytes());
+    data.extend_from_slice(&70u64.to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(funder.pubkey(), true),
+            AccountMeta::new(grant, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(approver.pubkey(), false),
+            AccountMeta::new_readonly(grantee_account, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(funder_account, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &funder.pubkey(), 1_000_000_000),
+            create_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &funder],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(token_amount(&mut context, token_vault).await, 100);
+
+    // When the approver releases the first milestone.
+
+    let release_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(grant, false),
+            AccountMeta::new_readonly(approver.pubkey(), true),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new(grantee_account, false),
+        ],
+        data: vec![131, 1],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[release_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &approver],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the grantee receives the milestone amount.
+
+    assert_eq!(token_amount(&mut context, grantee_account).await, 30);
+
+    // And the funder cannot be refunded before the deadline.
+
+    let refund_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(grant, false),
+            AccountMeta::new(funder.pubkey(), true),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new(funder_account, false),
+        ],
+        data: vec![131, 2],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[refund_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &funder],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(139) // GrantNotExpired
+        )
+    );
+
+    // When the deadline passes.
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = deadline;
+    context.set_sysvar(&clock);
+
+    // Then the second milestone cannot be released.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[release_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &approver],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(138) // GrantExpired
+        )
+    );
+
+    // And the funder is refunded the unreleased funds.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[refund_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &funder],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(token_amount(&mut context, funder_account).await, 70);
+
+    let account = context.banks_client.get_account(grant).await.unwrap();
+    assert!(account.is_none());
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+fn guard_ix(token_program: &Pubkey, account: &Pubkey, data: &[u8]) -> Instruction {
+    let mut instruction_data = vec![150];
+    instruction_data.extend_from_slice(data);
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![AccountMeta::new_readonly(*account, false)],
+        data: instruction_data,
+    }
+}
+
+fn comparison(discriminator: u8, operator: u8, expected: u64) -> Vec<u8> {
+    let mut data = vec![discriminator, operator];
+    data.extend_from_slice(&expected.to_le_bytes());
+    data
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn assert_balances(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+    let token_account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &token_account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // When the balances are asserted with conditions that hold.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            guard_ix(&token_program, &token_account, &comparison(1, 0, 100)),
+            guard_ix(&token_program, &token_account, &comparison(1, 3, 50)),
+            guard_ix(&token_program, &token_account, &comparison(0, 0, lamports)),
+            guard_ix(
+                &token_program,
+                &token_account,
+                &comparison(0, 4, lamports + 1),
+            ),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    // Then the assertions succeed.
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the token balance is asserted with a condition that does not hold.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[guard_ix(
+            &token_program,
+            &token_account,
+            &comparison(1, 2, 100),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the assertion fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(153) // AssertionFailed
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn assert_account_owner_and_data(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+    let token_account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    // When the account owner and the token account owner field are asserted.
+
+    let mut owner_data = vec![2];
+    owner_data.extend_from_slice(token_program.as_ref());
+
+    let mut slice_data = vec![3];
+    slice_data.extend_from_slice(&32u32.to_le_bytes());
+    slice_data.extend_from_slice(owner.pubkey().as_ref());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            guard_ix(&token_program, &token_account, &owner_data),
+            guard_ix(&token_program, &token_account, &slice_data),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    // Then the assertions succeed.
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When a slice past the end of the account data is asserted.
+
+    let mut slice_data = vec![3];
+    slice_data.extend_from_slice(&160u32.to_le_bytes());
+    slice_data.extend_from_slice(&[0; 8]);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[guard_ix(&token_program, &token_account, &slice_data)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the assertion fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(153) // AssertionFailed
+        )
+    );
+}
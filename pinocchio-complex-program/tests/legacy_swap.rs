@@ -0,0 +1,346 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+const TOKEN_SWAP_ID: Pubkey = pubkey!("SwaPpA9LAaLfeLi3a68M4DjnLqgtticKg6CnyNwgAC8");
+
+const SPL_TOKEN_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Returns a swap of 1_000 tokens expecting 1_000 tokens out, with placeholder
+/// pool and user accounts.
+fn legacy_swap_ix(
+    token_program: &Pubkey,
+    user_transfer_authority: &Pubkey,
+    slippage_bps: u16,
+    token_swap_program: &Pubkey,
+) -> Instruction {
+    let mut data = vec![148];
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&slippage_bps.to_le_bytes());
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(*user_transfer_authority, true),
+    ];
+    accounts.extend((0..6).map(|_| AccountMeta::new(Pubkey::new_unique(), false)));
+    accounts.push(AccountMeta::new_readonly(SPL_TOKEN_ID, false));
+    accounts.push(AccountMeta::new_readonly(*token_swap_program, false));
+
+    Instruction {
+        program_id: *token_program,
+        accounts,
+        data,
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_slippage_above_maximum(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a swap with a slippage tolerance above 100%.
+
+    let user_transfer_authority = Keypair::new();
+    let swap_ix = legacy_swap_ix(
+        &token_program,
+        &user_transfer_authority.pubkey(),
+        10_001,
+        &TOKEN_SWAP_ID,
+    );
+
+    // When the swap is executed.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &user_transfer_authority],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the instruction data is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_unknown_token_swap_program(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a swap passing a program that is not SPL Token Swap.
+
+    let user_transfer_authority = Keypair::new();
+    let swap_ix = legacy_swap_ix(
+        &token_program,
+        &user_transfer_authority.pubkey(),
+        100,
+        &Pubkey::new_unique(),
+    );
+
+    // When the swap is executed.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &user_transfer_authority],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the program is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::IncorrectProgramId)
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_missing_transfer_authority_signature(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a swap whose user transfer authority did not sign.
+
+    let mut swap_ix = legacy_swap_ix(&token_program, &Pubkey::new_unique(), 100, &TOKEN_SWAP_ID);
+    swap_ix.accounts[2].is_signer = false;
+
+    // When the swap is executed.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the swap fails before invoking the pool.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(166) // MissingSigner
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_unknown_token_program(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a swap passing a token program that is not SPL Token or Token-2022.
+
+    let user_transfer_authority = Keypair::new();
+    let fake_token_program = Pubkey::new_unique();
+
+    let mut data = vec![148];
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&100u16.to_le_bytes());
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(user_transfer_authority.pubkey(), true),
+    ];
+    accounts.extend((0..6).map(|_| AccountMeta::new(Pubkey::new_unique(), false)));
+    accounts.push(AccountMeta::new_readonly(fake_token_program, false));
+    accounts.push(AccountMeta::new_readonly(TOKEN_SWAP_ID, false));
+
+    let swap_ix = Instruction {
+        program_id: token_program,
+        accounts,
+        data,
+    };
+
+    // When the swap is executed.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &user_transfer_authority],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the token program is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::IncorrectProgramId)
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_frozen_source_account(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a frozen SPL Token source account.
+
+    let user_transfer_authority = Keypair::new();
+
+    let mut data = vec![0; 165];
+    data[32..64].copy_from_slice(user_transfer_authority.pubkey().as_ref());
+    data[64..72].copy_from_slice(&1_000u64.to_le_bytes());
+    // state = Frozen
+    data[108] = 2;
+
+    let source = Pubkey::new_unique();
+    context.set_account(
+        &source,
+        &Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: SPL_TOKEN_ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    let mut data = vec![148];
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&100u16.to_le_bytes());
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(user_transfer_authority.pubkey(), true),
+        AccountMeta::new(source, false),
+    ];
+    accounts.extend((0..5).map(|_| AccountMeta::new(Pubkey::new_unique(), false)));
+    accounts.push(AccountMeta::new_readonly(SPL_TOKEN_ID, false));
+    accounts.push(AccountMeta::new_readonly(TOKEN_SWAP_ID, false));
+
+    let swap_ix = Instruction {
+        program_id: token_program,
+        accounts,
+        data,
+    };
+
+    // When the swap is executed.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &user_transfer_authority],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the swap fails before invoking the pool.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(17) // AccountFrozen
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_source_account_of_other_program(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a source account with the layout of a token account, owned by another
+    // program.
+
+    let user_transfer_authority = Keypair::new();
+
+    let mut data = vec![0; 165];
+    data[32..64].copy_from_slice(user_transfer_authority.pubkey().as_ref());
+    data[64..72].copy_from_slice(&1_000u64.to_le_bytes());
+    // state = Initialized
+    data[108] = 1;
+
+    let source = Pubkey::new_unique();
+    context.set_account(
+        &source,
+        &Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    let mut data = vec![148];
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&100u16.to_le_bytes());
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(user_transfer_authority.pubkey(), true),
+        AccountMeta::new(source, false),
+    ];
+    accounts.extend((0..5).map(|_| AccountMeta::new(Pubkey::new_unique(), false)));
+    accounts.push(AccountMeta::new_readonly(SPL_TOKEN_ID, false));
+    accounts.push(AccountMeta::new_readonly(TOKEN_SWAP_ID, false));
+
+    let swap_ix = Instruction {
+        program_id: token_program,
+        accounts,
+        data,
+    };
+
+    // When the swap is executed.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[swap_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &user_transfer_authority],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the source account is rejected before it is read.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(172) // OwnerMismatch
+        )
+    );
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn set_authority_and_close_buffer(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a buffer account with a signer authority.
+
+    let buffer = Keypair::new();
+    let owner = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let buffer_lamports = rent.minimum_balance(UpgradeableLoaderState::size_of_buffer(16));
+
+    let create_buffer_ixs = bpf_loader_upgradeable::create_buffer(
+        &context.payer.pubkey(),
+        &buffer.pubkey(),
+        &owner.pubkey(),
+        buffer_lamports,
+        16,
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &create_buffer_ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &buffer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the authority is handed to the loader authority of the owner.
+
+    let (loader_authority, bump) = Pubkey::find_program_address(
+        &[b"loader_authority", owner.pubkey().as_ref()],
+        &token_program,
+    );
+
+    let set_authority_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(buffer.pubkey(), false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+            AccountMeta::new_readonly(loader_authority, false),
+            AccountMeta::new_readonly(bpf_loader_upgradeable::ID, false),
+        ],
+        data: vec![139, 0],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_authority_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the buffer authority is the loader authority.
+
+    let account = context
+        .banks_client
+        .get_account(buffer.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        account
+            .deserialize_data::<UpgradeableLoaderState>()
+            .unwrap(),
+        UpgradeableLoaderState::Buffer {
+            authority_address: Some(loader_authority)
+        }
+    );
+
+    // And the buffer is closed with the loader authority signing for the owner.
+
+    let recipient = Pubkey::new_unique();
+
+    let close_buffer_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(buffer.pubkey(), false),
+            AccountMeta::new(recipient, false),
+            AccountMeta::new_readonly(loader_authority, false),
+            AccountMeta::new_readonly(bpf_loader_upgradeable::ID, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data: vec![139, 1, bump],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_buffer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(buffer.pubkey())
+        .await
+        .unwrap();
+
+    assert!(account.is_none());
+
+    let account = context.banks_client.get_account(recipient).await.unwrap();
+
+    assert_eq!(account.unwrap().lamports, buffer_lamports);
+}
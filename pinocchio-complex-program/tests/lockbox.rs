@@ -0,0 +1,133 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn withdraw_sol(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a lockbox unlocking in 1 hour.
+
+    let owner = Keypair::new();
+
+    let create_key = Pubkey::new_unique();
+    let (lockbox, bump) =
+        Pubkey::find_program_address(&[b"lockbox", create_key.as_ref()], &token_program);
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let unlock_timestamp = clock.unix_timestamp + 3_600;
+
+    let mut data = vec![118, 0, bump];
+    data.extend_from_slice(&unlock_timestamp.to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(owner.pubkey(), true),
+            AccountMeta::new(lockbox, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    // And 1 SOL deposited into the lockbox.
+
+    let mut data = vec![118, 1];
+    data.extend_from_slice(&1_000_000_000u64.to_le_bytes());
+
+    let deposit_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(lockbox, false),
+            AccountMeta::new(owner.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &owner.pubkey(), 2_000_000_000),
+            create_ix,
+            deposit_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the owner withdraws before the unlock timestamp.
+
+    let withdraw_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(lockbox, false),
+            AccountMeta::new(owner.pubkey(), true),
+        ],
+        data: vec![118, 3],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[withdraw_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the withdraw fails with the lockbox locked.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(133))
+    );
+
+    // And the owner can withdraw once the unlock timestamp has passed.
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = unlock_timestamp + 1;
+    context.set_sysvar(&clock);
+
+    let owner_lamports = context
+        .banks_client
+        .get_balance(owner.pubkey())
+        .await
+        .unwrap();
+
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[withdraw_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let lockbox_account = context.banks_client.get_account(lockbox).await.unwrap();
+    assert!(lockbox_account.is_none());
+
+    let lamports = context
+        .banks_client
+        .get_balance(owner.pubkey())
+        .await
+        .unwrap();
+    assert!(lamports > owner_lamports + 1_000_000_000);
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+fn memo_ix(token_program: &Pubkey, signer: &Pubkey, text: &[u8]) -> Instruction {
+    let mut data = vec![136];
+    data.extend_from_slice(text);
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(MEMO_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*signer, true),
+        ],
+        data,
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn memo(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a signer of the memo.
+
+    let signer = Keypair::new();
+
+    // When a UTF-8 memo is recorded.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[memo_ix(
+            &token_program,
+            &signer.pubkey(),
+            "invoice #42 ✓".as_bytes(),
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signer],
+        context.last_blockhash,
+    );
+    let result = context.banks_client.process_transaction(tx).await;
+
+    // Then the memo is accepted by the Memo program.
+
+    assert!(result.is_ok());
+
+    // And a memo that is not valid UTF-8 is rejected.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[memo_ix(
+            &token_program,
+            &signer.pubkey(),
+            &[0xf0, 0x28, 0x8c],
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+    );
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    sysvar,
+    transaction::{Transaction, TransactionError},
+};
+
+const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+async fn token_amount(context: &mut ProgramTestContext, account: Pubkey) -> u64 {
+    let account = context.banks_client.get_account(account).await.unwrap();
+    spl_token::state::Account::unpack(&account.unwrap().data)
+        .unwrap()
+        .amount
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn transfer_with_memo(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a source account with 100 tokens and a destination account.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+    let source = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &source,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let mut data = vec![140, 0];
+    data.extend_from_slice(&40u64.to_le_bytes());
+
+    let transfer_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+            AccountMeta::new(source, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data,
+    };
+
+    // When the transfer is made without a memo.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the transfer fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(144) // MemoNotFound
+        )
+    );
+
+    // And the transfer succeeds with a memo in the transaction.
+
+    let memo_ix = Instruction {
+        program_id: MEMO_PROGRAM_ID,
+        accounts: vec![],
+        data: b"invoice #42".to_vec(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[memo_ix, transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(token_amount(&mut context, source).await, 60);
+    assert_eq!(token_amount(&mut context, destination).await, 40);
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::Transaction,
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn execute_transaction(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a 2-of-3 multisig wallet.
+
+    let owners = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let create_key = Pubkey::new_unique();
+
+    let (wallet, bump) =
+        Pubkey::find_program_address(&[b"multisig_wallet", create_key.as_ref()], &token_program);
+    let (vault, vault_bump) =
+        Pubkey::find_program_address(&[b"multisig_vault", wallet.as_ref()], &token_program);
+
+    let mut accounts = vec![
+        AccountMeta::new(context.payer.pubkey(), true),
+        AccountMeta::new(wallet, false),
+        AccountMeta::new_readonly(vault, false),
+        AccountMeta::new_readonly(create_key, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    accounts.extend(
+        owners
+            .iter()
+            .map(|owner| AccountMeta::new_readonly(owner.pubkey(), false)),
+    );
+
+    let create_wallet_ix = Instruction {
+        program_id: token_program,
+        accounts,
+        data: vec![102, 0, bump, vault_bump, 2],
+    };
+
+    // And a vault with 1 SOL.
+
+    let fund_vault_ix =
+        system_instruction::transfer(&context.payer.pubkey(), &vault, 1_000_000_000);
+    let fund_proposer_ix =
+        system_instruction::transfer(&context.payer.pubkey(), &owners[0].pubkey(), 1_000_000_000);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_wallet_ix, fund_vault_ix, fund_proposer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When a transfer from the vault is proposed by one owner.
+
+    let recipient = Pubkey::new_unique();
+    let transfer_ix = system_instruction::transfer(&vault, &recipient, 500_000_000);
+
+    let (transaction, bump) = Pubkey::find_program_address(
+        &[
+            b"multisig_transaction",
+            wallet.as_ref(),
+            &0u64.to_le_bytes(),
+        ],
+        &token_program,
+    );
+
+    let mut data = vec![102, 1, bump];
+    data.extend_from_slice(transfer_ix.program_id.as_ref());
+    data.push(transfer_ix.accounts.len() as u8);
+    for account in transfer_ix.accounts.iter() {
+        data.extend_from_slice(account.pubkey.as_ref());
+        data.push(account.is_signer as u8);
+        data.push(account.is_writable as u8);
+    }
+    data.extend_from_slice(&transfer_ix.data);
+
+    let propose_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(wallet, false),
+            AccountMeta::new(transaction, false),
+            AccountMeta::new(owners[0].pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    // And approved by a second owner.
+
+    let approve_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(wallet, false),
+            AccountMeta::new(transaction, false),
+            AccountMeta::new_readonly(owners[1].pubkey(), true),
+        ],
+        data: vec![102, 2],
+    };
+
+    // And executed.
+
+    let execute_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(wallet, false),
+            AccountMeta::new(transaction, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(recipient, false),
+        ],
+        data: vec![102, 3],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix, approve_ix, execute_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owners[0], &owners[1]],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the recipient received the lamports from the vault.
+
+    let account = context.banks_client.get_account(recipient).await.unwrap();
+
+    assert!(account.is_some());
+    assert_eq!(account.unwrap().lamports, 500_000_000);
+}
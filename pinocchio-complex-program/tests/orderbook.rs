@@ -0,0 +1,400 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+async fn token_amount(context: &mut ProgramTestContext, account: Pubkey) -> u64 {
+    let account = context.banks_client.get_account(account).await.unwrap();
+    spl_token::state::Account::unpack(&account.unwrap().data)
+        .unwrap()
+        .amount
+}
+
+struct Trader {
+    keypair: Keypair,
+    base_account: Pubkey,
+    quote_account: Pubkey,
+}
+
+struct MarketAccounts {
+    market: Pubkey,
+    quote_mint: Pubkey,
+    base_vault: Pubkey,
+    quote_vault: Pubkey,
+    seller: Trader,
+    buyer: Trader,
+}
+
+/// Creates a market with a seller holding 10 base tokens and a buyer holding 60
+/// quote tokens.
+async fn create_market(context: &mut ProgramTestContext, token_program: &Pubkey) -> MarketAccounts {
+    let mint_authority = Keypair::new();
+
+    let base_mint = mint::initialize(context, mint_authority.pubkey(), None, token_program)
+        .await
+        .unwrap();
+    let quote_mint = mint::initialize(context, mint_authority.pubkey(), None, token_program)
+        .await
+        .unwrap();
+
+    let base_vault = account::create(context, spl_token::state::Account::LEN, token_program).await;
+    let quote_vault = account::create(context, spl_token::state::Account::LEN, token_program).await;
+
+    let create_key = Pubkey::new_unique();
+    let (market, bump) =
+        Pubkey::find_program_address(&[b"market", create_key.as_ref()], token_program);
+
+    let create_ix = Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(market, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(base_vault, false),
+            AccountMeta::new_readonly(base_mint, false),
+            AccountMeta::new(quote_vault, false),
+            AccountMeta::new_readonly(quote_mint, false),
+        ],
+        data: vec![116, 0, bump],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let mut traders = Vec::new();
+
+    for (mint, amount) in [(base_mint, 10), (quote_mint, 60)] {
+        let keypair = Keypair::new();
+        let base_account =
+            account::initialize(context, &base_mint, &keypair.pubkey(), token_program).await;
+        let quote_account =
+            account::initialize(context, &quote_mint, &keypair.pubkey(), token_program).await;
+
+        let funded_account = if mint == base_mint {
+            base_account
+        } else {
+            quote_account
+        };
+
+        mint::mint(
+            context,
+            &mint,
+            &funded_account,
+            &mint_authority,
+            amount,
+            token_program,
+        )
+        .await
+        .unwrap();
+
+        traders.push(Trader {
+            keypair,
+            base_account,
+            quote_account,
+        });
+    }
+
+    let buyer = traders.pop().unwrap();
+    let seller = traders.pop().unwrap();
+
+    MarketAccounts {
+        market,
+        quote_mint,
+        base_vault,
+        quote_vault,
+        seller,
+        buyer,
+    }
+}
+
+fn place_order_ix(
+    token_program: &Pubkey,
+    market: &MarketAccounts,
+    trader: &Trader,
+    side: u8,
+    price: u64,
+    quantity: u64,
+) -> Instruction {
+    let mut data = vec![116, 1, side];
+    data.extend_from_slice(&price.to_le_bytes());
+    data.extend_from_slice(&quantity.to_le_bytes());
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(market.market, false),
+            AccountMeta::new(market.base_vault, false),
+            AccountMeta::new(market.quote_vault, false),
+            AccountMeta::new(trader.base_account, false),
+            AccountMeta::new(trader.quote_account, false),
+            AccountMeta::new_readonly(trader.keypair.pubkey(), true),
+        ],
+        data,
+    }
+}
+
+fn match_orders_ix(token_program: &Pubkey, market: &MarketAccounts) -> Instruction {
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![AccountMeta::new(market.market, false)],
+        data: vec![116, 3],
+    }
+}
+
+fn settle_order_ix(
+    token_program: &Pubkey,
+    market: &MarketAccounts,
+    owner: &Pubkey,
+    base_account: &Pubkey,
+    quote_account: &Pubkey,
+    side: u8,
+    id: u64,
+) -> Instruction {
+    let mut data = vec![116, 4, side];
+    data.extend_from_slice(&id.to_le_bytes());
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(market.market, false),
+            AccountMeta::new(market.base_vault, false),
+            AccountMeta::new(market.quote_vault, false),
+            AccountMeta::new(*base_account, false),
+            AccountMeta::new(*quote_account, false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        data,
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn match_orders(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a market with an ask of 10 at 5 followed by a bid of 10 at 6.
+
+    let market = create_market(&mut context, &token_program).await;
+    let MarketAccounts { seller, buyer, .. } = &market;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            place_order_ix(&token_program, &market, seller, 1, 5, 10),
+            place_order_ix(&token_program, &market, buyer, 0, 6, 10),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &seller.keypair, &buyer.keypair],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the orders are matched and settled.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            match_orders_ix(&token_program, &market),
+            settle_order_ix(
+                &token_program,
+                &market,
+                &buyer.keypair.pubkey(),
+                &buyer.base_account,
+                &buyer.quote_account,
+                0,
+                1,
+            ),
+            settle_order_ix(
+                &token_program,
+                &market,
+                &seller.keypair.pubkey(),
+                &seller.base_account,
+                &seller.quote_account,
+                1,
+                0,
+            ),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &buyer.keypair, &seller.keypair],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the fill is executed at the price of the older ask.
+
+    assert_eq!(token_amount(&mut context, buyer.base_account).await, 10);
+    assert_eq!(token_amount(&mut context, seller.quote_account).await, 50);
+
+    // And the buyer was refunded the price difference.
+
+    assert_eq!(token_amount(&mut context, buyer.quote_account).await, 10);
+
+    // And the vaults are empty.
+
+    assert_eq!(token_amount(&mut context, market.base_vault).await, 0);
+    assert_eq!(token_amount(&mut context, market.quote_vault).await, 0);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn fail_match_orders_not_crossing(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a market with an ask of 10 at 7 and a bid of 10 at 6.
+
+    let market = create_market(&mut context, &token_program).await;
+    let MarketAccounts { seller, buyer, .. } = &market;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            place_order_ix(&token_program, &market, seller, 1, 7, 10),
+            place_order_ix(&token_program, &market, buyer, 0, 6, 10),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &seller.keypair, &buyer.keypair],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the orders are matched.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[match_orders_ix(&token_program, &market)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the match fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(132) // OrdersNotCrossing
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn match_orders_with_closed_payout_account(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a market with an ask of 10 at 5 and a bid of 10 at 5.
+
+    let market = create_market(&mut context, &token_program).await;
+    let MarketAccounts { seller, buyer, .. } = &market;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            place_order_ix(&token_program, &market, seller, 1, 5, 10),
+            place_order_ix(&token_program, &market, buyer, 0, 5, 10),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &seller.keypair, &buyer.keypair],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And the seller closed the quote account of the ask.
+
+    let mut close_account_ix = spl_token::instruction::close_account(
+        &spl_token::ID,
+        &seller.quote_account,
+        &seller.keypair.pubkey(),
+        &seller.keypair.pubkey(),
+        &[],
+    )
+    .unwrap();
+    close_account_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &seller.keypair],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the orders are matched.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[match_orders_ix(&token_program, &market)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the crank is not blocked and the buyer settles the fill.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[settle_order_ix(
+            &token_program,
+            &market,
+            &buyer.keypair.pubkey(),
+            &buyer.base_account,
+            &buyer.quote_account,
+            0,
+            1,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &buyer.keypair],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(token_amount(&mut context, buyer.base_account).await, 10);
+
+    // And the seller settles the proceeds to another account.
+
+    let seller_quote = account::initialize(
+        &mut context,
+        &market.quote_mint,
+        &seller.keypair.pubkey(),
+        &token_program,
+    )
+    .await;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[settle_order_ix(
+            &token_program,
+            &market,
+            &seller.keypair.pubkey(),
+            &seller.base_account,
+            &seller_quote,
+            1,
+            0,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &seller.keypair],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(token_amount(&mut context, seller_quote).await, 50);
+    assert_eq!(token_amount(&mut context, market.quote_vault).await, 0);
+}
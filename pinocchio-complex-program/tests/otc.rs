@@ -0,0 +1,180 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, config, mint, treasury, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+async fn token_amount(context: &mut ProgramTestContext, account: Pubkey) -> u64 {
+    let account = context.banks_client.get_account(account).await.unwrap();
+    spl_token::state::Account::unpack(&account.unwrap().data)
+        .unwrap()
+        .amount
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn take_offer(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a config with a 1% protocol fee and a treasury.
+
+    let config = config::initialize(&mut context, 100, &token_program).await;
+    let treasury = treasury::initialize(&mut context, &token_program).await;
+
+    // And a maker with 100 tokens A and a taker with 50 tokens B.
+
+    let mint_authority = Keypair::new();
+
+    let mint_a = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+    let mint_b = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let maker = Keypair::new();
+    let taker = Keypair::new();
+
+    let maker_a = account::initialize(&mut context, &mint_a, &maker.pubkey(), &token_program).await;
+    let maker_b = account::initialize(&mut context, &mint_b, &maker.pubkey(), &token_program).await;
+    let taker_a = account::initialize(&mut context, &mint_a, &taker.pubkey(), &token_program).await;
+    let taker_b = account::initialize(&mut context, &mint_b, &taker.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint_a,
+        &maker_a,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+    mint::mint(
+        &mut context,
+        &mint_b,
+        &taker_b,
+        &mint_authority,
+        50,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And an offer of 100 tokens A for 50 tokens B.
+
+    let escrow =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let create_key = Pubkey::new_unique();
+    let (offer, bump) =
+        Pubkey::find_program_address(&[b"otc_offer", create_key.as_ref()], &token_program);
+
+    let mut data = vec![117, 0, bump];
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(&50u64.to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new(offer, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(mint_a, false),
+            AccountMeta::new(maker_a, false),
+            AccountMeta::new_readonly(maker_b, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &maker.pubkey(), 1_000_000_000),
+            create_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &maker],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(token_amount(&mut context, escrow).await, 100);
+
+    // When the taker fills the offer without the treasury accounts.
+
+    let mut take_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(offer, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(taker_a, false),
+            AccountMeta::new(taker_b, false),
+            AccountMeta::new(maker_b, false),
+            AccountMeta::new_readonly(taker.pubkey(), true),
+            AccountMeta::new(maker.pubkey(), false),
+            AccountMeta::new_readonly(config, false),
+        ],
+        data: vec![117, 1],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[take_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &taker],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the fill fails, since the protocol fee cannot be skipped.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+
+    // And the fill succeeds with the treasury accounts.
+
+    let treasury_vault =
+        account::initialize(&mut context, &mint_a, &treasury, &token_program).await;
+
+    take_ix.accounts.extend([
+        AccountMeta::new_readonly(treasury, false),
+        AccountMeta::new(treasury_vault, false),
+    ]);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[take_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &taker],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And the tokens were swapped, net of the protocol fee.
+
+    assert_eq!(token_amount(&mut context, taker_a).await, 99);
+    assert_eq!(token_amount(&mut context, treasury_vault).await, 1);
+    assert_eq!(token_amount(&mut context, maker_b).await, 50);
+
+    // And the offer and escrow accounts were closed.
+
+    let offer_account = context.banks_client.get_account(offer).await.unwrap();
+    assert!(offer_account.is_none());
+
+    let escrow_account = context.banks_client.get_account(escrow).await.unwrap();
+    assert!(escrow_account.is_none());
+}
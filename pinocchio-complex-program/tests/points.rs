@@ -0,0 +1,176 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+async fn points_balance(context: &mut ProgramTestContext, ledger: Pubkey) -> u64 {
+    let account = context.banks_client.get_account(ledger).await.unwrap();
+    let account = account.unwrap();
+    // discriminator (1) + bump (1) + points program (32) + user (32)
+    u64::from_le_bytes(account.data[66..74].try_into().unwrap())
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn award_deduct_and_query(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a points program with an issuer.
+
+    let issuer = Keypair::new();
+
+    let create_key = Pubkey::new_unique();
+    let (points_program, bump) =
+        Pubkey::find_program_address(&[b"points_program", create_key.as_ref()], &token_program);
+
+    let mut data = vec![127, 0, bump];
+    data.extend_from_slice(issuer.pubkey().as_ref());
+
+    let mut instructions = vec![Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(points_program, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }];
+
+    // And the ledgers of two users.
+
+    let mut ledgers = Vec::new();
+
+    for _ in 0..2 {
+        let user = Pubkey::new_unique();
+        let (ledger, bump) = Pubkey::find_program_address(
+            &[b"points_ledger", points_program.as_ref(), user.as_ref()],
+            &token_program,
+        );
+
+        instructions.push(Instruction {
+            program_id: token_program,
+            accounts: vec![
+                AccountMeta::new(context.payer.pubkey(), true),
+                AccountMeta::new(ledger, false),
+                AccountMeta::new_readonly(points_program, false),
+                AccountMeta::new_readonly(user, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+            data: vec![127, 2, bump],
+        });
+
+        ledgers.push(ledger);
+    }
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the issuer awards points to both users in a batch and deducts points
+    // from the first user.
+
+    let mut data = vec![127, 6];
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(&50u64.to_le_bytes());
+
+    let batch_award_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(points_program, false),
+            AccountMeta::new_readonly(issuer.pubkey(), true),
+            AccountMeta::new(ledgers[0], false),
+            AccountMeta::new(ledgers[1], false),
+        ],
+        data,
+    };
+
+    let deduct_ix = |amount: u64| {
+        let mut data = vec![127, 4];
+        data.extend_from_slice(&amount.to_le_bytes());
+
+        Instruction {
+            program_id: token_program,
+            accounts: vec![
+                AccountMeta::new_readonly(points_program, false),
+                AccountMeta::new_readonly(issuer.pubkey(), true),
+                AccountMeta::new(ledgers[0], false),
+            ],
+            data,
+        }
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[batch_award_ix, deduct_ix(30)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &issuer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the ledgers have the expected balances.
+
+    assert_eq!(points_balance(&mut context, ledgers[0]).await, 70);
+    assert_eq!(points_balance(&mut context, ledgers[1]).await, 50);
+
+    // And the balance can be queried.
+
+    let query_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![AccountMeta::new_readonly(ledgers[1], false)],
+        data: vec![127, 5],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[query_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+    let return_data = simulation
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap()
+        .data;
+
+    let (version, payload) = return_data.split_first().unwrap();
+
+    assert_eq!(*version, 1);
+    assert_eq!(payload, 50u64.to_le_bytes());
+
+    // And more points than the balance cannot be deducted.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[deduct_ix(71)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &issuer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(1) // InsufficientFunds
+        )
+    );
+}
@@ -0,0 +1,289 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program, sysvar,
+    transaction::{Transaction, TransactionError},
+};
+
+const WORMHOLE_ID: Pubkey = pubkey!("worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth");
+
+/// Returns a message post with placeholder bridge accounts.
+#[allow(clippy::too_many_arguments)]
+fn post_message_ix(
+    token_program: &Pubkey,
+    authority: &Pubkey,
+    emitter: &Pubkey,
+    bump: u8,
+    config: &Pubkey,
+    message: &Pubkey,
+    payer: &Pubkey,
+    payload: &[u8],
+) -> Instruction {
+    let mut data = vec![149, bump];
+    data.extend_from_slice(&7u32.to_le_bytes());
+    // consistency level = finalized
+    data.push(1);
+    data.extend_from_slice(payload);
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(*emitter, false),
+            AccountMeta::new(*config, false),
+            AccountMeta::new(*message, true),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(WORMHOLE_ID, false),
+        ],
+        data,
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn derive_emitter_from_authority(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given the emitter derived from the authority and a bridge configuration
+    // without the message fee.
+
+    let authority = Keypair::new();
+    let message = Keypair::new();
+    let (emitter, bump) =
+        Pubkey::find_program_address(&[b"emitter", authority.pubkey().as_ref()], &token_program);
+
+    let config = Pubkey::new_unique();
+    context.set_account(
+        &config,
+        &Account {
+            lamports: 1_000_000_000,
+            data: vec![0; 12],
+            owner: WORMHOLE_ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    // When the message is posted.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[post_message_ix(
+            &token_program,
+            &authority.pubkey(),
+            &emitter,
+            bump,
+            &config,
+            &message.pubkey(),
+            &context.payer.pubkey(),
+            b"hello",
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority, &message],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the emitter is accepted and the bridge configuration is read.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(174) // MalformedAccountData
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_emitter_of_another_authority(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given the emitter derived from another authority.
+
+    let authority = Keypair::new();
+    let message = Keypair::new();
+    let (emitter, bump) =
+        Pubkey::find_program_address(&[b"emitter", Pubkey::new_unique().as_ref()], &token_program);
+
+    // When the message is posted.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[post_message_ix(
+            &token_program,
+            &authority.pubkey(),
+            &emitter,
+            bump,
+            &Pubkey::new_unique(),
+            &message.pubkey(),
+            &context.payer.pubkey(),
+            b"hello",
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority, &message],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the emitter is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(100) // DerivedAddressMismatch
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_missing_authority_signature(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a message post whose authority did not sign.
+
+    let authority = Pubkey::new_unique();
+    let message = Keypair::new();
+    let (emitter, bump) =
+        Pubkey::find_program_address(&[b"emitter", authority.as_ref()], &token_program);
+
+    let mut post_message_ix = post_message_ix(
+        &token_program,
+        &authority,
+        &emitter,
+        bump,
+        &Pubkey::new_unique(),
+        &message.pubkey(),
+        &context.payer.pubkey(),
+        b"hello",
+    );
+    post_message_ix.accounts[0].is_signer = false;
+
+    // When the message is posted.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[post_message_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &message],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the post fails, since only the authority can emit from its emitter.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(166) // MissingSigner
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_unknown_bridge_program(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a message post passing a program that is not the Wormhole core bridge.
+
+    let authority = Keypair::new();
+    let message = Keypair::new();
+    let (emitter, bump) =
+        Pubkey::find_program_address(&[b"emitter", authority.pubkey().as_ref()], &token_program);
+
+    let mut post_message_ix = post_message_ix(
+        &token_program,
+        &authority.pubkey(),
+        &emitter,
+        bump,
+        &Pubkey::new_unique(),
+        &message.pubkey(),
+        &context.payer.pubkey(),
+        b"hello",
+    );
+    post_message_ix.accounts[10] = AccountMeta::new_readonly(Pubkey::new_unique(), false);
+
+    // When the message is posted.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[post_message_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority, &message],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the program is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::IncorrectProgramId)
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_payload_too_long(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a message with a payload over 512 bytes.
+
+    let authority = Keypair::new();
+    let message = Keypair::new();
+    let (emitter, bump) =
+        Pubkey::find_program_address(&[b"emitter", authority.pubkey().as_ref()], &token_program);
+
+    // When the message is posted.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[post_message_ix(
+            &token_program,
+            &authority.pubkey(),
+            &emitter,
+            bump,
+            &Pubkey::new_unique(),
+            &message.pubkey(),
+            &context.payer.pubkey(),
+            &[1; 513],
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority, &message],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the instruction data is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+    );
+}
@@ -0,0 +1,195 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+/// Discriminator of the profile account.
+const PROFILE_DISCRIMINATOR: u8 = 29;
+
+/// Length of the version `1` profile layout.
+const PROFILE_V1_LEN: usize = 39;
+
+/// Length of the current profile layout.
+const PROFILE_LEN: usize = 51;
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn migrate(token_program: Pubkey) {
+    let mut program_test = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None);
+
+    // Given a profile with the version 1 layout and a score of 7.
+
+    let authority = Keypair::new();
+
+    let (profile, bump) =
+        Pubkey::find_program_address(&[b"profile", authority.pubkey().as_ref()], &token_program);
+
+    let mut data = vec![PROFILE_DISCRIMINATOR, 1, bump];
+    data.extend_from_slice(authority.pubkey().as_ref());
+    data.extend_from_slice(&7u32.to_le_bytes());
+
+    program_test.add_account(
+        profile,
+        Account {
+            lamports: Rent::default().minimum_balance(PROFILE_V1_LEN),
+            data,
+            owner: token_program,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    program_test.add_account(
+        authority.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            ..Account::default()
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+
+    // When the authority updates the score before migrating the profile.
+
+    let update_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(profile, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+        ],
+        data: [&[119, 1][..], &3u64.to_le_bytes()].concat(),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the update fails with the version not supported.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(109))
+    );
+
+    // And the update succeeds once the profile is migrated.
+
+    let migrate_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(profile, false),
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: vec![119, 2],
+    };
+
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[migrate_ix, update_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(profile)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(account.data.len(), PROFILE_LEN);
+    assert_eq!(account.data[1], 2);
+    assert_eq!(account.data[35..43], 10u64.to_le_bytes());
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID, 0 ; "p-token version 0")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, 2 ; "p-token current version")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, 3 ; "p-token newer version")]
+#[tokio::test]
+async fn fail_migrate_unsupported_version(token_program: Pubkey, version: u8) {
+    let mut program_test = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None);
+
+    // Given a profile with the current layout length and the version under test.
+
+    let authority = Keypair::new();
+
+    let (profile, bump) =
+        Pubkey::find_program_address(&[b"profile", authority.pubkey().as_ref()], &token_program);
+
+    let mut data = vec![PROFILE_DISCRIMINATOR, version, bump];
+    data.extend_from_slice(authority.pubkey().as_ref());
+    data.extend_from_slice(&7u64.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    program_test.add_account(
+        profile,
+        Account {
+            lamports: Rent::default().minimum_balance(PROFILE_LEN),
+            data: data.clone(),
+            owner: token_program,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+
+    // When the profile is migrated.
+
+    let migrate_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(profile, false),
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: vec![119, 2],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[migrate_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the migration fails, since only older layouts can be upgraded.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(109) // InvalidVersion
+        )
+    );
+
+    // And the profile is unchanged.
+
+    let account = context
+        .banks_client
+        .get_account(profile)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(account.data, data);
+}
@@ -0,0 +1,306 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    hash::Hash,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    slot_hashes::SlotHashes,
+    system_instruction, system_program, sysvar,
+    transaction::{Transaction, TransactionError},
+};
+
+const TICKET_PRICE: u64 = 100_000_000;
+
+struct RaffleAccounts {
+    raffle: Pubkey,
+    tickets: Vec<Pubkey>,
+    buyers: [Keypair; 2],
+    end_slot: u64,
+}
+
+/// Creates a raffle ending in 10 slots, with two tickets bought by different
+/// buyers.
+async fn create_raffle(context: &mut ProgramTestContext, token_program: &Pubkey) -> RaffleAccounts {
+    let create_key = Pubkey::new_unique();
+    let (raffle, bump) =
+        Pubkey::find_program_address(&[b"raffle", create_key.as_ref()], token_program);
+
+    let end_slot = context.banks_client.get_root_slot().await.unwrap() + 10;
+
+    let mut data = vec![109, 0, bump];
+    data.extend_from_slice(&TICKET_PRICE.to_le_bytes());
+    data.extend_from_slice(&end_slot.to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(raffle, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let buyers = [Keypair::new(), Keypair::new()];
+    let mut tickets = Vec::new();
+    let mut instructions = vec![create_ix];
+
+    for (index, buyer) in buyers.iter().enumerate() {
+        let (ticket, bump) = Pubkey::find_program_address(
+            &[
+                b"raffle_ticket",
+                raffle.as_ref(),
+                &(index as u64).to_le_bytes(),
+            ],
+            token_program,
+        );
+        tickets.push(ticket);
+
+        instructions.push(system_instruction::transfer(
+            &context.payer.pubkey(),
+            &buyer.pubkey(),
+            1_000_000_000,
+        ));
+        instructions.push(Instruction {
+            program_id: *token_program,
+            accounts: vec![
+                AccountMeta::new(raffle, false),
+                AccountMeta::new(ticket, false),
+                AccountMeta::new(buyer.pubkey(), true),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+            data: vec![109, 1, bump],
+        });
+    }
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &buyers[0], &buyers[1]],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    RaffleAccounts {
+        raffle,
+        tickets,
+        buyers,
+        end_slot,
+    }
+}
+
+fn draw_ix(token_program: &Pubkey, raffle: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(*raffle, false),
+            AccountMeta::new_readonly(sysvar::slot_hashes::ID, false),
+        ],
+        data: vec![109, 2],
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn draw_and_claim_prize(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a raffle ending in 10 slots with two tickets bought by different buyers.
+
+    let RaffleAccounts {
+        raffle,
+        tickets,
+        buyers,
+        end_slot,
+    } = create_raffle(&mut context, &token_program).await;
+    let slot = end_slot - 10;
+
+    // When the winner is drawn after the end slot hash is available.
+
+    context.warp_to_slot(slot + 20).unwrap();
+    context.warp_to_slot(slot + 21).unwrap();
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[draw_ix(&token_program, &raffle)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context.banks_client.get_account(raffle).await.unwrap();
+    let winner = u64::from_le_bytes(account.unwrap().data[59..67].try_into().unwrap()) as usize;
+
+    assert!(winner < buyers.len());
+
+    // And the winner claims the prize.
+
+    let claim_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(raffle, false),
+            AccountMeta::new(tickets[winner], false),
+            AccountMeta::new(buyers[winner].pubkey(), false),
+        ],
+        data: vec![109, 3],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the winner received the ticket proceeds.
+
+    let account = context
+        .banks_client
+        .get_account(buyers[winner].pubkey())
+        .await
+        .unwrap();
+
+    assert!(account.unwrap().lamports > 1_000_000_000);
+
+    // And the raffle account was closed.
+
+    let account = context.banks_client.get_account(raffle).await.unwrap();
+
+    assert!(account.is_none());
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_draw_before_end(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a raffle ending in 10 slots.
+
+    let raffle = create_raffle(&mut context, &token_program).await;
+
+    // When the winner is drawn before the end slot.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[draw_ix(&token_program, &raffle.raffle)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the draw fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(118) // RaffleNotEnded
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn refund_tickets_after_draw_window(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a raffle whose end slot is older than the entries of the `SlotHashes`
+    // sysvar.
+
+    let RaffleAccounts {
+        raffle,
+        tickets,
+        buyers,
+        end_slot,
+    } = create_raffle(&mut context, &token_program).await;
+
+    context.warp_to_slot(end_slot + 1_000).unwrap();
+    context.set_sysvar(&SlotHashes::new(&[(end_slot + 999, Hash::new_unique())]));
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    // When the winner is drawn.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[draw_ix(&token_program, &raffle)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the draw fails instead of using a later slot hash.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(116) // SlotHashUnavailable
+        )
+    );
+
+    // And the tickets are refunded.
+
+    let refund_ix = |index: usize| Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(raffle, false),
+            AccountMeta::new(tickets[index], false),
+            AccountMeta::new(buyers[index].pubkey(), false),
+            AccountMeta::new_readonly(sysvar::slot_hashes::ID, false),
+        ],
+        data: vec![109, 4],
+    };
+
+    let starting_lamports = context
+        .banks_client
+        .get_account(buyers[0].pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let ticket_lamports = context
+        .banks_client
+        .get_account(tickets[0])
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[refund_ix(0), refund_ix(1)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(buyers[0].pubkey())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        account.unwrap().lamports,
+        starting_lamports + ticket_lamports + TICKET_PRICE
+    );
+
+    let account = context.banks_client.get_account(tickets[0]).await.unwrap();
+
+    assert!(account.is_none());
+}
@@ -0,0 +1,227 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::Transaction,
+};
+
+async fn token_amount(context: &mut ProgramTestContext, account: Pubkey) -> u64 {
+    let account = context.banks_client.get_account(account).await.unwrap();
+    spl_token::state::Account::unpack(&account.unwrap().data)
+        .unwrap()
+        .amount
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn referral_share(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a config with a 1% protocol fee and a treasury paying half of the fees
+    // to referrers.
+
+    let admin = context.payer.pubkey();
+    let (config, config_bump) = Pubkey::find_program_address(&[b"config"], &token_program);
+    let (treasury, treasury_bump) = Pubkey::find_program_address(&[b"treasury"], &token_program);
+
+    let mut data = vec![104, 0, config_bump];
+    data.extend_from_slice(admin.as_ref());
+    data.extend_from_slice(&100u16.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let initialize_config_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let initialize_treasury_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: vec![120, 0, treasury_bump],
+    };
+
+    let mut data = vec![120, 2];
+    data.extend_from_slice(&5_000u16.to_le_bytes());
+
+    let set_referral_share_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(admin, true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            initialize_config_ix,
+            initialize_treasury_ix,
+            set_referral_share_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And a taker referred by a referrer.
+
+    let taker = Keypair::new();
+    let referrer = Pubkey::new_unique();
+
+    let (referral, referral_bump) =
+        Pubkey::find_program_address(&[b"referral", taker.pubkey().as_ref()], &token_program);
+
+    let register_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(taker.pubkey(), true),
+            AccountMeta::new(referral, false),
+            AccountMeta::new_readonly(referrer, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: vec![121, 0, referral_bump],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &taker.pubkey(), 1_000_000_000),
+            register_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &taker],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And an offer of 1000 tokens A for 1 token B.
+
+    let mint_authority = Keypair::new();
+
+    let mint_a = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+    let mint_b = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let maker = context.payer.pubkey();
+
+    let maker_a = account::initialize(&mut context, &mint_a, &maker, &token_program).await;
+    let maker_b = account::initialize(&mut context, &mint_b, &maker, &token_program).await;
+    let taker_a = account::initialize(&mut context, &mint_a, &taker.pubkey(), &token_program).await;
+    let taker_b = account::initialize(&mut context, &mint_b, &taker.pubkey(), &token_program).await;
+    let treasury_vault =
+        account::initialize(&mut context, &mint_a, &treasury, &token_program).await;
+    let referrer_a = account::initialize(&mut context, &mint_a, &referrer, &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint_a,
+        &maker_a,
+        &mint_authority,
+        1_000,
+        &token_program,
+    )
+    .await
+    .unwrap();
+    mint::mint(
+        &mut context,
+        &mint_b,
+        &taker_b,
+        &mint_authority,
+        1,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let escrow =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let create_key = Pubkey::new_unique();
+    let (offer, offer_bump) =
+        Pubkey::find_program_address(&[b"otc_offer", create_key.as_ref()], &token_program);
+
+    let mut data = vec![117, 0, offer_bump];
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&1u64.to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(maker, true),
+            AccountMeta::new(offer, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(mint_a, false),
+            AccountMeta::new(maker_a, false),
+            AccountMeta::new_readonly(maker_b, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the taker fills the offer with the fee and referral accounts.
+
+    let take_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(offer, false),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(taker_a, false),
+            AccountMeta::new(taker_b, false),
+            AccountMeta::new(maker_b, false),
+            AccountMeta::new_readonly(taker.pubkey(), true),
+            AccountMeta::new(maker, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(treasury, false),
+            AccountMeta::new(treasury_vault, false),
+            AccountMeta::new_readonly(referral, false),
+            AccountMeta::new(referrer_a, false),
+        ],
+        data: vec![117, 1],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[take_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &taker],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the protocol fee was split between the treasury and the referrer.
+
+    assert_eq!(token_amount(&mut context, taker_a).await, 990);
+    assert_eq!(token_amount(&mut context, treasury_vault).await, 5);
+    assert_eq!(token_amount(&mut context, referrer_a).await, 5);
+}
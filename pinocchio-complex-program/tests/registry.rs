@@ -0,0 +1,249 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn register_update_transfer_and_close(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a registered name.
+
+    let owner = Keypair::new();
+    let name = "pinocchio.sol";
+    let target = Pubkey::new_unique();
+
+    let (record, bump) =
+        Pubkey::find_program_address(&[b"name", hash(name.as_bytes()).as_ref()], &token_program);
+
+    let mut data = vec![126, 0, bump];
+    data.extend_from_slice(target.as_ref());
+    data.extend_from_slice(name.as_bytes());
+
+    let register_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(owner.pubkey(), true),
+            AccountMeta::new(record, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &owner.pubkey(), 1_000_000_000),
+            register_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context.banks_client.get_account(record).await.unwrap();
+    let account = account.unwrap();
+
+    // discriminator (1) + bump (1) + owner (32) + target (32) + name length (1)
+    assert_eq!(&account.data[2..34], owner.pubkey().as_ref());
+    assert_eq!(&account.data[34..66], target.as_ref());
+    assert_eq!(account.data[66] as usize, name.len());
+    assert_eq!(&account.data[67..67 + name.len()], name.as_bytes());
+
+    // When the owner updates the target and transfers the name.
+
+    let new_target = Pubkey::new_unique();
+    let new_owner = Keypair::new();
+
+    let mut data = vec![126, 1];
+    data.extend_from_slice(new_target.as_ref());
+
+    let update_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(record, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data,
+    };
+
+    let transfer_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(record, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+            AccountMeta::new_readonly(new_owner.pubkey(), false),
+        ],
+        data: vec![126, 2],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_ix, transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the record has the new target and owner.
+
+    let account = context.banks_client.get_account(record).await.unwrap();
+    let account = account.unwrap();
+
+    assert_eq!(&account.data[2..34], new_owner.pubkey().as_ref());
+    assert_eq!(&account.data[34..66], new_target.as_ref());
+
+    // And the previous owner cannot close the record.
+
+    let close_ix = |owner: Pubkey| Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(record, false),
+            AccountMeta::new_readonly(owner, true),
+            AccountMeta::new(owner, false),
+        ],
+        data: vec![126, 3],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix(owner.pubkey())],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(4) // OwnerMismatch
+        )
+    );
+
+    // And the record cannot be closed into itself.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: token_program,
+            accounts: vec![
+                AccountMeta::new(record, false),
+                AccountMeta::new_readonly(new_owner.pubkey(), true),
+                AccountMeta::new(record, false),
+            ],
+            data: vec![126, 3],
+        }],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &new_owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(155) // DuplicateAccount
+        )
+    );
+
+    // When the new owner closes the record.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_ix(new_owner.pubkey())],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &new_owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the record is closed and its rent returned.
+
+    let account = context.banks_client.get_account(record).await.unwrap();
+    assert!(account.is_none());
+
+    let account = context
+        .banks_client
+        .get_account(new_owner.pubkey())
+        .await
+        .unwrap();
+    assert!(account.unwrap().lamports > 0);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_non_canonical_bump(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a name record address derived with a bump seed that is not the
+    // canonical one.
+
+    let owner = Keypair::new();
+    let name = "pinocchio.sol";
+    let name_hash = hash(name.as_bytes());
+
+    let (_, canonical_bump) =
+        Pubkey::find_program_address(&[b"name", name_hash.as_ref()], &token_program);
+
+    let (record, bump) = (0..canonical_bump)
+        .rev()
+        .find_map(|bump| {
+            Pubkey::create_program_address(&[b"name", name_hash.as_ref(), &[bump]], &token_program)
+                .ok()
+                .map(|record| (record, bump))
+        })
+        .unwrap();
+
+    let mut data = vec![126, 0, bump];
+    data.extend_from_slice(Pubkey::new_unique().as_ref());
+    data.extend_from_slice(name.as_bytes());
+
+    let register_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(owner.pubkey(), true),
+            AccountMeta::new(record, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    // When the name is registered at that address.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &owner.pubkey(), 1_000_000_000),
+            register_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the record is not created.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(100) // DerivedAddressMismatch
+        )
+    );
+}
@@ -0,0 +1,170 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn settle_sale(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an item mint with 2 creators receiving a 2.5% royalty each.
+
+    let item_mint_authority = Keypair::new();
+
+    let item_mint = mint::initialize(
+        &mut context,
+        item_mint_authority.pubkey(),
+        None,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let creators = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let (royalty, bump) =
+        Pubkey::find_program_address(&[b"royalty", item_mint.as_ref()], &token_program);
+
+    let mut accounts = vec![
+        AccountMeta::new(context.payer.pubkey(), true),
+        AccountMeta::new(royalty, false),
+        AccountMeta::new_readonly(item_mint, false),
+        AccountMeta::new_readonly(item_mint_authority.pubkey(), true),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    accounts.extend(
+        creators
+            .iter()
+            .map(|creator| AccountMeta::new_readonly(*creator, false)),
+    );
+
+    let mut data = vec![113, 0, bump];
+    data.extend_from_slice(&250u16.to_le_bytes());
+    data.extend_from_slice(&250u16.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts,
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &item_mint_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And a buyer with 1000 payment tokens.
+
+    let payment_mint_authority = Keypair::new();
+
+    let payment_mint = mint::initialize(
+        &mut context,
+        payment_mint_authority.pubkey(),
+        None,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let buyer = Keypair::new();
+
+    let buyer_account =
+        account::initialize(&mut context, &payment_mint, &buyer.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &payment_mint,
+        &buyer_account,
+        &payment_mint_authority,
+        1000,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let seller_account = account::initialize(
+        &mut context,
+        &payment_mint,
+        &Pubkey::new_unique(),
+        &token_program,
+    )
+    .await;
+
+    let mut creator_accounts = Vec::new();
+
+    for creator in creators.iter() {
+        creator_accounts
+            .push(account::initialize(&mut context, &payment_mint, creator, &token_program).await);
+    }
+
+    // When a sale of 1000 tokens is settled.
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(royalty, false),
+        AccountMeta::new(buyer_account, false),
+        AccountMeta::new(seller_account, false),
+        AccountMeta::new_readonly(buyer.pubkey(), true),
+    ];
+    accounts.extend(
+        creator_accounts
+            .iter()
+            .map(|creator_account| AccountMeta::new(*creator_account, false)),
+    );
+
+    let mut data = vec![113, 1];
+    data.extend_from_slice(&1000u64.to_le_bytes());
+
+    let settle_ix = Instruction {
+        program_id: token_program,
+        accounts,
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[settle_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then each creator received its royalty.
+
+    for creator_account in creator_accounts.iter() {
+        let account = context
+            .banks_client
+            .get_account(*creator_account)
+            .await
+            .unwrap();
+        let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+        assert_eq!(account.amount, 25);
+    }
+
+    // And the seller received the remaining amount.
+
+    let account = context
+        .banks_client
+        .get_account(seller_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 950);
+}
@@ -0,0 +1,173 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn delayed_withdraw(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a savings account with a 1 hour withdrawal delay.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+    let owner_account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &owner_account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let (savings, bump) = Pubkey::find_program_address(
+        &[b"savings", owner.pubkey().as_ref(), mint.as_ref()],
+        &token_program,
+    );
+
+    let token_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let mut data = vec![128, 0, bump];
+    data.extend_from_slice(&3_600i64.to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(owner.pubkey(), true),
+            AccountMeta::new(savings, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    // And 100 tokens deposited.
+
+    let mut data = vec![128, 1];
+    data.extend_from_slice(&100u64.to_le_bytes());
+
+    let deposit_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(savings, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new(owner_account, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data,
+    };
+
+    // And a withdrawal of 40 tokens requested.
+
+    let mut data = vec![128, 2];
+    data.extend_from_slice(&40u64.to_le_bytes());
+
+    let request_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(savings, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &owner.pubkey(), 1_000_000_000),
+            create_ix,
+            deposit_ix,
+            request_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the owner executes the withdrawal before the delay elapses.
+
+    let execute_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(savings, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new(owner_account, false),
+        ],
+        data: vec![128, 3],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the withdrawal fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(136) // WithdrawalNotReady
+        )
+    );
+
+    // When the owner executes the withdrawal after the delay.
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += 3_601;
+    context.set_sysvar(&clock);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[execute_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the owner receives the tokens.
+
+    let account = context
+        .banks_client
+        .get_account(owner_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 40);
+
+    let account = context.banks_client.get_account(token_vault).await.unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 60);
+}
@@ -95,3 +95,24 @@ pub async fn freeze(
     );
     context.banks_client.process_transaction(tx).await.unwrap();
 }
+
+pub async fn create(context: &mut ProgramTestContext, size: usize, program_id: &Pubkey) -> Pubkey {
+    let account = Keypair::new();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[system_instruction::create_account(
+            &context.payer.pubkey(),
+            &account.pubkey(),
+            rent.minimum_balance(size),
+            size as u64,
+            program_id,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    account.pubkey()
+}
@@ -0,0 +1,80 @@
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    account::Account,
+    bpf_loader_upgradeable,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    rent::Rent,
+    signer::Signer,
+    system_program,
+    transaction::Transaction,
+};
+
+/// Sets the upgrade authority of the program, stored in its program data account.
+///
+/// The test runtime does not deploy the program with the upgradeable loader, so the
+/// program data account is created directly.
+pub fn set_upgrade_authority(
+    context: &mut ProgramTestContext,
+    authority: &Pubkey,
+    program_id: &Pubkey,
+) -> Pubkey {
+    let (program_data, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::ID);
+
+    let mut data = vec![3, 0, 0, 0];
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(authority.as_ref());
+
+    context.set_account(
+        &program_data,
+        &Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: bpf_loader_upgradeable::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    program_data
+}
+
+/// Initializes the program config with the payer as admin and fee recipient.
+pub async fn initialize(
+    context: &mut ProgramTestContext,
+    fee_bps: u16,
+    program_id: &Pubkey,
+) -> Pubkey {
+    let admin = context.payer.pubkey();
+    let program_data = set_upgrade_authority(context, &admin, program_id);
+    let (config, bump) = Pubkey::find_program_address(&[b"config"], program_id);
+
+    let mut data = vec![104, 0, bump];
+    data.extend_from_slice(admin.as_ref());
+    data.extend_from_slice(&fee_bps.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(program_data, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    config
+}
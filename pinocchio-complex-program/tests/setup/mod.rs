@@ -3,6 +3,12 @@ use solana_sdk::pubkey::Pubkey;
 #[allow(dead_code)]
 pub mod account;
 #[allow(dead_code)]
+pub mod config;
+#[allow(dead_code)]
 pub mod mint;
+#[allow(dead_code)]
+pub mod query;
+#[allow(dead_code)]
+pub mod treasury;
 
 pub const TOKEN_PROGRAM_ID: Pubkey = Pubkey::new_from_array(token_interface::program::ID);
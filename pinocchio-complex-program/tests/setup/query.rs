@@ -0,0 +1,53 @@
+use solana_sdk::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{get_return_data, invoke, set_return_data},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Version of the return data envelope.
+const VERSION: u8 = 1;
+
+/// Invokes a query instruction of the program of the first account with the
+/// remaining accounts and the instruction data, and returns the payload of its
+/// return data envelope.
+///
+/// Fails when the return data was not set by the invoked program or has an
+/// unknown version.
+pub fn process_query(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let (program_info, instruction_accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let instruction = Instruction {
+        program_id: *program_info.key,
+        accounts: instruction_accounts
+            .iter()
+            .map(|account_info| AccountMeta {
+                pubkey: *account_info.key,
+                is_signer: account_info.is_signer,
+                is_writable: account_info.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+
+    invoke(&instruction, accounts)?;
+
+    let (program_id, return_data) =
+        get_return_data().ok_or(ProgramError::InvalidInstructionData)?;
+
+    if program_id != *program_info.key {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    match return_data.split_first() {
+        Some((&VERSION, payload)) => {
+            set_return_data(payload);
+            Ok(())
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
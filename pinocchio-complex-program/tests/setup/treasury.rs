@@ -0,0 +1,33 @@
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signer::Signer,
+    system_program,
+    transaction::Transaction,
+};
+
+/// Initializes the protocol fee treasury.
+pub async fn initialize(context: &mut ProgramTestContext, program_id: &Pubkey) -> Pubkey {
+    let (treasury, bump) = Pubkey::find_program_address(&[b"treasury"], program_id);
+
+    let initialize_ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: vec![120, 0, bump],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    treasury
+}
@@ -0,0 +1,206 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program, sysvar,
+    transaction::{Transaction, TransactionError},
+};
+
+const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+/// Creates an Ed25519 program instruction verifying the signature of `message` by
+/// `signer`.
+fn ed25519_ix(signer: &Keypair, message: &[u8]) -> Instruction {
+    let signature = signer.sign_message(message);
+
+    // Offsets are relative to the start of the instruction data: 2 bytes of header
+    // followed by 14 bytes of offsets.
+    let pubkey_offset = 16u16;
+    let signature_offset = pubkey_offset + 32;
+    let message_offset = signature_offset + 64;
+
+    let mut data = vec![1, 0];
+    data.extend_from_slice(&signature_offset.to_le_bytes());
+    data.extend_from_slice(&u16::MAX.to_le_bytes());
+    data.extend_from_slice(&pubkey_offset.to_le_bytes());
+    data.extend_from_slice(&u16::MAX.to_le_bytes());
+    data.extend_from_slice(&message_offset.to_le_bytes());
+    data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+    data.extend_from_slice(&u16::MAX.to_le_bytes());
+    data.extend_from_slice(signer.pubkey().as_ref());
+    data.extend_from_slice(signature.as_ref());
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ED25519_PROGRAM_ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn release(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a depositor with 100 tokens.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let depositor = Keypair::new();
+    let depositor_account =
+        account::initialize(&mut context, &mint, &depositor.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &depositor_account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And an escrow of 100 tokens released by a signer.
+
+    let signer = Keypair::new();
+
+    let create_key = Pubkey::new_unique();
+    let (escrow, bump) =
+        Pubkey::find_program_address(&[b"signed_escrow", create_key.as_ref()], &token_program);
+
+    let token_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let mut data = vec![141, 0, bump];
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(signer.pubkey().as_ref());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(depositor.pubkey(), true),
+            AccountMeta::new(escrow, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(depositor_account, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let payer = context.payer.pubkey();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&payer, &depositor.pubkey(), 1_000_000_000),
+            create_ix,
+        ],
+        Some(&payer),
+        &[&context.payer, &depositor],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    let release_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(escrow, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new(depositor.pubkey(), false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        ],
+        data: vec![141, 1],
+    };
+
+    let mut message = escrow.to_bytes().to_vec();
+    message.extend_from_slice(destination.as_ref());
+
+    // When the release is signed by a different key.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ed25519_ix(&Keypair::new(), &message), release_ix.clone()],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the release fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(145) // SignatureNotVerified
+        )
+    );
+
+    // When the signer signs the release to another account.
+
+    let mut other_message = escrow.to_bytes().to_vec();
+    other_message.extend_from_slice(Pubkey::new_unique().as_ref());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ed25519_ix(&signer, &other_message), release_ix.clone()],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the release fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(145) // SignatureNotVerified
+        )
+    );
+
+    // When the signer signs the release to the destination account.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ed25519_ix(&signer, &message), release_ix],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the destination receives the escrow funds.
+
+    let account = context.banks_client.get_account(destination).await.unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 100);
+
+    // And the escrow accounts are closed.
+
+    let account = context.banks_client.get_account(escrow).await.unwrap();
+    assert!(account.is_none());
+
+    let account = context.banks_client.get_account(token_vault).await.unwrap();
+    assert!(account.is_none());
+}
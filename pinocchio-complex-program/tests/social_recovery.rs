@@ -0,0 +1,118 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn recover(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a recovery account with 3 guardians and a threshold of 2.
+
+    let guardians = [Keypair::new(), Keypair::new(), Keypair::new()];
+
+    let create_key = Pubkey::new_unique();
+    let (recovery, bump) =
+        Pubkey::find_program_address(&[b"recovery", create_key.as_ref()], &token_program);
+
+    let mut accounts = vec![
+        AccountMeta::new(context.payer.pubkey(), true),
+        AccountMeta::new(recovery, false),
+        AccountMeta::new_readonly(create_key, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    accounts.extend(
+        guardians
+            .iter()
+            .map(|guardian| AccountMeta::new_readonly(guardian.pubkey(), false)),
+    );
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts,
+        data: vec![130, 0, bump, 2],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let recovery_ix = |discriminator: u8, guardian: Pubkey, new_authority: Pubkey| {
+        let mut data = vec![130, discriminator];
+        data.extend_from_slice(new_authority.as_ref());
+
+        Instruction {
+            program_id: token_program,
+            accounts: vec![
+                AccountMeta::new(recovery, false),
+                AccountMeta::new_readonly(guardian, true),
+            ],
+            data,
+        }
+    };
+
+    let new_authority = Pubkey::new_unique();
+
+    // When someone other than a guardian proposes a recovery.
+
+    let stranger = Keypair::new();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[recovery_ix(1, stranger.pubkey(), new_authority)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stranger],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the proposal fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(4) // OwnerMismatch
+        )
+    );
+
+    // When a guardian proposes a recovery and another guardian approves it.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            recovery_ix(1, guardians[0].pubkey(), new_authority),
+            recovery_ix(2, guardians[2].pubkey(), new_authority),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &guardians[0], &guardians[2]],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the authority is rotated.
+
+    let account = context.banks_client.get_account(recovery).await.unwrap();
+    let account = account.unwrap();
+
+    // discriminator (1) + bump (1) + threshold (1) + guardians length (1) +
+    // create key (32)
+    assert_eq!(&account.data[36..68], new_authority.as_ref());
+    // And the proposal is cleared.
+    assert_eq!(&account.data[68..100], Pubkey::default().as_ref());
+}
@@ -0,0 +1,172 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn issue_and_revoke(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an issuer which is the mint and freeze authority of the credentials.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let (issuer, bump) =
+        Pubkey::find_program_address(&[b"soulbound_issuer", mint.as_ref()], &token_program);
+
+    let mut set_authority_ixs = Vec::new();
+
+    for authority_type in [
+        spl_token::instruction::AuthorityType::MintTokens,
+        spl_token::instruction::AuthorityType::FreezeAccount,
+    ] {
+        let mut set_authority_ix = spl_token::instruction::set_authority(
+            &spl_token::ID,
+            &mint,
+            Some(&issuer),
+            authority_type,
+            &mint_authority.pubkey(),
+            &[],
+        )
+        .unwrap();
+        set_authority_ix.program_id = token_program;
+
+        set_authority_ixs.push(set_authority_ix);
+    }
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(issuer, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: vec![123, 0, bump],
+    };
+
+    set_authority_ixs.push(initialize_ix);
+
+    let tx = Transaction::new_signed_with_payer(
+        &set_authority_ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When a credential is issued to a recipient.
+
+    let recipient = Keypair::new();
+    let credential =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let issue_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(issuer, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new(credential, false),
+            AccountMeta::new_readonly(recipient.pubkey(), false),
+            AccountMeta::new_readonly(context.payer.pubkey(), true),
+        ],
+        data: vec![123, 1],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[issue_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the recipient holds a frozen credential.
+
+    let account = context.banks_client.get_account(credential).await.unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.owner, recipient.pubkey());
+    assert_eq!(account.amount, 1);
+    assert!(account.is_frozen());
+
+    // And the recipient cannot transfer it.
+
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    let mut transfer_ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &credential,
+        &destination,
+        &recipient.pubkey(),
+        &[],
+        1,
+    )
+    .unwrap();
+    transfer_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &recipient.pubkey(), 1_000_000),
+            transfer_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &recipient],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(17) // AccountFrozen
+        )
+    );
+
+    // And the issuer can revoke it.
+
+    let revoke_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(issuer, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new(credential, false),
+            AccountMeta::new_readonly(context.payer.pubkey(), true),
+        ],
+        data: vec![123, 2],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[revoke_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context.banks_client.get_account(credential).await.unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 0);
+    assert!(!account.is_frozen());
+}
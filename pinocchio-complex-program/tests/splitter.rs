@@ -0,0 +1,139 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, config, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn distribute(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a config without protocol fee.
+
+    let config = config::initialize(&mut context, 0, &token_program).await;
+
+    // And a splitter with 3 recipients of equal weight.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let mut recipients = Vec::new();
+
+    for _ in 0..3 {
+        recipients.push(
+            account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await,
+        );
+    }
+
+    let token_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let create_key = Pubkey::new_unique();
+    let (splitter, bump) =
+        Pubkey::find_program_address(&[b"splitter", create_key.as_ref()], &token_program);
+
+    let mut accounts = vec![
+        AccountMeta::new(context.payer.pubkey(), true),
+        AccountMeta::new(splitter, false),
+        AccountMeta::new_readonly(Pubkey::new_unique(), false),
+        AccountMeta::new_readonly(create_key, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(token_vault, false),
+        AccountMeta::new_readonly(mint, false),
+    ];
+    accounts.extend(
+        recipients
+            .iter()
+            .map(|recipient| AccountMeta::new_readonly(*recipient, false)),
+    );
+
+    let mut data = vec![112, 0, bump];
+    for _ in 0..3 {
+        data.extend_from_slice(&1u16.to_le_bytes());
+    }
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts,
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And 100 tokens accumulated in the token vault.
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &token_vault,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When the balance is distributed.
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(splitter, false),
+        AccountMeta::new(token_vault, false),
+    ];
+    accounts.extend(
+        recipients
+            .iter()
+            .map(|recipient| AccountMeta::new(*recipient, false)),
+    );
+    accounts.push(AccountMeta::new_readonly(config, false));
+
+    let distribute_ix = Instruction {
+        program_id: token_program,
+        accounts,
+        data: vec![112, 2],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[distribute_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then each recipient received its share and the first one the dust.
+
+    for (recipient, expected) in recipients.iter().zip([34, 33, 33]) {
+        let account = context.banks_client.get_account(*recipient).await.unwrap();
+        let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+        assert_eq!(account.amount, expected);
+    }
+
+    // And the token vault is empty.
+
+    let account = context.banks_client.get_account(token_vault).await.unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 0);
+}
@@ -0,0 +1,261 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    stake::{self, state::StakeStateV2},
+    system_instruction, sysvar,
+    transaction::Transaction,
+};
+
+fn initialize_ix(
+    token_program: &Pubkey,
+    stake_account: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    let mut data = vec![137, 0];
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(&0i64.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.extend_from_slice(Pubkey::default().as_ref());
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(*stake_account, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(stake::program::ID, false),
+        ],
+        data,
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn initialize_and_withdraw(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a stake account owned by the Stake program holding 1 SOL above rent.
+
+    let stake_account = Keypair::new();
+    let authority = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let rent_exempt_reserve = rent.minimum_balance(StakeStateV2::size_of());
+
+    let create_account_ix = system_instruction::create_account(
+        &context.payer.pubkey(),
+        &stake_account.pubkey(),
+        rent_exempt_reserve + 1_000_000_000,
+        StakeStateV2::size_of() as u64,
+        &stake::program::ID,
+    );
+
+    // When the stake account is initialized through the token program.
+
+    let initialize_ix = initialize_ix(&token_program, &stake_account.pubkey(), &authority.pubkey());
+
+    // And lamports are withdrawn by the withdraw authority.
+
+    let recipient = Pubkey::new_unique();
+
+    let mut data = vec![137, 3];
+    data.extend_from_slice(&500_000_000u64.to_le_bytes());
+
+    let withdraw_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(stake_account.pubkey(), false),
+            AccountMeta::new(recipient, false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(sysvar::stake_history::ID, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(stake::program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_account_ix, initialize_ix, withdraw_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_account, &authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the stake account is initialized with the authorities.
+
+    let account = context
+        .banks_client
+        .get_account(stake_account.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let state = account.deserialize_data::<StakeStateV2>().unwrap();
+
+    assert_eq!(
+        state.authorized().map(|authorized| authorized.withdrawer),
+        Some(authority.pubkey())
+    );
+    assert_eq!(account.lamports, rent_exempt_reserve + 500_000_000);
+
+    // And the recipient received the lamports.
+
+    let account = context.banks_client.get_account(recipient).await.unwrap();
+
+    assert_eq!(account.unwrap().lamports, 500_000_000);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn split_merge_and_authorize(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an initialized stake account holding 1 SOL above rent.
+
+    let stake_account = Keypair::new();
+    let split_stake_account = Keypair::new();
+    let authority = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let rent_exempt_reserve = rent.minimum_balance(StakeStateV2::size_of());
+
+    let create_account_ix = system_instruction::create_account(
+        &context.payer.pubkey(),
+        &stake_account.pubkey(),
+        rent_exempt_reserve + 1_000_000_000,
+        StakeStateV2::size_of() as u64,
+        &stake::program::ID,
+    );
+    let initialize_ix = initialize_ix(&token_program, &stake_account.pubkey(), &authority.pubkey());
+
+    // And an uninitialized split stake account funded for rent.
+
+    let create_split_account_ix = system_instruction::create_account(
+        &context.payer.pubkey(),
+        &split_stake_account.pubkey(),
+        rent_exempt_reserve,
+        StakeStateV2::size_of() as u64,
+        &stake::program::ID,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_account_ix, initialize_ix, create_split_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &stake_account, &split_stake_account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When half of the stake is split into the split stake account.
+
+    let mut data = vec![137, 4];
+    data.extend_from_slice(&500_000_000u64.to_le_bytes());
+
+    let split_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(stake_account.pubkey(), false),
+            AccountMeta::new(split_stake_account.pubkey(), false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(stake::program::ID, false),
+        ],
+        data,
+    };
+
+    // And merged back into the stake account.
+
+    let merge_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(stake_account.pubkey(), false),
+            AccountMeta::new(split_stake_account.pubkey(), false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(sysvar::stake_history::ID, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(stake::program::ID, false),
+        ],
+        data: vec![137, 5],
+    };
+
+    // And the staker authority is changed.
+
+    let new_staker = Pubkey::new_unique();
+
+    let mut data = vec![137, 6];
+    data.extend_from_slice(new_staker.as_ref());
+    data.push(0);
+
+    let authorize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(stake_account.pubkey(), false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new_readonly(stake::program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[split_ix, merge_ix, authorize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the split stake account is closed by the merge.
+
+    let account = context
+        .banks_client
+        .get_account(split_stake_account.pubkey())
+        .await
+        .unwrap();
+
+    assert!(account.is_none());
+
+    // And the stake state returns the new staker and the withdrawer.
+
+    let get_stake_state_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![AccountMeta::new_readonly(stake_account.pubkey(), false)],
+        data: vec![137, 7],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[get_stake_state_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let return_data = context
+        .banks_client
+        .simulate_transaction(tx)
+        .await
+        .unwrap()
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap()
+        .data;
+
+    let (version, payload) = return_data.split_first().unwrap();
+
+    assert_eq!(*version, 1);
+    assert_eq!(payload.len(), 185);
+    assert_eq!(payload[0], 1);
+    assert_eq!(&payload[9..41], new_staker.as_ref());
+    assert_eq!(&payload[41..73], authority.pubkey().as_ref());
+    assert!(payload[121..].iter().all(|byte| *byte == 0));
+}
@@ -0,0 +1,344 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn stake_and_claim_rewards(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a stake pool distributing 10 reward tokens per slot.
+
+    let mint_authority = Keypair::new();
+
+    let stake_mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+    let reward_mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let stake_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+    let reward_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let (stake_pool, bump) = Pubkey::find_program_address(
+        &[
+            b"stake_pool",
+            context.payer.pubkey().as_ref(),
+            stake_mint.as_ref(),
+            reward_mint.as_ref(),
+        ],
+        &token_program,
+    );
+
+    let mut data = vec![101, 0, bump];
+    data.extend_from_slice(&10u64.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(stake_pool, false),
+            AccountMeta::new_readonly(stake_mint, false),
+            AccountMeta::new_readonly(reward_mint, false),
+            AccountMeta::new(stake_vault, false),
+            AccountMeta::new(reward_vault, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    mint::mint(
+        &mut context,
+        &reward_mint,
+        &reward_vault,
+        &mint_authority,
+        1_000_000,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a staker with 1_000 tokens.
+
+    let owner = Keypair::new();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[solana_sdk::system_instruction::transfer(
+            &context.payer.pubkey(),
+            &owner.pubkey(),
+            1_000_000_000,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let stake_account =
+        account::initialize(&mut context, &stake_mint, &owner.pubkey(), &token_program).await;
+    let reward_account =
+        account::initialize(&mut context, &reward_mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &stake_mint,
+        &stake_account,
+        &mint_authority,
+        1_000,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When the tokens are staked.
+
+    let (stake_record, bump) = Pubkey::find_program_address(
+        &[
+            b"stake_record",
+            stake_pool.as_ref(),
+            owner.pubkey().as_ref(),
+        ],
+        &token_program,
+    );
+
+    let mut data = vec![101, 1];
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.push(bump);
+
+    let stake_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(stake_pool, false),
+            AccountMeta::new(stake_record, false),
+            AccountMeta::new(stake_account, false),
+            AccountMeta::new(stake_vault, false),
+            AccountMeta::new(owner.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[stake_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And the rewards are claimed after 100 slots.
+
+    let slot = context.banks_client.get_root_slot().await.unwrap();
+    context.warp_to_slot(slot + 100).unwrap();
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let claim_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(stake_pool, false),
+            AccountMeta::new(stake_record, false),
+            AccountMeta::new(reward_vault, false),
+            AccountMeta::new(reward_account, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data: vec![101, 3],
+    };
+
+    let mut data = vec![101, 2];
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+
+    let unstake_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(stake_pool, false),
+            AccountMeta::new(stake_record, false),
+            AccountMeta::new(stake_vault, false),
+            AccountMeta::new(stake_account, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix, unstake_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the staker received rewards for at least 100 slots.
+
+    let account = context
+        .banks_client
+        .get_account(reward_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert!(account.amount >= 1_000);
+
+    // And the staked tokens were returned.
+
+    let account = context
+        .banks_client
+        .get_account(stake_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 1_000);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn set_reward_rate(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a stake pool distributing 10 reward tokens per slot.
+
+    let mint_authority = Keypair::new();
+
+    let stake_mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+    let reward_mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let stake_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+    let reward_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let admin = context.payer.pubkey();
+
+    let (stake_pool, bump) = Pubkey::find_program_address(
+        &[
+            b"stake_pool",
+            admin.as_ref(),
+            stake_mint.as_ref(),
+            reward_mint.as_ref(),
+        ],
+        &token_program,
+    );
+
+    let mut data = vec![101, 0, bump];
+    data.extend_from_slice(&10u64.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(stake_pool, false),
+            AccountMeta::new_readonly(stake_mint, false),
+            AccountMeta::new_readonly(reward_mint, false),
+            AccountMeta::new(stake_vault, false),
+            AccountMeta::new(reward_vault, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When another signer updates the reward rate.
+
+    let other = Keypair::new();
+
+    let mut data = vec![101, 4];
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+
+    let set_reward_rate_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(stake_pool, false),
+            AccountMeta::new_readonly(other.pubkey(), true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_reward_rate_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &other],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the signer is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(4) // OwnerMismatch
+        )
+    );
+
+    // When the admin updates the reward rate.
+
+    let mut data = vec![101, 4];
+    data.extend_from_slice(&20u64.to_le_bytes());
+
+    let set_reward_rate_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(stake_pool, false),
+            AccountMeta::new_readonly(admin, true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_reward_rate_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the stake pool stores the new reward rate.
+
+    let account = context.banks_client.get_account(stake_pool).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+
+    assert_eq!(&account.data[2..34], admin.as_ref());
+    assert_eq!(&account.data[162..170], &20u64.to_le_bytes());
+}
@@ -0,0 +1,336 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn charge(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a subscriber token account with 1000 tokens.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let subscriber = Keypair::new();
+
+    let subscriber_account =
+        account::initialize(&mut context, &mint, &subscriber.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &subscriber_account,
+        &mint_authority,
+        1000,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let merchant_account =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // And a subscription of 100 tokens per day approved as delegate for 3 payments.
+
+    let (subscription, bump) = Pubkey::find_program_address(
+        &[
+            b"subscription",
+            subscriber_account.as_ref(),
+            merchant_account.as_ref(),
+        ],
+        &token_program,
+    );
+
+    let mut data = vec![111, 0, bump];
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(&86_400i64.to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(subscriber.pubkey(), true),
+            AccountMeta::new(subscription, false),
+            AccountMeta::new_readonly(subscriber_account, false),
+            AccountMeta::new_readonly(merchant_account, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(
+                &context.payer.pubkey(),
+                &subscriber.pubkey(),
+                1_000_000_000,
+            ),
+            create_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &subscriber],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    account::approve(
+        &mut context,
+        &subscriber_account,
+        &subscription,
+        &subscriber,
+        300,
+        &token_program,
+    )
+    .await;
+
+    // When the subscription is charged.
+
+    let charge_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(subscription, false),
+            AccountMeta::new(subscriber_account, false),
+            AccountMeta::new(merchant_account, false),
+        ],
+        data: vec![111, 1],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[charge_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then it cannot be charged again within the same period.
+
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[charge_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(125))
+    );
+
+    // And it can be charged again in the next period.
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += 86_400;
+    context.set_sysvar(&clock);
+
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[charge_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(merchant_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 200);
+
+    // And the delegated amount was spent.
+
+    let account = context
+        .banks_client
+        .get_account(subscriber_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 800);
+    assert_eq!(account.delegated_amount, 100);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn cancel_checks_accounts(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a subscription.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let subscriber = Keypair::new();
+
+    let subscriber_account =
+        account::initialize(&mut context, &mint, &subscriber.pubkey(), &token_program).await;
+
+    let merchant_account =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    let (subscription, bump) = Pubkey::find_program_address(
+        &[
+            b"subscription",
+            subscriber_account.as_ref(),
+            merchant_account.as_ref(),
+        ],
+        &token_program,
+    );
+
+    let mut data = vec![111, 0, bump];
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(&86_400i64.to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(subscriber.pubkey(), true),
+            AccountMeta::new(subscription, false),
+            AccountMeta::new_readonly(subscriber_account, false),
+            AccountMeta::new_readonly(merchant_account, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(
+                &context.payer.pubkey(),
+                &subscriber.pubkey(),
+                1_000_000_000,
+            ),
+            create_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &subscriber],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let cancel_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(subscription, false),
+            AccountMeta::new(subscriber.pubkey(), true),
+        ],
+        data: vec![111, 2],
+    };
+
+    // When the subscription is cancelled without the subscriber account.
+
+    let mut missing_account_ix = cancel_ix.clone();
+    missing_account_ix.accounts.truncate(1);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[missing_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the cancel fails with not enough accounts.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+
+    // When the subscription is cancelled without the subscriber signature.
+
+    let mut unsigned_ix = cancel_ix.clone();
+    unsigned_ix.accounts[1].is_signer = false;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[unsigned_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the cancel fails with a missing signer.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(166) // MissingSigner
+        )
+    );
+
+    // When the subscription is cancelled with a read-only subscription account.
+
+    let mut read_only_ix = cancel_ix.clone();
+    read_only_ix.accounts[0].is_writable = false;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[read_only_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &subscriber],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the cancel fails with a read-only account.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(165) // NotWritable
+        )
+    );
+
+    // When the subscription is cancelled with the expected accounts.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[cancel_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &subscriber],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the subscription account is closed.
+
+    let account = context
+        .banks_client
+        .get_account(subscription)
+        .await
+        .unwrap();
+
+    assert!(account.is_none());
+}
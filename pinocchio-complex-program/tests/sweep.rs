@@ -0,0 +1,275 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+async fn token_amount(context: &mut ProgramTestContext, account: Pubkey) -> u64 {
+    let account = context.banks_client.get_account(account).await.unwrap();
+    spl_token::state::Account::unpack(&account.unwrap().data)
+        .unwrap()
+        .amount
+}
+
+fn sweep_dust_ix(
+    token_program: &Pubkey,
+    destination: &Pubkey,
+    owner: &Pubkey,
+    sources: &[Pubkey],
+    close: bool,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*destination, false),
+        AccountMeta::new(*owner, true),
+    ];
+    accounts.extend(
+        sources
+            .iter()
+            .map(|source| AccountMeta::new(*source, false)),
+    );
+
+    Instruction {
+        program_id: *token_program,
+        accounts,
+        data: vec![134, 0, close as u8],
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn sweep_dust(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given 3 token accounts of the same owner holding small balances.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+    let mut sources = Vec::new();
+
+    for amount in 1..=3 {
+        let source =
+            account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+        mint::mint(
+            &mut context,
+            &mint,
+            &source,
+            &mint_authority,
+            amount,
+            &token_program,
+        )
+        .await
+        .unwrap();
+        sources.push(source);
+    }
+
+    // And a destination account.
+
+    let destination =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    // When the owner sweeps the accounts without closing them.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_dust_ix(
+            &token_program,
+            &destination,
+            &owner.pubkey(),
+            &sources[..2],
+            false,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the balances are moved to the destination account.
+
+    assert_eq!(token_amount(&mut context, destination).await, 3);
+    assert_eq!(token_amount(&mut context, sources[0]).await, 0);
+    assert_eq!(token_amount(&mut context, sources[1]).await, 0);
+
+    // And sweeping with the close flag closes the emptied accounts.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_dust_ix(
+            &token_program,
+            &destination,
+            &owner.pubkey(),
+            &sources,
+            true,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(token_amount(&mut context, destination).await, 6);
+
+    for source in &sources {
+        let account = context.banks_client.get_account(*source).await.unwrap();
+        assert!(account.is_none());
+    }
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let owner_account = context
+        .banks_client
+        .get_account(owner.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        owner_account.lamports,
+        rent.minimum_balance(spl_token::state::Account::LEN) * 3
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn sweep_dust_with_wrong_owner(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account of the owner and a token account of another owner.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+    let source = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+    let other_source =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    for account in [source, other_source] {
+        mint::mint(
+            &mut context,
+            &mint,
+            &account,
+            &mint_authority,
+            10,
+            &token_program,
+        )
+        .await
+        .unwrap();
+    }
+
+    let destination =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    // When the owner sweeps both accounts.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_dust_ix(
+            &token_program,
+            &destination,
+            &owner.pubkey(),
+            &[source, other_source],
+            true,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the sweep fails and no balance is moved.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(4) // OwnerMismatch
+        )
+    );
+
+    assert_eq!(token_amount(&mut context, source).await, 10);
+    assert_eq!(token_amount(&mut context, destination).await, 0);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn sweep_dust_to_foreign_account(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account of the owner.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+    let source = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+    mint::mint(
+        &mut context,
+        &mint,
+        &source,
+        &mint_authority,
+        10,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a destination account with token account data owned by another program.
+
+    let destination =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+    let mut foreign_account = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap();
+    foreign_account.owner = Pubkey::new_unique();
+
+    let foreign_destination = Pubkey::new_unique();
+    context.set_account(&foreign_destination, &foreign_account.into());
+
+    // When the owner sweeps the source account to the foreign account.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sweep_dust_ix(
+            &token_program,
+            &foreign_destination,
+            &owner.pubkey(),
+            &[source],
+            false,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the sweep fails before moving any balance.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::IncorrectProgramId)
+    );
+
+    assert_eq!(token_amount(&mut context, source).await, 10);
+}
@@ -0,0 +1,293 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+/// Wraps a System program instruction into a system proxy instruction.
+fn proxy_ix(token_program: &Pubkey, instruction: Instruction) -> Instruction {
+    let mut data = vec![151];
+    data.extend_from_slice(&instruction.data);
+
+    let mut accounts = vec![AccountMeta::new_readonly(system_program::ID, false)];
+    accounts.extend(instruction.accounts);
+
+    Instruction {
+        program_id: *token_program,
+        accounts,
+        data,
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn forward_system_instructions(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a recipient and accounts derived from the payer with a seed.
+
+    let recipient = Pubkey::new_unique();
+    let seed = "proxy";
+    let derived = Pubkey::create_with_seed(&context.payer.pubkey(), seed, &token_program).unwrap();
+    let allocate_seed = "proxy_allocate";
+    let allocated =
+        Pubkey::create_with_seed(&context.payer.pubkey(), allocate_seed, &token_program).unwrap();
+
+    // When System program instructions built with the SDK builders are forwarded.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            proxy_ix(
+                &token_program,
+                system_instruction::transfer(&context.payer.pubkey(), &recipient, 1_000_000),
+            ),
+            proxy_ix(
+                &token_program,
+                system_instruction::create_account_with_seed(
+                    &context.payer.pubkey(),
+                    &derived,
+                    &context.payer.pubkey(),
+                    seed,
+                    1_000_000,
+                    8,
+                    &token_program,
+                ),
+            ),
+            system_instruction::transfer(&context.payer.pubkey(), &allocated, 1_000_000),
+            proxy_ix(
+                &token_program,
+                system_instruction::allocate_with_seed(
+                    &allocated,
+                    &context.payer.pubkey(),
+                    allocate_seed,
+                    8,
+                    &token_program,
+                ),
+            ),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the instructions are executed by the System program.
+
+    let recipient_account = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(recipient_account.lamports, 1_000_000);
+
+    let derived_account = context
+        .banks_client
+        .get_account(derived)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(derived_account.owner, token_program);
+    assert_eq!(derived_account.data.len(), 8);
+
+    let allocated_account = context
+        .banks_client
+        .get_account(allocated)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(allocated_account.owner, token_program);
+    assert_eq!(allocated_account.data.len(), 8);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_unsupported_instructions(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a System program instruction not supported by the proxy.
+
+    let nonce = Keypair::new();
+    let instruction =
+        system_instruction::advance_nonce_account(&nonce.pubkey(), &context.payer.pubkey());
+
+    // When it is forwarded.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[proxy_ix(&token_program, instruction)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the instruction data is rejected.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_invalid_seeds(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    let create_with_seed_ix = |seed: &str| {
+        system_instruction::create_account_with_seed(
+            &context.payer.pubkey(),
+            &Pubkey::new_unique(),
+            &context.payer.pubkey(),
+            seed,
+            1_000_000,
+            8,
+            &token_program,
+        )
+    };
+
+    // Given a seed longer than the maximum seed length.
+
+    let long_seed_ix = create_with_seed_ix(&"a".repeat(33));
+
+    // And a seed that is not valid UTF-8.
+
+    let mut invalid_seed_ix = create_with_seed_ix("proxy");
+    // instruction (4) + base (32) + seed length (8)
+    invalid_seed_ix.data[44] = 0xff;
+
+    // When they are forwarded.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[proxy_ix(&token_program, long_seed_ix)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let long_seed_error = context.banks_client.process_transaction(tx).await.err();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[proxy_ix(&token_program, invalid_seed_ix)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let invalid_seed_error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then they are rejected before reaching the System program.
+
+    assert_matches!(
+        long_seed_error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::MaxSeedLengthExceeded)
+    );
+    assert_matches!(
+        invalid_seed_error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(160) // InvalidSeedEncoding
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_allocate_without_rent_exemption(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an account holding fewer lamports than the rent exemption of the
+    // allocated space.
+
+    let account = Keypair::new();
+
+    // When the allocation is forwarded.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &account.pubkey(), 1_000_000),
+            proxy_ix(
+                &token_program,
+                system_instruction::allocate(&account.pubkey(), 1_000),
+            ),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then it is rejected before reaching the System program.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(175) // NeedsRentExemption
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn reject_allocate_with_seed_without_rent_exemption(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an account derived from the payer with a seed, holding fewer lamports
+    // than the rent exemption of the allocated space.
+
+    let seed = "proxy";
+    let derived = Pubkey::create_with_seed(&context.payer.pubkey(), seed, &token_program).unwrap();
+
+    // When the allocation is forwarded.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &derived, 1_000_000),
+            proxy_ix(
+                &token_program,
+                system_instruction::allocate_with_seed(
+                    &derived,
+                    &context.payer.pubkey(),
+                    seed,
+                    1_000,
+                    &token_program,
+                ),
+            ),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then it is rejected before reaching the System program.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(175) // NeedsRentExemption
+        )
+    );
+}
@@ -0,0 +1,210 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn mint_validate_and_punch(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a ticket mint without decimals.
+
+    let organizer = Keypair::new();
+    let mint = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut initialize_mint_ix = spl_token::instruction::initialize_mint(
+        &spl_token::ID,
+        &mint.pubkey(),
+        &organizer.pubkey(),
+        None,
+        0,
+    )
+    .unwrap();
+    initialize_mint_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &mint.pubkey(),
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &token_program,
+            ),
+            system_instruction::transfer(
+                &context.payer.pubkey(),
+                &organizer.pubkey(),
+                1_000_000_000,
+            ),
+            initialize_mint_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And a holder token account.
+
+    let holder = Keypair::new();
+    let holder_account = account::initialize(
+        &mut context,
+        &mint.pubkey(),
+        &holder.pubkey(),
+        &token_program,
+    )
+    .await;
+
+    // When the organizer mints a ticket.
+
+    let (ticket, bump) =
+        Pubkey::find_program_address(&[b"ticket", mint.pubkey().as_ref()], &token_program);
+
+    let mut data = vec![124, 0, bump];
+    data.extend_from_slice(&7u64.to_le_bytes());
+    data.extend_from_slice(&42u32.to_le_bytes());
+
+    let mint_ticket_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(organizer.pubkey(), true),
+            AccountMeta::new(ticket, false),
+            AccountMeta::new(mint.pubkey(), false),
+            AccountMeta::new(holder_account, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ticket_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &organizer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the holder has the ticket and the supply is fixed.
+
+    let account = context
+        .banks_client
+        .get_account(holder_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 1);
+
+    let account = context
+        .banks_client
+        .get_account(mint.pubkey())
+        .await
+        .unwrap();
+    let mint_account = spl_token::state::Mint::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(mint_account.supply, 1);
+    assert_eq!(mint_account.mint_authority, COption::None);
+
+    // And the ticket can be validated.
+
+    let validate_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(ticket, false),
+            AccountMeta::new_readonly(holder_account, false),
+        ],
+        data: vec![124, 1],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[validate_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+    let return_data = simulation
+        .simulation_details
+        .unwrap()
+        .return_data
+        .unwrap()
+        .data;
+
+    let (version, payload) = return_data.split_first().unwrap();
+
+    assert_eq!(*version, 1);
+    assert_eq!(&payload[..8], &7u64.to_le_bytes());
+    assert_eq!(&payload[8..12], &42u32.to_le_bytes());
+    assert_eq!(payload[12], 0);
+
+    // When the organizer punches the ticket, burning it.
+
+    let punch_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(ticket, false),
+            AccountMeta::new_readonly(organizer.pubkey(), true),
+            AccountMeta::new(holder_account, false),
+            AccountMeta::new(mint.pubkey(), false),
+            AccountMeta::new_readonly(holder.pubkey(), true),
+        ],
+        data: vec![124, 2, 1],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[punch_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &organizer, &holder],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the ticket token is burned.
+
+    let account = context
+        .banks_client
+        .get_account(holder_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 0);
+
+    // And the ticket cannot be punched again.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &organizer.pubkey(), 1),
+            punch_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &organizer, &holder],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(134) // TicketUsed
+        )
+    );
+}
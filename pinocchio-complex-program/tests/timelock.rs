@@ -0,0 +1,347 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{config, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    clock::Clock,
+    hash::hashv,
+    instruction::{AccountMeta, Instruction},
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::Signer,
+    system_program, sysvar,
+    transaction::Transaction,
+};
+use spl_token::instruction::AuthorityType;
+
+/// Returns the instructions queuing and executing an action of a timelock without
+/// delay, where `accounts` are the accounts of the invoked instruction.
+fn action_ixs(
+    context: &ProgramTestContext,
+    clock: &Clock,
+    token_program: &Pubkey,
+    timelock: &Pubkey,
+    data: &[u8],
+    accounts: &[AccountMeta],
+) -> [Instruction; 2] {
+    let admin = context.payer.pubkey();
+
+    let length = (data.len() as u16).to_le_bytes();
+    let count = [accounts.len() as u8];
+    let flags = accounts
+        .iter()
+        .map(|meta| [meta.is_signer as u8 | (meta.is_writable as u8) << 1])
+        .collect::<Vec<_>>();
+
+    let mut values: Vec<&[u8]> = vec![&length, data, &count];
+
+    for (meta, flags) in accounts.iter().zip(&flags) {
+        values.push(meta.pubkey.as_ref());
+        values.push(flags);
+    }
+
+    let hash = hashv(&values);
+
+    let (action, bump) = Pubkey::find_program_address(
+        &[b"timelock_action", timelock.as_ref(), hash.as_ref()],
+        token_program,
+    );
+
+    let mut queue_data = vec![103, 1, bump];
+    queue_data.extend_from_slice(hash.as_ref());
+    queue_data.extend_from_slice(&clock.unix_timestamp.to_le_bytes());
+
+    let queue_ix = Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(*timelock, false),
+            AccountMeta::new(action, false),
+            AccountMeta::new(admin, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: queue_data,
+    };
+
+    let mut execute_data = vec![103, 2];
+    execute_data.extend_from_slice(data);
+
+    // The timelock signs the invoked instruction, so it is not a signer of the
+    // transaction.
+    let mut execute_accounts = vec![
+        AccountMeta::new_readonly(*timelock, false),
+        AccountMeta::new(action, false),
+        AccountMeta::new(admin, true),
+    ];
+    execute_accounts.extend(accounts.iter().map(|meta| AccountMeta {
+        is_signer: false,
+        ..meta.clone()
+    }));
+
+    let execute_ix = Instruction {
+        program_id: *token_program,
+        accounts: execute_accounts,
+        data: execute_data,
+    };
+
+    [queue_ix, execute_ix]
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn execute_action(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a timelock without delay.
+
+    let admin = context.payer.pubkey();
+
+    let (timelock, bump) =
+        Pubkey::find_program_address(&[b"timelock", admin.as_ref()], &token_program);
+
+    let mut data = vec![103, 0, bump];
+    data.extend_from_slice(&0i64.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(timelock, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And a mint with the timelock as the mint authority.
+
+    let mint = mint::initialize(&mut context, timelock, None, &token_program)
+        .await
+        .unwrap();
+
+    // When a mint authority change is queued.
+
+    let new_authority = Pubkey::new_unique();
+
+    let set_authority_ix = spl_token::instruction::set_authority(
+        &spl_token::ID,
+        &mint,
+        Some(&new_authority),
+        AuthorityType::MintTokens,
+        &timelock,
+        &[],
+    )
+    .unwrap();
+
+    // The mint is writable and the timelock signs the instruction.
+    let hash = hashv(&[
+        &(set_authority_ix.data.len() as u16).to_le_bytes(),
+        &set_authority_ix.data,
+        &[2],
+        mint.as_ref(),
+        &[2],
+        timelock.as_ref(),
+        &[1],
+    ]);
+
+    let (action, bump) = Pubkey::find_program_address(
+        &[b"timelock_action", timelock.as_ref(), hash.as_ref()],
+        &token_program,
+    );
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    let mut data = vec![103, 1, bump];
+    data.extend_from_slice(hash.as_ref());
+    data.extend_from_slice(&clock.unix_timestamp.to_le_bytes());
+
+    let queue_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(timelock, false),
+            AccountMeta::new(action, false),
+            AccountMeta::new(admin, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    // And executed.
+
+    let mut data = vec![103, 2];
+    data.extend_from_slice(&set_authority_ix.data);
+
+    let execute_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(timelock, false),
+            AccountMeta::new(action, false),
+            AccountMeta::new(admin, true),
+            AccountMeta::new(mint, false),
+            AccountMeta::new_readonly(timelock, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[queue_ix, execute_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the mint authority was updated.
+
+    let account = context.banks_client.get_account(mint).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let mint = spl_token::state::Mint::unpack(&account.data).unwrap();
+
+    assert!(mint.mint_authority == COption::Some(new_authority));
+
+    // And the action account was closed.
+
+    let account = context.banks_client.get_account(action).await.unwrap();
+
+    assert!(account.is_none());
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn update_config(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a timelock without delay.
+
+    let admin = context.payer.pubkey();
+
+    let (timelock, bump) =
+        Pubkey::find_program_address(&[b"timelock", admin.as_ref()], &token_program);
+
+    let mut data = vec![103, 0, bump];
+    data.extend_from_slice(&0i64.to_le_bytes());
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(timelock, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And a config with the timelock as the proposed admin.
+
+    let config = config::initialize(&mut context, 100, &token_program).await;
+
+    let mut data = vec![104, 2];
+    data.extend_from_slice(timelock.as_ref());
+
+    let propose_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(admin, true),
+        ],
+        data,
+    };
+
+    // And the timelock accepts the admin role.
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    let accept_ixs = action_ixs(
+        &context,
+        &clock,
+        &token_program,
+        &timelock,
+        &[104, 3],
+        &[
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(timelock, true),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        ],
+    );
+
+    let mut instructions = vec![propose_ix];
+    instructions.extend(accept_ixs);
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the timelock executes an update of the fee parameters and features.
+
+    let fee_recipient = Pubkey::new_unique();
+
+    let mut data = vec![104, 1];
+    data.extend_from_slice(fee_recipient.as_ref());
+    data.extend_from_slice(&250u16.to_le_bytes());
+    data.extend_from_slice(&1u64.to_le_bytes());
+
+    let update_ixs = action_ixs(
+        &context,
+        &clock,
+        &token_program,
+        &timelock,
+        &data,
+        &[
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(timelock, true),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        ],
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &update_ixs,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the timelock is the config admin.
+
+    let account = context.banks_client.get_account(config).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+
+    assert_eq!(&account.data[3..35], timelock.as_ref());
+
+    // And the config stores the new values.
+
+    assert_eq!(&account.data[35..67], fee_recipient.as_ref());
+    assert_eq!(&account.data[67..69], &250u16.to_le_bytes());
+    assert_eq!(&account.data[69..77], &1u64.to_le_bytes());
+}
@@ -0,0 +1,148 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+
+async fn add_entry(
+    context: &mut ProgramTestContext,
+    gate: &Pubkey,
+    address: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    let (entry, _) = Pubkey::find_program_address(
+        &[b"gate_entry", gate.as_ref(), address.as_ref()],
+        token_program,
+    );
+
+    let mut data = vec![105, 1];
+    data.extend_from_slice(address.as_ref());
+
+    let add_entry_ix = Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(*gate, false),
+            AccountMeta::new(entry, false),
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_entry_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    entry
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn gated_transfer(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint with an allowlist transfer gate.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let (gate, bump) =
+        Pubkey::find_program_address(&[b"transfer_gate", mint.as_ref()], &token_program);
+
+    let initialize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(gate, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: vec![105, 0, bump, 0],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And listed sender and recipient.
+
+    let owner = Keypair::new();
+    let recipient = Pubkey::new_unique();
+
+    let owner_entry = add_entry(&mut context, &gate, &owner.pubkey(), &token_program).await;
+    let recipient_entry = add_entry(&mut context, &gate, &recipient, &token_program).await;
+
+    let source = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+    let destination = account::initialize(&mut context, &mint, &recipient, &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &source,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When the sender transfers tokens through the gate.
+
+    let mut data = vec![105, 3];
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.push(4);
+
+    let gated_transfer_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(gate, false),
+            AccountMeta::new_readonly(owner_entry, false),
+            AccountMeta::new_readonly(recipient_entry, false),
+            AccountMeta::new(source, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[gated_transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the recipient received the tokens.
+
+    let account = context.banks_client.get_account(destination).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 100);
+}
@@ -0,0 +1,328 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    clock::Clock,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+const PYTH_RECEIVER_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// Sets a fully verified Pyth `PriceUpdateV2` account.
+fn set_price_update(
+    context: &mut ProgramTestContext,
+    price_update: &Pubkey,
+    feed_id: &[u8; 32],
+    price: i64,
+    conf: u64,
+    exponent: i32,
+    publish_time: i64,
+) {
+    let mut data = vec![34, 241, 35, 99, 157, 126, 244, 205];
+    data.extend_from_slice(Pubkey::new_unique().as_ref());
+    data.push(1);
+    data.extend_from_slice(feed_id);
+    data.extend_from_slice(&price.to_le_bytes());
+    data.extend_from_slice(&conf.to_le_bytes());
+    data.extend_from_slice(&exponent.to_le_bytes());
+    data.extend_from_slice(&publish_time.to_le_bytes());
+    data.extend_from_slice(&publish_time.to_le_bytes());
+    data.extend_from_slice(&price.to_le_bytes());
+    data.extend_from_slice(&conf.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(0);
+
+    context.set_account(
+        price_update,
+        &Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: PYTH_RECEIVER_ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn transfer_if_price(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+    let source = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &source,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // And a price of 150.00 published now.
+
+    let feed_id = [7u8; 32];
+    let price_update = Pubkey::new_unique();
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    set_price_update(
+        &mut context,
+        &price_update,
+        &feed_id,
+        15_000,
+        10,
+        -2,
+        clock.unix_timestamp,
+    );
+
+    let transfer_ix = |condition: u8, threshold: i64| {
+        let mut data = vec![147];
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.extend_from_slice(&feed_id);
+        data.extend_from_slice(&60u64.to_le_bytes());
+        data.extend_from_slice(&100u16.to_le_bytes());
+        data.push(condition);
+        data.extend_from_slice(&threshold.to_le_bytes());
+        data.extend_from_slice(&0i32.to_le_bytes());
+
+        Instruction {
+            program_id: token_program,
+            accounts: vec![
+                AccountMeta::new_readonly(price_update, false),
+                AccountMeta::new(source, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(owner.pubkey(), true),
+            ],
+            data,
+        }
+    };
+
+    // When the transfer requires a price of at least 200.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix(0, 200)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the transfer fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(152) // PriceConditionNotMet
+        )
+    );
+
+    // When the transfer requires a price of at least 100.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix(0, 100)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the destination receives the tokens.
+
+    let account = context.banks_client.get_account(destination).await.unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 100);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn fail_transfer_if_price_with_stale_price(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+    let source = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // And a price published 2 minutes ago.
+
+    let feed_id = [7u8; 32];
+    let price_update = Pubkey::new_unique();
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    set_price_update(
+        &mut context,
+        &price_update,
+        &feed_id,
+        15_000,
+        10,
+        -2,
+        clock.unix_timestamp - 120,
+    );
+
+    // When the transfer accepts prices of at most 1 minute.
+
+    let mut data = vec![147];
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.extend_from_slice(&feed_id);
+    data.extend_from_slice(&60u64.to_le_bytes());
+    data.extend_from_slice(&100u16.to_le_bytes());
+    data.push(0);
+    data.extend_from_slice(&100i64.to_le_bytes());
+    data.extend_from_slice(&0i32.to_le_bytes());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: token_program,
+            accounts: vec![
+                AccountMeta::new_readonly(price_update, false),
+                AccountMeta::new(source, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(owner.pubkey(), true),
+            ],
+            data,
+        }],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the transfer fails with a stale price.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(150) // StalePrice
+        )
+    );
+}
+
+#[cfg(feature = "switchboard")]
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn transfer_if_switchboard_price(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+    let source = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &source,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // And a Switchboard feed with a price of 150 updated now.
+
+    let feed = Pubkey::new_unique();
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+
+    let mut data = vec![0u8; 3208];
+    data[..8].copy_from_slice(&[196, 27, 108, 196, 10, 215, 219, 40]);
+    data[2216..2224].copy_from_slice(&clock.unix_timestamp.to_le_bytes());
+    data[2264..2280].copy_from_slice(&(150 * 10i128.pow(18)).to_le_bytes());
+    data[2280..2296].copy_from_slice(&10i128.pow(15).to_le_bytes());
+
+    context.set_account(
+        &feed,
+        &Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv"),
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    // When the transfer requires a price of at least 100.
+
+    let mut data = vec![147];
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(feed.as_ref());
+    data.extend_from_slice(&60u64.to_le_bytes());
+    data.extend_from_slice(&100u16.to_le_bytes());
+    data.push(0);
+    data.extend_from_slice(&100i64.to_le_bytes());
+    data.extend_from_slice(&0i32.to_le_bytes());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: token_program,
+            accounts: vec![
+                AccountMeta::new_readonly(feed, false),
+                AccountMeta::new(source, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(owner.pubkey(), true),
+            ],
+            data,
+        }],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the destination receives the tokens.
+
+    let account = context.banks_client.get_account(destination).await.unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 100);
+}
@@ -0,0 +1,207 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+async fn token_amount(context: &mut ProgramTestContext, account: Pubkey) -> u64 {
+    let account = context.banks_client.get_account(account).await.unwrap();
+    spl_token::state::Account::unpack(&account.unwrap().data)
+        .unwrap()
+        .amount
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn claim_fees(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a config with a 1% protocol fee and the payer as fee recipient.
+
+    let fee_authority = context.payer.pubkey();
+    let (config, config_bump) = Pubkey::find_program_address(&[b"config"], &token_program);
+
+    let mut data = vec![104, 0, config_bump];
+    data.extend_from_slice(fee_authority.as_ref());
+    data.extend_from_slice(&100u16.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+
+    let initialize_config_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(fee_authority, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    // And a treasury.
+
+    let (treasury, treasury_bump) = Pubkey::find_program_address(&[b"treasury"], &token_program);
+
+    let initialize_treasury_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: vec![120, 0, treasury_bump],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_config_ix, initialize_treasury_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And a splitter with a single recipient and 1000 tokens in its token vault.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let treasury_vault = account::initialize(&mut context, &mint, &treasury, &token_program).await;
+    let recipient =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    let token_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let create_key = Pubkey::new_unique();
+    let (splitter, bump) =
+        Pubkey::find_program_address(&[b"splitter", create_key.as_ref()], &token_program);
+
+    let mut data = vec![112, 0, bump];
+    data.extend_from_slice(&1u16.to_le_bytes());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(splitter, false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(recipient, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &token_vault,
+        &mint_authority,
+        1_000,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When the balance is distributed without the treasury accounts.
+
+    let mut distribute_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(splitter, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new(recipient, false),
+            AccountMeta::new_readonly(config, false),
+        ],
+        data: vec![112, 2],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[distribute_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the distribution fails, since the protocol fee cannot be skipped.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+
+    // And the distribution succeeds with the treasury accounts.
+
+    distribute_ix.accounts.extend([
+        AccountMeta::new_readonly(treasury, false),
+        AccountMeta::new(treasury_vault, false),
+    ]);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[distribute_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And the treasury vault accumulated the protocol fee.
+
+    assert_eq!(token_amount(&mut context, treasury_vault).await, 10);
+    assert_eq!(token_amount(&mut context, recipient).await, 990);
+
+    // And the fee recipient can claim the fees.
+
+    let destination =
+        account::initialize(&mut context, &mint, &fee_authority, &token_program).await;
+
+    let mut data = vec![120, 1];
+    data.extend_from_slice(&10u64.to_le_bytes());
+
+    let claim_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new_readonly(treasury, false),
+            AccountMeta::new(treasury_vault, false),
+            AccountMeta::new(destination, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(fee_authority, true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[claim_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_eq!(token_amount(&mut context, treasury_vault).await, 0);
+    assert_eq!(token_amount(&mut context, destination).await, 10);
+}
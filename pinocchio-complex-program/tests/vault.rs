@@ -0,0 +1,462 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::Transaction,
+};
+
+struct VaultAccounts {
+    vault: Pubkey,
+    token_vault: Pubkey,
+    mint: Pubkey,
+    mint_authority: Keypair,
+}
+
+async fn initialize_vault(
+    context: &mut ProgramTestContext,
+    token_program: &Pubkey,
+) -> VaultAccounts {
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(context, mint_authority.pubkey(), None, token_program)
+        .await
+        .unwrap();
+
+    let token_vault = account::create(context, spl_token::state::Account::LEN, token_program).await;
+
+    let create_key = Pubkey::new_unique();
+    let (vault, bump) =
+        Pubkey::find_program_address(&[b"vault", create_key.as_ref()], token_program);
+
+    let initialize_ix = Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new_readonly(mint, false),
+        ],
+        data: vec![106, 0, bump],
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[initialize_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    VaultAccounts {
+        vault,
+        token_vault,
+        mint,
+        mint_authority,
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn deposit_and_withdraw(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a vault with a token vault.
+
+    let VaultAccounts {
+        vault,
+        token_vault,
+        mint,
+        mint_authority,
+    } = initialize_vault(&mut context, &token_program).await;
+
+    // And a depositor with SOL and 1_000 tokens.
+
+    let owner = Keypair::new();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(
+            &context.payer.pubkey(),
+            &owner.pubkey(),
+            2_000_000_000,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let owner_account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &owner_account,
+        &mint_authority,
+        1_000,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When SOL and tokens are deposited.
+
+    let (deposit, bump) = Pubkey::find_program_address(
+        &[b"vault_deposit", vault.as_ref(), owner.pubkey().as_ref()],
+        &token_program,
+    );
+
+    let mut data = vec![106, 1];
+    data.extend_from_slice(&1_000_000_000u64.to_le_bytes());
+    data.push(bump);
+
+    let deposit_sol_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(vault, false),
+            AccountMeta::new(deposit, false),
+            AccountMeta::new(owner.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let mut data = vec![106, 3];
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.push(bump);
+
+    let deposit_token_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(vault, false),
+            AccountMeta::new(deposit, false),
+            AccountMeta::new(owner_account, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new(owner.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    // And the shares are redeemed.
+
+    let mut data = vec![106, 2];
+    data.extend_from_slice(&400_000_000u64.to_le_bytes());
+
+    let withdraw_sol_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(vault, false),
+            AccountMeta::new(deposit, false),
+            AccountMeta::new(owner.pubkey(), true),
+        ],
+        data,
+    };
+
+    let mut data = vec![106, 4];
+    data.extend_from_slice(&400u64.to_le_bytes());
+
+    let withdraw_token_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(vault, false),
+            AccountMeta::new(deposit, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new(owner_account, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            deposit_sol_ix,
+            deposit_token_ix,
+            withdraw_sol_ix,
+            withdraw_token_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the vault keeps the remaining SOL.
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let account = context.banks_client.get_account(vault).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+
+    assert_eq!(
+        account.lamports,
+        rent.minimum_balance(account.data.len()) + 600_000_000
+    );
+
+    // And the remaining tokens.
+
+    let account = context.banks_client.get_account(token_vault).await.unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 600);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn first_depositor_does_not_capture_donations(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a vault without shares holding 1_000 donated tokens.
+
+    let vault = initialize_vault(&mut context, &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &vault.mint,
+        &vault.token_vault,
+        &vault.mint_authority,
+        1_000,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a depositor with 1_000 tokens.
+
+    let owner = Keypair::new();
+
+    let owner_account =
+        account::initialize(&mut context, &vault.mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &vault.mint,
+        &owner_account,
+        &vault.mint_authority,
+        1_000,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When the depositor deposits the tokens and redeems all the shares.
+
+    let (deposit, bump) = Pubkey::find_program_address(
+        &[
+            b"vault_deposit",
+            vault.vault.as_ref(),
+            owner.pubkey().as_ref(),
+        ],
+        &token_program,
+    );
+
+    let mut data = vec![106, 3];
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.push(bump);
+
+    let deposit_token_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(vault.vault, false),
+            AccountMeta::new(deposit, false),
+            AccountMeta::new(owner_account, false),
+            AccountMeta::new(vault.token_vault, false),
+            AccountMeta::new(owner.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    // 1_000 * (0 + 1_000) / (1_000 + 1_000) = 500 shares
+    let mut data = vec![106, 4];
+    data.extend_from_slice(&500u64.to_le_bytes());
+
+    let withdraw_token_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(vault.vault, false),
+            AccountMeta::new(deposit, false),
+            AccountMeta::new(vault.token_vault, false),
+            AccountMeta::new(owner_account, false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &owner.pubkey(), 1_000_000_000),
+            deposit_token_ix,
+            withdraw_token_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the depositor gets back the deposit, without the donation.
+
+    let account = context
+        .banks_client
+        .get_account(owner_account)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 1_000);
+
+    let account = context
+        .banks_client
+        .get_account(vault.token_vault)
+        .await
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 1_000);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn deposit_and_withdraw_sol_move_exact_lamports(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a vault.
+
+    let VaultAccounts { vault, .. } = initialize_vault(&mut context, &token_program).await;
+
+    // And a depositor with SOL.
+
+    let owner = Keypair::new();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(
+            &context.payer.pubkey(),
+            &owner.pubkey(),
+            2_000_000_000,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let vault_starting_lamports = context
+        .banks_client
+        .get_account(vault)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // When SOL is deposited.
+
+    let (deposit, bump) = Pubkey::find_program_address(
+        &[b"vault_deposit", vault.as_ref(), owner.pubkey().as_ref()],
+        &token_program,
+    );
+
+    let mut data = vec![106, 1];
+    data.extend_from_slice(&1_000_000_000u64.to_le_bytes());
+    data.push(bump);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: token_program,
+            accounts: vec![
+                AccountMeta::new(vault, false),
+                AccountMeta::new(deposit, false),
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+            data,
+        }],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the vault receives exactly the deposited lamports.
+
+    let vault_lamports = context
+        .banks_client
+        .get_account(vault)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    assert_eq!(vault_lamports, vault_starting_lamports + 1_000_000_000);
+
+    // When the shares are redeemed.
+
+    let owner_starting_lamports = context
+        .banks_client
+        .get_account(owner.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let mut data = vec![106, 2];
+    data.extend_from_slice(&1_000_000_000u64.to_le_bytes());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: token_program,
+            accounts: vec![
+                AccountMeta::new(vault, false),
+                AccountMeta::new(deposit, false),
+                AccountMeta::new(owner.pubkey(), true),
+            ],
+            data,
+        }],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the owner receives exactly the withdrawn lamports.
+
+    let owner_lamports = context
+        .banks_client
+        .get_account(owner.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    assert_eq!(owner_lamports, owner_starting_lamports + 1_000_000_000);
+
+    // And the vault is back to its starting lamports.
+
+    let vault_lamports = context
+        .banks_client
+        .get_account(vault)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    assert_eq!(vault_lamports, vault_starting_lamports);
+}
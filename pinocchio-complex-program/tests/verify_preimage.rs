@@ -0,0 +1,116 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    blake3, hash,
+    instruction::{Instruction, InstructionError},
+    keccak,
+    pubkey::Pubkey,
+    signature::Signer,
+    transaction::{Transaction, TransactionError},
+};
+
+fn verify_preimage_ix(
+    token_program: &Pubkey,
+    algorithm: u8,
+    hash: &[u8],
+    preimage: &[u8],
+) -> Instruction {
+    let mut data = vec![143, algorithm];
+    data.extend_from_slice(hash);
+    data.extend_from_slice(preimage);
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![],
+        data,
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn verify_preimage(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a preimage and its hashes.
+
+    let preimage = b"hash-lock-secret";
+
+    let hashes = [
+        hash::hash(preimage).to_bytes(),
+        keccak::hash(preimage).to_bytes(),
+        blake3::hash(preimage).to_bytes(),
+    ];
+
+    // When the preimage is verified against each hash.
+
+    let instructions = hashes
+        .iter()
+        .enumerate()
+        .map(|(algorithm, hash)| {
+            verify_preimage_ix(&token_program, algorithm as u8, hash, preimage)
+        })
+        .collect::<Vec<_>>();
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    // Then the verification succeeds.
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When a wrong preimage is verified.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[verify_preimage_ix(
+            &token_program,
+            0,
+            &hashes[0],
+            b"wrong-secret",
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the verification fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(147) // PreimageMismatch
+        )
+    );
+
+    // When the hash of another algorithm is verified.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[verify_preimage_ix(&token_program, 0, &hashes[1], preimage)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the verification fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(147) // PreimageMismatch
+        )
+    );
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program, sysvar,
+    transaction::Transaction,
+    vote::{self, state::VoteStateVersions},
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn create_authorize_and_withdraw(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a validator identity and a withdraw authority.
+
+    let vote_account = Keypair::new();
+    let node = Keypair::new();
+    let withdrawer = Keypair::new();
+
+    // When a vote account is created with a 10% commission.
+
+    let mut data = vec![138, 0];
+    data.extend_from_slice(node.pubkey().as_ref());
+    data.extend_from_slice(withdrawer.pubkey().as_ref());
+    data.push(10);
+
+    let create_vote_account_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new(vote_account.pubkey(), true),
+            AccountMeta::new_readonly(node.pubkey(), true),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(vote::program::ID, false),
+        ],
+        data,
+    };
+
+    // And funded with 1 SOL above rent.
+
+    let fund_vote_account_ix = system_instruction::transfer(
+        &context.payer.pubkey(),
+        &vote_account.pubkey(),
+        1_000_000_000,
+    );
+
+    // And the voter authority is changed.
+
+    let new_voter = Pubkey::new_unique();
+
+    let mut data = vec![138, 1];
+    data.extend_from_slice(new_voter.as_ref());
+    data.push(0);
+
+    let authorize_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(vote_account.pubkey(), false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(node.pubkey(), true),
+            AccountMeta::new_readonly(vote::program::ID, false),
+        ],
+        data,
+    };
+
+    // And lamports are withdrawn by the withdraw authority.
+
+    let recipient = Pubkey::new_unique();
+
+    let mut data = vec![138, 3];
+    data.extend_from_slice(&500_000_000u64.to_le_bytes());
+
+    let withdraw_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(vote_account.pubkey(), false),
+            AccountMeta::new(recipient, false),
+            AccountMeta::new_readonly(withdrawer.pubkey(), true),
+            AccountMeta::new_readonly(vote::program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_vote_account_ix,
+            fund_vote_account_ix,
+            authorize_ix,
+            withdraw_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &vote_account, &node, &withdrawer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the vote account has the commission and the withdraw authority.
+
+    let account = context
+        .banks_client
+        .get_account(vote_account.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let vote_state = account
+        .deserialize_data::<VoteStateVersions>()
+        .unwrap()
+        .convert_to_current();
+
+    assert_eq!(vote_state.node_pubkey, node.pubkey());
+    assert_eq!(vote_state.authorized_withdrawer, withdrawer.pubkey());
+    assert_eq!(vote_state.commission, 10);
+
+    // And the recipient received the lamports.
+
+    let account = context.banks_client.get_account(recipient).await.unwrap();
+
+    assert_eq!(account.unwrap().lamports, 500_000_000);
+}
@@ -0,0 +1,150 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use assert_matches::assert_matches;
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn redeem(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an issuer with 100 tokens.
+
+    let mint_authority = Keypair::new();
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let issuer = Keypair::new();
+    let issuer_account =
+        account::initialize(&mut context, &mint, &issuer.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &issuer_account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a voucher of 100 tokens redeemable with a code.
+
+    let code = b"gift-card-code";
+
+    let create_key = Pubkey::new_unique();
+    let (voucher, bump) =
+        Pubkey::find_program_address(&[b"voucher", create_key.as_ref()], &token_program);
+
+    let token_vault =
+        account::create(&mut context, spl_token::state::Account::LEN, &token_program).await;
+
+    let mut data = vec![125, 0, bump];
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(hash(code).as_ref());
+
+    let create_ix = Instruction {
+        program_id: token_program,
+        accounts: vec![
+            AccountMeta::new(issuer.pubkey(), true),
+            AccountMeta::new(voucher, false),
+            AccountMeta::new_readonly(create_key, false),
+            AccountMeta::new(token_vault, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(issuer_account, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(&context.payer.pubkey(), &issuer.pubkey(), 1_000_000_000),
+            create_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &issuer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When the voucher is redeemed with a wrong code.
+
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    let redeem_ix = |code: &[u8]| {
+        let mut data = vec![125, 1];
+        data.extend_from_slice(code);
+
+        Instruction {
+            program_id: token_program,
+            accounts: vec![
+                AccountMeta::new(voucher, false),
+                AccountMeta::new(token_vault, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new(issuer.pubkey(), false),
+            ],
+            data,
+        }
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[redeem_ix(b"wrong-code")],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context.banks_client.process_transaction(tx).await.err();
+
+    // Then the redemption fails.
+
+    assert_matches!(
+        error.unwrap().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(135) // InvalidRedemptionCode
+        )
+    );
+
+    // When the voucher is redeemed with the code.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[redeem_ix(code)],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the destination receives the voucher funds.
+
+    let account = context.banks_client.get_account(destination).await.unwrap();
+    let account = spl_token::state::Account::unpack(&account.unwrap().data).unwrap();
+
+    assert_eq!(account.amount, 100);
+
+    // And the voucher accounts are closed.
+
+    let account = context.banks_client.get_account(voucher).await.unwrap();
+    assert!(account.is_none());
+
+    let account = context.banks_client.get_account(token_vault).await.unwrap();
+    assert!(account.is_none());
+}